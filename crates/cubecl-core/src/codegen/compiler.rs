@@ -19,4 +19,6 @@ pub trait Compiler: Sync + Send + 'static + Clone + Default + core::fmt::Debug {
     fn elem_size(elem: Elem) -> usize;
     /// The maximal size of a shared memory, in bytes
     fn max_shared_memory_size() -> usize;
+    /// The file extension of the compiled source.
+    fn extension() -> &'static str;
 }
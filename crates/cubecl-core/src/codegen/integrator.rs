@@ -3,8 +3,8 @@ use std::num::NonZero;
 use super::Compiler;
 use crate::{
     ir::{
-        Binding, CubeDim, Elem, Item, KernelDefinition, Location, ReadingStrategy, Scope, Variable,
-        Vectorization, Visibility,
+        Binding, CubeDim, DebugFormat, Elem, FunctionDefinition, Item, KernelDefinition, Location,
+        ReadingStrategy, Scope, UIntKind, Variable, Vectorization, Visibility,
     },
     Runtime,
 };
@@ -25,6 +25,8 @@ pub struct KernelExpansion {
     pub inputs: Vec<InputInfo>,
     pub outputs: Vec<OutputInfo>,
     pub scope: Scope,
+    pub functions: Vec<FunctionDefinition>,
+    pub debug: Vec<DebugFormat>,
 }
 
 /// Simply indicate the output that can be replaced by the input.
@@ -46,6 +48,14 @@ enum VectorizationPartial {
         pos: usize,
         vectorization: Vectorization,
     },
+    InputElements {
+        pos: usize,
+        vectorizations: Vec<Vectorization>,
+    },
+    OutputElements {
+        pos: usize,
+        vectorizations: Vec<Vectorization>,
+    },
 }
 
 #[derive(Default, Clone, Debug, Hash, PartialEq, Eq)]
@@ -76,6 +86,8 @@ impl core::fmt::Display for KernelSettings {
         // * Vectorization Global:    vg{factor}
         // * Vectorization Partial Input:    v{factor}i{pos}
         // * Vectorization Partial Output:    vo
+        // * Vectorization Elements Input:    e{factor}_{factor}i{pos}
+        // * Vectorization Elements Output:    e{factor}_{factor}o{pos}
         // * Cube Dim X: x
         // * Cube Dim Y: y
         // * Cube Dim Z: z
@@ -113,6 +125,14 @@ impl core::fmt::Display for KernelSettings {
                 VectorizationPartial::Output { pos, vectorization } => f.write_fmt(
                     format_args!("v{}o{pos}", vectorization.map(NonZero::get).unwrap_or(1)),
                 )?,
+                VectorizationPartial::InputElements {
+                    pos,
+                    vectorizations,
+                } => f.write_fmt(format_args!("e{}i{pos}", format_elements(vectorizations)))?,
+                VectorizationPartial::OutputElements {
+                    pos,
+                    vectorizations,
+                } => f.write_fmt(format_args!("e{}o{pos}", format_elements(vectorizations)))?,
             };
         }
 
@@ -123,6 +143,14 @@ impl core::fmt::Display for KernelSettings {
     }
 }
 
+fn format_elements(vectorizations: &[Vectorization]) -> String {
+    vectorizations
+        .iter()
+        .map(|vectorization| vectorization.map(NonZero::get).unwrap_or(1).to_string())
+        .collect::<Vec<_>>()
+        .join("_")
+}
+
 impl KernelSettings {
     /// Compile the shader with vectorization enabled for all inputs and outputs.
     #[allow(dead_code)]
@@ -165,6 +193,75 @@ impl KernelSettings {
         self
     }
 
+    /// Compile the shader with the given vectorization for each element of an input made of
+    /// multiple arrays, such as a sequence of tensors.
+    ///
+    /// The number of elements is always registered, since it changes the generated code.
+    pub fn vectorize_input_elements(
+        mut self,
+        position: usize,
+        vectorizations: Vec<Vectorization>,
+    ) -> Self {
+        self.vectorization_partial
+            .push(VectorizationPartial::InputElements {
+                pos: position,
+                vectorizations,
+            });
+        self
+    }
+
+    /// Compile the shader with the given vectorization for each element of an output made of
+    /// multiple arrays, such as a sequence of tensors.
+    ///
+    /// The number of elements is always registered, since it changes the generated code.
+    pub fn vectorize_output_elements(
+        mut self,
+        position: usize,
+        vectorizations: Vec<Vectorization>,
+    ) -> Self {
+        self.vectorization_partial
+            .push(VectorizationPartial::OutputElements {
+                pos: position,
+                vectorizations,
+            });
+        self
+    }
+
+    /// Fetch the vectorization of each element for the provided input position, if the input is
+    /// made of multiple elements.
+    pub fn vectorization_input_elements(&self, position: usize) -> Option<Vec<Vectorization>> {
+        self.vectorization_partial
+            .iter()
+            .find_map(|partial| match partial {
+                VectorizationPartial::InputElements {
+                    pos,
+                    vectorizations,
+                } if *pos == position => Some(self.elements_or_global(vectorizations)),
+                _ => None,
+            })
+    }
+
+    /// Fetch the vectorization of each element for the provided output position, if the output
+    /// is made of multiple elements.
+    pub fn vectorization_output_elements(&self, position: usize) -> Option<Vec<Vectorization>> {
+        self.vectorization_partial
+            .iter()
+            .find_map(|partial| match partial {
+                VectorizationPartial::OutputElements {
+                    pos,
+                    vectorizations,
+                } if *pos == position => Some(self.elements_or_global(vectorizations)),
+                _ => None,
+            })
+    }
+
+    fn elements_or_global(&self, vectorizations: &[Vectorization]) -> Vec<Vectorization> {
+        match self.vectorization_global {
+            Some(vec) => vec![vec; vectorizations.len()],
+            None => vectorizations.to_vec(),
+        }
+    }
+
     /// Fetch the vectorization for the provided input position.
     pub fn vectorization_input(&self, position: usize) -> Vectorization {
         if let Some(vec) = self.vectorization_global {
@@ -334,6 +431,10 @@ impl KernelIntegrator {
     pub fn integrate(mut self, mut settings: KernelSettings) -> KernelDefinition {
         if let Some(vectorization) = settings.vectorization_global {
             self.expansion.scope.vectorize(vectorization);
+            self.expansion
+                .functions
+                .iter_mut()
+                .for_each(|function| function.vectorize(vectorization));
         }
 
         self.register_inputs(&settings);
@@ -346,7 +447,7 @@ impl KernelIntegrator {
         named.push((
             "info".to_string(),
             Binding {
-                item: Item::new(Elem::UInt),
+                item: Item::new(Elem::UInt(UIntKind::U32)),
                 visibility: Visibility::Read,
                 location: Location::Storage,
                 size: None, // We avoid putting the length here since it will force a new kernel
@@ -358,12 +459,27 @@ impl KernelIntegrator {
             named.push((name, binding));
         }
 
+        // The debug buffer is bound last, only when the kernel writes debug output.
+        if !self.expansion.debug.is_empty() {
+            named.push((
+                "debug".to_string(),
+                Binding {
+                    item: Item::new(Elem::AtomicUInt),
+                    visibility: Visibility::ReadWrite,
+                    location: Location::Storage,
+                    size: None,
+                },
+            ));
+        }
+
         KernelDefinition {
             inputs,
             outputs,
             named,
             cube_dim: settings.cube_dim,
             body: self.expansion.scope,
+            functions: self.expansion.functions,
+            debug: self.expansion.debug,
         }
     }
 
@@ -565,7 +681,7 @@ fn bool_item(ty: Item) -> Item {
 pub fn bool_elem(elem: Elem) -> Elem {
     match elem {
         // U32 are used for bool tensors
-        Elem::Bool => Elem::UInt,
+        Elem::Bool => Elem::UInt(UIntKind::U32),
         _ => elem,
     }
 }
@@ -80,9 +80,14 @@ impl KernelBuilder {
     }
 
     /// Build the [kernel definition](KernelDefinition).
-    pub fn build(self, settings: KernelSettings) -> KernelDefinition {
+    pub fn build(mut self, settings: KernelSettings) -> KernelDefinition {
+        let functions = self.context.take_functions();
+        let debug = self.context.take_debug_formats();
+
         KernelIntegrator::new(KernelExpansion {
             scope: self.context.into_scope(),
+            functions,
+            debug,
             inputs: self.inputs,
             outputs: self.outputs,
         })
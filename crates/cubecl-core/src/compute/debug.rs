@@ -0,0 +1,256 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+use cubecl_runtime::{
+    channel::ComputeChannel,
+    client::{ComputeClient, SyncType},
+    server::{Binding, ComputeServer, Handle},
+};
+
+use crate::{
+    compute::{CubeTask, KernelTask},
+    ir::{DebugFormat, DebugKind, Elem},
+    Kernel, KernelId, Runtime,
+};
+
+/// The number of 32 bits words available for the records of a debug buffer.
+const DEBUG_BUFFER_CAPACITY: usize = 1 << 16;
+
+/// The debug formats of a kernel, or `None` when it doesn't write debug output.
+type KernelDebugFormats = Option<Arc<Vec<DebugFormat>>>;
+
+/// A record written by [cube_assert!](crate::cube_assert) or
+/// [cube_debug_print!](crate::cube_debug_print), decoded after synchronization.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DebugRecord {
+    /// The kind of debug operation that wrote the record.
+    pub kind: DebugKind,
+    /// The location of the debug operation in the kernel source.
+    pub location: String,
+    /// The message of the failed assertion, or the formatted print.
+    pub message: String,
+}
+
+impl core::fmt::Display for DebugRecord {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.kind {
+            DebugKind::Assert => {
+                write!(f, "Assertion failed at {}: {}", self.location, self.message)
+            }
+            DebugKind::Print => f.write_str(&self.message),
+        }
+    }
+}
+
+/// Synchronize the client and return the debug records written since the last synchronization,
+/// instead of printing them.
+pub fn read_debug_output<R: Runtime>(
+    client: &ComputeClient<R::Server, R::Channel>,
+) -> Vec<DebugRecord> {
+    let state = debug_state::<R>(client);
+    state.lock().unwrap().captured = Some(Vec::new());
+
+    client.sync(SyncType::Wait);
+
+    let records = state.lock().unwrap().captured.take();
+    records.unwrap_or_default()
+}
+
+/// The debug buffers of a client, created the first time a kernel writing debug output is
+/// launched and reused by its following launches.
+struct DebugState<S: ComputeServer> {
+    buffers: HashMap<KernelId, DebugBuffer<S>>,
+    /// The debug formats of every kernel launched on the client.
+    formats: HashMap<KernelId, KernelDebugFormats>,
+    /// The decoded records, when they are read instead of printed.
+    captured: Option<Vec<DebugRecord>>,
+}
+
+/// The buffer starts with the number of words reserved by the kernel, followed by the capacity
+/// available for the records. Since the client can't write to it, the reserved words keep
+/// growing across launches and a new buffer is created once the capacity is exhausted.
+struct DebugBuffer<S: ComputeServer> {
+    handle: Handle<S>,
+    formats: Arc<Vec<DebugFormat>>,
+    /// The number of words reserved at the last decoding.
+    reserved: usize,
+    /// Whether the buffer is decoded after the next synchronization.
+    read_pending: bool,
+}
+
+/// Get the debug buffer of the kernel if it writes debug output, registering its decoding after
+/// the next synchronization of the client.
+///
+/// The first launch of a kernel expands it to find its debug formats, and the definition is kept
+/// by the task for its compilation.
+pub(crate) fn debug_binding<R: Runtime, K: Kernel>(
+    kernel: &mut KernelTask<R::Compiler, K>,
+    client: &ComputeClient<R::Server, R::Channel>,
+) -> Option<Binding<R::Server>> {
+    // Debug operations are never registered without debug assertions.
+    if !cfg!(debug_assertions) {
+        return None;
+    }
+
+    let id = kernel.id();
+    let state = debug_state::<R>(client);
+    let mut state_ref = state.lock().unwrap();
+    let formats = state_ref
+        .formats
+        .entry(id.clone())
+        .or_insert_with(|| {
+            let formats = kernel.define().debug.clone();
+            (!formats.is_empty()).then(|| Arc::new(formats))
+        })
+        .clone()?;
+
+    let buffer = state_ref
+        .buffers
+        .entry(id.clone())
+        .or_insert_with(|| DebugBuffer::new(client, formats.clone()));
+
+    if buffer.reserved >= DEBUG_BUFFER_CAPACITY {
+        *buffer = DebugBuffer::new(client, formats);
+    }
+
+    if !buffer.read_pending {
+        buffer.read_pending = true;
+
+        let state = state.clone();
+        client.read_on_sync(buffer.handle.clone().binding(), move |data| {
+            state
+                .lock()
+                .unwrap()
+                .decode(&id, bytemuck::cast_slice(&data))
+        });
+    }
+
+    Some(buffer.handle.clone().binding())
+}
+
+fn debug_state<R: Runtime>(
+    client: &ComputeClient<R::Server, R::Channel>,
+) -> Arc<Mutex<DebugState<R::Server>>> {
+    client.extension(|| {
+        Mutex::new(DebugState {
+            buffers: HashMap::new(),
+            formats: HashMap::new(),
+            captured: None,
+        })
+    })
+}
+
+impl<S: ComputeServer> DebugBuffer<S> {
+    fn new<C: ComputeChannel<S>>(
+        client: &ComputeClient<S, C>,
+        formats: Arc<Vec<DebugFormat>>,
+    ) -> Self {
+        let mut header = vec![0u32; DEBUG_BUFFER_CAPACITY + 2];
+        header[1] = DEBUG_BUFFER_CAPACITY as u32;
+
+        Self {
+            handle: client.create(bytemuck::cast_slice(&header)),
+            formats,
+            reserved: 0,
+            read_pending: false,
+        }
+    }
+}
+
+impl<S: ComputeServer> DebugState<S> {
+    fn decode(&mut self, id: &KernelId, words: &[u32]) {
+        let buffer = self
+            .buffers
+            .get_mut(id)
+            .expect("Debug buffers are only decoded once registered");
+        buffer.read_pending = false;
+
+        let reserved = words[0] as usize;
+        let capacity = words[1] as usize;
+        let start = usize::min(buffer.reserved, capacity);
+        let end = usize::min(reserved, capacity);
+        let records = decode_records(&buffer.formats, &words[2 + start..2 + end]);
+
+        if reserved > capacity {
+            eprintln!(
+                "{} words of debug output were dropped, the debug buffer holds {capacity} words",
+                reserved - usize::max(buffer.reserved, capacity)
+            );
+        }
+        buffer.reserved = reserved;
+
+        match &mut self.captured {
+            Some(captured) => captured.extend(records),
+            None => {
+                for record in records {
+                    match record.kind {
+                        DebugKind::Assert => eprintln!("{record}"),
+                        DebugKind::Print => println!("{record}"),
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn decode_records(formats: &[DebugFormat], words: &[u32]) -> Vec<DebugRecord> {
+    let mut words = words.iter().copied();
+    let mut records = Vec::new();
+
+    // A record starts with the index of its format plus one, so reserved words that were never
+    // written end the output.
+    while let Some(format) = words
+        .next()
+        .and_then(|index| index.checked_sub(1))
+        .and_then(|index| formats.get(index as usize))
+    {
+        let args = format
+            .args
+            .iter()
+            .map(|elem| {
+                let lanes = words.next().unwrap_or(0) as usize;
+                let values: Vec<_> = words
+                    .by_ref()
+                    .take(lanes)
+                    .map(|word| format_word(*elem, word))
+                    .collect();
+                match values.len() {
+                    1 => values[0].clone(),
+                    _ => format!("[{}]", values.join(", ")),
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let message = match format.kind {
+            DebugKind::Assert => format.message.clone(),
+            DebugKind::Print => {
+                let mut parts = format.message.split("{}");
+                let mut message = parts.next().unwrap_or_default().to_string();
+                for (part, arg) in parts.zip(args) {
+                    message.push_str(&arg);
+                    message.push_str(part);
+                }
+                message
+            }
+        };
+
+        records.push(DebugRecord {
+            kind: format.kind,
+            location: format.location.clone(),
+            message,
+        });
+    }
+
+    records
+}
+
+fn format_word(elem: Elem, word: u32) -> String {
+    match elem {
+        Elem::Float(_) | Elem::AtomicFloat(_) => f32::from_bits(word).to_string(),
+        Elem::Int(_) | Elem::AtomicInt(_) => (word as i32).to_string(),
+        Elem::UInt(_) | Elem::AtomicUInt => word.to_string(),
+        Elem::Bool => (word != 0).to_string(),
+    }
+}
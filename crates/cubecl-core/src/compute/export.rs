@@ -0,0 +1,204 @@
+use std::{
+    fs, io,
+    marker::PhantomData,
+    path::{Path, PathBuf},
+};
+
+use cubecl_runtime::ExecutionMode;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    codegen::CompilerRepresentation,
+    ir::{Binding, CubeDim, KernelDefinition},
+    Compiler, Kernel, KernelId, StableKernelId,
+};
+
+use super::{CompiledKernel, CubeTask};
+
+/// The name of the file describing the kernels of a [bundle](KernelBundle).
+const MANIFEST_FILE: &str = "kernels.json";
+
+/// Compile kernels ahead of time into a [bundle](KernelBundle) that can be saved to disk and
+/// loaded back by a runtime without expanding the kernels again.
+///
+/// # Example
+///
+/// ```ignore
+/// let mut exporter = KernelExporter::<WgslCompiler>::new(ExecutionMode::Checked);
+/// exporter.kernel("gelu", gelu_array::GeluArray::<f32, WgpuRuntime>::new(settings));
+/// exporter.bundle().save("kernels/wgsl")?;
+/// ```
+pub struct KernelExporter<C: Compiler> {
+    mode: ExecutionMode,
+    kernels: Vec<ExportedKernel>,
+    _compiler: PhantomData<C>,
+}
+
+impl<C: Compiler> KernelExporter<C> {
+    /// Create a new exporter compiling kernels in the given [mode](ExecutionMode).
+    pub fn new(mode: ExecutionMode) -> Self {
+        Self {
+            mode,
+            kernels: Vec::new(),
+            _compiler: PhantomData,
+        }
+    }
+
+    /// Compile the [kernel](Kernel) under the given name.
+    pub fn kernel<K: Kernel>(&mut self, name: &str, kernel: K) -> &mut Self {
+        self.definition(name, kernel.define())
+    }
+
+    /// Compile the [kernel definition](KernelDefinition) under the given name.
+    ///
+    /// Debug output can't be decoded without the kernel definition, so kernels using
+    /// [cube_assert!](crate::cube_assert) or [cube_debug_print!](crate::cube_debug_print) can
+    /// only be exported in [unchecked mode](ExecutionMode::Unchecked), where it isn't written.
+    pub fn definition(&mut self, name: &str, mut definition: KernelDefinition) -> &mut Self {
+        assert!(
+            self.kernels.iter().all(|kernel| kernel.name != name),
+            "Kernel {name} is already exported"
+        );
+
+        let id = StableKernelId::from_definition(&definition);
+        match self.mode {
+            ExecutionMode::Checked => assert!(
+                definition.debug.is_empty(),
+                "Kernel {name} writes debug output, which can only be exported in unchecked mode"
+            ),
+            // The compilers don't bind the debug buffer in unchecked mode.
+            ExecutionMode::Unchecked => definition.named.retain(|(name, _)| name != "debug"),
+        }
+        let bindings = ExportedBindings {
+            inputs: definition.inputs.clone(),
+            outputs: definition.outputs.clone(),
+            named: definition.named.clone(),
+        };
+        let cube_dim = definition.cube_dim;
+
+        let representation = C::compile(definition, self.mode);
+
+        self.kernels.push(ExportedKernel {
+            name: name.to_string(),
+            id: id.to_string(),
+            unchecked: matches!(self.mode, ExecutionMode::Unchecked),
+            cube_dim,
+            shared_mem_bytes: representation.shared_memory_size(),
+            bindings,
+            source: representation.to_string(),
+        });
+        self
+    }
+
+    /// Create the bundle of all compiled kernels.
+    pub fn bundle(self) -> KernelBundle {
+        KernelBundle {
+            extension: C::extension().to_string(),
+            kernels: self.kernels,
+        }
+    }
+}
+
+/// Kernels compiled ahead of time by a [kernel exporter](KernelExporter).
+///
+/// When saved, each source is written in its own file next to a `kernels.json` manifest
+/// describing the kernels.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KernelBundle {
+    /// The file extension of the sources.
+    pub extension: String,
+    /// The compiled kernels.
+    pub kernels: Vec<ExportedKernel>,
+}
+
+/// A kernel compiled ahead of time, which can be launched as a [cube task](CubeTask) with
+/// [launch_task](crate::prelude::KernelLauncher::launch_task), registering the arguments in the
+/// same order as the generated launch function.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExportedKernel {
+    /// The name of the kernel in its bundle.
+    pub name: String,
+    /// The [stable id](StableKernelId) of the kernel definition.
+    pub id: String,
+    /// Whether the kernel was compiled in [unchecked mode](ExecutionMode::Unchecked).
+    pub unchecked: bool,
+    /// Size of a cube for the kernel.
+    pub cube_dim: CubeDim,
+    /// The number of bytes used by the shared memory.
+    pub shared_mem_bytes: usize,
+    /// The bindings expected at launch, in order.
+    pub bindings: ExportedBindings,
+    /// The compiled source, stored in its own file.
+    #[serde(skip)]
+    pub source: String,
+}
+
+/// The bindings of an [exported kernel](ExportedKernel): the inputs, followed by the outputs and
+/// the named bindings, such as the `info` buffer holding the metadata of the arrays.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[allow(missing_docs)]
+pub struct ExportedBindings {
+    pub inputs: Vec<Binding>,
+    pub outputs: Vec<Binding>,
+    pub named: Vec<(String, Binding)>,
+}
+
+impl KernelBundle {
+    /// Get the kernel with the given name.
+    pub fn get(&self, name: &str) -> Option<&ExportedKernel> {
+        self.kernels.iter().find(|kernel| kernel.name == name)
+    }
+
+    /// Write the bundle in the given directory.
+    pub fn save<P: AsRef<Path>>(&self, dir: P) -> io::Result<()> {
+        let dir = dir.as_ref();
+        fs::create_dir_all(dir)?;
+
+        for kernel in self.kernels.iter() {
+            fs::write(self.source_path(dir, kernel), &kernel.source)?;
+        }
+
+        let manifest = serde_json::to_string_pretty(self)?;
+        fs::write(dir.join(MANIFEST_FILE), manifest)
+    }
+
+    /// Read the bundle written in the given directory.
+    pub fn load<P: AsRef<Path>>(dir: P) -> io::Result<Self> {
+        let dir = dir.as_ref();
+        let manifest = fs::read_to_string(dir.join(MANIFEST_FILE))?;
+        let mut bundle: KernelBundle = serde_json::from_str(&manifest)?;
+
+        for i in 0..bundle.kernels.len() {
+            let path = bundle.source_path(dir, &bundle.kernels[i]);
+            bundle.kernels[i].source = fs::read_to_string(path)?;
+        }
+
+        Ok(bundle)
+    }
+
+    fn source_path(&self, dir: &Path, kernel: &ExportedKernel) -> PathBuf {
+        dir.join(format!("{}.{}", kernel.name, self.extension))
+    }
+}
+
+impl CubeTask for ExportedKernel {
+    fn id(&self) -> KernelId {
+        KernelId::new::<Self>().info((self.id.clone(), self.unchecked))
+    }
+
+    fn stable_id(&self) -> StableKernelId {
+        StableKernelId::from_name(&self.id, &self.unchecked)
+    }
+
+    /// The source was compiled during the export, so the mode is ignored.
+    fn compile(&self, _mode: ExecutionMode) -> CompiledKernel {
+        CompiledKernel {
+            name: None,
+            source: self.source.clone(),
+            entry_point: None,
+            cube_dim: self.cube_dim,
+            shared_mem_bytes: self.shared_mem_bytes,
+            debug_info: None,
+        }
+    }
+}
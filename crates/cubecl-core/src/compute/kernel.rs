@@ -3,7 +3,11 @@ use std::{
     marker::PhantomData,
 };
 
-use crate::{codegen::CompilerRepresentation, ir::CubeDim, Compiler, Kernel, KernelId};
+use crate::{
+    codegen::CompilerRepresentation,
+    ir::{CubeDim, KernelDefinition},
+    Compiler, Kernel, KernelId, StableKernelId,
+};
 use alloc::sync::Arc;
 use cubecl_runtime::{
     server::{Binding, ComputeServer},
@@ -15,6 +19,9 @@ pub struct CompiledKernel {
     pub name: Option<&'static str>,
     /// Source code of the kernel
     pub source: String,
+    /// The name of the function to launch, or the default entry point of the runtime when
+    /// `None`.
+    pub entry_point: Option<String>,
     /// Size of a cube for the compiled kernel
     pub cube_dim: CubeDim,
     /// The number of bytes used by the share memory
@@ -159,20 +166,47 @@ fn format_str(kernel_id: &str, markers: &[(char, char)], include_space: bool) ->
 pub trait CubeTask: Send + Sync {
     /// Identifier for the kernel, used for caching kernel compilation.
     fn id(&self) -> KernelId;
+    /// Identifier for the kernel that is stable across builds and processes, used for persistent
+    /// caches.
+    ///
+    /// Derived from the [id](CubeTask::id) by default, which contains a type id and therefore
+    /// isn't stable across builds, so persistent caches miss after a rebuild. Override it with
+    /// [StableKernelId::from_name] or [StableKernelId::from_definition] when possible.
+    fn stable_id(&self) -> StableKernelId {
+        StableKernelId::from_name(&format!("{:?}", self.id()), &())
+    }
     /// Compile the kernel into source
     fn compile(&self, mode: ExecutionMode) -> CompiledKernel;
+    /// The number of bindings expected at launch, checked by the runtime when known.
+    fn num_bindings(&self) -> Option<usize> {
+        None
+    }
 }
 
 /// Wraps a [kernel](Kernel) to create a [cube task](CubeTask).
 #[derive(new)]
 pub struct KernelTask<C: Compiler, K: Kernel> {
     kernel_definition: K,
+    /// The kernel expanded before compilation, reused instead of expanding it again.
+    #[new(default)]
+    expanded: Option<KernelDefinition>,
     _compiler: PhantomData<C>,
 }
 
+impl<C: Compiler, K: Kernel> KernelTask<C, K> {
+    /// Expand the kernel, keeping the definition for its compilation.
+    pub(crate) fn define(&mut self) -> &KernelDefinition {
+        self.expanded
+            .get_or_insert_with(|| self.kernel_definition.define())
+    }
+}
+
 impl<C: Compiler, K: Kernel> CubeTask for KernelTask<C, K> {
     fn compile(&self, mode: ExecutionMode) -> CompiledKernel {
-        let gpu_ir = self.kernel_definition.define();
+        let gpu_ir = match &self.expanded {
+            Some(definition) => definition.clone(),
+            None => self.kernel_definition.define(),
+        };
         let cube_dim = gpu_ir.cube_dim;
         let lower_level_ir = C::compile(gpu_ir, mode);
         let shared_mem_bytes = lower_level_ir.shared_memory_size();
@@ -181,6 +215,7 @@ impl<C: Compiler, K: Kernel> CubeTask for KernelTask<C, K> {
         CompiledKernel {
             name: Some(core::any::type_name::<K>()),
             source,
+            entry_point: None,
             cube_dim,
             shared_mem_bytes,
             debug_info: None,
@@ -190,6 +225,10 @@ impl<C: Compiler, K: Kernel> CubeTask for KernelTask<C, K> {
     fn id(&self) -> KernelId {
         self.kernel_definition.id().clone()
     }
+
+    fn stable_id(&self) -> StableKernelId {
+        self.kernel_definition.stable_id()
+    }
 }
 
 impl CubeTask for Arc<dyn CubeTask> {
@@ -200,6 +239,14 @@ impl CubeTask for Arc<dyn CubeTask> {
     fn id(&self) -> KernelId {
         self.as_ref().id()
     }
+
+    fn stable_id(&self) -> StableKernelId {
+        self.as_ref().stable_id()
+    }
+
+    fn num_bindings(&self) -> Option<usize> {
+        self.as_ref().num_bindings()
+    }
 }
 
 impl CubeTask for Box<dyn CubeTask> {
@@ -210,6 +257,14 @@ impl CubeTask for Box<dyn CubeTask> {
     fn id(&self) -> KernelId {
         self.as_ref().id()
     }
+
+    fn stable_id(&self) -> StableKernelId {
+        self.as_ref().stable_id()
+    }
+
+    fn num_bindings(&self) -> Option<usize> {
+        self.as_ref().num_bindings()
+    }
 }
 
 /// Provides launch information specifying the number of work groups to be used by a compute shader.
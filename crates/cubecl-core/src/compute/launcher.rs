@@ -1,5 +1,5 @@
-use crate::compute::{CubeCount, KernelTask};
-use crate::ir::{Elem, FloatKind, IntKind};
+use crate::compute::{debug::debug_binding, CubeCount, CubeTask, KernelTask};
+use crate::ir::{Elem, FloatKind, IntKind, UIntKind};
 use crate::prelude::ArrayHandleRef;
 use crate::KernelSettings;
 use crate::{calculate_num_elems_dyn_rank, frontend::TensorHandleRef, Kernel, Runtime};
@@ -15,9 +15,16 @@ pub struct KernelLauncher<R: Runtime> {
     scalar_f16: ScalarState<half::f16>,
     scalar_f32: ScalarState<f32>,
     scalar_f64: ScalarState<f64>,
+    scalar_e4m3: ScalarState<float8::F8E4M3>,
+    scalar_e5m2: ScalarState<float8::F8E5M2>,
+    scalar_u8: ScalarState<u8>,
+    scalar_u16: ScalarState<u16>,
     scalar_u32: ScalarState<u32>,
-    scalar_i64: ScalarState<i64>,
+    scalar_u64: ScalarState<u64>,
+    scalar_i8: ScalarState<i8>,
+    scalar_i16: ScalarState<i16>,
     scalar_i32: ScalarState<i32>,
+    scalar_i64: ScalarState<i64>,
     scalar_order: Vec<Elem>,
     pub settings: KernelSettings,
 }
@@ -33,12 +40,42 @@ impl<R: Runtime> KernelLauncher<R> {
         self.tensors.push(&array.as_tensor());
     }
 
+    /// Register a u8 scalar to be launched.
+    pub fn register_u8(&mut self, scalar: u8) {
+        self.register_scalar(Elem::UInt(UIntKind::U8));
+        self.scalar_u8.push(scalar);
+    }
+
+    /// Register a u16 scalar to be launched.
+    pub fn register_u16(&mut self, scalar: u16) {
+        self.register_scalar(Elem::UInt(UIntKind::U16));
+        self.scalar_u16.push(scalar);
+    }
+
     /// Register a u32 scalar to be launched.
     pub fn register_u32(&mut self, scalar: u32) {
-        self.register_scalar(Elem::UInt);
+        self.register_scalar(Elem::UInt(UIntKind::U32));
         self.scalar_u32.push(scalar);
     }
 
+    /// Register a u64 scalar to be launched.
+    pub fn register_u64(&mut self, scalar: u64) {
+        self.register_scalar(Elem::UInt(UIntKind::U64));
+        self.scalar_u64.push(scalar);
+    }
+
+    /// Register a i8 scalar to be launched.
+    pub fn register_i8(&mut self, scalar: i8) {
+        self.register_scalar(Elem::Int(IntKind::I8));
+        self.scalar_i8.push(scalar);
+    }
+
+    /// Register a i16 scalar to be launched.
+    pub fn register_i16(&mut self, scalar: i16) {
+        self.register_scalar(Elem::Int(IntKind::I16));
+        self.scalar_i16.push(scalar);
+    }
+
     /// Register a i32 scalar to be launched.
     pub fn register_i32(&mut self, scalar: i32) {
         self.register_scalar(Elem::Int(IntKind::I32));
@@ -75,22 +112,41 @@ impl<R: Runtime> KernelLauncher<R> {
         self.scalar_f64.push(scalar);
     }
 
+    /// Register a e4m3 scalar to be launched.
+    pub fn register_e4m3(&mut self, scalar: float8::F8E4M3) {
+        self.register_scalar(Elem::Float(FloatKind::E4M3));
+        self.scalar_e4m3.push(scalar);
+    }
+
+    /// Register a e5m2 scalar to be launched.
+    pub fn register_e5m2(&mut self, scalar: float8::F8E5M2) {
+        self.register_scalar(Elem::Float(FloatKind::E5M2));
+        self.scalar_e5m2.push(scalar);
+    }
+
     /// Launch the kernel.
+    ///
+    /// When the kernel writes debug output, a debug buffer is bound last and printed after the
+    /// next synchronization of the client.
     pub fn launch<K: Kernel>(
         self,
         cube_count: CubeCount<R::Server>,
         kernel: K,
         client: &ComputeClient<R::Server, R::Channel>,
     ) {
-        let bindings = self.into_bindings(client);
+        let mut bindings = self.into_bindings(client);
+        let mut kernel = KernelTask::<R::Compiler, K>::new(kernel);
+        bindings.extend(debug_binding::<R, K>(&mut kernel, client));
 
-        let kernel = Box::new(KernelTask::<R::Compiler, K>::new(kernel));
+        let kernel = Box::new(kernel);
 
         client.execute(kernel, cube_count, bindings);
     }
 
     /// Launch the kernel without check bounds.
     ///
+    /// Debug output isn't written in unchecked mode.
+    ///
     /// # Safety
     ///
     /// Out-of-bounds reads and writes can happen.
@@ -107,6 +163,36 @@ impl<R: Runtime> KernelLauncher<R> {
         client.execute_unchecked(kernel, cube_count, bindings);
     }
 
+    /// Launch a [cube task](CubeTask), such as a kernel compiled ahead of time.
+    ///
+    /// The task is responsible for its debug output, so no debug buffer is bound.
+    pub fn launch_task<T: CubeTask + 'static>(
+        self,
+        cube_count: CubeCount<R::Server>,
+        task: T,
+        client: &ComputeClient<R::Server, R::Channel>,
+    ) {
+        let bindings = self.into_bindings(client);
+
+        client.execute(Box::new(task), cube_count, bindings);
+    }
+
+    /// Launch a [cube task](CubeTask) without check bounds.
+    ///
+    /// # Safety
+    ///
+    /// Out-of-bounds reads and writes can happen.
+    pub unsafe fn launch_task_unchecked<T: CubeTask + 'static>(
+        self,
+        cube_count: CubeCount<R::Server>,
+        task: T,
+        client: &ComputeClient<R::Server, R::Channel>,
+    ) {
+        let bindings = self.into_bindings(client);
+
+        client.execute_unchecked(Box::new(task), cube_count, bindings);
+    }
+
     /// We need to create the bindings in the same order they are defined in the compilation step.
     ///
     /// The function [crate::KernelIntegrator::integrate] stars by registering the input tensors followed
@@ -123,21 +209,26 @@ impl<R: Runtime> KernelLauncher<R> {
 
         for elem in self.scalar_order.drain(..) {
             match elem {
-                Elem::Float(kind) => match kind {
+                Elem::Float(kind) | Elem::AtomicFloat(kind) => match kind {
                     FloatKind::F16 => self.scalar_f16.register::<R>(client, &mut bindings),
                     FloatKind::BF16 => self.scalar_bf16.register::<R>(client, &mut bindings),
                     FloatKind::F32 => self.scalar_f32.register::<R>(client, &mut bindings),
                     FloatKind::F64 => self.scalar_f64.register::<R>(client, &mut bindings),
+                    FloatKind::E4M3 => self.scalar_e4m3.register::<R>(client, &mut bindings),
+                    FloatKind::E5M2 => self.scalar_e5m2.register::<R>(client, &mut bindings),
                 },
-                Elem::Int(kind) => match kind {
+                Elem::Int(kind) | Elem::AtomicInt(kind) => match kind {
+                    IntKind::I8 => self.scalar_i8.register::<R>(client, &mut bindings),
+                    IntKind::I16 => self.scalar_i16.register::<R>(client, &mut bindings),
                     IntKind::I32 => self.scalar_i32.register::<R>(client, &mut bindings),
                     IntKind::I64 => self.scalar_i64.register::<R>(client, &mut bindings),
                 },
-                Elem::AtomicInt(kind) => match kind {
-                    IntKind::I32 => self.scalar_i32.register::<R>(client, &mut bindings),
-                    IntKind::I64 => self.scalar_i64.register::<R>(client, &mut bindings),
+                Elem::UInt(kind) => match kind {
+                    UIntKind::U8 => self.scalar_u8.register::<R>(client, &mut bindings),
+                    UIntKind::U16 => self.scalar_u16.register::<R>(client, &mut bindings),
+                    UIntKind::U32 => self.scalar_u32.register::<R>(client, &mut bindings),
+                    UIntKind::U64 => self.scalar_u64.register::<R>(client, &mut bindings),
                 },
-                Elem::UInt => self.scalar_u32.register::<R>(client, &mut bindings),
                 Elem::AtomicUInt => self.scalar_u32.register::<R>(client, &mut bindings),
                 Elem::Bool => panic!("Bool can't be passed as bindings."),
             }
@@ -328,7 +419,11 @@ impl<T: NoUninit> ScalarState<T> {
         match self {
             ScalarState::Empty => (),
             ScalarState::Some(values) => {
-                let handle = client.create(bytemuck::cast_slice(values));
+                // Pad the buffer to whole 32 bits words, so compilers without 8 or 16 bits
+                // integers can read the scalars packed in words.
+                let mut data = bytemuck::cast_slice::<T, u8>(values).to_vec();
+                data.resize(data.len().next_multiple_of(4), 0);
+                let handle = client.create(&data);
                 bindings.push(handle.binding());
             }
         }
@@ -343,9 +438,16 @@ impl<R: Runtime> Default for KernelLauncher<R> {
             scalar_f16: ScalarState::Empty,
             scalar_f32: ScalarState::Empty,
             scalar_f64: ScalarState::Empty,
+            scalar_e4m3: ScalarState::Empty,
+            scalar_e5m2: ScalarState::Empty,
+            scalar_u8: ScalarState::Empty,
+            scalar_u16: ScalarState::Empty,
             scalar_u32: ScalarState::Empty,
-            scalar_i64: ScalarState::Empty,
+            scalar_u64: ScalarState::Empty,
+            scalar_i8: ScalarState::Empty,
+            scalar_i16: ScalarState::Empty,
             scalar_i32: ScalarState::Empty,
+            scalar_i64: ScalarState::Empty,
             scalar_order: Vec::new(),
             settings: Default::default(),
         }
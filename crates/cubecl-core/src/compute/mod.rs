@@ -1,7 +1,13 @@
 mod builder;
+mod debug;
+mod export;
 mod kernel;
 mod launcher;
+mod source;
 
 pub use builder::*;
+pub use debug::{read_debug_output, DebugRecord};
+pub use export::*;
 pub use kernel::*;
 pub use launcher::*;
+pub use source::*;
@@ -0,0 +1,89 @@
+use cubecl_runtime::ExecutionMode;
+
+use crate::{
+    ir::{Binding, CubeDim},
+    KernelId, StableKernelId,
+};
+
+use super::{CompiledKernel, CubeTask};
+
+/// A kernel written directly in the language of a runtime, such as WGSL or CUDA C++, which can be
+/// executed by a [compute client](cubecl_runtime::client::ComputeClient) like generated kernels.
+///
+/// The bindings are passed to the entry point in order, and the runtime checks that as many are
+/// given at launch. For CUDA, the entry point should be declared with `extern "C"` so its name
+/// isn't mangled.
+#[derive(Debug, Clone)]
+pub struct SourceKernel {
+    source: String,
+    entry_point: String,
+    cube_dim: CubeDim,
+    bindings: Vec<Binding>,
+    shared_mem_bytes: usize,
+}
+
+impl SourceKernel {
+    /// Create a new source kernel.
+    pub fn new<S: Into<String>, E: Into<String>>(
+        source: S,
+        entry_point: E,
+        cube_dim: CubeDim,
+        bindings: Vec<Binding>,
+    ) -> Self {
+        Self {
+            source: source.into(),
+            entry_point: entry_point.into(),
+            cube_dim,
+            bindings,
+            shared_mem_bytes: 0,
+        }
+    }
+
+    /// Set the number of bytes of shared memory allocated dynamically at launch.
+    ///
+    /// Only used by runtimes where the shared memory isn't declared in the source, such as CUDA
+    /// with `extern __shared__` arrays.
+    pub fn shared_mem_bytes(mut self, shared_mem_bytes: usize) -> Self {
+        self.shared_mem_bytes = shared_mem_bytes;
+        self
+    }
+
+    /// The bindings expected by the kernel, in order.
+    pub fn bindings(&self) -> &[Binding] {
+        &self.bindings
+    }
+}
+
+impl CubeTask for SourceKernel {
+    fn id(&self) -> KernelId {
+        KernelId::new::<Self>().info((
+            self.source.clone(),
+            self.entry_point.clone(),
+            self.cube_dim,
+            self.shared_mem_bytes,
+        ))
+    }
+
+    fn stable_id(&self) -> StableKernelId {
+        StableKernelId::from_name(
+            &self.entry_point,
+            &(&self.source, self.cube_dim, self.shared_mem_bytes),
+        )
+    }
+
+    /// The source is written by hand, so the mode only affects how the runtime validates it.
+    fn compile(&self, _mode: ExecutionMode) -> CompiledKernel {
+        CompiledKernel {
+            name: None,
+            source: self.source.clone(),
+            entry_point: Some(self.entry_point.clone()),
+            cube_dim: self.cube_dim,
+            shared_mem_bytes: self.shared_mem_bytes,
+            debug_info: None,
+        }
+    }
+
+    fn num_bindings(&self) -> Option<usize> {
+        Some(self.bindings.len())
+    }
+}
@@ -1,9 +1,9 @@
 use num_traits::NumCast;
 
 use crate::frontend::{CubeContext, ExpandElement};
-use crate::ir::{Branch, If, IfElse, Item, Loop, RangeLoop};
+use crate::ir::{Branch, If, IfElse, Item, Loop, RangeLoop, Switch, Variable};
 
-use super::{assign, CubePrimitive, CubeType, ExpandElementTyped, Int, Numeric};
+use super::{assign, not, CubePrimitive, CubeType, ExpandElementTyped, Int, Numeric};
 
 /// Something that can be iterated on by a for loop. Currently only includes `Range`, `StepBy` and
 /// `Sequence`.
@@ -407,6 +407,186 @@ pub fn if_else_expr_expand<C: CubePrimitive>(
     }
 }
 
+pub enum SwitchExpand {
+    /// The value is known at compile time and a case already matched it.
+    ComptimeMatched,
+    /// The value is known at compile time and no case matched it yet.
+    Comptime(i64),
+    Runtime {
+        value: ExpandElement,
+        cases: Vec<(Vec<Variable>, CubeContext)>,
+    },
+}
+
+impl SwitchExpand {
+    pub fn case(
+        self,
+        context: &mut CubeContext,
+        values: &[i64],
+        block: impl FnOnce(&mut CubeContext),
+    ) -> Self {
+        match self {
+            Self::Comptime(value) if values.contains(&value) => {
+                block(context);
+                Self::ComptimeMatched
+            }
+            Self::Runtime { value, mut cases } => {
+                let values = case_values(&value, values, &cases);
+                if values.is_empty() {
+                    // Every value is already matched by a previous case.
+                    return Self::Runtime { value, cases };
+                }
+
+                let mut case_child = context.child();
+                block(&mut case_child);
+                cases.push((values, case_child));
+
+                Self::Runtime { value, cases }
+            }
+            switch => switch,
+        }
+    }
+
+    pub fn finish(self, context: &mut CubeContext, default_block: impl FnOnce(&mut CubeContext)) {
+        match self {
+            Self::Runtime { value, cases } => {
+                let mut default_child = context.child();
+                default_block(&mut default_child);
+
+                context.register(Branch::Switch(Switch {
+                    value: *value,
+                    scope_default: default_child.into_scope(),
+                    cases: cases
+                        .into_iter()
+                        .map(|(values, child)| (values, child.into_scope()))
+                        .collect(),
+                }));
+            }
+            Self::Comptime(_) => default_block(context),
+            Self::ComptimeMatched => (),
+        }
+    }
+}
+
+/// Start a switch over an integer value. Cases are added with [SwitchExpand::case] and the
+/// default case with [SwitchExpand::finish].
+pub fn switch_expand(_context: &mut CubeContext, value: ExpandElement) -> SwitchExpand {
+    match value.as_const() {
+        Some(constant) => SwitchExpand::Comptime(constant.as_i64()),
+        None => SwitchExpand::Runtime {
+            value,
+            cases: Vec::new(),
+        },
+    }
+}
+
+/// Constant values of a case, skipping the ones already matched by previous cases since the
+/// first matching arm wins.
+fn case_values(
+    value: &ExpandElement,
+    values: &[i64],
+    cases: &[(Vec<Variable>, CubeContext)],
+) -> Vec<Variable> {
+    let elem = value.item().elem();
+    values
+        .iter()
+        .map(|val| elem.constant_from_i64(*val))
+        .filter(|val| !cases.iter().any(|(values, _)| values.contains(val)))
+        .collect()
+}
+
+pub enum SwitchExprExpand<C: CubeType> {
+    ComptimeMatched(ExpandElementTyped<C>),
+    Comptime(i64),
+    Runtime {
+        value: ExpandElement,
+        out: Option<ExpandElementTyped<C>>,
+        cases: Vec<(Vec<Variable>, CubeContext)>,
+    },
+}
+
+impl<C: CubePrimitive> SwitchExprExpand<C> {
+    pub fn case(
+        self,
+        context: &mut CubeContext,
+        values: &[i64],
+        block: impl FnOnce(&mut CubeContext) -> ExpandElementTyped<C>,
+    ) -> Self {
+        match self {
+            Self::Comptime(value) if values.contains(&value) => {
+                Self::ComptimeMatched(block(context))
+            }
+            Self::Runtime {
+                value,
+                out,
+                mut cases,
+            } => {
+                let values = case_values(&value, values, &cases);
+                if values.is_empty() {
+                    // Every value is already matched by a previous case.
+                    return Self::Runtime { value, out, cases };
+                }
+
+                let mut case_child = context.child();
+                let ret = block(&mut case_child);
+                let out = out.unwrap_or_else(|| context.create_local(ret.expand.item()).into());
+                assign::expand(&mut case_child, ret, out.clone());
+                cases.push((values, case_child));
+
+                Self::Runtime {
+                    value,
+                    out: Some(out),
+                    cases,
+                }
+            }
+            switch => switch,
+        }
+    }
+
+    pub fn finish(
+        self,
+        context: &mut CubeContext,
+        default_block: impl FnOnce(&mut CubeContext) -> ExpandElementTyped<C>,
+    ) -> ExpandElementTyped<C> {
+        match self {
+            Self::Runtime { value, out, cases } => {
+                let mut default_child = context.child();
+                let ret = default_block(&mut default_child);
+                let out = out.unwrap_or_else(|| context.create_local(ret.expand.item()).into());
+                assign::expand(&mut default_child, ret, out.clone());
+
+                context.register(Branch::Switch(Switch {
+                    value: *value,
+                    scope_default: default_child.into_scope(),
+                    cases: cases
+                        .into_iter()
+                        .map(|(values, child)| (values, child.into_scope()))
+                        .collect(),
+                }));
+                out
+            }
+            Self::Comptime(_) => default_block(context),
+            Self::ComptimeMatched(ret) => ret,
+        }
+    }
+}
+
+/// Start a switch over an integer value where every case returns a value. Cases are added with
+/// [SwitchExprExpand::case] and the default case with [SwitchExprExpand::finish].
+pub fn switch_expand_expr<C: CubePrimitive>(
+    _context: &mut CubeContext,
+    value: ExpandElement,
+) -> SwitchExprExpand<C> {
+    match value.as_const() {
+        Some(constant) => SwitchExprExpand::Comptime(constant.as_i64()),
+        None => SwitchExprExpand::Runtime {
+            value,
+            out: None,
+            cases: Vec::new(),
+        },
+    }
+}
+
 pub fn break_expand(context: &mut CubeContext) {
     context.register(Branch::Break);
 }
@@ -424,3 +604,26 @@ pub fn loop_expand(context: &mut CubeContext, mut block: impl FnMut(&mut CubeCon
         scope: inside_loop.into_scope(),
     }));
 }
+
+/// Expand a `while` loop as a [loop](Loop) that starts by breaking out when the condition is
+/// false. A condition known at compile time to be false doesn't generate any loop.
+// Don't make these `FnOnce`, they must be executable multiple times
+pub fn while_loop_expand(
+    context: &mut CubeContext,
+    mut cond: impl FnMut(&mut CubeContext) -> ExpandElementTyped<bool>,
+    mut block: impl FnMut(&mut CubeContext),
+) {
+    let mut inside_loop = context.child();
+
+    let cond = cond(&mut inside_loop);
+    if cond.constant().map(|it| it.as_bool()) == Some(false) {
+        return;
+    }
+    let cond = not::expand(&mut inside_loop, cond);
+    if_expand(&mut inside_loop, cond.into(), break_expand);
+
+    block(&mut inside_loop);
+    context.register(Branch::Loop(Loop {
+        scope: inside_loop.into_scope(),
+    }));
+}
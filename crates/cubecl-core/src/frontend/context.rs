@@ -1,5 +1,6 @@
+use super::function::{FunctionFrame, FunctionTable};
 use crate::frontend::ExpandElement;
-use crate::ir::{self, Elem, Item, Operation, Scope};
+use crate::ir::{self, DebugFormat, Elem, FunctionDefinition, Item, Operation, Scope, Variable};
 use alloc::rc::Rc;
 use core::cell::RefCell;
 use std::collections::HashMap;
@@ -51,6 +52,10 @@ pub struct CubeContext {
     pub root: Rc<RefCell<Scope>>,
     pub scope: Rc<RefCell<Scope>>,
     pub pool: VariablePool,
+    pub(crate) functions: Rc<RefCell<FunctionTable>>,
+    pub(crate) frame: Option<Rc<RefCell<FunctionFrame>>>,
+    pub(crate) debug: Rc<RefCell<Vec<DebugFormat>>>,
+    pub(crate) slice_origins: Rc<RefCell<Vec<(Variable, Variable)>>>,
 }
 
 impl CubeContext {
@@ -65,6 +70,10 @@ impl CubeContext {
             pool: Default::default(),
             scope,
             root,
+            functions: Default::default(),
+            frame: None,
+            debug: Default::default(),
+            slice_origins: Default::default(),
         }
     }
 
@@ -79,6 +88,10 @@ impl CubeContext {
             scope: Rc::new(RefCell::new(scope)),
             root: self.root.clone(),
             pool: self.pool.clone(),
+            functions: self.functions.clone(),
+            frame: self.frame.clone(),
+            debug: self.debug.clone(),
+            slice_origins: self.slice_origins.clone(),
         }
     }
 
@@ -90,6 +103,28 @@ impl CubeContext {
             .into_inner()
     }
 
+    /// Take the [functions](FunctionDefinition) registered while expanding the kernel.
+    pub fn take_functions(&mut self) -> Vec<FunctionDefinition> {
+        self.functions.borrow_mut().take()
+    }
+
+    /// Take the [debug formats](DebugFormat) registered while expanding the kernel.
+    pub fn take_debug_formats(&mut self) -> Vec<DebugFormat> {
+        core::mem::take(&mut *self.debug.borrow_mut())
+    }
+
+    /// Register a [debug format](DebugFormat), returning its index.
+    pub(crate) fn register_debug_format(&mut self, format: DebugFormat) -> u32 {
+        let mut formats = self.debug.borrow_mut();
+        match formats.iter().position(|registered| *registered == format) {
+            Some(index) => index as u32,
+            None => {
+                formats.push(format);
+                formats.len() as u32 - 1
+            }
+        }
+    }
+
     /// When a new variable is required, we check if we can reuse an old one
     /// Otherwise we create a new one.
     pub fn create_local(&mut self, item: Item) -> ExpandElement {
@@ -123,14 +158,58 @@ impl CubeContext {
         ExpandElement::Plain(variable)
     }
 
+    /// Register the variable viewed by a slice, which is the origin of the viewed variable when it
+    /// is itself a slice.
+    pub(crate) fn register_slice_origin(&mut self, slice: Variable, viewed: Variable) {
+        let origin = self.slice_origin(viewed).unwrap_or(viewed);
+        self.slice_origins.borrow_mut().push((slice, origin));
+    }
+
+    /// The array or shared memory viewed by a slice.
+    pub(crate) fn slice_origin(&self, slice: Variable) -> Option<Variable> {
+        // Slices of sibling scopes can share an id, so the latest one is the one in scope.
+        self.slice_origins
+            .borrow()
+            .iter()
+            .rev()
+            .find(|(registered, _)| *registered == slice)
+            .map(|(_, origin)| *origin)
+    }
+
     pub fn create_shared(&mut self, item: Item, size: u32) -> ExpandElement {
-        ExpandElement::Plain(self.root.borrow_mut().create_shared(item, size))
+        // Shared memories are declared by the kernel, even when created inside a function.
+        let root = match &self.frame {
+            Some(frame) => frame.borrow().kernel.clone(),
+            None => self.root.clone(),
+        };
+        let variable = root.borrow_mut().create_shared(item, size);
+        ExpandElement::Plain(variable)
     }
 
     pub fn create_local_array(&mut self, item: Item, size: u32) -> ExpandElement {
         ExpandElement::Plain(self.root.borrow_mut().create_local_array(item, size))
     }
 
+    /// Obtain a builtin variable.
+    ///
+    /// Builtins are only available in the kernel body, so inside a function they are passed as
+    /// extra parameters.
+    pub fn builtin(&mut self, variable: Variable) -> ExpandElement {
+        let frame = match &self.frame {
+            Some(frame) => frame.clone(),
+            None => return ExpandElement::Plain(variable),
+        };
+        let mut frame = frame.borrow_mut();
+
+        if let Some((_, param)) = frame.builtins.iter().find(|(var, _)| *var == variable) {
+            return param.clone();
+        }
+
+        let param = ExpandElement::Plain(self.root.borrow_mut().create_local(variable.item()));
+        frame.builtins.push((variable, param.clone()));
+        param
+    }
+
     /// Obtain the index-th input
     pub fn input(&mut self, id: u16, item: Item) -> ExpandElement {
         ExpandElement::Plain(crate::ir::Variable::GlobalInputArray { id, item })
@@ -0,0 +1,74 @@
+use super::{CubeContext, ExpandElement, ExpandElementTyped};
+use crate::ir::{DebugFormat, DebugKind, DebugOperation};
+
+/// Module containing the expand function of [cube_assert!](crate::cube_assert).
+pub mod cube_assert {
+    use super::*;
+
+    /// Expand method of [cube_assert!](crate::cube_assert).
+    ///
+    /// Nothing is registered when debug assertions are disabled.
+    pub fn expand(
+        context: &mut CubeContext,
+        condition: ExpandElementTyped<bool>,
+        message: &str,
+        location: &str,
+    ) {
+        if !cfg!(debug_assertions) {
+            return;
+        }
+
+        let format = context.register_debug_format(DebugFormat {
+            kind: DebugKind::Assert,
+            message: message.to_string(),
+            location: location.to_string(),
+            args: Vec::new(),
+        });
+
+        context.register(DebugOperation::Assert {
+            condition: *condition.expand,
+            format,
+        });
+    }
+}
+
+/// Module containing the expand function of [cube_debug_print!](crate::cube_debug_print).
+pub mod cube_debug_print {
+    use super::*;
+
+    /// Expand method of [cube_debug_print!](crate::cube_debug_print).
+    ///
+    /// Nothing is registered when debug assertions are disabled.
+    pub fn expand(
+        context: &mut CubeContext,
+        format: &str,
+        location: &str,
+        args: Vec<ExpandElement>,
+    ) {
+        if !cfg!(debug_assertions) {
+            return;
+        }
+
+        let args: Vec<_> = args.iter().map(|arg| **arg).collect();
+        let elems = args
+            .iter()
+            .map(|arg| {
+                let elem = arg.item().elem;
+                assert!(
+                    !elem.is_atomic(),
+                    "Atomics can't be printed, load them first"
+                );
+                elem
+            })
+            .collect();
+
+        let format = context.register_debug_format(DebugFormat {
+            kind: DebugKind::Print,
+            message: format.to_string(),
+            location: location.to_string(),
+            args: elems,
+        });
+
+        context.register(DebugOperation::Print { format, args });
+    }
+}
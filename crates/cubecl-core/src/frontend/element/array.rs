@@ -207,6 +207,18 @@ impl<'a, R: Runtime> ArgSettings<R> for ArrayArg<'a, R> {
             }
         }
     }
+
+    fn vectorization(&self) -> Vectorization {
+        match self {
+            Self::Handle {
+                handle: _,
+                vectorization_factor,
+            } => NonZero::new(*vectorization_factor),
+            Self::Alias { input_pos: _ } => {
+                panic!("Aliases aren't supported as elements of an argument.")
+            }
+        }
+    }
 }
 
 impl<'a, R: Runtime> ArrayArg<'a, R> {
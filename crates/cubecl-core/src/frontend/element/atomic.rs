@@ -5,14 +5,17 @@ use super::{
 use crate::{
     frontend::{CubeContext, CubePrimitive, CubeType, ExpandElement},
     ir::{
-        BinaryOperator, CompareAndSwapOperator, Elem, IntKind, Item, Operator, UnaryOperator,
-        Vectorization,
+        AtomicOrderedOperator, BinaryOperator, CompareAndSwapOperator, Elem, FloatKind, IntKind,
+        Item, MemoryOrdering, Operator, UnaryOperator, Vectorization,
     },
     prelude::KernelBuilder,
     unexpanded,
 };
 
 /// An atomic type. Represents an shared value that can be operated on atomically.
+///
+/// Atomics can live in global arrays as well as in [shared memory](crate::prelude::SharedMemory).
+/// Float atomics don't support bitwise operations.
 pub trait Atomic: Sized + CubeType
 where
     ExpandElement: From<<Self::Primitive as CubeType>::ExpandType>,
@@ -33,6 +36,28 @@ where
         unexpanded!()
     }
 
+    /// Load the value of the atomic with the given ordering, which can't be a release.
+    ///
+    /// With an [acquire](MemoryOrdering::Acquire) ordering, the memory accesses that follow the
+    /// load see the writes released by the unit that stored the loaded value.
+    ///
+    /// WebGPU atomics are always relaxed, so the ordering is ignored there.
+    #[allow(unused_variables)]
+    fn load_ordered(pointer: &Self, ordering: MemoryOrdering) -> Self::Primitive {
+        unexpanded!()
+    }
+
+    /// Store the value of the atomic with the given ordering, which can't be an acquire.
+    ///
+    /// With a [release](MemoryOrdering::Release) ordering, the memory accesses that precede the
+    /// store are visible to the units that acquire the stored value.
+    ///
+    /// WebGPU atomics are always relaxed, so the ordering is ignored there.
+    #[allow(unused_variables)]
+    fn store_ordered(pointer: &Self, value: Self::Primitive, ordering: MemoryOrdering) {
+        unexpanded!()
+    }
+
     /// Atomically stores the value into the atomic and returns the old value.
     #[allow(unused_variables)]
     fn swap(pointer: &Self, value: Self::Primitive) -> Self::Primitive {
@@ -123,6 +148,44 @@ where
         }));
     }
 
+    fn __expand_load_ordered(
+        context: &mut CubeContext,
+        pointer: <Self as CubeType>::ExpandType,
+        ordering: MemoryOrdering,
+    ) -> <Self::Primitive as CubeType>::ExpandType {
+        assert!(
+            !ordering.is_release(),
+            "An atomic load can't have a release ordering"
+        );
+        let pointer: ExpandElement = pointer.into();
+        let new_var = context.create_local(Item::new(Self::Primitive::as_elem()));
+        context.register(Operator::AtomicLoadOrdered(AtomicOrderedOperator {
+            input: *pointer,
+            ordering,
+            out: *new_var,
+        }));
+        new_var.into()
+    }
+
+    fn __expand_store_ordered(
+        context: &mut CubeContext,
+        pointer: <Self as CubeType>::ExpandType,
+        value: <Self::Primitive as CubeType>::ExpandType,
+        ordering: MemoryOrdering,
+    ) {
+        assert!(
+            !ordering.is_acquire(),
+            "An atomic store can't have an acquire ordering"
+        );
+        let ptr: ExpandElement = pointer.into();
+        let value: ExpandElement = value.into();
+        context.register(Operator::AtomicStoreOrdered(AtomicOrderedOperator {
+            input: *value,
+            ordering,
+            out: *ptr,
+        }));
+    }
+
     fn __expand_swap(
         context: &mut CubeContext,
         pointer: <Self as CubeType>::ExpandType,
@@ -366,6 +429,53 @@ impl LaunchArgExpand for AtomicU32 {
     }
 }
 
+/// An atomic version of `f32`. Can only be acted on atomically.
+#[derive(Clone, Copy, PartialEq)]
+pub struct AtomicF32 {
+    pub val: f32,
+}
+
+impl core::fmt::Debug for AtomicF32 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_fmt(format_args!("{}", self.val))
+    }
+}
+
+impl CubeType for AtomicF32 {
+    type ExpandType = ExpandElementTyped<Self>;
+}
+
+impl CubePrimitive for AtomicF32 {
+    fn as_elem() -> Elem {
+        Elem::AtomicFloat(FloatKind::F32)
+    }
+}
+
+impl IntoRuntime for AtomicF32 {
+    fn __expand_runtime_method(self, _context: &mut CubeContext) -> ExpandElementTyped<Self> {
+        unimplemented!("Atomics don't exist at compile time")
+    }
+}
+
+impl ExpandElementBaseInit for AtomicF32 {
+    fn init_elem(context: &mut CubeContext, elem: ExpandElement) -> ExpandElement {
+        init_expand_element(context, elem)
+    }
+}
+
+impl LaunchArgExpand for AtomicF32 {
+    fn expand(
+        builder: &mut KernelBuilder,
+        vectorization: Vectorization,
+    ) -> ExpandElementTyped<Self> {
+        assert_eq!(vectorization, None, "Attempted to vectorize a scalar");
+        builder.scalar(Elem::AtomicFloat(FloatKind::F32)).into()
+    }
+}
+
+impl Atomic for AtomicF32 {
+    type Primitive = f32;
+}
 impl Atomic for AtomicI32 {
     type Primitive = i32;
 }
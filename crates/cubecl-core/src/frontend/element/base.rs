@@ -5,6 +5,7 @@ use crate::{
     KernelSettings, Runtime,
 };
 use alloc::rc::Rc;
+use float8::{F8E4M3, F8E5M2};
 use half::{bf16, f16};
 use std::{marker::PhantomData, num::NonZero};
 
@@ -65,6 +66,24 @@ pub trait LaunchArgExpand: CubeType {
     ) -> <Self as CubeType>::ExpandType {
         Self::expand(builder, vectorization)
     }
+    /// Register the input variable at the given position, configured by the
+    /// [settings](KernelSettings) of the kernel.
+    fn expand_with_settings(
+        builder: &mut KernelBuilder,
+        settings: &KernelSettings,
+        position: usize,
+    ) -> <Self as CubeType>::ExpandType {
+        Self::expand(builder, settings.vectorization_input(position))
+    }
+    /// Register the output variable at the given position, configured by the
+    /// [settings](KernelSettings) of the kernel.
+    fn expand_output_with_settings(
+        builder: &mut KernelBuilder,
+        settings: &KernelSettings,
+        position: usize,
+    ) -> <Self as CubeType>::ExpandType {
+        Self::expand_output(builder, settings.vectorization_output(position))
+    }
 }
 
 /// Defines a type that can be used as argument to a kernel.
@@ -113,6 +132,11 @@ pub trait ArgSettings<R: Runtime>: Send + Sync {
     fn configure_output(&self, _position: usize, settings: KernelSettings) -> KernelSettings {
         settings
     }
+    /// The vectorization of the argument when it's an element of an argument made of multiple
+    /// arrays, such as a [sequence](crate::frontend::SequenceArg) or a tuple.
+    fn vectorization(&self) -> Vectorization {
+        None
+    }
 }
 
 /// Reference to a JIT variable
@@ -143,9 +167,14 @@ macro_rules! from_const {
     };
 }
 
+from_const!(u8);
+from_const!(u16);
 from_const!(u32);
-from_const!(i64);
+from_const!(u64);
+from_const!(i8);
+from_const!(i16);
 from_const!(i32);
+from_const!(i64);
 from_const!(f64);
 from_const!(f32);
 from_const!(bool);
@@ -166,6 +195,22 @@ impl From<bf16> for ExpandElementTyped<bf16> {
     }
 }
 
+impl From<F8E4M3> for ExpandElementTyped<F8E4M3> {
+    fn from(value: F8E4M3) -> Self {
+        let variable =
+            Variable::ConstantScalar(ConstantScalarValue::Float(value.to_f64(), FloatKind::E4M3));
+        ExpandElement::Plain(variable).into()
+    }
+}
+
+impl From<F8E5M2> for ExpandElementTyped<F8E5M2> {
+    fn from(value: F8E5M2) -> Self {
+        let variable =
+            Variable::ConstantScalar(ConstantScalarValue::Float(value.to_f64(), FloatKind::E5M2));
+        ExpandElement::Plain(variable).into()
+    }
+}
+
 macro_rules! tuple_cube_type {
     ($($P:ident),*) => {
         impl<$($P: CubeType),*> CubeType for ($($P,)*) {
@@ -200,6 +245,74 @@ macro_rules! tuple_runtime {
     }
 }
 
+macro_rules! tuple_launch_arg {
+    ($($P:ident),*) => {
+        impl<$($P: LaunchArg),*> LaunchArg for ($($P,)*) {
+            type RuntimeArg<'a, R: Runtime> = ($($P::RuntimeArg<'a, R>,)*);
+        }
+
+        impl<$($P: LaunchArgExpand),*> LaunchArgExpand for ($($P,)*) {
+            fn expand(
+                builder: &mut KernelBuilder,
+                vectorization: Vectorization,
+            ) -> <Self as CubeType>::ExpandType {
+                ($($P::expand(builder, vectorization),)*)
+            }
+
+            fn expand_output(
+                builder: &mut KernelBuilder,
+                vectorization: Vectorization,
+            ) -> <Self as CubeType>::ExpandType {
+                ($($P::expand_output(builder, vectorization),)*)
+            }
+
+            fn expand_with_settings(
+                builder: &mut KernelBuilder,
+                settings: &KernelSettings,
+                position: usize,
+            ) -> <Self as CubeType>::ExpandType {
+                let vectorizations = settings
+                    .vectorization_input_elements(position)
+                    .expect("Tuple arguments should be configured");
+                let mut vectorizations = vectorizations.into_iter();
+                ($($P::expand(builder, vectorizations.next().unwrap()),)*)
+            }
+
+            fn expand_output_with_settings(
+                builder: &mut KernelBuilder,
+                settings: &KernelSettings,
+                position: usize,
+            ) -> <Self as CubeType>::ExpandType {
+                let vectorizations = settings
+                    .vectorization_output_elements(position)
+                    .expect("Tuple arguments should be configured");
+                let mut vectorizations = vectorizations.into_iter();
+                ($($P::expand_output(builder, vectorizations.next().unwrap()),)*)
+            }
+        }
+
+        impl<R: Runtime, $($P: ArgSettings<R>),*> ArgSettings<R> for ($($P,)*) {
+            #[allow(non_snake_case)]
+            fn register(&self, launcher: &mut KernelLauncher<R>) {
+                let ($($P,)*) = self;
+                $($P.register(launcher);)*
+            }
+
+            #[allow(non_snake_case)]
+            fn configure_input(&self, position: usize, settings: KernelSettings) -> KernelSettings {
+                let ($($P,)*) = self;
+                settings.vectorize_input_elements(position, vec![$($P.vectorization()),*])
+            }
+
+            #[allow(non_snake_case)]
+            fn configure_output(&self, position: usize, settings: KernelSettings) -> KernelSettings {
+                let ($($P,)*) = self;
+                settings.vectorize_output_elements(position, vec![$($P.vectorization()),*])
+            }
+        }
+    }
+}
+
 tuple_cube_type!(P1);
 tuple_cube_type!(P1, P2);
 tuple_cube_type!(P1, P2, P3);
@@ -221,6 +334,13 @@ tuple_runtime!(P1, P2, P3, P4);
 tuple_runtime!(P1, P2, P3, P4, P5);
 tuple_runtime!(P1, P2, P3, P4, P5, P6);
 
+tuple_launch_arg!(P1);
+tuple_launch_arg!(P1, P2);
+tuple_launch_arg!(P1, P2, P3);
+tuple_launch_arg!(P1, P2, P3, P4);
+tuple_launch_arg!(P1, P2, P3, P4, P5);
+tuple_launch_arg!(P1, P2, P3, P4, P5, P6);
+
 pub trait ExpandElementBaseInit: CubeType {
     fn init_elem(context: &mut CubeContext, elem: ExpandElement) -> ExpandElement;
 }
@@ -398,6 +518,16 @@ impl Init for ExpandElement {
     }
 }
 
+impl<T: CubeType> CubeType for Option<T> {
+    type ExpandType = Option<T::ExpandType>;
+}
+
+impl<T: IntoRuntime> IntoRuntime for Option<T> {
+    fn __expand_runtime_method(self, context: &mut CubeContext) -> Self::ExpandType {
+        self.map(|value| value.__expand_runtime_method(context))
+    }
+}
+
 impl<T: Init> Init for Option<T> {
     fn init(self, context: &mut CubeContext) -> Self {
         self.map(|o| Init::init(o, context))
@@ -27,6 +27,23 @@ pub trait Float:
     + Erf
     + Recip
     + Normalize
+    + Tan
+    + Asin
+    + Acos
+    + Atan
+    + Atan2
+    + Sinh
+    + Cosh
+    + Exp2
+    + Log2
+    + Hypot
+    + Trunc
+    + Fract
+    + Sign
+    + IsNan
+    + IsInf
+    + IsFinite
+    + CopySign
     + Into<Self::ExpandType>
     + core::ops::Add<Output = Self>
     + core::ops::Sub<Output = Self>
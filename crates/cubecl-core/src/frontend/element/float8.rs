@@ -0,0 +1,78 @@
+pub use float8::{F8E4M3, F8E5M2};
+
+use crate::frontend::{CubeContext, CubePrimitive, CubeType, ExpandElement};
+use crate::ir::{Elem, FloatKind, Vectorization};
+use crate::prelude::{KernelBuilder, KernelLauncher};
+use crate::{unexpanded, Runtime};
+
+use super::{
+    init_expand_element, ExpandElementBaseInit, ExpandElementTyped, Init, IntoRuntime,
+    LaunchArgExpand, ScalarArgSettings, Vectorized,
+};
+
+/// 8 bits floats are storage types: they can be read, written and cast to and from other
+/// primitives, but arithmetic must be done after casting them to a wider float.
+macro_rules! impl_float8 {
+    ($type:ident, $kind:ident) => {
+        impl CubeType for $type {
+            type ExpandType = ExpandElementTyped<Self>;
+        }
+
+        impl ExpandElementBaseInit for $type {
+            fn init_elem(context: &mut CubeContext, elem: ExpandElement) -> ExpandElement {
+                init_expand_element(context, elem)
+            }
+        }
+
+        impl CubePrimitive for $type {
+            fn as_elem() -> Elem {
+                Elem::Float(FloatKind::$kind)
+            }
+        }
+
+        impl IntoRuntime for $type {
+            fn __expand_runtime_method(
+                self,
+                context: &mut CubeContext,
+            ) -> ExpandElementTyped<Self> {
+                let expand: ExpandElementTyped<Self> = self.into();
+                Init::init(expand, context)
+            }
+        }
+
+        impl LaunchArgExpand for $type {
+            fn expand(
+                builder: &mut KernelBuilder,
+                vectorization: Vectorization,
+            ) -> ExpandElementTyped<Self> {
+                assert_eq!(vectorization, None, "Attempted to vectorize a scalar");
+                builder.scalar($type::as_elem()).into()
+            }
+        }
+
+        impl Vectorized for $type {
+            fn vectorization_factor(&self) -> u32 {
+                1
+            }
+
+            fn vectorize(self, _factor: u32) -> Self {
+                unexpanded!()
+            }
+        }
+    };
+}
+
+impl_float8!(F8E4M3, E4M3);
+impl_float8!(F8E5M2, E5M2);
+
+impl ScalarArgSettings for F8E4M3 {
+    fn register<R: Runtime>(&self, settings: &mut KernelLauncher<R>) {
+        settings.register_e4m3(*self);
+    }
+}
+
+impl ScalarArgSettings for F8E5M2 {
+    fn register<R: Runtime>(&self, settings: &mut KernelLauncher<R>) {
+        settings.register_e5m2(*self);
+    }
+}
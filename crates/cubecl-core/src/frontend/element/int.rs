@@ -1,6 +1,6 @@
 use crate::frontend::{
-    CubeContext, CubePrimitive, CubeType, ExpandElement, ExpandElementBaseInit, ExpandElementTyped,
-    Numeric,
+    CountOnes, CubeContext, CubePrimitive, CubeType, ExpandElement, ExpandElementBaseInit,
+    ExpandElementTyped, LeadingZeros, MulHi, Numeric, ReverseBits, TrailingZeros,
 };
 use crate::ir::{Elem, IntKind, Vectorization};
 use crate::Runtime;
@@ -10,13 +10,18 @@ use crate::{
 };
 
 use super::{
-    init_expand_element, Init, IntoRuntime, LaunchArgExpand, ScalarArgSettings, Vectorized,
-    __expand_new, __expand_vectorized,
+    __expand_new, __expand_vectorized, init_expand_element, Init, IntoRuntime, LaunchArgExpand,
+    ScalarArgSettings, Vectorized,
 };
 
 /// Signed or unsigned integer. Used as input in int kernels
 pub trait Int:
     Numeric
+    + CountOnes
+    + LeadingZeros
+    + TrailingZeros
+    + ReverseBits
+    + MulHi
     + std::ops::Rem<Output = Self>
     + core::ops::Add<Output = Self>
     + core::ops::Sub<Output = Self>
@@ -123,28 +128,20 @@ macro_rules! impl_int {
     };
 }
 
+impl_int!(i8, I8);
+impl_int!(i16, I16);
 impl_int!(i32, I32);
 impl_int!(i64, I64);
 
-impl Int for u32 {
-    const BITS: u32 = u32::BITS;
-
-    fn new(val: i64) -> Self {
-        val as u32
-    }
-
-    fn vectorized(val: i64, _vectorization: u32) -> Self {
-        Self::new(val)
+impl ScalarArgSettings for i8 {
+    fn register<R: Runtime>(&self, settings: &mut KernelLauncher<R>) {
+        settings.register_i8(*self);
     }
 }
 
-impl Vectorized for u32 {
-    fn vectorization_factor(&self) -> u32 {
-        1
-    }
-
-    fn vectorize(self, _factor: u32) -> Self {
-        unexpanded!()
+impl ScalarArgSettings for i16 {
+    fn register<R: Runtime>(&self, settings: &mut KernelLauncher<R>) {
+        settings.register_i16(*self);
     }
 }
 
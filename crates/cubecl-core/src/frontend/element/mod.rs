@@ -5,6 +5,7 @@ mod bool;
 mod cast;
 mod cube_elem;
 mod float;
+mod float8;
 mod int;
 mod numeric;
 mod shared_memory;
@@ -19,6 +20,7 @@ pub use bool::*;
 pub use cast::*;
 pub use cube_elem::*;
 pub use float::*;
+pub use float8::*;
 pub use int::*;
 pub use numeric::*;
 pub use shared_memory::*;
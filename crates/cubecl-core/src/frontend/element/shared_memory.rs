@@ -46,6 +46,10 @@ impl<T: CubePrimitive + Clone> SharedMemory<T> {
             .constant()
             .expect("Shared memory need constant initialization value")
             .as_u32();
+        assert!(
+            vectorization_factor == 1 || !T::as_elem().is_atomic(),
+            "Atomics in shared memory can't be vectorized"
+        );
         let var = context.create_shared(
             Item::vectorized(T::as_elem(), NonZero::new(vectorization_factor as u8)),
             size,
@@ -297,6 +297,7 @@ pub fn slice_expand<I: Into<ExpandElement>, S1: Index, S2: Index>(
 ) -> ExpandElement {
     let input = input.into();
     let out = context.create_slice(input.item());
+    context.register_slice_origin(*out, *input);
 
     context.register(Operator::Slice(ir::SliceOperator {
         input: *input,
@@ -3,7 +3,7 @@ use crate::{
     frontend::{
         indexation::Index, ArgSettings, CubeContext, CubePrimitive, CubeType, ExpandElement,
     },
-    ir::{Elem, Item, Metadata, Variable, Vectorization},
+    ir::{Elem, Item, Metadata, UIntKind, Variable, Vectorization},
     prelude::{KernelBuilder, KernelLauncher},
     unexpanded, KernelSettings, LaunchArg, Runtime,
 };
@@ -165,6 +165,18 @@ impl<'a, R: Runtime> ArgSettings<R> for TensorArg<'a, R> {
             }
         }
     }
+
+    fn vectorization(&self) -> Vectorization {
+        match self {
+            Self::Handle {
+                handle: _,
+                vectorization_factor,
+            } => NonZero::new(*vectorization_factor),
+            Self::Alias { input_pos: _ } => {
+                panic!("Aliases aren't supported as elements of an argument.")
+            }
+        }
+    }
 }
 
 impl<T: CubeType> Tensor<T> {
@@ -203,7 +215,7 @@ impl<T: CubeType> ExpandElementTyped<T> {
         dim: ExpandElementTyped<u32>,
     ) -> ExpandElementTyped<u32> {
         let dim: ExpandElement = dim.into();
-        let out = context.create_local(Item::new(Elem::UInt));
+        let out = context.create_local(Item::new(Elem::UInt(UIntKind::U32)));
         context.register(Metadata::Stride {
             dim: *dim,
             var: self.expand.into(),
@@ -219,7 +231,7 @@ impl<T: CubeType> ExpandElementTyped<T> {
         dim: ExpandElementTyped<u32>,
     ) -> ExpandElementTyped<u32> {
         let dim: ExpandElement = dim.into();
-        let out = context.create_local(Item::new(Elem::UInt));
+        let out = context.create_local(Item::new(Elem::UInt(UIntKind::U32)));
         context.register(Metadata::Shape {
             dim: *dim,
             var: self.expand.into(),
@@ -230,7 +242,7 @@ impl<T: CubeType> ExpandElementTyped<T> {
 
     // Expanded version of len
     pub fn __expand_len_method(self, context: &mut CubeContext) -> ExpandElementTyped<u32> {
-        let out = context.create_local(Item::new(Elem::UInt));
+        let out = context.create_local(Item::new(Elem::UInt(UIntKind::U32)));
         context.register(Metadata::Length {
             var: self.expand.into(),
             out: out.clone().into(),
@@ -1,43 +1,94 @@
 use crate::frontend::{CubeContext, CubePrimitive, CubeType, ExpandElement, Numeric};
-use crate::ir::{Elem, Vectorization};
+use crate::ir::{Elem, UIntKind, Vectorization};
 use crate::prelude::{KernelBuilder, KernelLauncher};
-use crate::Runtime;
+use crate::{unexpanded, Runtime};
 
 use super::{
-    init_expand_element, ExpandElementBaseInit, ExpandElementTyped, Init, IntoRuntime,
-    LaunchArgExpand, ScalarArgSettings,
+    init_expand_element, ExpandElementBaseInit, ExpandElementTyped, Init, Int, IntoRuntime,
+    LaunchArgExpand, ScalarArgSettings, Vectorized,
 };
 
-impl CubeType for u32 {
-    type ExpandType = ExpandElementTyped<Self>;
-}
+macro_rules! impl_uint {
+    ($type:ident, $kind:ident) => {
+        impl CubeType for $type {
+            type ExpandType = ExpandElementTyped<Self>;
+        }
 
-impl ExpandElementBaseInit for u32 {
-    fn init_elem(context: &mut CubeContext, elem: ExpandElement) -> ExpandElement {
-        init_expand_element(context, elem)
-    }
-}
+        impl ExpandElementBaseInit for $type {
+            fn init_elem(context: &mut CubeContext, elem: ExpandElement) -> ExpandElement {
+                init_expand_element(context, elem)
+            }
+        }
 
-impl CubePrimitive for u32 {
-    fn as_elem() -> Elem {
-        Elem::UInt
-    }
+        impl CubePrimitive for $type {
+            fn as_elem() -> Elem {
+                Elem::UInt(UIntKind::$kind)
+            }
+        }
+
+        impl IntoRuntime for $type {
+            fn __expand_runtime_method(
+                self,
+                context: &mut CubeContext,
+            ) -> ExpandElementTyped<Self> {
+                let expand: ExpandElementTyped<Self> = self.into();
+                Init::init(expand, context)
+            }
+        }
+
+        impl LaunchArgExpand for $type {
+            fn expand(
+                builder: &mut KernelBuilder,
+                vectorization: Vectorization,
+            ) -> ExpandElementTyped<Self> {
+                assert_eq!(vectorization, None, "Attempted to vectorize a scalar");
+                builder.scalar($type::as_elem()).into()
+            }
+        }
+
+        impl Numeric for $type {
+            const MAX: Self = $type::MAX;
+            const MIN: Self = $type::MIN;
+        }
+
+        impl Int for $type {
+            const BITS: u32 = $type::BITS;
+
+            fn new(val: i64) -> Self {
+                val as $type
+            }
+
+            fn vectorized(val: i64, _vectorization: u32) -> Self {
+                Self::new(val)
+            }
+        }
+
+        impl Vectorized for $type {
+            fn vectorization_factor(&self) -> u32 {
+                1
+            }
+
+            fn vectorize(self, _factor: u32) -> Self {
+                unexpanded!()
+            }
+        }
+    };
 }
 
-impl IntoRuntime for u32 {
-    fn __expand_runtime_method(self, context: &mut CubeContext) -> ExpandElementTyped<Self> {
-        let expand: ExpandElementTyped<Self> = self.into();
-        Init::init(expand, context)
+impl_uint!(u8, U8);
+impl_uint!(u16, U16);
+impl_uint!(u32, U32);
+impl_uint!(u64, U64);
+
+impl ScalarArgSettings for u8 {
+    fn register<R: Runtime>(&self, settings: &mut KernelLauncher<R>) {
+        settings.register_u8(*self);
     }
 }
 
-impl LaunchArgExpand for u32 {
-    fn expand(
-        builder: &mut KernelBuilder,
-        vectorization: Vectorization,
-    ) -> ExpandElementTyped<Self> {
-        assert_eq!(vectorization, None, "Attempted to vectorize a scalar");
-        builder.scalar(u32::as_elem()).into()
+impl ScalarArgSettings for u16 {
+    fn register<R: Runtime>(&self, settings: &mut KernelLauncher<R>) {
+        settings.register_u16(*self);
     }
 }
 
@@ -47,7 +98,8 @@ impl ScalarArgSettings for u32 {
     }
 }
 
-impl Numeric for u32 {
-    const MAX: Self = u32::MAX;
-    const MIN: Self = u32::MIN;
+impl ScalarArgSettings for u64 {
+    fn register<R: Runtime>(&self, settings: &mut KernelLauncher<R>) {
+        settings.register_u64(*self);
+    }
 }
@@ -0,0 +1,277 @@
+use alloc::rc::Rc;
+use core::cell::RefCell;
+use std::collections::HashMap;
+
+use crate::frontend::{
+    Array, CubeContext, CubePrimitive, CubeType, ExpandElement, ExpandElementTyped, SharedMemory,
+    SliceMut, Tensor,
+};
+use crate::ir::{Call, FunctionDefinition, Scope, Variable};
+
+/// All functions registered while expanding a kernel, deduplicated by their key.
+#[derive(Default)]
+pub(crate) struct FunctionTable {
+    keys: HashMap<String, u32>,
+    entries: Vec<FunctionEntry>,
+}
+
+struct FunctionEntry {
+    definition: Option<FunctionDefinition>,
+    builtins: Vec<Variable>,
+}
+
+/// State of the function currently being expanded.
+pub(crate) struct FunctionFrame {
+    pub(crate) id: u32,
+    pub(crate) kernel: Rc<RefCell<Scope>>,
+    pub(crate) builtins: Vec<(Variable, ExpandElement)>,
+}
+
+impl FunctionTable {
+    pub(crate) fn take(&mut self) -> Vec<FunctionDefinition> {
+        self.keys.clear();
+        core::mem::take(&mut self.entries)
+            .into_iter()
+            .map(|entry| entry.definition.expect("Function should be defined"))
+            .collect()
+    }
+}
+
+/// Expand a call to a `#[cube(noinline)]` function.
+///
+/// The body is expanded only the first time a function is called with a given key, which is made
+/// of the function path, its generics, its comptime arguments and the kind of its runtime
+/// arguments. Following calls reuse the same [definition](FunctionDefinition).
+pub struct FunctionExpand {
+    name: &'static str,
+    key: String,
+    scope: Rc<RefCell<Scope>>,
+    params: Vec<Variable>,
+    args: Vec<Variable>,
+    slice_origins: Vec<(Variable, Variable)>,
+}
+
+impl FunctionExpand {
+    /// Start the expansion of a function call.
+    pub fn new(name: &'static str, key: String) -> Self {
+        Self {
+            name,
+            key,
+            scope: Rc::new(RefCell::new(Scope::root())),
+            params: Vec::new(),
+            args: Vec::new(),
+            slice_origins: Vec::new(),
+        }
+    }
+
+    /// Register an argument, returning the parameter to use in the function body.
+    pub fn arg<A: FunctionArg>(&mut self, context: &mut CubeContext, arg: A) -> A {
+        arg.param(context, self)
+    }
+
+    /// Register an argument passed by mutable reference, which is only accepted for types whose
+    /// writes are visible to the caller.
+    pub fn arg_mut<T: FunctionArgMut>(
+        &mut self,
+        context: &mut CubeContext,
+        arg: ExpandElementTyped<T>,
+    ) -> ExpandElementTyped<T> {
+        arg.param(context, self)
+    }
+
+    fn param(&mut self, context: &mut CubeContext, arg: ExpandElement) -> ExpandElement {
+        let var = *arg;
+        let param = match var {
+            // Global arrays and shared memories are accessible from every function.
+            Variable::GlobalInputArray { .. }
+            | Variable::GlobalOutputArray { .. }
+            | Variable::SharedMemory { .. } => {
+                self.key.push_str(&format!("|{var:?}"));
+                return arg;
+            }
+            Variable::Slice { item, .. } => {
+                // A slice keeps a reference to the array it was created from, so the function is
+                // specialized for the slice it receives.
+                let caller = context.frame.as_ref().map(|frame| frame.borrow().id);
+                self.key.push_str(&format!("|{var:?}@{caller:?}"));
+                let param = self.scope.borrow_mut().create_slice(item);
+                if let Some(origin) = context.slice_origin(var) {
+                    self.slice_origins.push((param, origin));
+                }
+                param
+            }
+            Variable::LocalArray { .. } | Variable::Matrix { .. } => {
+                panic!("Local arrays and matrices can't be passed to a noinline function")
+            }
+            _ => {
+                self.key.push_str(&format!("|{:?}", var.item()));
+                self.scope.borrow_mut().create_local(var.item())
+            }
+        };
+
+        self.params.push(param);
+        self.args.push(var);
+        ExpandElement::Plain(param)
+    }
+
+    /// Register the call, expanding the body of the function if it isn't defined yet.
+    pub fn call<O: FunctionOutput>(
+        self,
+        context: &mut CubeContext,
+        body: impl FnOnce(&mut CubeContext) -> O,
+    ) -> O {
+        let table = context.functions.clone();
+        let id = table.borrow().keys.get(&self.key).copied();
+        let id = match id {
+            Some(id) => id,
+            None => self.define(context, body),
+        };
+
+        let (builtins, output) = {
+            let table = table.borrow();
+            let entry = &table.entries[id as usize];
+            let definition = entry
+                .definition
+                .as_ref()
+                .expect("Recursive functions aren't supported");
+            (entry.builtins.clone(), definition.output)
+        };
+
+        let mut args = self.args;
+        for builtin in builtins {
+            args.push(*context.builtin(builtin));
+        }
+
+        let out = output.map(|output| context.create_local(output.item()));
+        context.register(Call {
+            function: id,
+            args,
+            out: out.as_deref().copied(),
+        });
+
+        O::from_output(out)
+    }
+
+    fn define<O: FunctionOutput>(
+        &self,
+        context: &mut CubeContext,
+        body: impl FnOnce(&mut CubeContext) -> O,
+    ) -> u32 {
+        let table = context.functions.clone();
+        let id = {
+            let mut table = table.borrow_mut();
+            let id = table.entries.len() as u32;
+            table.keys.insert(self.key.clone(), id);
+            table.entries.push(FunctionEntry {
+                definition: None,
+                builtins: Vec::new(),
+            });
+            id
+        };
+
+        let kernel = match &context.frame {
+            Some(frame) => frame.borrow().kernel.clone(),
+            None => context.root.clone(),
+        };
+        let frame = Rc::new(RefCell::new(FunctionFrame {
+            id,
+            kernel,
+            builtins: Vec::new(),
+        }));
+        let mut function_context = CubeContext {
+            root: self.scope.clone(),
+            scope: self.scope.clone(),
+            pool: Default::default(),
+            functions: table.clone(),
+            frame: Some(frame.clone()),
+            debug: context.debug.clone(),
+            slice_origins: Rc::new(RefCell::new(self.slice_origins.clone())),
+        };
+
+        let output = body(&mut function_context).into_output();
+        core::mem::drop(function_context);
+
+        let frame = Rc::into_inner(frame)
+            .expect("Only one reference")
+            .into_inner();
+        let mut params = self.params.clone();
+        let mut builtins = Vec::with_capacity(frame.builtins.len());
+        for (builtin, param) in frame.builtins {
+            builtins.push(builtin);
+            params.push(*param);
+        }
+
+        let body = core::mem::replace(&mut *self.scope.borrow_mut(), Scope::root());
+        table.borrow_mut().entries[id as usize] = FunctionEntry {
+            definition: Some(FunctionDefinition {
+                id,
+                name: self.name.to_string(),
+                params,
+                output,
+                body,
+            }),
+            builtins,
+        };
+
+        id
+    }
+}
+
+/// An argument that can be passed to a `#[cube(noinline)]` function.
+pub trait FunctionArg {
+    /// Register the argument and return the parameter to use in the function body.
+    fn param(self, context: &mut CubeContext, function: &mut FunctionExpand) -> Self;
+}
+
+impl<T: CubeType> FunctionArg for ExpandElementTyped<T> {
+    fn param(self, context: &mut CubeContext, function: &mut FunctionExpand) -> Self {
+        function.param(context, self.expand).into()
+    }
+}
+
+impl FunctionArg for ExpandElement {
+    fn param(self, context: &mut CubeContext, function: &mut FunctionExpand) -> Self {
+        function.param(context, self)
+    }
+}
+
+/// A type that can be passed by mutable reference to a `#[cube(noinline)]` function.
+///
+/// Other arguments are copied into the function, so writing to them wouldn't update the variable
+/// of the caller.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` can't be passed by mutable reference to a noinline function",
+    note = "Return the new value from the function instead"
+)]
+pub trait FunctionArgMut: CubeType {}
+
+impl<T: CubeType> FunctionArgMut for Array<T> {}
+impl<T: CubeType> FunctionArgMut for Tensor<T> {}
+impl<T: CubePrimitive> FunctionArgMut for SharedMemory<T> {}
+impl<'a, T: CubeType> FunctionArgMut for SliceMut<'a, T> {}
+
+/// A value that can be returned from a `#[cube(noinline)]` function.
+pub trait FunctionOutput {
+    /// The variable returned by the function body.
+    fn into_output(self) -> Option<Variable>;
+    /// Create the value from the output of a call.
+    fn from_output(output: Option<ExpandElement>) -> Self;
+}
+
+impl FunctionOutput for () {
+    fn into_output(self) -> Option<Variable> {
+        None
+    }
+
+    fn from_output(_output: Option<ExpandElement>) -> Self {}
+}
+
+impl<T: CubeType> FunctionOutput for ExpandElementTyped<T> {
+    fn into_output(self) -> Option<Variable> {
+        Some(*self.expand)
+    }
+
+    fn from_output(output: Option<ExpandElement>) -> Self {
+        output.expect("Function should have an output").into()
+    }
+}
@@ -35,7 +35,10 @@ impl Index for i32 {
 
 impl Index for u32 {
     fn value(self) -> Variable {
-        Variable::ConstantScalar(crate::ir::ConstantScalarValue::UInt(self as u64))
+        Variable::ConstantScalar(crate::ir::ConstantScalarValue::UInt(
+            self as u64,
+            crate::ir::UIntKind::U32,
+        ))
     }
 }
 
@@ -5,9 +5,12 @@ pub mod synchronization;
 mod base;
 mod const_expand;
 mod context;
+mod debug;
 mod element;
+mod function;
 mod indexation;
 mod operation;
+mod pipeline;
 mod sequence;
 mod subcube;
 mod topology;
@@ -15,9 +18,12 @@ mod topology;
 pub use branch::{range, range_stepped, RangeExpand, SteppedRangeExpand};
 pub use const_expand::*;
 pub use context::*;
+pub use debug::*;
 pub use element::*;
+pub use function::{FunctionArg, FunctionArgMut, FunctionExpand, FunctionOutput};
 pub use indexation::*;
 pub use operation::*;
+pub use pipeline::*;
 pub use sequence::*;
 pub use subcube::*;
 pub use topology::*;
@@ -1,7 +1,7 @@
 use half::{bf16, f16};
 
 use crate::{
-    frontend::{Array, CubeContext, ExpandElement, SharedMemory, Tensor},
+    frontend::{Array, CubeContext, ExpandElement, SharedMemory, Tensor, F8E4M3, F8E5M2},
     prelude::{CubeIndex, CubeIndexMut, CubeType},
 };
 use crate::{ir, prelude::Index};
@@ -45,9 +45,9 @@ pub mod index_assign {
     {
         let index: Variable = index.expand.into();
         let index = match index {
-            Variable::ConstantScalar(value) => {
-                Variable::ConstantScalar(ir::ConstantScalarValue::UInt(value.as_u64()))
-            }
+            Variable::ConstantScalar(value) => Variable::ConstantScalar(
+                ir::ConstantScalarValue::UInt(value.as_u64(), ir::UIntKind::U32),
+            ),
             _ => index,
         };
         context.register(Operator::IndexAssign(BinaryOperator {
@@ -73,7 +73,7 @@ pub mod index_assign {
     impl_index!(Array);
     impl_index!(Tensor);
     impl_index!(SharedMemory);
-    impl_index_vec!(i64, i32, f16, bf16, f32, f64, u32);
+    impl_index_vec!(i8, i16, i32, i64, f16, bf16, f32, f64, F8E4M3, F8E5M2, u8, u16, u32, u64);
 
     impl<'a, E: CubeType, I: Index> CubeIndexMut<I> for SliceMut<'a, E> {}
 }
@@ -103,7 +103,7 @@ pub mod index {
         let index_var: Variable = *index;
         let index = match index_var {
             Variable::ConstantScalar(value) => ExpandElement::Plain(Variable::ConstantScalar(
-                ir::ConstantScalarValue::UInt(value.as_u64()),
+                ir::ConstantScalarValue::UInt(value.as_u64(), ir::UIntKind::U32),
             )),
             _ => index,
         };
@@ -137,7 +137,7 @@ pub mod index {
     impl_index!(Array);
     impl_index!(Tensor);
     impl_index!(SharedMemory);
-    impl_index_vec!(i64, i32, f16, bf16, f32, f64, u32);
+    impl_index_vec!(i8, i16, i32, i64, f16, bf16, f32, f64, F8E4M3, F8E5M2, u8, u16, u32, u64);
 
     impl<'a, E: CubeType, I: Index> CubeIndex<I> for Slice<'a, E> {
         type Output = E;
@@ -1,4 +1,7 @@
-use crate::ir::{BinaryOperator, Elem, Item, Operator, UnaryOperator, Variable, Vectorization};
+use crate::ir::{
+    BinaryOperator, ConstantScalarValue, Elem, Item, Operator, UnaryOperator, Variable,
+    Vectorization,
+};
 use crate::prelude::{CubeType, ExpandElementTyped};
 use crate::{
     frontend::{CubeContext, ExpandElement},
@@ -170,6 +173,80 @@ where
     out
 }
 
+/// Expand a unary operation whose output is a boolean of the same vectorization as its input.
+pub(crate) fn unary_expand_bool<F>(
+    context: &mut CubeContext,
+    input: ExpandElement,
+    func: F,
+) -> ExpandElement
+where
+    F: Fn(UnaryOperator) -> Operator,
+{
+    let input_var: Variable = *input;
+
+    let out_item = Item {
+        elem: Elem::Bool,
+        vectorization: input_var.item().vectorization,
+    };
+
+    let out = context.create_local(out_item);
+    let out_var = *out;
+
+    let op = func(UnaryOperator {
+        input: input_var,
+        out: out_var,
+    });
+
+    context.register(op);
+
+    out
+}
+
+/// Expand a unary operation, or evaluate it directly when its input is a constant.
+pub(crate) fn unary_expand_fold<F>(
+    context: &mut CubeContext,
+    input: ExpandElement,
+    func: F,
+    fold: fn(f64) -> f64,
+) -> ExpandElement
+where
+    F: Fn(UnaryOperator) -> Operator,
+{
+    match *input {
+        Variable::ConstantScalar(ConstantScalarValue::Float(value, kind)) => ExpandElement::Plain(
+            Variable::ConstantScalar(ConstantScalarValue::Float(fold(value), kind)),
+        ),
+        Variable::ConstantScalar(ConstantScalarValue::Int(value, kind)) => ExpandElement::Plain(
+            Variable::ConstantScalar(ConstantScalarValue::Int(fold(value as f64) as i64, kind)),
+        ),
+        _ => unary_expand(context, input, func),
+    }
+}
+
+/// Expand a binary operation, or evaluate it directly when both of its inputs are float
+/// constants.
+pub(crate) fn binary_expand_fold<F>(
+    context: &mut CubeContext,
+    lhs: ExpandElement,
+    rhs: ExpandElement,
+    func: F,
+    fold: fn(f64, f64) -> f64,
+) -> ExpandElement
+where
+    F: Fn(BinaryOperator) -> Operator,
+{
+    match (*lhs, *rhs) {
+        (
+            Variable::ConstantScalar(ConstantScalarValue::Float(lhs, kind)),
+            Variable::ConstantScalar(ConstantScalarValue::Float(rhs, _)),
+        ) => ExpandElement::Plain(Variable::ConstantScalar(ConstantScalarValue::Float(
+            fold(lhs, rhs),
+            kind,
+        ))),
+        _ => binary_expand(context, lhs, rhs, func),
+    }
+}
+
 pub fn init_expand<F>(context: &mut CubeContext, input: ExpandElement, func: F) -> ExpandElement
 where
     F: Fn(UnaryOperator) -> Operator,
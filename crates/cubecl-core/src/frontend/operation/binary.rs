@@ -1,7 +1,10 @@
 use crate::frontend::CubeType;
 use crate::frontend::{CubeContext, CubePrimitive, ExpandElementTyped};
 use crate::ir::Operator;
-use crate::{frontend::operation::base::binary_expand, unexpanded};
+use crate::{
+    frontend::operation::base::{binary_expand, binary_expand_fold},
+    unexpanded,
+};
 use half::{bf16, f16};
 
 pub mod add {
@@ -210,6 +213,32 @@ macro_rules! impl_binary_func {
     }
 }
 
+/// Binary functions that are evaluated during expansion when both inputs are constants.
+macro_rules! impl_binary_func_fold {
+    ($trait_name:ident, $method_name:ident, $func_name_expand:ident, $method_name_expand:ident, $operator:expr, $fold:expr, $($type:ty),*) => {
+        pub trait $trait_name: CubeType + Sized {
+            fn $method_name(self, _rhs: Self) -> Self {
+                unexpanded!()
+            }
+
+            fn $func_name_expand(
+                context: &mut CubeContext,
+                lhs: ExpandElementTyped<Self>,
+                rhs: ExpandElementTyped<Self>,
+            ) -> ExpandElementTyped<Self> {
+                binary_expand_fold(context, lhs.into(), rhs.into(), $operator, $fold).into()
+            }
+        }
+
+        $(impl $trait_name for $type {})*
+        $(impl ExpandElementTyped<$type> {
+            pub fn $method_name_expand(self, context: &mut CubeContext, rhs: ExpandElementTyped<$type>) -> ExpandElementTyped<$type> {
+                binary_expand_fold(context, self.into(), rhs.into(), $operator, $fold).into()
+            }
+        })*
+    }
+}
+
 impl_binary_func!(
     Powf,
     powf,
@@ -221,6 +250,68 @@ impl_binary_func!(
     f32,
     f64
 );
+impl_binary_func_fold!(
+    Atan2,
+    atan2,
+    __expand_atan2,
+    __expand_atan2_method,
+    Operator::Atan2,
+    f64::atan2,
+    f16,
+    bf16,
+    f32,
+    f64
+);
+impl_binary_func_fold!(
+    Hypot,
+    hypot,
+    __expand_hypot,
+    __expand_hypot_method,
+    Operator::Hypot,
+    f64::hypot,
+    f16,
+    bf16,
+    f32,
+    f64
+);
+impl_binary_func_fold!(
+    CopySign,
+    copysign,
+    __expand_copysign,
+    __expand_copysign_method,
+    Operator::CopySign,
+    f64::copysign,
+    f16,
+    bf16,
+    f32,
+    f64
+);
+// Not part of `Float`: the next representable value depends on the precision, which isn't
+// available to every compiler for half precision types.
+impl_binary_func!(
+    NextAfter,
+    next_after,
+    __expand_next_after,
+    __expand_next_after_method,
+    Operator::NextAfter,
+    f32,
+    f64
+);
+impl_binary_func!(
+    MulHi,
+    mul_hi,
+    __expand_mul_hi,
+    __expand_mul_hi_method,
+    Operator::MulHi,
+    i8,
+    i16,
+    i32,
+    i64,
+    u8,
+    u16,
+    u32,
+    u64
+);
 impl_binary_func!(
     Max,
     max,
@@ -231,9 +322,14 @@ impl_binary_func!(
     bf16,
     f32,
     f64,
+    i8,
+    i16,
     i32,
     i64,
-    u32
+    u8,
+    u16,
+    u32,
+    u64
 );
 impl_binary_func!(
     Min,
@@ -245,9 +341,14 @@ impl_binary_func!(
     bf16,
     f32,
     f64,
+    i8,
+    i16,
     i32,
     i64,
-    u32
+    u8,
+    u16,
+    u32,
+    u64
 );
 impl_binary_func!(
     Remainder,
@@ -259,7 +360,12 @@ impl_binary_func!(
     bf16,
     f32,
     f64,
+    i8,
+    i16,
     i32,
     i64,
-    u32
+    u8,
+    u16,
+    u32,
+    u64
 );
@@ -40,6 +40,11 @@ impl Clamp for f16 {}
 impl Clamp for bf16 {}
 impl Clamp for f32 {}
 impl Clamp for f64 {}
+impl Clamp for i8 {}
+impl Clamp for i16 {}
 impl Clamp for i32 {}
 impl Clamp for i64 {}
+impl Clamp for u8 {}
+impl Clamp for u16 {}
 impl Clamp for u32 {}
+impl Clamp for u64 {}
@@ -5,6 +5,7 @@ mod clamp;
 mod cmp;
 mod fma;
 mod unary;
+mod vector;
 
 pub use assignation::*;
 pub use base::*;
@@ -13,3 +14,4 @@ pub use clamp::*;
 pub use cmp::*;
 pub use fma::*;
 pub use unary::*;
+pub use vector::*;
@@ -7,7 +7,7 @@ use crate::{
     unexpanded,
 };
 
-use super::base::unary_expand;
+use super::base::{unary_expand, unary_expand_bool, unary_expand_fold};
 
 pub mod not {
     use super::*;
@@ -50,6 +50,42 @@ macro_rules! impl_unary_func {
     }
 }
 
+/// Unary functions that are evaluated during expansion when their input is a constant.
+macro_rules! impl_unary_func_fold {
+    ($trait_name:ident, $method_name:ident, $method_name_expand:ident, $operator:expr, $fold:expr, $($type:ty),*) => {
+        pub trait $trait_name: CubePrimitive + Sized {
+            #[allow(unused_variables)]
+            fn $method_name(x: Self) -> Self {
+                unexpanded!()
+            }
+
+            fn $method_name_expand(context: &mut CubeContext, x: Self::ExpandType) -> ExpandElementTyped<Self> {
+                unary_expand_fold(context, x.into(), $operator, $fold).into()
+            }
+        }
+
+        $(impl $trait_name for $type {})*
+    }
+}
+
+/// Unary functions checking a property of their input, returning a boolean.
+macro_rules! impl_unary_func_bool {
+    ($trait_name:ident, $method_name:ident, $method_name_expand:ident, $operator:expr, $($type:ty),*) => {
+        pub trait $trait_name: CubePrimitive + Sized {
+            #[allow(unused_variables)]
+            fn $method_name(x: Self) -> bool {
+                unexpanded!()
+            }
+
+            fn $method_name_expand(context: &mut CubeContext, x: Self::ExpandType) -> ExpandElementTyped<bool> {
+                unary_expand_bool(context, x.into(), $operator).into()
+            }
+        }
+
+        $(impl $trait_name for $type {})*
+    }
+}
+
 impl_unary_func!(
     Abs,
     abs,
@@ -59,9 +95,14 @@ impl_unary_func!(
     bf16,
     f32,
     f64,
+    i8,
+    i16,
     i32,
     i64,
-    u32
+    u8,
+    u16,
+    u32,
+    u64
 );
 impl_unary_func!(Exp, exp, __expand_exp, Operator::Exp, f16, bf16, f32, f64);
 impl_unary_func!(Log, log, __expand_log, Operator::Log, f16, bf16, f32, f64);
@@ -148,3 +189,220 @@ impl_unary_func!(
     f32,
     f64
 );
+impl_unary_func_fold!(
+    Tan,
+    tan,
+    __expand_tan,
+    Operator::Tan,
+    f64::tan,
+    f16,
+    bf16,
+    f32,
+    f64
+);
+impl_unary_func_fold!(
+    Asin,
+    asin,
+    __expand_asin,
+    Operator::Asin,
+    f64::asin,
+    f16,
+    bf16,
+    f32,
+    f64
+);
+impl_unary_func_fold!(
+    Acos,
+    acos,
+    __expand_acos,
+    Operator::Acos,
+    f64::acos,
+    f16,
+    bf16,
+    f32,
+    f64
+);
+impl_unary_func_fold!(
+    Atan,
+    atan,
+    __expand_atan,
+    Operator::Atan,
+    f64::atan,
+    f16,
+    bf16,
+    f32,
+    f64
+);
+impl_unary_func_fold!(
+    Sinh,
+    sinh,
+    __expand_sinh,
+    Operator::Sinh,
+    f64::sinh,
+    f16,
+    bf16,
+    f32,
+    f64
+);
+impl_unary_func_fold!(
+    Cosh,
+    cosh,
+    __expand_cosh,
+    Operator::Cosh,
+    f64::cosh,
+    f16,
+    bf16,
+    f32,
+    f64
+);
+impl_unary_func_fold!(
+    Exp2,
+    exp2,
+    __expand_exp2,
+    Operator::Exp2,
+    f64::exp2,
+    f16,
+    bf16,
+    f32,
+    f64
+);
+impl_unary_func_fold!(
+    Log2,
+    log2,
+    __expand_log2,
+    Operator::Log2,
+    f64::log2,
+    f16,
+    bf16,
+    f32,
+    f64
+);
+impl_unary_func_fold!(
+    Trunc,
+    trunc,
+    __expand_trunc,
+    Operator::Trunc,
+    f64::trunc,
+    f16,
+    bf16,
+    f32,
+    f64
+);
+impl_unary_func_fold!(
+    Fract,
+    fract,
+    __expand_fract,
+    Operator::Fract,
+    f64::fract,
+    f16,
+    bf16,
+    f32,
+    f64
+);
+impl_unary_func_fold!(
+    Sign,
+    sign,
+    __expand_sign,
+    Operator::Sign,
+    |x| if x > 0.0 {
+        1.0
+    } else if x < 0.0 {
+        -1.0
+    } else {
+        x
+    },
+    f16,
+    bf16,
+    f32,
+    f64,
+    i8,
+    i16,
+    i32,
+    i64
+);
+impl_unary_func_bool!(
+    IsNan,
+    is_nan,
+    __expand_is_nan,
+    Operator::IsNan,
+    f16,
+    bf16,
+    f32,
+    f64
+);
+impl_unary_func_bool!(
+    IsInf,
+    is_inf,
+    __expand_is_inf,
+    Operator::IsInf,
+    f16,
+    bf16,
+    f32,
+    f64
+);
+impl_unary_func_bool!(
+    IsFinite,
+    is_finite,
+    __expand_is_finite,
+    Operator::IsFinite,
+    f16,
+    bf16,
+    f32,
+    f64
+);
+impl_unary_func!(
+    CountOnes,
+    count_ones,
+    __expand_count_ones,
+    Operator::CountOnes,
+    i8,
+    i16,
+    i32,
+    i64,
+    u8,
+    u16,
+    u32,
+    u64
+);
+impl_unary_func!(
+    LeadingZeros,
+    leading_zeros,
+    __expand_leading_zeros,
+    Operator::LeadingZeros,
+    i8,
+    i16,
+    i32,
+    i64,
+    u8,
+    u16,
+    u32,
+    u64
+);
+impl_unary_func!(
+    TrailingZeros,
+    trailing_zeros,
+    __expand_trailing_zeros,
+    Operator::TrailingZeros,
+    i8,
+    i16,
+    i32,
+    i64,
+    u8,
+    u16,
+    u32,
+    u64
+);
+impl_unary_func!(
+    ReverseBits,
+    reverse_bits,
+    __expand_reverse_bits,
+    Operator::ReverseBits,
+    i8,
+    i16,
+    i32,
+    i64,
+    u8,
+    u16,
+    u32,
+    u64
+);
@@ -0,0 +1,220 @@
+use std::num::NonZero;
+
+use crate::{
+    ir::{BinaryOperator, InsertLaneOperator, Item, Operator, SwizzleOperator, UnaryOperator},
+    prelude::{CubeContext, CubePrimitive, ExpandElement, ExpandElementTyped},
+    unexpanded,
+};
+
+/// Sum of the products of the lanes of two vectorized values.
+#[allow(unused_variables)]
+pub fn dot<C: CubePrimitive>(lhs: C, rhs: C) -> C {
+    unexpanded!()
+}
+
+/// Sum of the lanes of a vectorized value.
+#[allow(unused_variables)]
+pub fn reduce_sum<C: CubePrimitive>(value: C) -> C {
+    unexpanded!()
+}
+
+/// Maximum of the lanes of a vectorized value.
+#[allow(unused_variables)]
+pub fn reduce_max<C: CubePrimitive>(value: C) -> C {
+    unexpanded!()
+}
+
+/// Minimum of the lanes of a vectorized value.
+#[allow(unused_variables)]
+pub fn reduce_min<C: CubePrimitive>(value: C) -> C {
+    unexpanded!()
+}
+
+/// Read the given lane of a vectorized value, the lane being known at compile time.
+#[allow(unused_variables)]
+pub fn extract<C: CubePrimitive>(value: C, lane: u32) -> C {
+    unexpanded!()
+}
+
+/// Copy a vectorized value with the given lane replaced, the lane being known at compile time.
+#[allow(unused_variables)]
+pub fn insert<C: CubePrimitive>(value: C, lane: u32, scalar: C) -> C {
+    unexpanded!()
+}
+
+/// Build a value from the lanes of a vectorized value, the output having one lane per index.
+///
+/// The same lane can be read multiple times, e.g. `swizzle(value, [0, 0, 1, 1])`.
+#[allow(unused_variables)]
+pub fn swizzle<C: CubePrimitive, const N: usize>(value: C, lanes: [u32; N]) -> C {
+    unexpanded!()
+}
+
+pub mod dot {
+    use super::*;
+
+    pub fn expand<C: CubePrimitive>(
+        context: &mut CubeContext,
+        lhs: ExpandElementTyped<C>,
+        rhs: ExpandElementTyped<C>,
+    ) -> ExpandElementTyped<C> {
+        let lhs: ExpandElement = lhs.into();
+        let rhs: ExpandElement = rhs.into();
+        let item = lhs.item();
+
+        assert_eq!(
+            item.vectorization,
+            rhs.item().vectorization,
+            "Dot product inputs must have the same vectorization"
+        );
+
+        let out = context.create_local(Item::new(item.elem()));
+
+        context.register(Operator::Dot(BinaryOperator {
+            lhs: *lhs,
+            rhs: *rhs,
+            out: *out,
+        }));
+
+        out.into()
+    }
+}
+
+pub mod reduce_sum {
+    use super::*;
+
+    pub fn expand<C: CubePrimitive>(
+        context: &mut CubeContext,
+        value: ExpandElementTyped<C>,
+    ) -> ExpandElementTyped<C> {
+        reduce_expand(context, value.into(), Operator::ReduceSum).into()
+    }
+}
+
+pub mod reduce_max {
+    use super::*;
+
+    pub fn expand<C: CubePrimitive>(
+        context: &mut CubeContext,
+        value: ExpandElementTyped<C>,
+    ) -> ExpandElementTyped<C> {
+        reduce_expand(context, value.into(), Operator::ReduceMax).into()
+    }
+}
+
+pub mod reduce_min {
+    use super::*;
+
+    pub fn expand<C: CubePrimitive>(
+        context: &mut CubeContext,
+        value: ExpandElementTyped<C>,
+    ) -> ExpandElementTyped<C> {
+        reduce_expand(context, value.into(), Operator::ReduceMin).into()
+    }
+}
+
+pub mod extract {
+    use super::*;
+
+    pub fn expand<C: CubePrimitive>(
+        context: &mut CubeContext,
+        value: ExpandElementTyped<C>,
+        lane: ExpandElementTyped<u32>,
+    ) -> ExpandElementTyped<C> {
+        swizzle::expand(context, value, [constant_lane(lane)])
+    }
+}
+
+pub mod insert {
+    use super::*;
+
+    pub fn expand<C: CubePrimitive>(
+        context: &mut CubeContext,
+        value: ExpandElementTyped<C>,
+        lane: ExpandElementTyped<u32>,
+        scalar: ExpandElementTyped<C>,
+    ) -> ExpandElementTyped<C> {
+        let lane = constant_lane(lane);
+        let value: ExpandElement = value.into();
+        let scalar: ExpandElement = scalar.into();
+        let item = value.item();
+
+        check_lane(item, lane);
+        assert_eq!(
+            scalar.item().vectorization,
+            None,
+            "Only a scalar can be inserted in a lane"
+        );
+
+        let out = context.create_local(item);
+
+        context.register(Operator::InsertLane(InsertLaneOperator {
+            input: *value,
+            lane,
+            value: *scalar,
+            out: *out,
+        }));
+
+        out.into()
+    }
+}
+
+pub mod swizzle {
+    use super::*;
+
+    pub fn expand<C: CubePrimitive, const N: usize>(
+        context: &mut CubeContext,
+        value: ExpandElementTyped<C>,
+        lanes: [u32; N],
+    ) -> ExpandElementTyped<C> {
+        let value: ExpandElement = value.into();
+        let item = value.item();
+
+        for lane in lanes {
+            check_lane(item, lane);
+        }
+
+        let vectorization = match N {
+            1 => None,
+            _ => Some(NonZero::new(N as u8).expect("A swizzle needs at least one lane")),
+        };
+        let out = context.create_local(Item::vectorized(item.elem(), vectorization));
+
+        context.register(Operator::Swizzle(SwizzleOperator {
+            input: *value,
+            lanes: lanes.to_vec(),
+            out: *out,
+        }));
+
+        out.into()
+    }
+}
+
+fn reduce_expand<F>(context: &mut CubeContext, input: ExpandElement, func: F) -> ExpandElement
+where
+    F: Fn(UnaryOperator) -> Operator,
+{
+    let out = context.create_local(Item::new(input.item().elem()));
+
+    context.register(func(UnaryOperator {
+        input: *input,
+        out: *out,
+    }));
+
+    out
+}
+
+fn constant_lane(lane: ExpandElementTyped<u32>) -> u32 {
+    lane.constant()
+        .expect("Lanes must be known at compile time")
+        .as_u32()
+}
+
+fn check_lane(item: Item, lane: u32) {
+    let vectorization = item.vectorization.map(|it| it.get() as u32).unwrap_or(1);
+
+    assert!(
+        lane < vectorization,
+        "Lane {lane} is out of bounds for a vectorization of {vectorization}"
+    );
+}
@@ -0,0 +1,95 @@
+use super::{CubeContext, CubePrimitive, ExpandElementTyped, Slice, SliceMut};
+use crate::{
+    ir::{Operator, Synchronization, UnaryOperator, Variable},
+    unexpanded,
+};
+
+/// Starts copying the source slice into the destination slice, without waiting for the copy to
+/// complete.
+///
+/// The source must be a slice of global memory, and the destination a slice of a
+/// [SharedMemory](crate::frontend::SharedMemory). Only the current unit copies, so every unit of
+/// the cube usually copies its own part of a tile. The number of elements copied is the smallest
+/// length of both slices.
+///
+/// The copies are grouped in batches with [pipeline_commit()], and a batch must be waited with
+/// [pipeline_wait()] before reading the destination. Since the wait only covers the copies of the
+/// current unit, [sync_units()](crate::frontend::synchronization::sync_units()) is still needed
+/// before reading what other units copied.
+///
+/// On backends without asynchronous copies, the copy is done synchronously.
+#[allow(unused_variables)]
+pub fn memcpy_async<C: CubePrimitive>(source: &Slice<'_, C>, destination: &mut SliceMut<'_, C>) {
+    unexpanded!()
+}
+
+/// Module containing the expand function for [memcpy_async()].
+pub mod memcpy_async {
+    use super::*;
+
+    /// Expand method of [memcpy_async()].
+    pub fn expand<C: CubePrimitive>(
+        context: &mut CubeContext,
+        source: ExpandElementTyped<Slice<'static, C>>,
+        destination: ExpandElementTyped<SliceMut<'static, C>>,
+    ) {
+        if let Some(origin) = context.slice_origin(*source.expand) {
+            assert!(
+                matches!(
+                    origin,
+                    Variable::GlobalInputArray { .. } | Variable::GlobalOutputArray { .. }
+                ),
+                "The source of an asynchronous copy must be a slice of global memory"
+            );
+        }
+        if let Some(origin) = context.slice_origin(*destination.expand) {
+            assert!(
+                matches!(origin, Variable::SharedMemory { .. }),
+                "The destination of an asynchronous copy must be a slice of shared memory"
+            );
+        }
+
+        context.register(Operator::CopyAsync(UnaryOperator {
+            input: *source.expand,
+            out: *destination.expand,
+        }));
+    }
+}
+
+/// Closes the batch of all the asynchronous copies started by the current unit since the last
+/// commit.
+pub fn pipeline_commit() {}
+
+/// Module containing the expand function for [pipeline_commit()].
+pub mod pipeline_commit {
+    use super::*;
+
+    /// Expand method of [pipeline_commit()].
+    pub fn expand(context: &mut CubeContext) {
+        context.register(Synchronization::PipelineCommit)
+    }
+}
+
+/// Waits until at most `pending` of the batches committed by the current unit are still being
+/// copied.
+///
+/// With a multi-stage pipeline, waiting with `pending` equal to the number of stages minus one lets
+/// the copies of the next stages overlap with the computation of the current one. The number of
+/// pending batches must be known at compile time.
+#[allow(unused_variables)]
+pub fn pipeline_wait(pending: u32) {}
+
+/// Module containing the expand function for [pipeline_wait()].
+pub mod pipeline_wait {
+    use super::*;
+
+    /// Expand method of [pipeline_wait()].
+    pub fn expand(context: &mut CubeContext, pending: ExpandElementTyped<u32>) {
+        let pending = pending
+            .constant()
+            .expect("The number of pending batches must be a comptime value")
+            .as_u32();
+
+        context.register(Synchronization::PipelineWait { pending })
+    }
+}
@@ -1,15 +1,19 @@
 use super::{
-    branch::Iterable, indexation::Index, CubeContext, CubeType, ExpandElementTyped, Init,
-    IntoRuntime,
+    branch::Iterable, indexation::Index, ArgSettings, CubeContext, CubeType, ExpandElementTyped,
+    Init, IntoRuntime, LaunchArg, LaunchArgExpand,
+};
+use crate::{
+    ir::Vectorization,
+    prelude::{KernelBuilder, KernelLauncher},
+    unexpanded, KernelSettings, Runtime,
 };
-use crate::unexpanded;
 use std::{cell::RefCell, rc::Rc};
 
 /// A sequence of [cube types](CubeType) that is inlined during compilation.
 ///
 /// In other words, it allows you to group a dynamic amount of variables at compile time.
 ///
-/// All methods [push](Sequence::push), [index](Sequence::index) and
+/// All methods [push](Sequence::push), [index](Sequence::index), [iter](Sequence::iter) and
 /// [into_iter](Sequence::into_iter) are executed _during_ compilation and don't add any overhead
 /// on the generated kernel.
 #[derive(Debug, Clone)]
@@ -34,12 +38,25 @@ impl<T: CubeType> Sequence<T> {
         self.values.push(value);
     }
 
+    /// The number of values in the sequence, known at compile time.
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(&self) -> u32 {
+        self.values.len() as u32
+    }
+
     /// Get the variable at the given position in the sequence.
     #[allow(unused_variables, clippy::should_implement_trait)]
     pub fn index<I: Index>(&self, index: I) -> &T {
         unexpanded!();
     }
 
+    /// Iterate over the values of the sequence without consuming it, such as a sequence received
+    /// as a kernel argument.
+    #[allow(unused_variables)]
+    pub fn iter(&self) -> Sequence<T> {
+        unexpanded!();
+    }
+
     /// Expand function of [new](Self::new).
     pub fn __expand_new(_context: &mut CubeContext) -> SequenceExpand<T> {
         SequenceExpand {
@@ -64,6 +81,14 @@ impl<T: CubeType> Sequence<T> {
     ) -> T::ExpandType {
         expand.__expand_index_method(context, index)
     }
+
+    /// Expand function of [iter](Self::iter).
+    pub fn __expand_iter(
+        context: &mut CubeContext,
+        expand: SequenceExpand<T>,
+    ) -> SequenceExpand<T> {
+        expand.__expand_iter_method(context)
+    }
 }
 
 /// Expand type of [Sequence].
@@ -149,6 +174,19 @@ impl<T: CubeType> SequenceExpand<T> {
             .as_usize();
         self.values.borrow()[index].clone()
     }
+
+    /// Expand method of [iter](Sequence::iter), which copies the values so that iterating
+    /// doesn't drain the sequence.
+    pub fn __expand_iter_method(&self, _context: &mut CubeContext) -> SequenceExpand<T> {
+        SequenceExpand {
+            values: Rc::new(RefCell::new(self.values.borrow().clone())),
+        }
+    }
+
+    /// Expand method of [len](Sequence::len).
+    pub fn __expand_len_method(&self, _context: &mut CubeContext) -> ExpandElementTyped<u32> {
+        (self.values.borrow().len() as u32).into()
+    }
 }
 
 impl<T: CubeType> IntoRuntime for Sequence<T> {
@@ -156,3 +194,102 @@ impl<T: CubeType> IntoRuntime for Sequence<T> {
         unimplemented!("Sequence doesn't exist at compile time");
     }
 }
+
+impl<T: LaunchArgExpand> LaunchArgExpand for Sequence<T> {
+    fn expand(_builder: &mut KernelBuilder, _vectorization: Vectorization) -> SequenceExpand<T> {
+        panic!("The length of a sequence argument is only known from the kernel settings");
+    }
+
+    fn expand_with_settings(
+        builder: &mut KernelBuilder,
+        settings: &KernelSettings,
+        position: usize,
+    ) -> SequenceExpand<T> {
+        let vectorizations = settings
+            .vectorization_input_elements(position)
+            .expect("Sequence arguments should be configured");
+        let values = vectorizations
+            .into_iter()
+            .map(|vectorization| T::expand(builder, vectorization))
+            .collect();
+
+        SequenceExpand {
+            values: Rc::new(RefCell::new(values)),
+        }
+    }
+
+    fn expand_output_with_settings(
+        builder: &mut KernelBuilder,
+        settings: &KernelSettings,
+        position: usize,
+    ) -> SequenceExpand<T> {
+        let vectorizations = settings
+            .vectorization_output_elements(position)
+            .expect("Sequence arguments should be configured");
+        let values = vectorizations
+            .into_iter()
+            .map(|vectorization| T::expand_output(builder, vectorization))
+            .collect();
+
+        SequenceExpand {
+            values: Rc::new(RefCell::new(values)),
+        }
+    }
+}
+
+impl<T: LaunchArg> LaunchArg for Sequence<T> {
+    type RuntimeArg<'a, R: Runtime> = SequenceArg<'a, R, T>;
+}
+
+/// Runtime argument of a [sequence](Sequence), where every value is registered as its own
+/// kernel argument.
+///
+/// The number of values is part of the kernel settings, so launching with sequences of different
+/// lengths compiles different kernels.
+pub struct SequenceArg<'a, R: Runtime, T: LaunchArg> {
+    values: Vec<T::RuntimeArg<'a, R>>,
+}
+
+impl<'a, R: Runtime, T: LaunchArg> Default for SequenceArg<'a, R, T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, R: Runtime, T: LaunchArg> SequenceArg<'a, R, T> {
+    /// Create a new empty sequence argument.
+    pub fn new() -> Self {
+        Self { values: Vec::new() }
+    }
+
+    /// Push a new value into the sequence argument.
+    pub fn push(&mut self, value: T::RuntimeArg<'a, R>) {
+        self.values.push(value);
+    }
+}
+
+impl<'a, R: Runtime, T: LaunchArg> FromIterator<T::RuntimeArg<'a, R>> for SequenceArg<'a, R, T> {
+    fn from_iter<I: IntoIterator<Item = T::RuntimeArg<'a, R>>>(iter: I) -> Self {
+        Self {
+            values: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl<'a, R: Runtime, T: LaunchArg> ArgSettings<R> for SequenceArg<'a, R, T> {
+    fn register(&self, launcher: &mut KernelLauncher<R>) {
+        self.values
+            .iter()
+            .for_each(|value| value.register(launcher));
+    }
+
+    fn configure_input(&self, position: usize, settings: KernelSettings) -> KernelSettings {
+        let vectorizations = self.values.iter().map(|value| value.vectorization());
+        settings.vectorize_input_elements(position, vectorizations.collect())
+    }
+
+    fn configure_output(&self, position: usize, settings: KernelSettings) -> KernelSettings {
+        let vectorizations = self.values.iter().map(|value| value.vectorization());
+        settings.vectorize_output_elements(position, vectorizations.collect())
+    }
+}
@@ -1,7 +1,7 @@
 use super::{CubeContext, CubePrimitive, ExpandElement};
 use crate::prelude::ExpandElementTyped;
 use crate::{
-    ir::{Elem, InitOperator, Item, Operation, Subcube, UnaryOperator},
+    ir::{BinaryOperator, Elem, InitOperator, Item, Operation, Subcube, UIntKind, UnaryOperator},
     unexpanded,
 };
 
@@ -57,6 +57,143 @@ pub mod subcube_broadcast {
     }
 }
 
+/// Reads the value of the subcube unit at the given lane.
+///
+/// Unlike [subcube_broadcast()], the lane can be different for every unit.
+#[allow(unused_variables)]
+pub fn subcube_shuffle<E: CubePrimitive>(value: E, lane: u32) -> E {
+    unexpanded!()
+}
+
+/// Module containing the expand function for [subcube_shuffle()].
+pub mod subcube_shuffle {
+
+    use super::*;
+
+    /// Expand method of [subcube_shuffle()].
+    pub fn expand<E: CubePrimitive>(
+        context: &mut CubeContext,
+        value: ExpandElementTyped<E>,
+        lane: ExpandElementTyped<u32>,
+    ) -> ExpandElementTyped<E> {
+        let output = context.create_local(value.expand.item());
+        let out = *output;
+        let lhs = *value.expand;
+        let rhs = *lane.expand;
+
+        context.register(Operation::Subcube(Subcube::Shuffle(BinaryOperator {
+            lhs,
+            rhs,
+            out,
+        })));
+
+        output.into()
+    }
+}
+
+/// Reads the value of the subcube unit whose lane is the lane of the current unit xor the given
+/// mask, which is the building block of butterfly reductions.
+#[allow(unused_variables)]
+pub fn subcube_shuffle_xor<E: CubePrimitive>(value: E, mask: u32) -> E {
+    unexpanded!()
+}
+
+/// Module containing the expand function for [subcube_shuffle_xor()].
+pub mod subcube_shuffle_xor {
+
+    use super::*;
+
+    /// Expand method of [subcube_shuffle_xor()].
+    pub fn expand<E: CubePrimitive>(
+        context: &mut CubeContext,
+        value: ExpandElementTyped<E>,
+        mask: ExpandElementTyped<u32>,
+    ) -> ExpandElementTyped<E> {
+        let output = context.create_local(value.expand.item());
+        let out = *output;
+        let lhs = *value.expand;
+        let rhs = *mask.expand;
+
+        context.register(Operation::Subcube(Subcube::ShuffleXor(BinaryOperator {
+            lhs,
+            rhs,
+            out,
+        })));
+
+        output.into()
+    }
+}
+
+/// Reads the value of the subcube unit whose lane is the lane of the current unit minus the given
+/// delta.
+///
+/// The result is undefined for units without a source lane.
+#[allow(unused_variables)]
+pub fn subcube_shuffle_up<E: CubePrimitive>(value: E, delta: u32) -> E {
+    unexpanded!()
+}
+
+/// Module containing the expand function for [subcube_shuffle_up()].
+pub mod subcube_shuffle_up {
+
+    use super::*;
+
+    /// Expand method of [subcube_shuffle_up()].
+    pub fn expand<E: CubePrimitive>(
+        context: &mut CubeContext,
+        value: ExpandElementTyped<E>,
+        delta: ExpandElementTyped<u32>,
+    ) -> ExpandElementTyped<E> {
+        let output = context.create_local(value.expand.item());
+        let out = *output;
+        let lhs = *value.expand;
+        let rhs = *delta.expand;
+
+        context.register(Operation::Subcube(Subcube::ShuffleUp(BinaryOperator {
+            lhs,
+            rhs,
+            out,
+        })));
+
+        output.into()
+    }
+}
+
+/// Reads the value of the subcube unit whose lane is the lane of the current unit plus the given
+/// delta.
+///
+/// The result is undefined for units without a source lane.
+#[allow(unused_variables)]
+pub fn subcube_shuffle_down<E: CubePrimitive>(value: E, delta: u32) -> E {
+    unexpanded!()
+}
+
+/// Module containing the expand function for [subcube_shuffle_down()].
+pub mod subcube_shuffle_down {
+
+    use super::*;
+
+    /// Expand method of [subcube_shuffle_down()].
+    pub fn expand<E: CubePrimitive>(
+        context: &mut CubeContext,
+        value: ExpandElementTyped<E>,
+        delta: ExpandElementTyped<u32>,
+    ) -> ExpandElementTyped<E> {
+        let output = context.create_local(value.expand.item());
+        let out = *output;
+        let lhs = *value.expand;
+        let rhs = *delta.expand;
+
+        context.register(Operation::Subcube(Subcube::ShuffleDown(BinaryOperator {
+            lhs,
+            rhs,
+            out,
+        })));
+
+        output.into()
+    }
+}
+
 /// Perform a reduce sum operation across all units in a subcube.
 #[allow(unused_variables)]
 pub fn subcube_sum<E: CubePrimitive>(value: E) -> E {
@@ -87,6 +224,70 @@ pub mod subcube_sum {
     }
 }
 
+/// Perform an inclusive prefix sum across all units in a subcube.
+///
+/// Each unit receives the sum of the values of all units with a lower or equal subcube
+/// unit id.
+pub fn subcube_inclusive_sum<E: CubePrimitive>(_elem: E) -> E {
+    unexpanded!()
+}
+
+/// Module containing the expand function for [subcube_inclusive_sum()].
+pub mod subcube_inclusive_sum {
+    use super::*;
+
+    /// Expand method of [subcube_inclusive_sum()].
+    pub fn expand<E: CubePrimitive>(
+        context: &mut CubeContext,
+        elem: ExpandElementTyped<E>,
+    ) -> ExpandElementTyped<E> {
+        let elem: ExpandElement = elem.into();
+        let output = context.create_local(elem.item());
+
+        let out = *output;
+        let input = *elem;
+
+        context.register(Operation::Subcube(Subcube::InclusiveSum(UnaryOperator {
+            input,
+            out,
+        })));
+
+        output.into()
+    }
+}
+
+/// Perform an exclusive prefix sum across all units in a subcube.
+///
+/// Each unit receives the sum of the values of all units with a lower subcube unit id,
+/// the first unit receiving zero.
+pub fn subcube_exclusive_sum<E: CubePrimitive>(_elem: E) -> E {
+    unexpanded!()
+}
+
+/// Module containing the expand function for [subcube_exclusive_sum()].
+pub mod subcube_exclusive_sum {
+    use super::*;
+
+    /// Expand method of [subcube_exclusive_sum()].
+    pub fn expand<E: CubePrimitive>(
+        context: &mut CubeContext,
+        elem: ExpandElementTyped<E>,
+    ) -> ExpandElementTyped<E> {
+        let elem: ExpandElement = elem.into();
+        let output = context.create_local(elem.item());
+
+        let out = *output;
+        let input = *elem;
+
+        context.register(Operation::Subcube(Subcube::ExclusiveSum(UnaryOperator {
+            input,
+            out,
+        })));
+
+        output.into()
+    }
+}
+
 /// Perform a reduce prod operation across all units in a subcube.
 pub fn subcube_prod<E: CubePrimitive>(_elem: E) -> E {
     unexpanded!()
@@ -233,3 +434,36 @@ pub mod subcube_any {
         output.into()
     }
 }
+
+/// Returns a mask where the bit at the lane of each active unit of the subcube is set when its
+/// predicate is true.
+///
+/// Only the first 32 units of a subcube are represented in the mask.
+pub fn subcube_ballot(_elem: bool) -> u32 {
+    unexpanded!()
+}
+
+/// Module containing the expand function for [subcube_ballot()].
+pub mod subcube_ballot {
+
+    use super::*;
+
+    /// Expand method of [subcube_ballot()].
+    pub fn expand(
+        context: &mut CubeContext,
+        elem: ExpandElementTyped<bool>,
+    ) -> ExpandElementTyped<u32> {
+        let elem: ExpandElement = elem.into();
+        let output = context.create_local(Item::new(Elem::UInt(UIntKind::U32)));
+
+        let out = *output;
+        let input = *elem;
+
+        context.register(Operation::Subcube(Subcube::Ballot(UnaryOperator {
+            input,
+            out,
+        })));
+
+        output.into()
+    }
+}
@@ -1,5 +1,7 @@
 use crate::frontend::CubeContext;
 use crate::ir::Synchronization;
+
+pub use crate::ir::{MemoryOrdering, MemoryScope};
 // Among all backends, the memory order guarantee of WebGPU is the weakest
 // So Cubecl's memory order cannot be stronger than that of WebGPU
 
@@ -33,3 +35,33 @@ pub mod sync_storage {
         context.register(Synchronization::SyncStorage)
     }
 }
+
+/// Orders the memory accesses of the current unit as seen by the units of the given scope,
+/// without waiting for them like [sync_units()] does.
+///
+/// * An [acquire](MemoryOrdering::Acquire) fence prevents later accesses from being moved before
+///   the fence, typically after reading a flag set by another unit.
+///
+/// * A [release](MemoryOrdering::Release) fence prevents earlier accesses from being moved after
+///   the fence, typically before setting a flag read by another unit.
+///
+/// WebGPU has no standalone fences, so they are emulated with barriers there. All units of the
+/// cube must then reach the fence in uniform control flow, and only the
+/// [cube](MemoryScope::Cube) scope is supported. Check the
+/// [device fence feature](crate::Feature::DeviceFence) before using the device scope.
+#[allow(unused_variables)]
+pub fn fence(scope: MemoryScope, ordering: MemoryOrdering) {}
+
+pub mod fence {
+    use super::*;
+
+    pub fn expand(context: &mut CubeContext, scope: MemoryScope, ordering: MemoryOrdering) {
+        assert_ne!(
+            ordering,
+            MemoryOrdering::Relaxed,
+            "A relaxed fence doesn't order any memory access"
+        );
+
+        context.register(Synchronization::Fence { scope, ordering })
+    }
+}
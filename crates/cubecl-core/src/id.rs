@@ -1,4 +1,6 @@
+use crate::ir::KernelDefinition;
 use cubecl_runtime::ExecutionMode;
+use serde::Serialize;
 use std::any::{Any, TypeId};
 use std::fmt::Display;
 use std::hash::{DefaultHasher, Hash, Hasher};
@@ -49,6 +51,57 @@ impl KernelId {
     }
 }
 
+/// Kernel identifier that is stable across builds and processes.
+///
+/// Unlike [KernelId], which is based on the type of the kernel, it is computed from the content
+/// of the kernel, so it can be used as a key for persistent caches, remote compilation or to
+/// compare kernels between runs.
+#[derive(Hash, PartialEq, Eq, Clone, Debug)]
+pub struct StableKernelId {
+    hash: String,
+}
+
+impl StableKernelId {
+    /// Create a new [stable kernel id](StableKernelId) from the serialized
+    /// [kernel definition](KernelDefinition).
+    pub fn from_definition(definition: &KernelDefinition) -> Self {
+        let data =
+            serde_json::to_vec(definition).expect("Kernel definition should be serializable");
+        Self::from_bytes(&data)
+    }
+
+    /// Create a new [stable kernel id](StableKernelId) from a name and the serialized
+    /// configuration of the kernel.
+    ///
+    /// The name and the configuration should identify the generated code, which avoids
+    /// defining the kernel to compute its identifier.
+    pub fn from_name<C: Serialize>(name: &str, config: &C) -> Self {
+        let config = serde_json::to_vec(config).expect("Kernel config should be serializable");
+        let mut data = Vec::with_capacity(name.len() + 1 + config.len());
+        data.extend_from_slice(name.as_bytes());
+        data.push(0);
+        data.extend_from_slice(&config);
+        Self::from_bytes(&data)
+    }
+
+    fn from_bytes(data: &[u8]) -> Self {
+        Self {
+            hash: format!("{:x}", md5::compute(data)),
+        }
+    }
+
+    /// The hash of the kernel, as an hexadecimal string.
+    pub fn as_str(&self) -> &str {
+        &self.hash
+    }
+}
+
+impl Display for StableKernelId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.hash)
+    }
+}
+
 /// Extra information
 #[derive(Clone, Debug)]
 struct Info {
@@ -123,6 +176,7 @@ impl<T: 'static + PartialEq + Eq + Hash + core::fmt::Debug + Send + Sync> DynKey
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{prelude::KernelBuilder, CubeDim, KernelSettings};
     use std::collections::HashSet;
 
     #[test]
@@ -137,4 +191,27 @@ mod tests {
         assert!(set.contains(&value_1));
         assert!(!set.contains(&value_2));
     }
+
+    #[test]
+    pub fn stable_kernel_id_from_definition() {
+        let definition = |cube_dim_x| {
+            KernelBuilder::default()
+                .build(KernelSettings::default().cube_dim(CubeDim::new(cube_dim_x, 1, 1)))
+        };
+        let value_1 = StableKernelId::from_definition(&definition(16));
+        let value_2 = StableKernelId::from_definition(&definition(32));
+
+        assert_eq!(value_1, StableKernelId::from_definition(&definition(16)));
+        assert_ne!(value_1, value_2);
+    }
+
+    #[test]
+    pub fn stable_kernel_id_from_name() {
+        let value_1 = StableKernelId::from_name("kernel", &(1, "config"));
+        let value_2 = StableKernelId::from_name("kernel", &(2, "config"));
+
+        assert_eq!(value_1, StableKernelId::from_name("kernel", &(1, "config")));
+        assert_ne!(value_1, value_2);
+        assert_eq!(value_1.as_str().len(), 32);
+    }
 }
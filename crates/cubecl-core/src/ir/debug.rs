@@ -0,0 +1,60 @@
+use super::{Elem, Operation, Variable, Vectorization};
+use serde::{Deserialize, Serialize};
+
+/// Write debug output to the `debug` binding of the kernel, which is decoded and printed by the
+/// client after synchronization.
+///
+/// Each record starts with the index of its [format](DebugFormat), followed by every argument as
+/// its number of lanes and the 32 bits representation of each lane. Debug operations are only
+/// registered when `cubecl-core` is built with debug assertions, so they are no-ops in release
+/// builds, and they are compiled to nothing in unchecked mode.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[allow(missing_docs)]
+pub enum DebugOperation {
+    /// Write a record when the condition is false.
+    Assert { condition: Variable, format: u32 },
+    /// Write a record with the value of the arguments.
+    Print { format: u32, args: Vec<Variable> },
+}
+
+/// How to decode and print a debug record.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[allow(missing_docs)]
+pub struct DebugFormat {
+    pub kind: DebugKind,
+    pub message: String,
+    pub location: String,
+    pub args: Vec<Elem>,
+}
+
+/// The kind of a [debug operation](DebugOperation).
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[allow(missing_docs)]
+pub enum DebugKind {
+    Assert,
+    Print,
+}
+
+impl DebugOperation {
+    pub(crate) fn vectorize(&self, vectorization: Vectorization) -> Self {
+        match self {
+            DebugOperation::Assert { condition, format } => DebugOperation::Assert {
+                condition: condition.vectorize(vectorization),
+                format: *format,
+            },
+            DebugOperation::Print { format, args } => DebugOperation::Print {
+                format: *format,
+                args: args
+                    .iter()
+                    .map(|arg| arg.vectorize(vectorization))
+                    .collect(),
+            },
+        }
+    }
+}
+
+impl From<DebugOperation> for Operation {
+    fn from(value: DebugOperation) -> Self {
+        Self::Debug(value)
+    }
+}
@@ -0,0 +1,58 @@
+use super::{Operation, Scope, Variable, Vectorization};
+use serde::{Deserialize, Serialize};
+
+/// A function that isn't inlined in the kernel body, but compiled once and invoked with
+/// [calls](Call).
+///
+/// Scalar parameters are passed by value, while global arrays and shared memories are accessed
+/// directly in the body. Slices are passed along with their length.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[allow(missing_docs)]
+pub struct FunctionDefinition {
+    pub id: u32,
+    pub name: String,
+    pub params: Vec<Variable>,
+    pub output: Option<Variable>,
+    pub body: Scope,
+}
+
+/// Invoke a [function](FunctionDefinition) of the kernel.
+///
+/// The arguments are matched with the parameters of the function in order.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[allow(missing_docs)]
+pub struct Call {
+    pub function: u32,
+    pub args: Vec<Variable>,
+    pub out: Option<Variable>,
+}
+
+impl FunctionDefinition {
+    pub(crate) fn vectorize(&mut self, vectorization: Vectorization) {
+        self.body.vectorize(vectorization);
+        self.params
+            .iter_mut()
+            .for_each(|var| *var = var.vectorize(vectorization));
+        self.output = self.output.map(|var| var.vectorize(vectorization));
+    }
+}
+
+impl Call {
+    pub(crate) fn vectorize(&self, vectorization: Vectorization) -> Self {
+        Self {
+            function: self.function,
+            args: self
+                .args
+                .iter()
+                .map(|var| var.vectorize(vectorization))
+                .collect(),
+            out: self.out.map(|var| var.vectorize(vectorization)),
+        }
+    }
+}
+
+impl From<Call> for Operation {
+    fn from(value: Call) -> Self {
+        Self::Call(value)
+    }
+}
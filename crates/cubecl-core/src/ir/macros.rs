@@ -1,6 +1,6 @@
 use crate::ir::ConstantScalarValue;
 
-use super::{FloatKind, IntKind, Variable};
+use super::{FloatKind, IntKind, UIntKind, Variable};
 
 #[macro_export(local_inner_macros)]
 /// Cube Pseudo Assembly.
@@ -314,6 +314,168 @@ macro_rules! cpa {
             cpa!(unary $input, $out)
         ));
     };
+    // out = tan(input)
+    ($scope:expr, $out:ident = tan($input:expr)) => {
+        $scope.register($crate::ir::Operator::Tan(
+            cpa!(unary $input, $out)
+        ));
+    };
+    // out = asin(input)
+    ($scope:expr, $out:ident = asin($input:expr)) => {
+        $scope.register($crate::ir::Operator::Asin(
+            cpa!(unary $input, $out)
+        ));
+    };
+    // out = acos(input)
+    ($scope:expr, $out:ident = acos($input:expr)) => {
+        $scope.register($crate::ir::Operator::Acos(
+            cpa!(unary $input, $out)
+        ));
+    };
+    // out = atan(input)
+    ($scope:expr, $out:ident = atan($input:expr)) => {
+        $scope.register($crate::ir::Operator::Atan(
+            cpa!(unary $input, $out)
+        ));
+    };
+    // out = atan2(lhs, rhs)
+    ($scope:expr, $out:ident = atan2($lhs:expr, $rhs:expr)) => {
+        $scope.register($crate::ir::Operator::Atan2(
+            cpa!(binary $lhs, $rhs, $out)
+        ));
+    };
+    // out = sinh(input)
+    ($scope:expr, $out:ident = sinh($input:expr)) => {
+        $scope.register($crate::ir::Operator::Sinh(
+            cpa!(unary $input, $out)
+        ));
+    };
+    // out = cosh(input)
+    ($scope:expr, $out:ident = cosh($input:expr)) => {
+        $scope.register($crate::ir::Operator::Cosh(
+            cpa!(unary $input, $out)
+        ));
+    };
+    // out = exp2(input)
+    ($scope:expr, $out:ident = exp2($input:expr)) => {
+        $scope.register($crate::ir::Operator::Exp2(
+            cpa!(unary $input, $out)
+        ));
+    };
+    // out = log2(input)
+    ($scope:expr, $out:ident = log2($input:expr)) => {
+        $scope.register($crate::ir::Operator::Log2(
+            cpa!(unary $input, $out)
+        ));
+    };
+    // out = hypot(lhs, rhs)
+    ($scope:expr, $out:ident = hypot($lhs:expr, $rhs:expr)) => {
+        $scope.register($crate::ir::Operator::Hypot(
+            cpa!(binary $lhs, $rhs, $out)
+        ));
+    };
+    // out = trunc(input)
+    ($scope:expr, $out:ident = trunc($input:expr)) => {
+        $scope.register($crate::ir::Operator::Trunc(
+            cpa!(unary $input, $out)
+        ));
+    };
+    // out = fract(input)
+    ($scope:expr, $out:ident = fract($input:expr)) => {
+        $scope.register($crate::ir::Operator::Fract(
+            cpa!(unary $input, $out)
+        ));
+    };
+    // out = sign(input)
+    ($scope:expr, $out:ident = sign($input:expr)) => {
+        $scope.register($crate::ir::Operator::Sign(
+            cpa!(unary $input, $out)
+        ));
+    };
+    // out = is_nan(input)
+    ($scope:expr, $out:ident = is_nan($input:expr)) => {
+        $scope.register($crate::ir::Operator::IsNan(
+            cpa!(unary $input, $out)
+        ));
+    };
+    // out = is_inf(input)
+    ($scope:expr, $out:ident = is_inf($input:expr)) => {
+        $scope.register($crate::ir::Operator::IsInf(
+            cpa!(unary $input, $out)
+        ));
+    };
+    // out = is_finite(input)
+    ($scope:expr, $out:ident = is_finite($input:expr)) => {
+        $scope.register($crate::ir::Operator::IsFinite(
+            cpa!(unary $input, $out)
+        ));
+    };
+    // out = copysign(lhs, rhs)
+    ($scope:expr, $out:ident = copysign($lhs:expr, $rhs:expr)) => {
+        $scope.register($crate::ir::Operator::CopySign(
+            cpa!(binary $lhs, $rhs, $out)
+        ));
+    };
+    // out = next_after(lhs, rhs)
+    ($scope:expr, $out:ident = next_after($lhs:expr, $rhs:expr)) => {
+        $scope.register($crate::ir::Operator::NextAfter(
+            cpa!(binary $lhs, $rhs, $out)
+        ));
+    };
+    // out = count_ones(input)
+    ($scope:expr, $out:ident = count_ones($input:expr)) => {
+        $scope.register($crate::ir::Operator::CountOnes(
+            cpa!(unary $input, $out)
+        ));
+    };
+    // out = leading_zeros(input)
+    ($scope:expr, $out:ident = leading_zeros($input:expr)) => {
+        $scope.register($crate::ir::Operator::LeadingZeros(
+            cpa!(unary $input, $out)
+        ));
+    };
+    // out = trailing_zeros(input)
+    ($scope:expr, $out:ident = trailing_zeros($input:expr)) => {
+        $scope.register($crate::ir::Operator::TrailingZeros(
+            cpa!(unary $input, $out)
+        ));
+    };
+    // out = reverse_bits(input)
+    ($scope:expr, $out:ident = reverse_bits($input:expr)) => {
+        $scope.register($crate::ir::Operator::ReverseBits(
+            cpa!(unary $input, $out)
+        ));
+    };
+    // out = mul_hi(lhs, rhs)
+    ($scope:expr, $out:ident = mul_hi($lhs:expr, $rhs:expr)) => {
+        $scope.register($crate::ir::Operator::MulHi(
+            cpa!(binary $lhs, $rhs, $out)
+        ));
+    };
+    // out = dot(lhs, rhs)
+    ($scope:expr, $out:ident = dot($lhs:expr, $rhs:expr)) => {
+        $scope.register($crate::ir::Operator::Dot(
+            cpa!(binary $lhs, $rhs, $out)
+        ));
+    };
+    // out = reduce_sum(input)
+    ($scope:expr, $out:ident = reduce_sum($input:expr)) => {
+        $scope.register($crate::ir::Operator::ReduceSum(
+            cpa!(unary $input, $out)
+        ));
+    };
+    // out = reduce_max(input)
+    ($scope:expr, $out:ident = reduce_max($input:expr)) => {
+        $scope.register($crate::ir::Operator::ReduceMax(
+            cpa!(unary $input, $out)
+        ));
+    };
+    // out = reduce_min(input)
+    ($scope:expr, $out:ident = reduce_min($input:expr)) => {
+        $scope.register($crate::ir::Operator::ReduceMin(
+            cpa!(unary $input, $out)
+        ));
+    };
     // out = input
     ($scope:expr, $out:ident = $input:ident) => {
         $scope.register($crate::ir::Operator::Assign(
@@ -322,7 +484,7 @@ macro_rules! cpa {
     };
     // out = vec4(a, b, c, d)
     ($scope:expr, $out:ident = vec4($a:ident,$b:ident,$c:ident,$d:ident)) => {
-        let i = $scope.zero(Elem::UInt);
+        let i = $scope.zero(Elem::UInt(UIntKind::U32));
         cpa!($scope, $out[i] = $a);
         cpa!($scope, i = i + 1u32);
         cpa!($scope, $out[i] = $b);
@@ -421,6 +583,18 @@ impl From<bool> for Variable {
     }
 }
 
+impl From<i8> for Variable {
+    fn from(value: i8) -> Self {
+        Variable::ConstantScalar(ConstantScalarValue::Int(value as i64, IntKind::I8))
+    }
+}
+
+impl From<i16> for Variable {
+    fn from(value: i16) -> Self {
+        Variable::ConstantScalar(ConstantScalarValue::Int(value as i64, IntKind::I16))
+    }
+}
+
 impl From<i32> for Variable {
     fn from(value: i32) -> Self {
         Variable::ConstantScalar(ConstantScalarValue::Int(value as i64, IntKind::I32))
@@ -445,15 +619,33 @@ impl From<f64> for Variable {
     }
 }
 
+impl From<u8> for Variable {
+    fn from(value: u8) -> Self {
+        Variable::ConstantScalar(ConstantScalarValue::UInt(value as u64, UIntKind::U8))
+    }
+}
+
+impl From<u16> for Variable {
+    fn from(value: u16) -> Self {
+        Variable::ConstantScalar(ConstantScalarValue::UInt(value as u64, UIntKind::U16))
+    }
+}
+
 impl From<u32> for Variable {
     fn from(value: u32) -> Self {
-        Variable::ConstantScalar(ConstantScalarValue::UInt(value as u64))
+        Variable::ConstantScalar(ConstantScalarValue::UInt(value as u64, UIntKind::U32))
+    }
+}
+
+impl From<u64> for Variable {
+    fn from(value: u64) -> Self {
+        Variable::ConstantScalar(ConstantScalarValue::UInt(value, UIntKind::U64))
     }
 }
 
 impl From<usize> for Variable {
     fn from(value: usize) -> Self {
-        Variable::ConstantScalar(ConstantScalarValue::UInt(value as u64))
+        Variable::ConstantScalar(ConstantScalarValue::UInt(value as u64, UIntKind::U32))
     }
 }
 
@@ -1,5 +1,7 @@
 mod branch;
 mod cmma;
+mod debug;
+mod function;
 mod kernel;
 mod macros;
 mod operation;
@@ -13,6 +15,8 @@ mod vectorization;
 
 pub use branch::*;
 pub use cmma::*;
+pub use debug::*;
+pub use function::*;
 pub use kernel::*;
 pub use operation::*;
 pub use procedure::*;
@@ -1,4 +1,7 @@
-use super::{Branch, CoopMma, Procedure, Subcube, Synchronization, Variable};
+use super::{
+    Branch, Call, CoopMma, DebugOperation, MemoryOrdering, Procedure, Subcube, Synchronization,
+    Variable,
+};
 use serde::{Deserialize, Serialize};
 
 /// All operations that can be used in a GPU compute shader.
@@ -19,6 +22,8 @@ pub enum Operation {
     Synchronization(Synchronization),
     Subcube(Subcube),
     CoopMma(CoopMma),
+    Call(Call),
+    Debug(DebugOperation),
 }
 
 /// All operators that can be used in a GPU compute shader.
@@ -43,6 +48,29 @@ pub enum Operator {
     Floor(UnaryOperator),
     Ceil(UnaryOperator),
     Erf(UnaryOperator),
+    Tan(UnaryOperator),
+    Asin(UnaryOperator),
+    Acos(UnaryOperator),
+    Atan(UnaryOperator),
+    Atan2(BinaryOperator),
+    Sinh(UnaryOperator),
+    Cosh(UnaryOperator),
+    Exp2(UnaryOperator),
+    Log2(UnaryOperator),
+    Hypot(BinaryOperator),
+    Trunc(UnaryOperator),
+    Fract(UnaryOperator),
+    Sign(UnaryOperator),
+    IsNan(UnaryOperator),
+    IsInf(UnaryOperator),
+    IsFinite(UnaryOperator),
+    CopySign(BinaryOperator),
+    NextAfter(BinaryOperator),
+    CountOnes(UnaryOperator),
+    LeadingZeros(UnaryOperator),
+    TrailingZeros(UnaryOperator),
+    ReverseBits(UnaryOperator),
+    MulHi(BinaryOperator),
     Recip(UnaryOperator),
     Equal(BinaryOperator),
     NotEqual(BinaryOperator),
@@ -58,6 +86,7 @@ pub enum Operator {
     UncheckedIndex(BinaryOperator),
     IndexAssign(BinaryOperator),
     UncheckedIndexAssign(BinaryOperator),
+    CopyAsync(UnaryOperator),
     And(BinaryOperator),
     Or(BinaryOperator),
     Not(UnaryOperator),
@@ -73,6 +102,8 @@ pub enum Operator {
     Bitcast(UnaryOperator),
     AtomicLoad(UnaryOperator),
     AtomicStore(UnaryOperator),
+    AtomicLoadOrdered(AtomicOrderedOperator),
+    AtomicStoreOrdered(AtomicOrderedOperator),
     AtomicSwap(BinaryOperator),
     AtomicAdd(BinaryOperator),
     AtomicSub(BinaryOperator),
@@ -83,6 +114,12 @@ pub enum Operator {
     AtomicXor(BinaryOperator),
     AtomicCompareAndSwap(CompareAndSwapOperator),
     Normalize(UnaryOperator),
+    Dot(BinaryOperator),
+    ReduceSum(UnaryOperator),
+    ReduceMax(UnaryOperator),
+    ReduceMin(UnaryOperator),
+    Swizzle(SwizzleOperator),
+    InsertLane(InsertLaneOperator),
 }
 
 /// All metadata that can be access in a shader.
@@ -155,6 +192,15 @@ pub struct CompareAndSwapOperator {
     pub out: Variable,
 }
 
+/// An atomic load or store with an explicit memory ordering.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[allow(missing_docs)]
+pub struct AtomicOrderedOperator {
+    pub input: Variable,
+    pub ordering: MemoryOrdering,
+    pub out: Variable,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[allow(missing_docs)]
 pub struct ReadGlobalOperator {
@@ -178,6 +224,25 @@ pub struct FmaOperator {
     pub out: Variable,
 }
 
+/// Builds a value from the given lanes of the input, the output having one lane per index.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[allow(missing_docs)]
+pub struct SwizzleOperator {
+    pub input: Variable,
+    pub lanes: Vec<u32>,
+    pub out: Variable,
+}
+
+/// Copies the input with the given lane replaced by a scalar value.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[allow(missing_docs)]
+pub struct InsertLaneOperator {
+    pub input: Variable,
+    pub lane: u32,
+    pub value: Variable,
+    pub out: Variable,
+}
+
 impl From<Operator> for Operation {
     fn from(val: Operator) -> Self {
         Operation::Operator(val)
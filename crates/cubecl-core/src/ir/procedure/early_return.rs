@@ -1,4 +1,4 @@
-use crate::ir::{macros::cpa, Branch, Elem, Item, Scope, Variable, Vectorization};
+use crate::ir::{macros::cpa, Branch, Elem, Item, Scope, UIntKind, Variable, Vectorization};
 use serde::{Deserialize, Serialize};
 
 /// Perform a check bound on the index (lhs) of value (rhs)
@@ -15,7 +15,7 @@ impl EarlyReturn {
         let variable = self.global;
         let index = self.position;
 
-        let array_len = scope.create_local(Item::new(Elem::UInt));
+        let array_len = scope.create_local(Item::new(Elem::UInt(UIntKind::U32)));
         let outside_bound = scope.create_local(Item::new(Elem::Bool));
 
         cpa!(scope, array_len = len(variable));
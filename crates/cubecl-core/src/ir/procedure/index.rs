@@ -1,4 +1,4 @@
-use crate::ir::{macros::cpa, Elem, Item, Scope, Variable, Vectorization};
+use crate::ir::{macros::cpa, Elem, Item, Scope, UIntKind, Variable, Vectorization};
 use serde::{Deserialize, Serialize};
 
 /// Perform a check bound on the index (lhs) of value (rhs)
@@ -16,7 +16,7 @@ impl CheckedIndex {
         let lhs = self.lhs;
         let rhs = self.rhs;
         let out = self.out;
-        let array_len = scope.create_local(Item::new(Elem::UInt));
+        let array_len = scope.create_local(Item::new(Elem::UInt(UIntKind::U32)));
         let inside_bound = scope.create_local(Item::new(Elem::Bool));
 
         cpa!(scope, array_len = len(lhs));
@@ -53,7 +53,7 @@ impl CheckedIndexAssign {
         let lhs = self.lhs;
         let rhs = self.rhs;
         let out = self.out;
-        let array_len = scope.create_local(Item::new(Elem::UInt));
+        let array_len = scope.create_local(Item::new(Elem::UInt(UIntKind::U32)));
         let inside_bound = scope.create_local(Item::new(Elem::Bool));
 
         cpa!(scope, array_len = len(out));
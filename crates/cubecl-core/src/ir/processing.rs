@@ -1,4 +1,4 @@
-use super::{Branch, CoopMma, Elem, Metadata, Operation, Operator, Procedure, Variable};
+use super::{Branch, CoopMma, Elem, Metadata, Operation, Operator, Procedure, UIntKind, Variable};
 use crate::ir::ReadGlobalWithLayout;
 
 /// Information necessary when compiling a scope.
@@ -88,6 +88,74 @@ impl ScopeProcessing {
                 Operator::Erf(op) => {
                     sanitize_constant_scalar_ref_var(&mut op.input, &op.out);
                 }
+                Operator::Tan(op) => {
+                    sanitize_constant_scalar_ref_var(&mut op.input, &op.out);
+                }
+                Operator::Asin(op) => {
+                    sanitize_constant_scalar_ref_var(&mut op.input, &op.out);
+                }
+                Operator::Acos(op) => {
+                    sanitize_constant_scalar_ref_var(&mut op.input, &op.out);
+                }
+                Operator::Atan(op) => {
+                    sanitize_constant_scalar_ref_var(&mut op.input, &op.out);
+                }
+                Operator::Atan2(op) => {
+                    sanitize_constant_scalar_ref_var(&mut op.lhs, &op.out);
+                    sanitize_constant_scalar_ref_var(&mut op.rhs, &op.out);
+                }
+                Operator::Sinh(op) => {
+                    sanitize_constant_scalar_ref_var(&mut op.input, &op.out);
+                }
+                Operator::Cosh(op) => {
+                    sanitize_constant_scalar_ref_var(&mut op.input, &op.out);
+                }
+                Operator::Exp2(op) => {
+                    sanitize_constant_scalar_ref_var(&mut op.input, &op.out);
+                }
+                Operator::Log2(op) => {
+                    sanitize_constant_scalar_ref_var(&mut op.input, &op.out);
+                }
+                Operator::Hypot(op) => {
+                    sanitize_constant_scalar_ref_var(&mut op.lhs, &op.out);
+                    sanitize_constant_scalar_ref_var(&mut op.rhs, &op.out);
+                }
+                Operator::Trunc(op) => {
+                    sanitize_constant_scalar_ref_var(&mut op.input, &op.out);
+                }
+                Operator::Fract(op) => {
+                    sanitize_constant_scalar_ref_var(&mut op.input, &op.out);
+                }
+                Operator::Sign(op) => {
+                    sanitize_constant_scalar_ref_var(&mut op.input, &op.out);
+                }
+                Operator::IsNan(_) => {}
+                Operator::IsInf(_) => {}
+                Operator::IsFinite(_) => {}
+                Operator::CopySign(op) => {
+                    sanitize_constant_scalar_ref_var(&mut op.lhs, &op.out);
+                    sanitize_constant_scalar_ref_var(&mut op.rhs, &op.out);
+                }
+                Operator::NextAfter(op) => {
+                    sanitize_constant_scalar_ref_var(&mut op.lhs, &op.out);
+                    sanitize_constant_scalar_ref_var(&mut op.rhs, &op.out);
+                }
+                Operator::CountOnes(op) => {
+                    sanitize_constant_scalar_ref_var(&mut op.input, &op.out);
+                }
+                Operator::LeadingZeros(op) => {
+                    sanitize_constant_scalar_ref_var(&mut op.input, &op.out);
+                }
+                Operator::TrailingZeros(op) => {
+                    sanitize_constant_scalar_ref_var(&mut op.input, &op.out);
+                }
+                Operator::ReverseBits(op) => {
+                    sanitize_constant_scalar_ref_var(&mut op.input, &op.out);
+                }
+                Operator::MulHi(op) => {
+                    sanitize_constant_scalar_ref_var(&mut op.lhs, &op.out);
+                    sanitize_constant_scalar_ref_var(&mut op.rhs, &op.out);
+                }
                 Operator::Recip(op) => {
                     sanitize_constant_scalar_ref_var(&mut op.input, &op.out);
                 }
@@ -129,25 +197,26 @@ impl ScopeProcessing {
                 }
                 Operator::Slice(op) => {
                     sanitize_constant_scalar_ref_var(&mut op.input, &op.out);
-                    sanitize_constant_scalar_ref_elem(&mut op.start, Elem::UInt);
-                    sanitize_constant_scalar_ref_elem(&mut op.end, Elem::UInt);
+                    sanitize_constant_scalar_ref_elem(&mut op.start, Elem::UInt(UIntKind::U32));
+                    sanitize_constant_scalar_ref_elem(&mut op.end, Elem::UInt(UIntKind::U32));
                 }
                 Operator::Index(op) => {
                     sanitize_constant_scalar_ref_var(&mut op.lhs, &op.out);
-                    sanitize_constant_scalar_ref_elem(&mut op.rhs, Elem::UInt);
+                    sanitize_constant_scalar_ref_elem(&mut op.rhs, Elem::UInt(UIntKind::U32));
                 }
                 Operator::UncheckedIndex(op) => {
                     sanitize_constant_scalar_ref_var(&mut op.lhs, &op.out);
-                    sanitize_constant_scalar_ref_elem(&mut op.rhs, Elem::UInt);
+                    sanitize_constant_scalar_ref_elem(&mut op.rhs, Elem::UInt(UIntKind::U32));
                 }
                 Operator::IndexAssign(op) => {
-                    sanitize_constant_scalar_ref_elem(&mut op.lhs, Elem::UInt);
+                    sanitize_constant_scalar_ref_elem(&mut op.lhs, Elem::UInt(UIntKind::U32));
                     sanitize_constant_scalar_ref_var(&mut op.rhs, &op.out);
                 }
                 Operator::UncheckedIndexAssign(op) => {
-                    sanitize_constant_scalar_ref_elem(&mut op.lhs, Elem::UInt);
+                    sanitize_constant_scalar_ref_elem(&mut op.lhs, Elem::UInt(UIntKind::U32));
                     sanitize_constant_scalar_ref_var(&mut op.rhs, &op.out);
                 }
+                Operator::CopyAsync(_) => {}
                 Operator::And(op) => {
                     sanitize_constant_scalar_ref_var(&mut op.lhs, &op.rhs);
                     sanitize_constant_scalar_ref_var(&mut op.rhs, &op.lhs);
@@ -195,6 +264,8 @@ impl ScopeProcessing {
                 Operator::Bitcast(_) => {}
                 Operator::AtomicLoad(_) => {}
                 Operator::AtomicStore(_) => {}
+                Operator::AtomicLoadOrdered(_) => {}
+                Operator::AtomicStoreOrdered(_) => {}
                 Operator::AtomicSwap(op) => {
                     sanitize_constant_scalar_ref_var(&mut op.rhs, &op.out);
                 }
@@ -226,13 +297,24 @@ impl ScopeProcessing {
                 Operator::Normalize(op) => {
                     sanitize_constant_scalar_ref_var(&mut op.input, &op.out);
                 }
+                Operator::Dot(op) => {
+                    sanitize_constant_scalar_ref_var(&mut op.lhs, &op.rhs);
+                    sanitize_constant_scalar_ref_var(&mut op.rhs, &op.lhs);
+                }
+                Operator::ReduceSum(_) => {}
+                Operator::ReduceMax(_) => {}
+                Operator::ReduceMin(_) => {}
+                Operator::Swizzle(_) => {}
+                Operator::InsertLane(op) => {
+                    sanitize_constant_scalar_ref_elem(&mut op.value, op.out.item().elem());
+                }
             },
             Operation::Metadata(op) => match op {
                 Metadata::Stride { dim, .. } => {
-                    sanitize_constant_scalar_ref_elem(dim, Elem::UInt);
+                    sanitize_constant_scalar_ref_elem(dim, Elem::UInt(UIntKind::U32));
                 }
                 Metadata::Shape { dim, .. } => {
-                    sanitize_constant_scalar_ref_elem(dim, Elem::UInt);
+                    sanitize_constant_scalar_ref_elem(dim, Elem::UInt(UIntKind::U32));
                 }
                 Metadata::Length { .. } => {
                     // Nothing to do
@@ -246,11 +328,18 @@ impl ScopeProcessing {
                 Branch::IfElse(op) => {
                     sanitize_constant_scalar_ref_elem(&mut op.cond, Elem::Bool);
                 }
+                Branch::Switch(op) => {
+                    for (values, _) in op.cases.iter_mut() {
+                        for value in values.iter_mut() {
+                            sanitize_constant_scalar_ref_var(value, &op.value);
+                        }
+                    }
+                }
                 Branch::RangeLoop(op) => {
                     sanitize_constant_scalar_ref_var(&mut op.end, &op.start);
                     sanitize_constant_scalar_ref_var(&mut op.i, &op.start);
                     if let Some(step) = &mut op.step {
-                        sanitize_constant_scalar_ref_elem(step, Elem::UInt);
+                        sanitize_constant_scalar_ref_elem(step, Elem::UInt(UIntKind::U32));
                     }
                 }
                 _ => {
@@ -269,18 +358,24 @@ impl ScopeProcessing {
                 }
                 CoopMma::Load { mat, value, stride } => {
                     sanitize_constant_scalar_ref_var(value, mat);
-                    sanitize_constant_scalar_ref_elem(stride, Elem::UInt);
+                    sanitize_constant_scalar_ref_elem(stride, Elem::UInt(UIntKind::U32));
                 }
                 CoopMma::Execute { .. } => {
                     // Nothing to do.
                 }
                 CoopMma::Store { stride, .. } => {
-                    sanitize_constant_scalar_ref_elem(stride, Elem::UInt);
+                    sanitize_constant_scalar_ref_elem(stride, Elem::UInt(UIntKind::U32));
                 }
             },
             Operation::Procedure(_) => {
                 // Nothing to do since they are re-processed.
             }
+            Operation::Call(_) => {
+                // Nothing to do since arguments match the parameters of the function.
+            }
+            Operation::Debug(_) => {
+                // Nothing to do since arguments are written as 32 bits words.
+            }
         });
         self
     }
@@ -378,7 +473,7 @@ fn sanitize_constant_scalar_ref_elem(var: &mut Variable, elem: Elem) {
             *var = match scalar {
                 super::ConstantScalarValue::Int(val, _) => elem.constant_from_i64(*val),
                 super::ConstantScalarValue::Float(val, _) => elem.constant_from_f64(*val),
-                super::ConstantScalarValue::UInt(val) => elem.constant_from_u64(*val),
+                super::ConstantScalarValue::UInt(val, _) => elem.constant_from_u64(*val),
                 super::ConstantScalarValue::Bool(val) => elem.constant_from_bool(*val),
             };
         }
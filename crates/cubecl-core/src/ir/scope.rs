@@ -2,8 +2,8 @@ use crate::ir::ConstantScalarValue;
 
 use super::{
     cpa, processing::ScopeProcessing, Elem, IndexOffsetGlobalWithLayout, Item, Matrix, Operation,
-    Operator, Procedure, ReadGlobal, ReadGlobalWithLayout, UnaryOperator, Variable, Vectorization,
-    WriteGlobal,
+    Operator, Procedure, ReadGlobal, ReadGlobalWithLayout, UIntKind, UnaryOperator, Variable,
+    Vectorization, WriteGlobal,
 };
 use serde::{Deserialize, Serialize};
 
@@ -80,11 +80,13 @@ impl Scope {
     {
         let item: Item = item.into();
         let value = match item.elem() {
-            Elem::Float(kind) => ConstantScalarValue::Float(value.to_f64().unwrap(), kind),
+            Elem::Float(kind) | Elem::AtomicFloat(kind) => {
+                ConstantScalarValue::Float(value.to_f64().unwrap(), kind)
+            }
             Elem::Int(kind) => ConstantScalarValue::Int(value.to_i64().unwrap(), kind),
             Elem::AtomicInt(kind) => ConstantScalarValue::Int(value.to_i64().unwrap(), kind),
-            Elem::UInt => ConstantScalarValue::UInt(value.to_u64().unwrap()),
-            Elem::AtomicUInt => ConstantScalarValue::UInt(value.to_u64().unwrap()),
+            Elem::UInt(kind) => ConstantScalarValue::UInt(value.to_u64().unwrap(), kind),
+            Elem::AtomicUInt => ConstantScalarValue::UInt(value.to_u64().unwrap(), UIntKind::U32),
             Elem::Bool => ConstantScalarValue::Bool(value.to_u32().unwrap() == 1),
         };
         let local = self.create_local(item);
@@ -407,7 +409,7 @@ impl Scope {
     ) -> Variable {
         let item_global = match item.elem() {
             Elem::Bool => Item {
-                elem: Elem::UInt,
+                elem: Elem::UInt(UIntKind::U32),
                 vectorization: item.vectorization,
             },
             _ => item,
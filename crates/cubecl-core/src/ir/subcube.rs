@@ -15,4 +15,11 @@ pub enum Subcube {
     Prod(UnaryOperator),
     Min(UnaryOperator),
     Max(UnaryOperator),
+    InclusiveSum(UnaryOperator),
+    ExclusiveSum(UnaryOperator),
+    Shuffle(BinaryOperator),
+    ShuffleXor(BinaryOperator),
+    ShuffleUp(BinaryOperator),
+    ShuffleDown(BinaryOperator),
+    Ballot(UnaryOperator),
 }
@@ -7,4 +7,51 @@ pub enum Synchronization {
     // Synchronizize units in a cube.
     SyncUnits,
     SyncStorage,
+    // Order the memory accesses of a unit, without waiting for the other units.
+    Fence {
+        scope: MemoryScope,
+        ordering: MemoryOrdering,
+    },
+    // Close the batch of asynchronous copies issued by a unit since the last commit.
+    PipelineCommit,
+    // Wait until at most `pending` committed batches of a unit are still in flight.
+    PipelineWait {
+        pending: u32,
+    },
+}
+
+/// The units that observe the ordering of memory accesses.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum MemoryScope {
+    /// Only the units of the same cube.
+    Cube,
+    /// All units of the device.
+    ///
+    /// Fences with this scope require the [device fence feature](crate::Feature::DeviceFence).
+    Device,
+}
+
+/// How memory accesses are ordered around an atomic operation or a fence.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum MemoryOrdering {
+    /// No ordering, only the atomicity of the operation is guaranteed.
+    Relaxed,
+    /// Later memory accesses can't be moved before the operation.
+    Acquire,
+    /// Earlier memory accesses can't be moved after the operation.
+    Release,
+    /// Both [acquire](MemoryOrdering::Acquire) and [release](MemoryOrdering::Release).
+    AcquireRelease,
+}
+
+impl MemoryOrdering {
+    /// Whether later memory accesses can't be moved before the operation.
+    pub fn is_acquire(&self) -> bool {
+        matches!(self, Self::Acquire | Self::AcquireRelease)
+    }
+
+    /// Whether earlier memory accesses can't be moved after the operation.
+    pub fn is_release(&self) -> bool {
+        matches!(self, Self::Release | Self::AcquireRelease)
+    }
 }
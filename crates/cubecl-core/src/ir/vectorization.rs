@@ -1,8 +1,9 @@
 use std::num::NonZero;
 
 use super::{
-    BinaryOperator, ClampOperator, CompareAndSwapOperator, FmaOperator, InitOperator, Item,
-    Operation, Operator, SliceOperator, Subcube, UnaryOperator, Variable,
+    AtomicOrderedOperator, BinaryOperator, ClampOperator, CompareAndSwapOperator, FmaOperator,
+    InitOperator, InsertLaneOperator, Item, Operation, Operator, SliceOperator, Subcube,
+    SwizzleOperator, UnaryOperator, Variable,
 };
 
 pub type Vectorization = Option<NonZero<u8>>;
@@ -25,6 +26,8 @@ impl Operation {
             Operation::CoopMma(_) => panic!(
                 "Cooperative matrix-multiply and accumulate doesn't support vectorization."
             ),
+            Operation::Call(op) => Operation::Call(op.vectorize(vectorization)),
+            Operation::Debug(op) => Operation::Debug(op.vectorize(vectorization)),
         }
     }
 }
@@ -54,6 +57,29 @@ impl Operator {
             Operator::Powf(op) => Operator::Powf(op.vectorize(vectorization)),
             Operator::Sqrt(op) => Operator::Sqrt(op.vectorize(vectorization)),
             Operator::Erf(op) => Operator::Erf(op.vectorize(vectorization)),
+            Operator::Tan(op) => Operator::Tan(op.vectorize(vectorization)),
+            Operator::Asin(op) => Operator::Asin(op.vectorize(vectorization)),
+            Operator::Acos(op) => Operator::Acos(op.vectorize(vectorization)),
+            Operator::Atan(op) => Operator::Atan(op.vectorize(vectorization)),
+            Operator::Atan2(op) => Operator::Atan2(op.vectorize(vectorization)),
+            Operator::Sinh(op) => Operator::Sinh(op.vectorize(vectorization)),
+            Operator::Cosh(op) => Operator::Cosh(op.vectorize(vectorization)),
+            Operator::Exp2(op) => Operator::Exp2(op.vectorize(vectorization)),
+            Operator::Log2(op) => Operator::Log2(op.vectorize(vectorization)),
+            Operator::Hypot(op) => Operator::Hypot(op.vectorize(vectorization)),
+            Operator::Trunc(op) => Operator::Trunc(op.vectorize(vectorization)),
+            Operator::Fract(op) => Operator::Fract(op.vectorize(vectorization)),
+            Operator::Sign(op) => Operator::Sign(op.vectorize(vectorization)),
+            Operator::IsNan(op) => Operator::IsNan(op.vectorize(vectorization)),
+            Operator::IsInf(op) => Operator::IsInf(op.vectorize(vectorization)),
+            Operator::IsFinite(op) => Operator::IsFinite(op.vectorize(vectorization)),
+            Operator::CopySign(op) => Operator::CopySign(op.vectorize(vectorization)),
+            Operator::NextAfter(op) => Operator::NextAfter(op.vectorize(vectorization)),
+            Operator::CountOnes(op) => Operator::CountOnes(op.vectorize(vectorization)),
+            Operator::LeadingZeros(op) => Operator::LeadingZeros(op.vectorize(vectorization)),
+            Operator::TrailingZeros(op) => Operator::TrailingZeros(op.vectorize(vectorization)),
+            Operator::ReverseBits(op) => Operator::ReverseBits(op.vectorize(vectorization)),
+            Operator::MulHi(op) => Operator::MulHi(op.vectorize(vectorization)),
             Operator::Recip(op) => Operator::Recip(op.vectorize(vectorization)),
             Operator::Equal(op) => Operator::Equal(op.vectorize(vectorization)),
             Operator::NotEqual(op) => Operator::NotEqual(op.vectorize(vectorization)),
@@ -87,9 +113,16 @@ impl Operator {
             Operator::ShiftRight(op) => Operator::ShiftRight(op.vectorize(vectorization)),
             Operator::Remainder(op) => Operator::Remainder(op.vectorize(vectorization)),
             Operator::Slice(op) => Operator::Slice(op.vectorize(vectorization)),
+            Operator::CopyAsync(op) => Operator::CopyAsync(op.vectorize(vectorization)),
             Operator::Bitcast(op) => Operator::Bitcast(op.vectorize(vectorization)),
             Operator::AtomicLoad(op) => Operator::AtomicLoad(op.vectorize(vectorization)),
             Operator::AtomicStore(op) => Operator::AtomicStore(op.vectorize(vectorization)),
+            Operator::AtomicLoadOrdered(op) => {
+                Operator::AtomicLoadOrdered(op.vectorize(vectorization))
+            }
+            Operator::AtomicStoreOrdered(op) => {
+                Operator::AtomicStoreOrdered(op.vectorize(vectorization))
+            }
             Operator::AtomicSwap(op) => Operator::AtomicSwap(op.vectorize(vectorization)),
             Operator::AtomicCompareAndSwap(op) => {
                 Operator::AtomicCompareAndSwap(op.vectorize(vectorization))
@@ -102,6 +135,26 @@ impl Operator {
             Operator::AtomicOr(op) => Operator::AtomicOr(op.vectorize(vectorization)),
             Operator::AtomicXor(op) => Operator::AtomicXor(op.vectorize(vectorization)),
             Operator::Normalize(op) => Operator::Normalize(op.vectorize(vectorization)),
+            // Operations across lanes keep the vectorization of their output.
+            Operator::Dot(op) => Operator::Dot(BinaryOperator {
+                lhs: op.lhs.vectorize(vectorization),
+                rhs: op.rhs.vectorize(vectorization),
+                out: op.out,
+            }),
+            Operator::ReduceSum(op) => Operator::ReduceSum(op.vectorize_input(vectorization)),
+            Operator::ReduceMax(op) => Operator::ReduceMax(op.vectorize_input(vectorization)),
+            Operator::ReduceMin(op) => Operator::ReduceMin(op.vectorize_input(vectorization)),
+            Operator::Swizzle(op) => Operator::Swizzle(SwizzleOperator {
+                input: op.input.vectorize(vectorization),
+                lanes: op.lanes.clone(),
+                out: op.out,
+            }),
+            Operator::InsertLane(op) => Operator::InsertLane(InsertLaneOperator {
+                input: op.input.vectorize(vectorization),
+                lane: op.lane,
+                value: op.value,
+                out: op.out.vectorize(vectorization),
+            }),
         }
     }
 }
@@ -123,6 +176,28 @@ impl UnaryOperator {
 
         Self { input, out }
     }
+
+    pub(crate) fn vectorize_input(&self, vectorization: Vectorization) -> Self {
+        let input = self.input.vectorize(vectorization);
+
+        Self {
+            input,
+            out: self.out,
+        }
+    }
+}
+
+impl AtomicOrderedOperator {
+    pub(crate) fn vectorize(&self, vectorization: Vectorization) -> Self {
+        let input = self.input.vectorize(vectorization);
+        let out = self.out.vectorize(vectorization);
+
+        Self {
+            input,
+            ordering: self.ordering,
+            out,
+        }
+    }
 }
 
 impl SliceOperator {
@@ -176,6 +251,14 @@ impl Subcube {
             Subcube::Prod(op) => Subcube::Prod(op.vectorize(vectorization)),
             Subcube::Min(op) => Subcube::Min(op.vectorize(vectorization)),
             Subcube::Max(op) => Subcube::Max(op.vectorize(vectorization)),
+            Subcube::InclusiveSum(op) => Subcube::InclusiveSum(op.vectorize(vectorization)),
+            Subcube::ExclusiveSum(op) => Subcube::ExclusiveSum(op.vectorize(vectorization)),
+            Subcube::Shuffle(op) => Subcube::Shuffle(op.vectorize(vectorization)),
+            Subcube::ShuffleXor(op) => Subcube::ShuffleXor(op.vectorize(vectorization)),
+            Subcube::ShuffleUp(op) => Subcube::ShuffleUp(op.vectorize(vectorization)),
+            Subcube::ShuffleDown(op) => Subcube::ShuffleDown(op.vectorize(vectorization)),
+            // The mask of a ballot is always a scalar.
+            Subcube::Ballot(op) => Subcube::Ballot(op.vectorize_input(vectorization)),
         }
     }
 }
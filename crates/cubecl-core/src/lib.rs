@@ -23,7 +23,7 @@ pub use codegen::*;
 pub use pod::*;
 pub use runtime::*;
 
-pub use cubecl_macros::{comptime, cube, CubeLaunch, CubeType};
+pub use cubecl_macros::{comptime, cube, cube_assert, cube_debug_print, CubeLaunch, CubeType};
 pub use cubecl_runtime::benchmark;
 
 /// An approximation of the subcube dimension.
@@ -46,6 +46,13 @@ pub trait Kernel: Send + Sync + 'static + Sized {
     fn id(&self) -> KernelId {
         KernelId::new::<Self>()
     }
+    /// Identifier for the kernel that is stable across builds and processes.
+    ///
+    /// Computed from the [kernel definition](KernelDefinition) by default, it can be overridden
+    /// with [StableKernelId::from_name] when the kernel is expensive to define.
+    fn stable_id(&self) -> StableKernelId {
+        StableKernelId::from_definition(&self.define())
+    }
 }
 
 /// Calculate the number of cubes required to execute an operation where one cube unit is
@@ -1,4 +1,4 @@
-pub use crate::{cube, CubeLaunch, CubeType, Kernel, RuntimeArg};
+pub use crate::{cube, cube_assert, cube_debug_print, CubeLaunch, CubeType, Kernel, RuntimeArg};
 
 pub use crate::codegen::{KernelExpansion, KernelIntegrator, KernelSettings};
 pub use crate::compute::{
@@ -11,8 +11,8 @@ pub use crate::runtime::Runtime;
 
 /// Elements
 pub use crate::frontend::{
-    Array, ArrayHandleRef, AtomicI32, AtomicI64, AtomicU32, Float, LaunchArg, Slice, SliceMut,
-    Tensor, TensorArg,
+    Array, ArrayHandleRef, AtomicF32, AtomicI32, AtomicI64, AtomicU32, Float, LaunchArg, Slice,
+    SliceMut, Tensor, TensorArg,
 };
 pub use crate::pod::CubeElement;
 
@@ -80,6 +80,8 @@ impl FeatureSet {
 pub enum Feature {
     /// The subcube feature enables all basic warp/subgroup operations.
     Subcube,
+    /// Fences with a [device scope](crate::ir::MemoryScope::Device).
+    DeviceFence,
     /// The cmma feature enables cooperative matrix-multiply and accumulate operations.
     Cmma {
         a: Elem,
@@ -0,0 +1,279 @@
+use crate as cubecl;
+use crate::Feature;
+use cubecl::prelude::*;
+
+#[cube(launch)]
+pub fn kernel_atomic_add_f32(output: &mut Array<AtomicF32>, input: &Array<f32>) {
+    if UNIT_POS < input.len() {
+        AtomicF32::add(&output[UNIT_POS % 2], input[UNIT_POS]);
+    }
+}
+
+#[cube(launch)]
+pub fn kernel_shared_histogram(output: &mut Array<u32>, input: &Array<u32>) {
+    let histogram = SharedMemory::<AtomicU32>::new(4);
+
+    if UNIT_POS < 4 {
+        AtomicU32::store(&histogram[UNIT_POS], 0);
+    }
+    sync_units();
+
+    AtomicU32::add(&histogram[input[UNIT_POS]], 1);
+    sync_units();
+
+    if UNIT_POS < 4 {
+        output[UNIT_POS] = AtomicU32::load(&histogram[UNIT_POS]);
+    }
+}
+
+#[cube(launch)]
+pub fn kernel_shared_atomic_f32(output: &mut Array<f32>, input: &Array<f32>) {
+    let shared = SharedMemory::<AtomicF32>::new(4);
+
+    if UNIT_POS == 0 {
+        AtomicF32::store(&shared[0], 0.0);
+        AtomicF32::store(&shared[1], -100.0);
+        AtomicF32::store(&shared[2], 100.0);
+        AtomicF32::store(&shared[3], 0.0);
+    }
+    sync_units();
+
+    let value = input[UNIT_POS];
+    AtomicF32::add(&shared[0], value);
+    AtomicF32::max(&shared[1], value);
+    AtomicF32::min(&shared[2], value);
+    AtomicF32::sub(&shared[3], value);
+    sync_units();
+
+    if UNIT_POS < 4 {
+        output[UNIT_POS] = AtomicF32::load(&shared[UNIT_POS]);
+    }
+}
+
+#[cube(launch)]
+pub fn kernel_atomic_ordered(
+    output: &mut Array<u32>,
+    data: &mut Array<u32>,
+    flag: &mut Array<AtomicU32>,
+) {
+    // The first cube publishes the data, the second one spins on the flag before reading it.
+    if CUBE_POS == 0 {
+        for i in 0..data.len() {
+            data[i] = i + 1;
+        }
+        AtomicU32::store_ordered(&flag[0], 1, MemoryOrdering::Release);
+    } else {
+        while AtomicU32::load_ordered(&flag[0], MemoryOrdering::Acquire) == 0 {}
+
+        for i in 0..data.len() {
+            output[i] = data[i];
+        }
+    }
+}
+
+#[cube(launch)]
+pub fn kernel_fence(output: &mut Array<u32>, data: &mut Array<u32>, flag: &mut Array<AtomicU32>) {
+    // Same as the ordered atomics, with relaxed atomics ordered by fences.
+    if CUBE_POS == 0 {
+        for i in 0..data.len() {
+            data[i] = i + 1;
+        }
+        fence(MemoryScope::Device, MemoryOrdering::Release);
+        AtomicU32::store(&flag[0], 1);
+    } else {
+        while AtomicU32::load(&flag[0]) == 0 {}
+        fence(MemoryScope::Device, MemoryOrdering::Acquire);
+
+        for i in 0..data.len() {
+            output[i] = data[i];
+        }
+    }
+}
+
+#[cube(launch)]
+pub fn kernel_fence_cube(output: &mut Array<u32>) {
+    // Each unit reads the value written by the next one, in uniform control flow.
+    let mut shared = SharedMemory::<u32>::new(4);
+
+    shared[UNIT_POS] = UNIT_POS + 1;
+    fence(MemoryScope::Cube, MemoryOrdering::Release);
+    sync_units();
+
+    output[UNIT_POS] = shared[(UNIT_POS + 1) % 4];
+}
+
+pub fn test_atomic_add_f32<R: Runtime>(client: ComputeClient<R::Server, R::Channel>) {
+    let output = client.create(f32::as_bytes(&[0.5, 0.0]));
+    let input = client.create(f32::as_bytes(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0]));
+
+    unsafe {
+        kernel_atomic_add_f32::launch::<R>(
+            &client,
+            CubeCount::Static(1, 1, 1),
+            CubeDim::new(8, 1, 1),
+            ArrayArg::from_raw_parts(&output, 2, 1),
+            ArrayArg::from_raw_parts(&input, 8, 1),
+        )
+    };
+
+    let actual = client.read(output.binding());
+    let actual = f32::from_bytes(&actual);
+
+    assert_eq!(actual, &[16.5, 20.0]);
+}
+
+pub fn test_shared_histogram<R: Runtime>(client: ComputeClient<R::Server, R::Channel>) {
+    let output = client.empty(4 * core::mem::size_of::<u32>());
+    let input = client.create(u32::as_bytes(&[0, 1, 1, 3, 3, 3, 2, 0]));
+
+    unsafe {
+        kernel_shared_histogram::launch::<R>(
+            &client,
+            CubeCount::Static(1, 1, 1),
+            CubeDim::new(8, 1, 1),
+            ArrayArg::from_raw_parts(&output, 4, 1),
+            ArrayArg::from_raw_parts(&input, 8, 1),
+        )
+    };
+
+    let actual = client.read(output.binding());
+    let actual = u32::from_bytes(&actual);
+
+    assert_eq!(actual, &[2, 2, 1, 3]);
+}
+
+pub fn test_shared_atomic_f32<R: Runtime>(client: ComputeClient<R::Server, R::Channel>) {
+    let output = client.empty(4 * core::mem::size_of::<f32>());
+    let input = client.create(f32::as_bytes(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0]));
+
+    unsafe {
+        kernel_shared_atomic_f32::launch::<R>(
+            &client,
+            CubeCount::Static(1, 1, 1),
+            CubeDim::new(8, 1, 1),
+            ArrayArg::from_raw_parts(&output, 4, 1),
+            ArrayArg::from_raw_parts(&input, 8, 1),
+        )
+    };
+
+    let actual = client.read(output.binding());
+    let actual = f32::from_bytes(&actual);
+
+    assert_eq!(actual, &[36.0, 8.0, 1.0, -36.0]);
+}
+
+pub fn test_atomic_ordered<R: Runtime>(client: ComputeClient<R::Server, R::Channel>) {
+    // WebGPU neither orders memory accesses across cubes nor guarantees that the first cube makes
+    // progress while the second one spins.
+    if R::name() == "wgpu" {
+        return;
+    }
+
+    let output = client.empty(4 * core::mem::size_of::<u32>());
+    let data = client.empty(4 * core::mem::size_of::<u32>());
+    let flag = client.create(u32::as_bytes(&[0]));
+
+    unsafe {
+        kernel_atomic_ordered::launch::<R>(
+            &client,
+            CubeCount::Static(2, 1, 1),
+            CubeDim::new(1, 1, 1),
+            ArrayArg::from_raw_parts(&output, 4, 1),
+            ArrayArg::from_raw_parts(&data, 4, 1),
+            ArrayArg::from_raw_parts(&flag, 1, 1),
+        )
+    };
+
+    let actual = client.read(output.binding());
+    let actual = u32::from_bytes(&actual);
+
+    assert_eq!(actual, &[1, 2, 3, 4]);
+}
+
+pub fn test_fence<R: Runtime>(client: ComputeClient<R::Server, R::Channel>) {
+    if !client.features().enabled(Feature::DeviceFence) {
+        return;
+    }
+
+    let output = client.empty(4 * core::mem::size_of::<u32>());
+    let data = client.empty(4 * core::mem::size_of::<u32>());
+    let flag = client.create(u32::as_bytes(&[0]));
+
+    unsafe {
+        kernel_fence::launch::<R>(
+            &client,
+            CubeCount::Static(2, 1, 1),
+            CubeDim::new(1, 1, 1),
+            ArrayArg::from_raw_parts(&output, 4, 1),
+            ArrayArg::from_raw_parts(&data, 4, 1),
+            ArrayArg::from_raw_parts(&flag, 1, 1),
+        )
+    };
+
+    let actual = client.read(output.binding());
+    let actual = u32::from_bytes(&actual);
+
+    assert_eq!(actual, &[1, 2, 3, 4]);
+}
+
+pub fn test_fence_cube<R: Runtime>(client: ComputeClient<R::Server, R::Channel>) {
+    let output = client.empty(4 * core::mem::size_of::<u32>());
+
+    unsafe {
+        kernel_fence_cube::launch::<R>(
+            &client,
+            CubeCount::Static(1, 1, 1),
+            CubeDim::new(4, 1, 1),
+            ArrayArg::from_raw_parts(&output, 4, 1),
+        )
+    };
+
+    let actual = client.read(output.binding());
+    let actual = u32::from_bytes(&actual);
+
+    assert_eq!(actual, &[2, 3, 4, 1]);
+}
+
+#[allow(missing_docs)]
+#[macro_export]
+macro_rules! testgen_atomic {
+    () => {
+        use super::*;
+
+        #[test]
+        fn test_atomic_add_f32() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::atomic::test_atomic_add_f32::<TestRuntime>(client);
+        }
+
+        #[test]
+        fn test_shared_histogram() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::atomic::test_shared_histogram::<TestRuntime>(client);
+        }
+
+        #[test]
+        fn test_shared_atomic_f32() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::atomic::test_shared_atomic_f32::<TestRuntime>(client);
+        }
+
+        #[test]
+        fn test_atomic_ordered() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::atomic::test_atomic_ordered::<TestRuntime>(client);
+        }
+
+        #[test]
+        fn test_fence() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::atomic::test_fence::<TestRuntime>(client);
+        }
+
+        #[test]
+        fn test_fence_cube() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::atomic::test_fence_cube::<TestRuntime>(client);
+        }
+    };
+}
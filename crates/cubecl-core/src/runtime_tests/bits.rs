@@ -0,0 +1,127 @@
+use crate as cubecl;
+
+use cubecl::prelude::*;
+
+#[cube(launch)]
+pub fn kernel_bits<I: Int>(lhs: &Array<I>, rhs: &Array<I>, output: &mut Array<I>) {
+    if ABSOLUTE_POS < lhs.len() {
+        let x = lhs[ABSOLUTE_POS];
+        let offset = ABSOLUTE_POS * 5;
+
+        output[offset] = I::count_ones(x);
+        output[offset + 1] = I::leading_zeros(x);
+        output[offset + 2] = I::trailing_zeros(x);
+        output[offset + 3] = I::reverse_bits(x);
+        output[offset + 4] = I::mul_hi(x, rhs[ABSOLUTE_POS]);
+    }
+}
+
+/// The results of [kernel_bits] computed with the standard library, where each vectorized
+/// output holds the results of a single operation.
+macro_rules! expected_bits {
+    ($lhs:expr, $rhs:expr, $vectorization:expr, $ty:ty, $wide:ty) => {
+        $lhs.iter()
+            .zip($rhs.iter())
+            .map(|(x, y)| {
+                [
+                    x.count_ones() as $ty,
+                    x.leading_zeros() as $ty,
+                    x.trailing_zeros() as $ty,
+                    x.reverse_bits(),
+                    ((*x as $wide * *y as $wide) >> <$ty>::BITS) as $ty,
+                ]
+            })
+            .collect::<Vec<_>>()
+            .chunks($vectorization)
+            .flat_map(|lanes| (0..5).flat_map(move |op| lanes.iter().map(move |lane| lane[op])))
+            .collect::<Vec<_>>()
+    };
+}
+
+fn launch_bits<R: Runtime, I: Int + CubeElement>(
+    client: &ComputeClient<R::Server, R::Channel>,
+    lhs: &[I],
+    rhs: &[I],
+    vectorization: u8,
+) -> Vec<I> {
+    let lhs_handle = client.create(I::as_bytes(lhs));
+    let rhs_handle = client.create(I::as_bytes(rhs));
+    let output = client.empty(lhs.len() * 5 * core::mem::size_of::<I>());
+
+    unsafe {
+        kernel_bits::launch::<I, R>(
+            client,
+            CubeCount::Static(1, 1, 1),
+            CubeDim::new(lhs.len() as u32 / vectorization as u32, 1, 1),
+            ArrayArg::from_raw_parts(&lhs_handle, lhs.len(), vectorization),
+            ArrayArg::from_raw_parts(&rhs_handle, rhs.len(), vectorization),
+            ArrayArg::from_raw_parts(&output, lhs.len() * 5, vectorization),
+        )
+    };
+
+    let actual = client.read(output.binding());
+    I::from_bytes(&actual).to_vec()
+}
+
+pub fn test_bits_u32<R: Runtime>(client: ComputeClient<R::Server, R::Channel>) {
+    let lhs = [0u32, 1, 0x8000_0000, 0xdead_beef, u32::MAX];
+    let rhs = [5u32, u32::MAX, 4, 0x1234_5678, u32::MAX];
+
+    let actual = launch_bits::<R, u32>(&client, &lhs, &rhs, 1);
+
+    assert_eq!(actual, expected_bits!(lhs, rhs, 1, u32, u64));
+}
+
+pub fn test_bits_i32<R: Runtime>(client: ComputeClient<R::Server, R::Channel>) {
+    let lhs = [0i32, -1, i32::MIN, 0x1234_5678, -7, 1, 64, i32::MAX];
+    let rhs = [
+        3i32,
+        -1,
+        -1,
+        -0x1234_5678,
+        i32::MAX,
+        i32::MIN,
+        -64,
+        i32::MAX,
+    ];
+
+    let actual = launch_bits::<R, i32>(&client, &lhs, &rhs, 4);
+
+    assert_eq!(actual, expected_bits!(lhs, rhs, 4, i32, i64));
+}
+
+pub fn test_bits_i8<R: Runtime>(client: ComputeClient<R::Server, R::Channel>) {
+    // Arrays of 8 bits integers are packed in 32 bits words on some runtimes.
+    let lhs = [0i8, -1, i8::MIN, 0x16];
+    let rhs = [3i8, -1, i8::MIN, -100];
+
+    let actual = launch_bits::<R, i8>(&client, &lhs, &rhs, 4);
+
+    assert_eq!(actual, expected_bits!(lhs, rhs, 4, i8, i16));
+}
+
+#[allow(missing_docs)]
+#[macro_export]
+macro_rules! testgen_bits {
+    () => {
+        use super::*;
+
+        #[test]
+        fn test_bits_u32() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::bits::test_bits_u32::<TestRuntime>(client);
+        }
+
+        #[test]
+        fn test_bits_i32() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::bits::test_bits_i32::<TestRuntime>(client);
+        }
+
+        #[test]
+        fn test_bits_i8() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::bits::test_bits_i8::<TestRuntime>(client);
+        }
+    };
+}
@@ -0,0 +1,107 @@
+use crate as cubecl;
+
+use cubecl::{
+    compute::{read_debug_output, DebugRecord},
+    ir::DebugKind,
+    prelude::*,
+};
+
+#[cube(launch, launch_unchecked)]
+pub fn kernel_debug_output<F: Float>(output: &mut Array<F>) {
+    let value = output[UNIT_POS];
+    cube_assert!(value < F::new(10.0), "value should be smaller than 10");
+    cube_debug_print!("output[{}] = {}", UNIT_POS, value);
+    output[UNIT_POS] = value * F::new(2.0);
+}
+
+pub fn test_debug_output<R: Runtime>(client: ComputeClient<R::Server, R::Channel>) {
+    let handle = client.create(f32::as_bytes(&[1.0, 20.0]));
+
+    kernel_debug_output::launch::<f32, R>(
+        &client,
+        CubeCount::Static(1, 1, 1),
+        CubeDim::new(2, 1, 1),
+        unsafe { ArrayArg::from_raw_parts(&handle, 2, 1) },
+    );
+    let records = read_debug_output::<R>(&client);
+
+    let actual = client.read(handle.clone().binding());
+    let actual = f32::from_bytes(&actual);
+
+    assert_eq!(actual, &[2.0, 40.0]);
+    assert_debug_records(records, &["output[0] = 1", "output[1] = 20"]);
+
+    // The second launch reuses the debug buffer, so only its own records are decoded.
+    kernel_debug_output::launch::<f32, R>(
+        &client,
+        CubeCount::Static(1, 1, 1),
+        CubeDim::new(2, 1, 1),
+        unsafe { ArrayArg::from_raw_parts(&handle, 2, 1) },
+    );
+    let records = read_debug_output::<R>(&client);
+
+    assert_debug_records(records, &["output[0] = 2", "output[1] = 40"]);
+}
+
+fn assert_debug_records(records: Vec<DebugRecord>, prints: &[&str]) {
+    if !cfg!(debug_assertions) {
+        assert_eq!(records, []);
+        return;
+    }
+
+    let (asserts, mut actual_prints): (Vec<_>, Vec<_>) = records
+        .into_iter()
+        .partition(|record| record.kind == DebugKind::Assert);
+    actual_prints.sort_by(|a, b| a.message.cmp(&b.message));
+
+    assert_eq!(asserts.len(), 1);
+    assert_eq!(asserts[0].message, "value should be smaller than 10");
+    assert!(asserts[0].location.contains("runtime_tests/debug.rs"));
+    assert_eq!(
+        actual_prints
+            .iter()
+            .map(|record| record.message.as_str())
+            .collect::<Vec<_>>(),
+        prints
+    );
+}
+
+pub fn test_debug_output_unchecked<R: Runtime>(client: ComputeClient<R::Server, R::Channel>) {
+    let handle = client.create(f32::as_bytes(&[1.0, 20.0]));
+
+    unsafe {
+        kernel_debug_output::launch_unchecked::<f32, R>(
+            &client,
+            CubeCount::Static(1, 1, 1),
+            CubeDim::new(2, 1, 1),
+            ArrayArg::from_raw_parts(&handle, 2, 1),
+        )
+    };
+    let records = read_debug_output::<R>(&client);
+
+    let actual = client.read(handle.binding());
+    let actual = f32::from_bytes(&actual);
+
+    assert_eq!(actual, &[2.0, 40.0]);
+    assert_eq!(records, []);
+}
+
+#[allow(missing_docs)]
+#[macro_export]
+macro_rules! testgen_debug {
+    () => {
+        use super::*;
+
+        #[test]
+        fn test_debug_output() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::debug::test_debug_output::<TestRuntime>(client);
+        }
+
+        #[test]
+        fn test_debug_output_unchecked() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::debug::test_debug_output_unchecked::<TestRuntime>(client);
+        }
+    };
+}
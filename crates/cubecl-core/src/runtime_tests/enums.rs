@@ -0,0 +1,114 @@
+use crate as cubecl;
+
+use cubecl::prelude::*;
+
+#[derive(CubeLaunch)]
+pub enum Operation<F: Float> {
+    Identity,
+    Scale(F),
+    Affine { scale: F, offset: F },
+}
+
+#[cube]
+fn apply<F: Float>(operation: Operation<F>, value: F) -> F {
+    match operation {
+        Operation::Identity => value,
+        Operation::Scale(scale) => value * scale,
+        Operation::Affine { scale, offset } => value * scale + offset,
+    }
+}
+
+#[cube(launch)]
+pub fn kernel_enum_launch<F: Float>(output: &mut Array<F>, operation: Operation<F>) {
+    output[UNIT_POS] = apply::<F>(operation, output[UNIT_POS]);
+}
+
+#[cube(launch)]
+pub fn kernel_enum_construct<F: Float>(output: &mut Array<F>, selector: &Array<u32>) {
+    let value = output[UNIT_POS];
+    let scaled = apply::<F>(Operation::Scale(value), value);
+    let affine = apply::<F>(
+        Operation::Affine {
+            scale: value,
+            offset: value,
+        },
+        value,
+    );
+    match selector[UNIT_POS] {
+        0 => {
+            output[UNIT_POS] = scaled;
+        }
+        _ => {
+            output[UNIT_POS] = affine;
+        }
+    }
+}
+
+pub fn test_enum_launch<R: Runtime>(client: ComputeClient<R::Server, R::Channel>) {
+    let cases = [
+        (Operation::Identity, [1.0, 2.0]),
+        (Operation::Scale(3.0), [3.0, 6.0]),
+        (
+            Operation::Affine {
+                scale: 2.0,
+                offset: 1.0,
+            },
+            [3.0, 5.0],
+        ),
+    ];
+
+    for (operation, expected) in cases {
+        let handle = client.create(f32::as_bytes(&[1.0, 2.0]));
+
+        kernel_enum_launch::launch::<f32, R>(
+            &client,
+            CubeCount::Static(1, 1, 1),
+            CubeDim::new(2, 1, 1),
+            unsafe { ArrayArg::from_raw_parts(&handle, 2, 1) },
+            OperationLaunch::new(operation),
+        );
+
+        let actual = client.read(handle.binding());
+        let actual = f32::from_bytes(&actual);
+
+        assert_eq!(actual, &expected);
+    }
+}
+
+pub fn test_enum_construct<R: Runtime>(client: ComputeClient<R::Server, R::Channel>) {
+    let handle = client.create(f32::as_bytes(&[2.0, 3.0]));
+    let selector = client.create(u32::as_bytes(&[0, 1]));
+
+    kernel_enum_construct::launch::<f32, R>(
+        &client,
+        CubeCount::Static(1, 1, 1),
+        CubeDim::new(2, 1, 1),
+        unsafe { ArrayArg::from_raw_parts(&handle, 2, 1) },
+        unsafe { ArrayArg::from_raw_parts(&selector, 2, 1) },
+    );
+
+    let actual = client.read(handle.binding());
+    let actual = f32::from_bytes(&actual);
+
+    assert_eq!(actual, &[4.0, 12.0]);
+}
+
+#[allow(missing_docs)]
+#[macro_export]
+macro_rules! testgen_enums {
+    () => {
+        use super::*;
+
+        #[test]
+        fn test_enum_launch() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::enums::test_enum_launch::<TestRuntime>(client);
+        }
+
+        #[test]
+        fn test_enum_construct() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::enums::test_enum_construct::<TestRuntime>(client);
+        }
+    };
+}
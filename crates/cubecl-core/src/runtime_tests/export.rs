@@ -0,0 +1,85 @@
+use crate as cubecl;
+use cubecl::{
+    compute::{KernelBundle, KernelExporter},
+    prelude::*,
+    ExecutionMode, KernelSettings,
+};
+
+#[cube(launch)]
+pub fn exported_double(input: &Array<f32>, output: &mut Array<f32>) {
+    if UNIT_POS < input.len() {
+        output[UNIT_POS] = input[UNIT_POS] * 2.0;
+    }
+}
+
+fn export_bundle<R: Runtime>(name: &str) -> KernelBundle {
+    let settings = KernelSettings::default().cube_dim(CubeDim::new(4, 1, 1));
+    let mut exporter = KernelExporter::<R::Compiler>::new(ExecutionMode::Checked);
+    exporter.kernel(
+        "double",
+        exported_double::ExportedDouble::<R>::new(settings),
+    );
+
+    let dir = std::env::temp_dir().join(format!("cubecl-export-{}-{name}", R::name()));
+    exporter.bundle().save(&dir).unwrap();
+    let bundle = KernelBundle::load(&dir).unwrap();
+    std::fs::remove_dir_all(&dir).unwrap();
+
+    bundle
+}
+
+pub fn test_export_bundle<R: Runtime>() {
+    let settings = KernelSettings::default().cube_dim(CubeDim::new(4, 1, 1));
+    let kernel =
+        KernelTask::<R::Compiler, _>::new(exported_double::ExportedDouble::<R>::new(settings));
+    let expected = kernel.compile(ExecutionMode::Checked);
+
+    let bundle = export_bundle::<R>("bundle");
+    let exported = bundle.get("double").unwrap();
+
+    assert_eq!(exported.source, expected.source);
+    assert_eq!(exported.cube_dim, expected.cube_dim);
+    assert_eq!(exported.shared_mem_bytes, expected.shared_mem_bytes);
+    assert_eq!(exported.bindings.inputs.len(), 1);
+    assert_eq!(exported.bindings.outputs.len(), 1);
+    assert_eq!(exported.id, kernel.stable_id().to_string());
+}
+
+pub fn test_export_launch<R: Runtime>(client: ComputeClient<R::Server, R::Channel>) {
+    let bundle = export_bundle::<R>("launch");
+    let exported = bundle.get("double").unwrap().clone();
+
+    let input = client.create(f32::as_bytes(&[1.0, 2.0, 3.0, 4.0]));
+    let output = client.empty(4 * core::mem::size_of::<f32>());
+
+    let mut launcher = KernelLauncher::<R>::default();
+    unsafe {
+        launcher.register_array(&ArrayHandleRef::from_raw_parts(&input, 4));
+        launcher.register_array(&ArrayHandleRef::from_raw_parts(&output, 4));
+    }
+    launcher.launch_task(CubeCount::Static(1, 1, 1), exported, &client);
+
+    let actual = client.read(output.binding());
+    let actual = f32::from_bytes(&actual);
+
+    assert_eq!(actual, &[2.0, 4.0, 6.0, 8.0]);
+}
+
+#[allow(missing_docs)]
+#[macro_export]
+macro_rules! testgen_export {
+    () => {
+        use super::*;
+
+        #[test]
+        fn test_export_bundle() {
+            cubecl_core::runtime_tests::export::test_export_bundle::<TestRuntime>();
+        }
+
+        #[test]
+        fn test_export_launch() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::export::test_export_launch::<TestRuntime>(client);
+        }
+    };
+}
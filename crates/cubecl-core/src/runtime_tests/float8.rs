@@ -0,0 +1,86 @@
+use crate as cubecl;
+
+use cubecl::prelude::*;
+
+#[cube(launch)]
+pub fn kernel_quantize_e4m3(input: &Array<f32>, output: &mut Array<F8E4M3>) {
+    if ABSOLUTE_POS < input.len() {
+        output[ABSOLUTE_POS] = F8E4M3::cast_from(input[ABSOLUTE_POS]);
+    }
+}
+
+#[cube(launch)]
+pub fn kernel_dequantize_e5m2(input: &Array<F8E5M2>, output: &mut Array<f32>) {
+    if ABSOLUTE_POS < input.len() {
+        output[ABSOLUTE_POS] = f32::cast_from(input[ABSOLUTE_POS]);
+    }
+}
+
+pub fn test_quantize_e4m3<R: Runtime>(client: ComputeClient<R::Server, R::Channel>) {
+    // Covers rounding ties to even, saturation and subnormals.
+    let values = [0.0, 1.0, -1.5, 17.0, 500.0, -300.0, 0.001, 3.1];
+    let input = client.create(f32::as_bytes(&values));
+    let output = client.empty(values.len() * core::mem::size_of::<F8E4M3>());
+
+    unsafe {
+        kernel_quantize_e4m3::launch::<R>(
+            &client,
+            CubeCount::Static(1, 1, 1),
+            CubeDim::new(2, 1, 1),
+            ArrayArg::from_raw_parts(&input, values.len(), 4),
+            ArrayArg::from_raw_parts(&output, values.len(), 4),
+        )
+    };
+
+    let actual = client.read(output.binding());
+    let actual = F8E4M3::from_bytes(&actual);
+    let expected = values.map(F8E4M3::from_f32);
+
+    assert_eq!(
+        actual.iter().map(F8E4M3::to_bits).collect::<Vec<_>>(),
+        expected.iter().map(F8E4M3::to_bits).collect::<Vec<_>>()
+    );
+}
+
+pub fn test_dequantize_e5m2<R: Runtime>(client: ComputeClient<R::Server, R::Channel>) {
+    // Covers the largest finite value, subnormals and negative zero.
+    let floats = [1.0, -2.5, 57344.0, 1.0 / 65536.0, 0.75, -0.0, 3.0, 12.0];
+    let values = floats.map(F8E5M2::from_f32);
+    let input = client.create(F8E5M2::as_bytes(&values));
+    let output = client.empty(values.len() * core::mem::size_of::<f32>());
+
+    unsafe {
+        kernel_dequantize_e5m2::launch::<R>(
+            &client,
+            CubeCount::Static(1, 1, 1),
+            CubeDim::new(2, 1, 1),
+            ArrayArg::from_raw_parts(&input, values.len(), 4),
+            ArrayArg::from_raw_parts(&output, values.len(), 4),
+        )
+    };
+
+    let actual = client.read(output.binding());
+    let actual = f32::from_bytes(&actual);
+
+    assert_eq!(actual, values.map(|value| value.to_f32()));
+}
+
+#[allow(missing_docs)]
+#[macro_export]
+macro_rules! testgen_float8 {
+    () => {
+        use super::*;
+
+        #[test]
+        fn test_quantize_e4m3() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::float8::test_quantize_e4m3::<TestRuntime>(client);
+        }
+
+        #[test]
+        fn test_dequantize_e5m2() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::float8::test_dequantize_e5m2::<TestRuntime>(client);
+        }
+    };
+}
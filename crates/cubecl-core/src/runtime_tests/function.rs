@@ -0,0 +1,83 @@
+use crate as cubecl;
+
+use cubecl::prelude::*;
+
+#[cube(noinline)]
+fn scale<F: Float>(value: F, factor: F) -> F {
+    value * factor
+}
+
+#[cube(noinline)]
+fn sum_slice<F: Float>(output: &mut Array<F>, input: &Slice<'_, F>) {
+    let mut sum = F::new(0.0);
+    for i in 0..input.len() {
+        sum += input[i];
+    }
+    output[UNIT_POS] = sum;
+}
+
+#[cube(launch)]
+pub fn kernel_noinline_scalar<F: Float>(output: &mut Array<F>) {
+    let value = scale::<F>(output[UNIT_POS], F::new(2.0));
+    output[UNIT_POS] = scale::<F>(value, F::new(3.0));
+}
+
+#[cube(launch)]
+pub fn kernel_noinline_slice<F: Float>(input: &Array<F>, output: &mut Array<F>) {
+    let slice = input.slice(UNIT_POS, UNIT_POS + 2);
+    sum_slice::<F>(output, slice);
+}
+
+pub fn test_noinline_scalar<R: Runtime>(client: ComputeClient<R::Server, R::Channel>) {
+    let handle = client.create(f32::as_bytes(&[1.0, 2.0]));
+
+    kernel_noinline_scalar::launch::<f32, R>(
+        &client,
+        CubeCount::Static(1, 1, 1),
+        CubeDim::new(2, 1, 1),
+        unsafe { ArrayArg::from_raw_parts(&handle, 2, 1) },
+    );
+
+    let actual = client.read(handle.binding());
+    let actual = f32::from_bytes(&actual);
+
+    assert_eq!(actual, &[6.0, 12.0]);
+}
+
+pub fn test_noinline_slice<R: Runtime>(client: ComputeClient<R::Server, R::Channel>) {
+    let input = client.create(f32::as_bytes(&[1.0, 2.0, 3.0]));
+    let output = client.create(f32::as_bytes(&[0.0, 0.0]));
+
+    kernel_noinline_slice::launch::<f32, R>(
+        &client,
+        CubeCount::Static(1, 1, 1),
+        CubeDim::new(2, 1, 1),
+        unsafe { ArrayArg::from_raw_parts(&input, 3, 1) },
+        unsafe { ArrayArg::from_raw_parts(&output, 2, 1) },
+    );
+
+    let actual = client.read(output.binding());
+    let actual = f32::from_bytes(&actual);
+
+    assert_eq!(actual, &[3.0, 5.0]);
+}
+
+#[allow(missing_docs)]
+#[macro_export]
+macro_rules! testgen_function {
+    () => {
+        use super::*;
+
+        #[test]
+        fn test_noinline_scalar() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::function::test_noinline_scalar::<TestRuntime>(client);
+        }
+
+        #[test]
+        fn test_noinline_slice() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::function::test_noinline_slice::<TestRuntime>(client);
+        }
+    };
+}
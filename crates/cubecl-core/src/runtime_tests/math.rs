@@ -0,0 +1,212 @@
+use crate as cubecl;
+
+use cubecl::prelude::*;
+
+#[cube(launch)]
+pub fn kernel_unary_math(input: &Array<f32>, output: &mut Array<f32>) {
+    if ABSOLUTE_POS < input.len() {
+        let x = input[ABSOLUTE_POS];
+        let offset = ABSOLUTE_POS * 11;
+
+        output[offset] = f32::tan(x);
+        output[offset + 1] = f32::asin(x);
+        output[offset + 2] = f32::acos(x);
+        output[offset + 3] = f32::atan(x);
+        output[offset + 4] = f32::sinh(x);
+        output[offset + 5] = f32::cosh(x);
+        output[offset + 6] = f32::exp2(x);
+        output[offset + 7] = f32::log2(x + 1.0);
+        output[offset + 8] = f32::trunc(x * 4.0);
+        output[offset + 9] = f32::fract(x * 4.0);
+        output[offset + 10] = f32::sign(x);
+    }
+}
+
+#[cube(launch)]
+pub fn kernel_binary_math(lhs: &Array<f32>, rhs: &Array<f32>, output: &mut Array<f32>) {
+    if ABSOLUTE_POS < lhs.len() {
+        output[ABSOLUTE_POS] = f32::atan2(lhs[ABSOLUTE_POS], rhs[ABSOLUTE_POS])
+            + f32::hypot(lhs[ABSOLUTE_POS], rhs[ABSOLUTE_POS]);
+    }
+}
+
+#[cube(launch)]
+pub fn kernel_float_checks(input: &Array<f32>, output: &mut Array<f32>) {
+    if ABSOLUTE_POS < input.len() {
+        let x = input[ABSOLUTE_POS];
+        let offset = ABSOLUTE_POS * 5;
+
+        output[offset] = f32::new(0.0);
+        output[offset + 1] = f32::new(0.0);
+        output[offset + 2] = f32::new(0.0);
+        if f32::is_nan(x) {
+            output[offset] = f32::new(1.0);
+        }
+        if f32::is_inf(x) {
+            output[offset + 1] = f32::new(1.0);
+        }
+        if f32::is_finite(x) {
+            output[offset + 2] = f32::new(1.0);
+        }
+        output[offset + 3] = f32::copysign(f32::new(2.0), x);
+        output[offset + 4] = f32::next_after(x, f32::INFINITY);
+    }
+}
+
+pub fn test_unary_math<R: Runtime>(client: ComputeClient<R::Server, R::Channel>) {
+    let values = [-0.75f32, -0.2, 0.3, 0.9];
+    let input = client.create(f32::as_bytes(&values));
+    let output = client.empty(values.len() * 11 * core::mem::size_of::<f32>());
+
+    unsafe {
+        kernel_unary_math::launch::<R>(
+            &client,
+            CubeCount::Static(1, 1, 1),
+            CubeDim::new(values.len() as u32, 1, 1),
+            ArrayArg::from_raw_parts(&input, values.len(), 1),
+            ArrayArg::from_raw_parts(&output, values.len() * 11, 1),
+        )
+    };
+
+    let actual = client.read(output.binding());
+    let actual = f32::from_bytes(&actual);
+    let expected = values
+        .iter()
+        .flat_map(|x| {
+            [
+                x.tan(),
+                x.asin(),
+                x.acos(),
+                x.atan(),
+                x.sinh(),
+                x.cosh(),
+                x.exp2(),
+                (x + 1.0).log2(),
+                (x * 4.0).trunc(),
+                (x * 4.0).fract(),
+                x.signum(),
+            ]
+        })
+        .collect::<Vec<_>>();
+
+    for (i, (actual, expected)) in actual.iter().zip(expected).enumerate() {
+        assert!(
+            (actual - expected).abs() < 1e-4,
+            "Values differ at index {i}: actual={actual}, expected={expected}"
+        );
+    }
+}
+
+pub fn test_binary_math<R: Runtime>(client: ComputeClient<R::Server, R::Channel>) {
+    let lhs = [1.0f32, -3.0, 0.0, 1e30, 2.5, -0.5, 0.0, 4.0];
+    let rhs = [1.0f32, 4.0, -2.0, 1e30, -1.5, -0.5, 0.0, 3.0];
+    let lhs_handle = client.create(f32::as_bytes(&lhs));
+    let rhs_handle = client.create(f32::as_bytes(&rhs));
+    let output = client.empty(lhs.len() * core::mem::size_of::<f32>());
+
+    unsafe {
+        kernel_binary_math::launch::<R>(
+            &client,
+            CubeCount::Static(1, 1, 1),
+            CubeDim::new(2, 1, 1),
+            ArrayArg::from_raw_parts(&lhs_handle, lhs.len(), 4),
+            ArrayArg::from_raw_parts(&rhs_handle, rhs.len(), 4),
+            ArrayArg::from_raw_parts(&output, lhs.len(), 4),
+        )
+    };
+
+    let actual = client.read(output.binding());
+    let actual = f32::from_bytes(&actual);
+
+    // Large inputs make sure hypot doesn't overflow when squaring.
+    for (i, actual) in actual.iter().enumerate() {
+        let expected = lhs[i].atan2(rhs[i]) + lhs[i].hypot(rhs[i]);
+        assert!(
+            ((actual - expected) / expected.abs().max(1.0)).abs() < 1e-4,
+            "Values differ at index {i}: actual={actual}, expected={expected}"
+        );
+    }
+}
+
+pub fn test_float_checks<R: Runtime>(client: ComputeClient<R::Server, R::Channel>) {
+    let values = [
+        1.5f32,
+        -0.0,
+        f32::NAN,
+        f32::INFINITY,
+        f32::NEG_INFINITY,
+        -3.0,
+    ];
+    let input = client.create(f32::as_bytes(&values));
+    let output = client.empty(values.len() * 5 * core::mem::size_of::<f32>());
+
+    unsafe {
+        kernel_float_checks::launch::<R>(
+            &client,
+            CubeCount::Static(1, 1, 1),
+            CubeDim::new(values.len() as u32, 1, 1),
+            ArrayArg::from_raw_parts(&input, values.len(), 1),
+            ArrayArg::from_raw_parts(&output, values.len() * 5, 1),
+        )
+    };
+
+    let actual = client.read(output.binding());
+    let actual = f32::from_bytes(&actual);
+    let expected = values
+        .iter()
+        .flat_map(|x| {
+            [
+                x.is_nan() as u32 as f32,
+                x.is_infinite() as u32 as f32,
+                x.is_finite() as u32 as f32,
+                2.0f32.copysign(*x),
+                // Stepping from -0.0 goes to the smallest subnormal, which may be flushed.
+                match *x {
+                    x if x.is_nan() || x == f32::INFINITY => x,
+                    0.0 => 0.0,
+                    x if x > 0.0 => f32::from_bits(x.to_bits() + 1),
+                    x => f32::from_bits(x.to_bits() - 1),
+                },
+            ]
+        })
+        .collect::<Vec<_>>();
+
+    for (i, (actual, expected)) in actual.iter().zip(expected).enumerate() {
+        let is_next_after_zero = i % 5 == 4 && values[i / 5] == 0.0;
+        let equal = match expected.is_nan() {
+            true => actual.is_nan(),
+            false if is_next_after_zero => actual.abs() <= f32::MIN_POSITIVE,
+            false => *actual == expected,
+        };
+        assert!(
+            equal,
+            "Values differ at index {i}: actual={actual}, expected={expected}"
+        );
+    }
+}
+
+#[allow(missing_docs)]
+#[macro_export]
+macro_rules! testgen_math {
+    () => {
+        use super::*;
+
+        #[test]
+        fn test_unary_math() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::math::test_unary_math::<TestRuntime>(client);
+        }
+
+        #[test]
+        fn test_binary_math() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::math::test_binary_math::<TestRuntime>(client);
+        }
+
+        #[test]
+        fn test_float_checks() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::math::test_float_checks::<TestRuntime>(client);
+        }
+    };
+}
@@ -1,10 +1,24 @@
 pub mod assign;
+pub mod atomic;
+pub mod bits;
 pub mod cmma;
+pub mod debug;
+pub mod enums;
+pub mod export;
+pub mod float8;
+pub mod function;
 pub mod launch;
+pub mod math;
+pub mod optional;
+pub mod pipeline;
 pub mod sequence;
 pub mod slice;
+pub mod small_int;
+pub mod source;
 pub mod subcube;
+pub mod switch;
 pub mod topology;
+pub mod vector;
 
 #[allow(missing_docs)]
 #[macro_export]
@@ -16,8 +30,22 @@ macro_rules! testgen_all {
         cubecl_core::testgen_launch!();
         cubecl_core::testgen_cmma!();
         cubecl_core::testgen_slice!();
+        cubecl_core::testgen_small_int!();
+        cubecl_core::testgen_float8!();
+        cubecl_core::testgen_math!();
+        cubecl_core::testgen_bits!();
+        cubecl_core::testgen_vector!();
+        cubecl_core::testgen_atomic!();
+        cubecl_core::testgen_pipeline!();
         cubecl_core::testgen_assign!();
         cubecl_core::testgen_topology!();
         cubecl_core::testgen_sequence!();
+        cubecl_core::testgen_switch!();
+        cubecl_core::testgen_enums!();
+        cubecl_core::testgen_optional!();
+        cubecl_core::testgen_function!();
+        cubecl_core::testgen_debug!();
+        cubecl_core::testgen_export!();
+        cubecl_core::testgen_source!();
     };
 }
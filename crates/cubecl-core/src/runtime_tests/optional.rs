@@ -0,0 +1,145 @@
+use crate as cubecl;
+
+use cubecl::prelude::*;
+
+#[cube]
+fn add_bias<F: Float>(value: F, bias: Option<F>) -> F {
+    if let Some(bias) = bias {
+        value + bias
+    } else {
+        value
+    }
+}
+
+#[cube]
+fn scale_or_return<F: Float>(output: &mut Array<F>, scale: Option<F>) {
+    let Some(scale) = scale else {
+        return;
+    };
+    output[UNIT_POS] *= scale;
+}
+
+#[cube(launch)]
+pub fn kernel_optional_bias<F: Float>(
+    output: &mut Array<F>,
+    bias: &Array<F>,
+    #[comptime] with_bias: bool,
+) {
+    let bias = if with_bias {
+        Some(bias[UNIT_POS])
+    } else {
+        None
+    };
+    output[UNIT_POS] = add_bias::<F>(output[UNIT_POS], bias);
+    scale_or_return::<F>(output, bias);
+}
+
+#[cube(launch)]
+pub fn kernel_let_else_break(output: &mut Array<u32>, #[comptime] with_limit: bool) {
+    let mut count: u32 = 0;
+    loop {
+        let limit = if with_limit { Some(output[0]) } else { None };
+        let Some(limit) = limit else {
+            break;
+        };
+        if count >= limit {
+            break;
+        }
+        count += 1;
+    }
+    output[1] = count + 10;
+}
+
+#[cube(launch)]
+pub fn kernel_while_loop(output: &mut Array<f32>) {
+    let mut value = output[UNIT_POS];
+    let mut steps: u32 = 0;
+    while value > 1.0 {
+        value /= 2.0;
+        steps += 1;
+    }
+    output[UNIT_POS] = f32::cast_from(steps);
+}
+
+pub fn test_optional_bias<R: Runtime>(client: ComputeClient<R::Server, R::Channel>) {
+    for (with_bias, expected) in [(true, [2.0, 15.0]), (false, [1.0, 2.0])] {
+        let handle = client.create(f32::as_bytes(&[1.0, 2.0]));
+        let bias = client.create(f32::as_bytes(&[1.0, 3.0]));
+
+        kernel_optional_bias::launch::<f32, R>(
+            &client,
+            CubeCount::Static(1, 1, 1),
+            CubeDim::new(2, 1, 1),
+            unsafe { ArrayArg::from_raw_parts(&handle, 2, 1) },
+            unsafe { ArrayArg::from_raw_parts(&bias, 2, 1) },
+            with_bias,
+        );
+
+        let actual = client.read(handle.binding());
+        let actual = f32::from_bytes(&actual);
+
+        assert_eq!(actual, &expected);
+    }
+}
+
+pub fn test_let_else_break<R: Runtime>(client: ComputeClient<R::Server, R::Channel>) {
+    // The kernel keeps running after the loop, whether the let-else breaks or not.
+    for (with_limit, expected) in [(true, [3, 13]), (false, [3, 10])] {
+        let handle = client.create(u32::as_bytes(&[3, 0]));
+
+        kernel_let_else_break::launch::<R>(
+            &client,
+            CubeCount::Static(1, 1, 1),
+            CubeDim::new(1, 1, 1),
+            unsafe { ArrayArg::from_raw_parts(&handle, 2, 1) },
+            with_limit,
+        );
+
+        let actual = client.read(handle.binding());
+        let actual = u32::from_bytes(&actual);
+
+        assert_eq!(actual, &expected);
+    }
+}
+
+pub fn test_while_loop<R: Runtime>(client: ComputeClient<R::Server, R::Channel>) {
+    let handle = client.create(f32::as_bytes(&[0.5, 2.0, 5.0, 16.0]));
+
+    kernel_while_loop::launch::<R>(
+        &client,
+        CubeCount::Static(1, 1, 1),
+        CubeDim::new(4, 1, 1),
+        unsafe { ArrayArg::from_raw_parts(&handle, 4, 1) },
+    );
+
+    let actual = client.read(handle.binding());
+    let actual = f32::from_bytes(&actual);
+
+    assert_eq!(actual, &[0.0, 1.0, 3.0, 4.0]);
+}
+
+#[allow(missing_docs)]
+#[macro_export]
+macro_rules! testgen_optional {
+    () => {
+        use super::*;
+
+        #[test]
+        fn test_optional_bias() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::optional::test_optional_bias::<TestRuntime>(client);
+        }
+
+        #[test]
+        fn test_let_else_break() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::optional::test_let_else_break::<TestRuntime>(client);
+        }
+
+        #[test]
+        fn test_while_loop() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::optional::test_while_loop::<TestRuntime>(client);
+        }
+    };
+}
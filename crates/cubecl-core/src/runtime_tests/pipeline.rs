@@ -0,0 +1,105 @@
+use crate as cubecl;
+
+use cubecl::prelude::*;
+
+#[cube(launch)]
+pub fn kernel_memcpy_async(output: &mut Array<f32>, input: &Array<f32>) {
+    let mut shared = SharedMemory::<f32>::new(8);
+    let start = UNIT_POS * 2;
+
+    memcpy_async(
+        input.slice(start, start + 2),
+        shared.slice_mut(start, start + 2),
+    );
+    pipeline_commit();
+    pipeline_wait(0);
+    sync_units();
+
+    output[start] = shared[7 - start];
+    output[start + 1] = shared[6 - start];
+}
+
+#[cube(launch)]
+pub fn kernel_pipeline_stages(output: &mut Array<f32>, input: &Array<f32>) {
+    // Two stages of 4 elements, each unit copying one element per stage.
+    let mut shared = SharedMemory::<f32>::new(8);
+
+    memcpy_async(
+        input.slice(UNIT_POS, UNIT_POS + 1),
+        shared.slice_mut(UNIT_POS, UNIT_POS + 1),
+    );
+    pipeline_commit();
+    memcpy_async(
+        input.slice(UNIT_POS + 4, UNIT_POS + 5),
+        shared.slice_mut(UNIT_POS + 4, UNIT_POS + 5),
+    );
+    pipeline_commit();
+
+    pipeline_wait(1);
+    sync_units();
+    output[UNIT_POS] = shared[3 - UNIT_POS];
+
+    pipeline_wait(0);
+    sync_units();
+    output[UNIT_POS + 4] = shared[7 - UNIT_POS];
+}
+
+pub fn test_memcpy_async<R: Runtime>(client: ComputeClient<R::Server, R::Channel>) {
+    let input = client.create(f32::as_bytes(&[0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0]));
+    let output = client.empty(8 * core::mem::size_of::<f32>());
+
+    unsafe {
+        kernel_memcpy_async::launch::<R>(
+            &client,
+            CubeCount::Static(1, 1, 1),
+            CubeDim::new(4, 1, 1),
+            ArrayArg::from_raw_parts(&output, 8, 1),
+            ArrayArg::from_raw_parts(&input, 8, 1),
+        )
+    };
+
+    let actual = client.read(output.binding());
+    let actual = f32::from_bytes(&actual);
+
+    assert_eq!(actual, &[7.0, 6.0, 5.0, 4.0, 3.0, 2.0, 1.0, 0.0]);
+}
+
+pub fn test_pipeline_stages<R: Runtime>(client: ComputeClient<R::Server, R::Channel>) {
+    let input = client.create(f32::as_bytes(&[0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0]));
+    let output = client.empty(8 * core::mem::size_of::<f32>());
+
+    unsafe {
+        kernel_pipeline_stages::launch::<R>(
+            &client,
+            CubeCount::Static(1, 1, 1),
+            CubeDim::new(4, 1, 1),
+            ArrayArg::from_raw_parts(&output, 8, 1),
+            ArrayArg::from_raw_parts(&input, 8, 1),
+        )
+    };
+
+    let actual = client.read(output.binding());
+    let actual = f32::from_bytes(&actual);
+
+    assert_eq!(actual, &[3.0, 2.0, 1.0, 0.0, 7.0, 6.0, 5.0, 4.0]);
+}
+
+#[allow(missing_docs)]
+#[macro_export]
+macro_rules! testgen_pipeline {
+    () => {
+        use super::*;
+
+        #[test]
+        fn test_memcpy_async() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::pipeline::test_memcpy_async::<TestRuntime>(client);
+        }
+
+        #[test]
+        fn test_pipeline_stages() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::pipeline::test_pipeline_stages::<TestRuntime>(client);
+        }
+    };
+}
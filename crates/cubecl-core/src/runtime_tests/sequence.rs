@@ -16,6 +16,23 @@ pub fn sequence_for_loop(output: &mut Array<f32>) {
     }
 }
 
+#[cube(launch)]
+pub fn sequence_iter(output: &mut Array<f32>) {
+    if UNIT_POS != 0 {
+        return;
+    }
+
+    let mut sequence = Sequence::<f32>::new();
+    sequence.push(1.0);
+    sequence.push(4.0);
+
+    for value in sequence.iter() {
+        output[0] += value;
+    }
+    // The sequence isn't drained by the loop.
+    output[0] *= f32::cast_from(sequence.len());
+}
+
 #[cube(launch)]
 pub fn sequence_index(output: &mut Array<f32>) {
     if UNIT_POS != 0 {
@@ -30,6 +47,25 @@ pub fn sequence_index(output: &mut Array<f32>) {
     output[0] += Sequence::<f32>::index(&sequence, 1);
 }
 
+#[cube(launch)]
+pub fn sequence_arg_sum(inputs: &Sequence<Tensor<f32>>, output: &mut Tensor<f32>) {
+    if ABSOLUTE_POS < output.len() {
+        let mut sum = f32::new(0.0);
+        #[unroll]
+        for i in 0..inputs.len() {
+            sum += inputs.index(i)[ABSOLUTE_POS];
+        }
+        output[ABSOLUTE_POS] = sum * f32::cast_from(inputs.len());
+    }
+}
+
+#[cube(launch)]
+pub fn tuple_arg_sub(inputs: &(Tensor<f32>, Tensor<f32>), output: &mut Tensor<f32>) {
+    if ABSOLUTE_POS < output.len() {
+        output[ABSOLUTE_POS] = inputs.0[ABSOLUTE_POS] - inputs.1[ABSOLUTE_POS];
+    }
+}
+
 pub fn test_sequence_for_loop<R: Runtime>(client: ComputeClient<R::Server, R::Channel>) {
     let handle = client.create(f32::as_bytes(&[0.0]));
 
@@ -62,6 +98,69 @@ pub fn test_sequence_index<R: Runtime>(client: ComputeClient<R::Server, R::Chann
     assert_eq!(actual[0], 6.0);
 }
 
+pub fn test_sequence_arg<R: Runtime>(client: ComputeClient<R::Server, R::Channel>) {
+    let lhs = client.create(f32::as_bytes(&[1.0, 2.0]));
+    let rhs = client.create(f32::as_bytes(&[3.0, 4.0]));
+    let output = client.empty(2 * core::mem::size_of::<f32>());
+
+    let mut inputs = SequenceArg::new();
+    inputs.push(unsafe { TensorArg::from_raw_parts(&lhs, &[1], &[2], 1) });
+    inputs.push(unsafe { TensorArg::from_raw_parts(&rhs, &[1], &[2], 1) });
+
+    sequence_arg_sum::launch::<R>(
+        &client,
+        CubeCount::Static(1, 1, 1),
+        CubeDim::default(),
+        inputs,
+        unsafe { TensorArg::from_raw_parts(&output, &[1], &[2], 1) },
+    );
+
+    let actual = client.read(output.binding());
+    let actual = f32::from_bytes(&actual);
+
+    assert_eq!(actual, &[8.0, 12.0]);
+}
+
+pub fn test_sequence_iter<R: Runtime>(client: ComputeClient<R::Server, R::Channel>) {
+    let handle = client.create(f32::as_bytes(&[0.0]));
+
+    sequence_iter::launch::<R>(
+        &client,
+        CubeCount::Static(1, 1, 1),
+        CubeDim::default(),
+        unsafe { ArrayArg::from_raw_parts(&handle, 2, 1) },
+    );
+
+    let actual = client.read(handle.binding());
+    let actual = f32::from_bytes(&actual);
+
+    assert_eq!(actual[0], 10.0);
+}
+
+pub fn test_tuple_arg<R: Runtime>(client: ComputeClient<R::Server, R::Channel>) {
+    let lhs = client.create(f32::as_bytes(&[5.0, 7.0]));
+    let rhs = client.create(f32::as_bytes(&[3.0, 4.0]));
+    let output = client.empty(2 * core::mem::size_of::<f32>());
+
+    tuple_arg_sub::launch::<R>(
+        &client,
+        CubeCount::Static(1, 1, 1),
+        CubeDim::default(),
+        unsafe {
+            (
+                TensorArg::from_raw_parts(&lhs, &[1], &[2], 1),
+                TensorArg::from_raw_parts(&rhs, &[1], &[2], 1),
+            )
+        },
+        unsafe { TensorArg::from_raw_parts(&output, &[1], &[2], 1) },
+    );
+
+    let actual = client.read(output.binding());
+    let actual = f32::from_bytes(&actual);
+
+    assert_eq!(actual, &[2.0, 3.0]);
+}
+
 #[allow(missing_docs)]
 #[macro_export]
 macro_rules! testgen_sequence {
@@ -79,5 +178,23 @@ macro_rules! testgen_sequence {
             let client = TestRuntime::client(&Default::default());
             cubecl_core::runtime_tests::sequence::test_sequence_index::<TestRuntime>(client);
         }
+
+        #[test]
+        fn test_sequence_arg() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::sequence::test_sequence_arg::<TestRuntime>(client);
+        }
+
+        #[test]
+        fn test_sequence_iter() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::sequence::test_sequence_iter::<TestRuntime>(client);
+        }
+
+        #[test]
+        fn test_tuple_arg() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::sequence::test_tuple_arg::<TestRuntime>(client);
+        }
     };
 }
@@ -0,0 +1,242 @@
+use crate as cubecl;
+
+use cubecl::prelude::*;
+
+#[cube(launch)]
+pub fn kernel_u8_add(input: &Array<u8>, output: &mut Array<u8>, offset: u8) {
+    if ABSOLUTE_POS < input.len() {
+        output[ABSOLUTE_POS] = input[ABSOLUTE_POS] + offset;
+    }
+}
+
+#[cube(launch)]
+pub fn kernel_i8_cast(input: &Array<i32>, output: &mut Array<i8>) {
+    if ABSOLUTE_POS < input.len() {
+        output[ABSOLUTE_POS] = i8::cast_from(input[ABSOLUTE_POS]);
+    }
+}
+
+#[cube(launch)]
+pub fn kernel_i16_scalars(input: &Array<i16>, output: &mut Array<i16>, scale: i16, bias: i16) {
+    if ABSOLUTE_POS < input.len() {
+        output[ABSOLUTE_POS] = input[ABSOLUTE_POS] * scale + bias;
+    }
+}
+
+#[cube(launch)]
+pub fn kernel_u8_shared(input: &Array<u8>, output: &mut Array<u8>) {
+    let mut shared = SharedMemory::<u8>::new(8);
+
+    shared[UNIT_POS] = input[UNIT_POS] * 2;
+    sync_units();
+
+    output[UNIT_POS] = shared[7 - UNIT_POS];
+}
+
+#[cube(launch)]
+pub fn kernel_u8_len(input: &Array<u8>, output: &mut Array<u32>) {
+    if UNIT_POS == 0 {
+        output[0] = input.len();
+    }
+}
+
+pub fn test_u8_add<R: Runtime>(client: ComputeClient<R::Server, R::Channel>) {
+    let input = client.create(u8::as_bytes(&[1, 2, 3, 250, 4, 5, 6, 255]));
+    let output = client.empty(8 * core::mem::size_of::<u8>());
+
+    unsafe {
+        kernel_u8_add::launch::<R>(
+            &client,
+            CubeCount::Static(1, 1, 1),
+            CubeDim::new(2, 1, 1),
+            ArrayArg::from_raw_parts(&input, 8, 4),
+            ArrayArg::from_raw_parts(&output, 8, 4),
+            ScalarArg::new(10),
+        )
+    };
+
+    let actual = client.read(output.binding());
+    let actual = u8::from_bytes(&actual);
+
+    assert_eq!(actual, &[11, 12, 13, 4, 14, 15, 16, 9]);
+}
+
+pub fn test_i8_cast<R: Runtime>(client: ComputeClient<R::Server, R::Channel>) {
+    let input = client.create(i32::as_bytes(&[1, -1, 200, -200]));
+    let output = client.empty(4 * core::mem::size_of::<i8>());
+
+    unsafe {
+        kernel_i8_cast::launch::<R>(
+            &client,
+            CubeCount::Static(1, 1, 1),
+            CubeDim::new(1, 1, 1),
+            ArrayArg::from_raw_parts(&input, 4, 4),
+            ArrayArg::from_raw_parts(&output, 4, 4),
+        )
+    };
+
+    let actual = client.read(output.binding());
+    let actual = i8::from_bytes(&actual);
+
+    assert_eq!(actual, &[1, -1, -56, 56]);
+}
+
+pub fn test_i16_scalars<R: Runtime>(client: ComputeClient<R::Server, R::Channel>) {
+    let input = client.create(i16::as_bytes(&[1, -2, 300, 4]));
+    let output = client.empty(4 * core::mem::size_of::<i16>());
+
+    unsafe {
+        kernel_i16_scalars::launch::<R>(
+            &client,
+            CubeCount::Static(1, 1, 1),
+            CubeDim::new(2, 1, 1),
+            ArrayArg::from_raw_parts(&input, 4, 2),
+            ArrayArg::from_raw_parts(&output, 4, 2),
+            ScalarArg::new(-2),
+            ScalarArg::new(3),
+        )
+    };
+
+    let actual = client.read(output.binding());
+    let actual = i16::from_bytes(&actual);
+
+    assert_eq!(actual, &[1, 7, -597, -5]);
+}
+
+pub fn test_u8_add_partial_words<R: Runtime>(client: ComputeClient<R::Server, R::Channel>) {
+    for vectorization in [1, 2] {
+        let input = client.create(u8::as_bytes(&[1, 2, 3, 250, 4, 5, 6, 255]));
+        let output = client.empty(8 * core::mem::size_of::<u8>());
+
+        unsafe {
+            kernel_u8_add::launch::<R>(
+                &client,
+                CubeCount::Static(1, 1, 1),
+                CubeDim::new(8 / vectorization as u32, 1, 1),
+                ArrayArg::from_raw_parts(&input, 8, vectorization),
+                ArrayArg::from_raw_parts(&output, 8, vectorization),
+                ScalarArg::new(10),
+            )
+        };
+
+        let actual = client.read(output.binding());
+        let actual = u8::from_bytes(&actual);
+
+        assert_eq!(actual, &[11, 12, 13, 4, 14, 15, 16, 9]);
+    }
+}
+
+pub fn test_i16_scalars_unvectorized<R: Runtime>(client: ComputeClient<R::Server, R::Channel>) {
+    let input = client.create(i16::as_bytes(&[1, -2, 300, 4]));
+    let output = client.empty(4 * core::mem::size_of::<i16>());
+
+    unsafe {
+        kernel_i16_scalars::launch::<R>(
+            &client,
+            CubeCount::Static(1, 1, 1),
+            CubeDim::new(4, 1, 1),
+            ArrayArg::from_raw_parts(&input, 4, 1),
+            ArrayArg::from_raw_parts(&output, 4, 1),
+            ScalarArg::new(-2),
+            ScalarArg::new(3),
+        )
+    };
+
+    let actual = client.read(output.binding());
+    let actual = i16::from_bytes(&actual);
+
+    assert_eq!(actual, &[1, 7, -597, -5]);
+}
+
+pub fn test_u8_shared<R: Runtime>(client: ComputeClient<R::Server, R::Channel>) {
+    let input = client.create(u8::as_bytes(&[0, 1, 2, 3, 4, 5, 6, 7]));
+    let output = client.empty(8 * core::mem::size_of::<u8>());
+
+    unsafe {
+        kernel_u8_shared::launch::<R>(
+            &client,
+            CubeCount::Static(1, 1, 1),
+            CubeDim::new(8, 1, 1),
+            ArrayArg::from_raw_parts(&input, 8, 1),
+            ArrayArg::from_raw_parts(&output, 8, 1),
+        )
+    };
+
+    let actual = client.read(output.binding());
+    let actual = u8::from_bytes(&actual);
+
+    assert_eq!(actual, &[14, 12, 10, 8, 6, 4, 2, 0]);
+}
+
+pub fn test_u8_len<R: Runtime>(client: ComputeClient<R::Server, R::Channel>) {
+    // The buffer holds whole words, but only the first 5 values belong to the array.
+    let input = client.create(u8::as_bytes(&[1, 2, 3, 4, 5, 0, 0, 0]));
+    let output = client.empty(core::mem::size_of::<u32>());
+
+    unsafe {
+        kernel_u8_len::launch::<R>(
+            &client,
+            CubeCount::Static(1, 1, 1),
+            CubeDim::new(1, 1, 1),
+            ArrayArg::from_raw_parts(&input, 5, 1),
+            ArrayArg::from_raw_parts(&output, 1, 1),
+        )
+    };
+
+    let actual = client.read(output.binding());
+    let actual = u32::from_bytes(&actual);
+
+    assert_eq!(actual, &[5]);
+}
+
+#[allow(missing_docs)]
+#[macro_export]
+macro_rules! testgen_small_int {
+    () => {
+        use super::*;
+
+        #[test]
+        fn test_u8_add() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::small_int::test_u8_add::<TestRuntime>(client);
+        }
+
+        #[test]
+        fn test_i8_cast() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::small_int::test_i8_cast::<TestRuntime>(client);
+        }
+
+        #[test]
+        fn test_i16_scalars() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::small_int::test_i16_scalars::<TestRuntime>(client);
+        }
+
+        #[test]
+        fn test_u8_add_partial_words() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::small_int::test_u8_add_partial_words::<TestRuntime>(client);
+        }
+
+        #[test]
+        fn test_i16_scalars_unvectorized() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::small_int::test_i16_scalars_unvectorized::<TestRuntime>(
+                client,
+            );
+        }
+
+        #[test]
+        fn test_u8_shared() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::small_int::test_u8_shared::<TestRuntime>(client);
+        }
+
+        #[test]
+        fn test_u8_len() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::small_int::test_u8_len::<TestRuntime>(client);
+        }
+    };
+}
@@ -0,0 +1,75 @@
+use crate as cubecl;
+use cubecl::{
+    compute::SourceKernel,
+    ir::{Binding, Elem, FloatKind, Item, Location, Visibility},
+    prelude::*,
+    Compiler,
+};
+
+const WGSL_SOURCE: &str = "
+@group(0) @binding(0) var<storage, read_write> input: array<f32>;
+@group(0) @binding(1) var<storage, read_write> output: array<f32>;
+
+@compute @workgroup_size(4, 1, 1)
+fn double_values(@builtin(local_invocation_index) index: u32) {
+    output[index] = input[index] * 2.0;
+}
+";
+
+const CUDA_SOURCE: &str = "
+extern \"C\" __global__ void double_values(float input[], float output[]) {
+    int index = threadIdx.x;
+    output[index] = input[index] * 2.0f;
+}
+";
+
+fn source_kernel<R: Runtime>() -> SourceKernel {
+    let source = match <R::Compiler as Compiler>::extension() {
+        "wgsl" => WGSL_SOURCE,
+        "cu" => CUDA_SOURCE,
+        extension => panic!("No source for language {extension}"),
+    };
+    let binding = |visibility| Binding {
+        location: Location::Storage,
+        visibility,
+        item: Item::new(Elem::Float(FloatKind::F32)),
+        size: None,
+    };
+
+    SourceKernel::new(
+        source,
+        "double_values",
+        CubeDim::new(4, 1, 1),
+        vec![binding(Visibility::Read), binding(Visibility::ReadWrite)],
+    )
+}
+
+pub fn test_source_kernel<R: Runtime>(client: ComputeClient<R::Server, R::Channel>) {
+    let input = client.create(f32::as_bytes(&[1.0, 2.0, 3.0, 4.0]));
+    let output = client.empty(4 * core::mem::size_of::<f32>());
+
+    client.execute(
+        Box::new(source_kernel::<R>()),
+        CubeCount::Static(1, 1, 1),
+        vec![input.binding(), output.clone().binding()],
+    );
+
+    let actual = client.read(output.binding());
+    let actual = f32::from_bytes(&actual);
+
+    assert_eq!(actual, &[2.0, 4.0, 6.0, 8.0]);
+}
+
+#[allow(missing_docs)]
+#[macro_export]
+macro_rules! testgen_source {
+    () => {
+        use super::*;
+
+        #[test]
+        fn test_source_kernel() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::source::test_source_kernel::<TestRuntime>(client);
+        }
+    };
+}
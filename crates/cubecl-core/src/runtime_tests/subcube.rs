@@ -12,6 +12,18 @@ pub fn kernel_sum(output: &mut Tensor<f32>) {
     }
 }
 
+#[cube(launch)]
+pub fn kernel_inclusive_sum(output: &mut Tensor<f32>) {
+    let val = output[UNIT_POS];
+    output[UNIT_POS] = subcube_inclusive_sum(val);
+}
+
+#[cube(launch)]
+pub fn kernel_exclusive_sum(output: &mut Tensor<f32>) {
+    let val = output[UNIT_POS];
+    output[UNIT_POS] = subcube_exclusive_sum(val);
+}
+
 #[cube(launch)]
 pub fn kernel_prod(output: &mut Tensor<f32>) {
     let val = output[UNIT_POS];
@@ -75,6 +87,45 @@ pub fn kernel_broadcast(output: &mut Tensor<f32>) {
     }
 }
 
+#[cube(launch)]
+pub fn kernel_shuffle(output: &mut Tensor<f32>) {
+    let val = output[UNIT_POS];
+    output[UNIT_POS] = subcube_shuffle(val, 3 - UNIT_POS);
+}
+
+#[cube(launch)]
+pub fn kernel_shuffle_xor(output: &mut Tensor<f32>) {
+    let val = output[UNIT_POS];
+    output[UNIT_POS] = subcube_shuffle_xor(val, 1);
+}
+
+#[cube(launch)]
+pub fn kernel_shuffle_up(output: &mut Tensor<f32>) {
+    let val = output[UNIT_POS];
+    let val2 = subcube_shuffle_up(val, 1);
+
+    if UNIT_POS > 0 {
+        output[UNIT_POS] = val2;
+    }
+}
+
+#[cube(launch)]
+pub fn kernel_shuffle_down(output: &mut Tensor<f32>) {
+    let val = output[UNIT_POS];
+    let val2 = subcube_shuffle_down(val, 1);
+
+    if UNIT_POS < 3 {
+        output[UNIT_POS] = val2;
+    }
+}
+
+#[cube(launch)]
+pub fn kernel_ballot(output: &mut Tensor<f32>) {
+    let val = output[UNIT_POS];
+    let mask = subcube_ballot(val < 5.0);
+    output[UNIT_POS] = mask as f32;
+}
+
 pub fn test_subcube_sum<TestRuntime: Runtime>(
     client: ComputeClient<TestRuntime::Server, TestRuntime::Channel>,
 ) {
@@ -88,6 +139,32 @@ pub fn test_subcube_sum<TestRuntime: Runtime>(
     );
 }
 
+pub fn test_subcube_inclusive_sum<TestRuntime: Runtime>(
+    client: ComputeClient<TestRuntime::Server, TestRuntime::Channel>,
+) {
+    test_subcube_operation::<TestRuntime, _>(
+        &[4.0, 5.0, 7.0, 1.0],
+        &[4.0, 9.0, 16.0, 17.0],
+        client.clone(),
+        |cube_dim, settings, handle| {
+            kernel_inclusive_sum::launch::<TestRuntime>(&client, cube_dim, settings, handle)
+        },
+    );
+}
+
+pub fn test_subcube_exclusive_sum<TestRuntime: Runtime>(
+    client: ComputeClient<TestRuntime::Server, TestRuntime::Channel>,
+) {
+    test_subcube_operation::<TestRuntime, _>(
+        &[4.0, 5.0, 7.0, 1.0],
+        &[0.0, 4.0, 9.0, 16.0],
+        client.clone(),
+        |cube_dim, settings, handle| {
+            kernel_exclusive_sum::launch::<TestRuntime>(&client, cube_dim, settings, handle)
+        },
+    );
+}
+
 pub fn test_subcube_prod<TestRuntime: Runtime>(
     client: ComputeClient<TestRuntime::Server, TestRuntime::Channel>,
 ) {
@@ -194,6 +271,99 @@ pub fn test_subcube_broadcast<TestRuntime: Runtime>(
     );
 }
 
+pub fn test_subcube_shuffle<TestRuntime: Runtime>(
+    client: ComputeClient<TestRuntime::Server, TestRuntime::Channel>,
+) {
+    test_subcube_operation::<TestRuntime, _>(
+        &[2.0, 1.0, -6.0, 3.0],
+        &[3.0, -6.0, 1.0, 2.0],
+        client.clone(),
+        |cube_dim, settings, handle| {
+            kernel_shuffle::launch::<TestRuntime>(&client, cube_dim, settings, handle)
+        },
+    );
+}
+
+pub fn test_subcube_shuffle_xor<TestRuntime: Runtime>(
+    client: ComputeClient<TestRuntime::Server, TestRuntime::Channel>,
+) {
+    test_subcube_operation::<TestRuntime, _>(
+        &[2.0, 1.0, -6.0, 3.0],
+        &[1.0, 2.0, 3.0, -6.0],
+        client.clone(),
+        |cube_dim, settings, handle| {
+            kernel_shuffle_xor::launch::<TestRuntime>(&client, cube_dim, settings, handle)
+        },
+    );
+}
+
+pub fn test_subcube_shuffle_up<TestRuntime: Runtime>(
+    client: ComputeClient<TestRuntime::Server, TestRuntime::Channel>,
+) {
+    test_subcube_operation::<TestRuntime, _>(
+        &[2.0, 1.0, -6.0, 3.0],
+        &[2.0, 2.0, 1.0, -6.0],
+        client.clone(),
+        |cube_dim, settings, handle| {
+            kernel_shuffle_up::launch::<TestRuntime>(&client, cube_dim, settings, handle)
+        },
+    );
+}
+
+pub fn test_subcube_shuffle_down<TestRuntime: Runtime>(
+    client: ComputeClient<TestRuntime::Server, TestRuntime::Channel>,
+) {
+    test_subcube_operation::<TestRuntime, _>(
+        &[2.0, 1.0, -6.0, 3.0],
+        &[1.0, -6.0, 3.0, 3.0],
+        client.clone(),
+        |cube_dim, settings, handle| {
+            kernel_shuffle_down::launch::<TestRuntime>(&client, cube_dim, settings, handle)
+        },
+    );
+}
+
+pub fn test_subcube_ballot<TestRuntime: Runtime>(
+    client: ComputeClient<TestRuntime::Server, TestRuntime::Channel>,
+) {
+    test_subcube_operation::<TestRuntime, _>(
+        &[2.0, 8.0, -6.0, 3.0],
+        &[13.0, 13.0, 13.0, 13.0],
+        client.clone(),
+        |cube_dim, settings, handle| {
+            kernel_ballot::launch::<TestRuntime>(&client, cube_dim, settings, handle)
+        },
+    );
+}
+
+pub fn test_subcube_shuffle_vectorized<TestRuntime: Runtime>(
+    client: ComputeClient<TestRuntime::Server, TestRuntime::Channel>,
+) {
+    test_subcube_operation_vectorized::<TestRuntime, _>(
+        &[2.0, 1.0, -6.0, 3.0, 5.0, 4.0, 7.0, 8.0],
+        &[7.0, 8.0, 5.0, 4.0, -6.0, 3.0, 2.0, 1.0],
+        2,
+        client.clone(),
+        |cube_dim, settings, handle| {
+            kernel_shuffle::launch::<TestRuntime>(&client, cube_dim, settings, handle)
+        },
+    );
+}
+
+pub fn test_subcube_exclusive_sum_vectorized<TestRuntime: Runtime>(
+    client: ComputeClient<TestRuntime::Server, TestRuntime::Channel>,
+) {
+    test_subcube_operation_vectorized::<TestRuntime, _>(
+        &[2.0, 1.0, -6.0, 3.0, 5.0, 4.0, 7.0, 8.0],
+        &[0.0, 0.0, 2.0, 1.0, -4.0, 4.0, 1.0, 8.0],
+        2,
+        client.clone(),
+        |cube_dim, settings, handle| {
+            kernel_exclusive_sum::launch::<TestRuntime>(&client, cube_dim, settings, handle)
+        },
+    );
+}
+
 fn test_subcube_operation<TestRuntime: Runtime, Launch>(
     input: &[f32],
     expected: &[f32],
@@ -201,6 +371,18 @@ fn test_subcube_operation<TestRuntime: Runtime, Launch>(
     launch: Launch,
 ) where
     Launch: Fn(CubeCount<TestRuntime::Server>, CubeDim, TensorArg<'_, TestRuntime>),
+{
+    test_subcube_operation_vectorized::<TestRuntime, _>(input, expected, 1, client, launch);
+}
+
+fn test_subcube_operation_vectorized<TestRuntime: Runtime, Launch>(
+    input: &[f32],
+    expected: &[f32],
+    vectorization: u8,
+    client: ComputeClient<TestRuntime::Server, TestRuntime::Channel>,
+    launch: Launch,
+) where
+    Launch: Fn(CubeCount<TestRuntime::Server>, CubeDim, TensorArg<'_, TestRuntime>),
 {
     if !client.features().enabled(Feature::Subcube) {
         // Can't execute the test.
@@ -213,8 +395,8 @@ fn test_subcube_operation<TestRuntime: Runtime, Launch>(
     unsafe {
         launch(
             CubeCount::Static(1, 1, 1),
-            CubeDim::new(input.len() as u32, 1, 1),
-            TensorArg::from_raw_parts(&handle, &strides, &shape, 1),
+            CubeDim::new(input.len() as u32 / vectorization as u32, 1, 1),
+            TensorArg::from_raw_parts(&handle, &strides, &shape, vectorization),
         );
     }
 
@@ -236,6 +418,18 @@ macro_rules! testgen_subcube {
             cubecl_core::runtime_tests::subcube::test_subcube_sum::<TestRuntime>(client);
         }
 
+        #[test]
+        fn test_subcube_inclusive_sum() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::subcube::test_subcube_inclusive_sum::<TestRuntime>(client);
+        }
+
+        #[test]
+        fn test_subcube_exclusive_sum() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::subcube::test_subcube_exclusive_sum::<TestRuntime>(client);
+        }
+
         #[test]
         fn test_subcube_prod() {
             let client = TestRuntime::client(&Default::default());
@@ -278,5 +472,51 @@ macro_rules! testgen_subcube {
             let client = TestRuntime::client(&Default::default());
             cubecl_core::runtime_tests::subcube::test_subcube_broadcast::<TestRuntime>(client);
         }
+
+        #[test]
+        fn test_subcube_shuffle() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::subcube::test_subcube_shuffle::<TestRuntime>(client);
+        }
+
+        #[test]
+        fn test_subcube_shuffle_xor() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::subcube::test_subcube_shuffle_xor::<TestRuntime>(client);
+        }
+
+        #[test]
+        fn test_subcube_shuffle_up() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::subcube::test_subcube_shuffle_up::<TestRuntime>(client);
+        }
+
+        #[test]
+        fn test_subcube_shuffle_down() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::subcube::test_subcube_shuffle_down::<TestRuntime>(client);
+        }
+
+        #[test]
+        fn test_subcube_ballot() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::subcube::test_subcube_ballot::<TestRuntime>(client);
+        }
+
+        #[test]
+        fn test_subcube_shuffle_vectorized() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::subcube::test_subcube_shuffle_vectorized::<TestRuntime>(
+                client,
+            );
+        }
+
+        #[test]
+        fn test_subcube_exclusive_sum_vectorized() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::subcube::test_subcube_exclusive_sum_vectorized::<
+                TestRuntime,
+            >(client);
+        }
     };
 }
@@ -0,0 +1,141 @@
+use crate as cubecl;
+
+use cubecl::prelude::*;
+
+#[cube(launch)]
+pub fn kernel_switch_statement(output: &mut Array<f32>) {
+    match UNIT_POS {
+        0 => {
+            output[UNIT_POS] = 10.0;
+        }
+        1 | 2 => {
+            output[UNIT_POS] = 20.0;
+        }
+        3..=5 => {
+            output[UNIT_POS] = 30.0;
+        }
+        _ => {
+            output[UNIT_POS] = 40.0;
+        }
+    }
+}
+
+#[cube(launch)]
+pub fn kernel_switch_value(output: &mut Array<f32>, selector: &Array<i32>) {
+    let value = match selector[UNIT_POS] {
+        -1 => 1.0f32,
+        0..3 => 2.0f32,
+        _ => 3.0f32,
+    };
+    output[UNIT_POS] = value;
+}
+
+#[cube(launch)]
+pub fn kernel_switch_in_loop(output: &mut Array<u32>, selector: &Array<u32>) {
+    // `break` can't be used in the arms directly, so the match only decides when to leave.
+    let mut i = 0u32;
+    let mut count = 0u32;
+    loop {
+        let mut done: bool = false;
+        match selector[i] {
+            0 => {
+                done = true;
+            }
+            1 => {
+                count += 1;
+            }
+            _ => {
+                // Nested loops can still be left from an arm.
+                for j in 0..selector[i] {
+                    if j == 2 {
+                        break;
+                    }
+                    count += 10;
+                }
+            }
+        }
+        if done {
+            break;
+        }
+        i += 1;
+    }
+    output[0] = count;
+}
+
+pub fn test_switch_statement<R: Runtime>(client: ComputeClient<R::Server, R::Channel>) {
+    let handle = client.create(f32::as_bytes(&[0.0; 8]));
+
+    kernel_switch_statement::launch::<R>(
+        &client,
+        CubeCount::Static(1, 1, 1),
+        CubeDim::new(8, 1, 1),
+        unsafe { ArrayArg::from_raw_parts(&handle, 8, 1) },
+    );
+
+    let actual = client.read(handle.binding());
+    let actual = f32::from_bytes(&actual);
+
+    assert_eq!(actual, &[10.0, 20.0, 20.0, 30.0, 30.0, 30.0, 40.0, 40.0]);
+}
+
+pub fn test_switch_value<R: Runtime>(client: ComputeClient<R::Server, R::Channel>) {
+    let handle = client.create(f32::as_bytes(&[0.0; 6]));
+    let selector = client.create(i32::as_bytes(&[-2, -1, 0, 2, 3, 100]));
+
+    kernel_switch_value::launch::<R>(
+        &client,
+        CubeCount::Static(1, 1, 1),
+        CubeDim::new(6, 1, 1),
+        unsafe { ArrayArg::from_raw_parts(&handle, 6, 1) },
+        unsafe { ArrayArg::from_raw_parts(&selector, 6, 1) },
+    );
+
+    let actual = client.read(handle.binding());
+    let actual = f32::from_bytes(&actual);
+
+    assert_eq!(actual, &[3.0, 1.0, 2.0, 2.0, 3.0, 3.0]);
+}
+
+pub fn test_switch_in_loop<R: Runtime>(client: ComputeClient<R::Server, R::Channel>) {
+    let handle = client.empty(core::mem::size_of::<u32>());
+    let selector = client.create(u32::as_bytes(&[1, 5, 1, 0, 1]));
+
+    kernel_switch_in_loop::launch::<R>(
+        &client,
+        CubeCount::Static(1, 1, 1),
+        CubeDim::new(1, 1, 1),
+        unsafe { ArrayArg::from_raw_parts(&handle, 1, 1) },
+        unsafe { ArrayArg::from_raw_parts(&selector, 5, 1) },
+    );
+
+    let actual = client.read(handle.binding());
+    let actual = u32::from_bytes(&actual);
+
+    assert_eq!(actual, &[22]);
+}
+
+#[allow(missing_docs)]
+#[macro_export]
+macro_rules! testgen_switch {
+    () => {
+        use super::*;
+
+        #[test]
+        fn test_switch_statement() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::switch::test_switch_statement::<TestRuntime>(client);
+        }
+
+        #[test]
+        fn test_switch_value() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::switch::test_switch_value::<TestRuntime>(client);
+        }
+
+        #[test]
+        fn test_switch_in_loop() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::switch::test_switch_in_loop::<TestRuntime>(client);
+        }
+    };
+}
@@ -0,0 +1,71 @@
+use crate as cubecl;
+
+use cubecl::prelude::*;
+
+#[cube(launch)]
+pub fn kernel_vector<F: Float>(
+    lhs: &Array<F>,
+    rhs: &Array<F>,
+    scalars: &mut Array<F>,
+    vectors: &mut Array<F>,
+) {
+    if UNIT_POS == 0 {
+        let x = lhs[0];
+        let y = rhs[0];
+        let lane = extract(y, 3);
+
+        scalars[0] = dot(x, y);
+        scalars[1] = reduce_sum(x);
+        scalars[2] = reduce_max(x);
+        scalars[3] = reduce_min(x);
+        scalars[4] = extract(x, 2);
+        vectors[0] = swizzle(x, [3, 2, 1, 0]);
+        vectors[1] = insert(x, 1, lane);
+    }
+}
+
+pub fn test_vector_f32<R: Runtime>(client: ComputeClient<R::Server, R::Channel>) {
+    let lhs = [1.0f32, -2.0, 5.0, 3.0];
+    let rhs = [2.0f32, 4.0, 0.5, -1.0];
+
+    let lhs_handle = client.create(f32::as_bytes(&lhs));
+    let rhs_handle = client.create(f32::as_bytes(&rhs));
+    let scalars = client.empty(5 * core::mem::size_of::<f32>());
+    let vectors = client.empty(8 * core::mem::size_of::<f32>());
+
+    unsafe {
+        kernel_vector::launch::<f32, R>(
+            &client,
+            CubeCount::Static(1, 1, 1),
+            CubeDim::default(),
+            ArrayArg::from_raw_parts(&lhs_handle, 4, 4),
+            ArrayArg::from_raw_parts(&rhs_handle, 4, 4),
+            ArrayArg::from_raw_parts(&scalars, 5, 1),
+            ArrayArg::from_raw_parts(&vectors, 8, 4),
+        )
+    };
+
+    let actual = client.read(scalars.binding());
+    let actual = f32::from_bytes(&actual);
+
+    assert_eq!(actual, &[-6.5, 7.0, 5.0, -2.0, 5.0]);
+
+    let actual = client.read(vectors.binding());
+    let actual = f32::from_bytes(&actual);
+
+    assert_eq!(actual, &[3.0, 5.0, -2.0, 1.0, 1.0, -1.0, 5.0, 3.0]);
+}
+
+#[allow(missing_docs)]
+#[macro_export]
+macro_rules! testgen_vector {
+    () => {
+        use super::*;
+
+        #[test]
+        fn test_vector_f32() {
+            let client = TestRuntime::client(&Default::default());
+            cubecl_core::runtime_tests::vector::test_vector_f32::<TestRuntime>(client);
+        }
+    };
+}
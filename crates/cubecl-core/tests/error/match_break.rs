@@ -0,0 +1,13 @@
+use cubecl_core::prelude::*;
+
+#[cube]
+fn match_break(x: u32) {
+    loop {
+        match x {
+            0 => break,
+            _ => {}
+        }
+    }
+}
+
+fn main() {}
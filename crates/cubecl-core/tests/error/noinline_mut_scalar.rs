@@ -0,0 +1,9 @@
+use cubecl_core as cubecl;
+use cubecl_core::prelude::*;
+
+#[cube(noinline)]
+fn increment(value: &mut u32) {
+    *value += 1;
+}
+
+fn main() {}
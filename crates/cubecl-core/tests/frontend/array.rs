@@ -40,7 +40,7 @@ mod tests {
     use super::*;
     use cubecl_core::{
         cpa,
-        ir::{self, Elem, Item, Variable},
+        ir::{self, Elem, Item, UIntKind, Variable},
     };
 
     type ElemType = f32;
@@ -59,7 +59,7 @@ mod tests {
     #[test]
     fn array_add_assign() {
         let mut context = CubeContext::root();
-        let array = context.input(0, Item::new(Elem::UInt));
+        let array = context.input(0, Item::new(Elem::UInt(UIntKind::U32)));
 
         array_add_assign_simple::expand(&mut context, array.into());
         let scope = context.into_scope();
@@ -112,7 +112,7 @@ mod tests {
     #[test]
     fn array_add_assign_expr() {
         let mut context = CubeContext::root();
-        let array = context.input(0, Item::new(Elem::UInt));
+        let array = context.input(0, Item::new(Elem::UInt(UIntKind::U32)));
 
         array_add_assign_expr::expand(&mut context, array.into());
         let scope = context.into_scope();
@@ -124,11 +124,11 @@ mod tests {
         let context = CubeContext::root();
 
         let mut scope = context.into_scope();
-        let local = scope.create_local(Item::new(Elem::UInt));
+        let local = scope.create_local(Item::new(Elem::UInt(UIntKind::U32)));
 
         let array = Variable::GlobalInputArray {
             id: 0,
-            item: Item::new(Elem::UInt),
+            item: Item::new(Elem::UInt(UIntKind::U32)),
         };
         let index: Variable = 1u32.into();
         let value: Variable = 1u32.into();
@@ -184,11 +184,11 @@ mod tests {
         let context = CubeContext::root();
 
         let mut scope = context.into_scope();
-        let local = scope.create_local(Item::new(Elem::UInt));
+        let local = scope.create_local(Item::new(Elem::UInt(UIntKind::U32)));
 
         let array = Variable::GlobalInputArray {
             id: 0,
-            item: Item::new(Elem::UInt),
+            item: Item::new(Elem::UInt(UIntKind::U32)),
         };
         let index: Variable = 6u32.into();
         let value: Variable = 1u32.into();
@@ -42,7 +42,7 @@ mod tests {
     use super::*;
     use cubecl_core::{
         cpa,
-        ir::{Elem, Item, Operation, Variable},
+        ir::{Elem, Item, Operation, UIntKind, Variable},
     };
 
     #[test]
@@ -107,7 +107,7 @@ mod tests {
         let context = CubeContext::root();
 
         let mut scope = context.into_scope();
-        let x = scope.create_local(Item::new(Elem::UInt));
+        let x = scope.create_local(Item::new(Elem::UInt(UIntKind::U32)));
 
         let zero: Variable = 0u32.into();
         let one: Variable = 1u32.into();
@@ -120,7 +120,7 @@ mod tests {
 
     fn inline_macro_ref_mut_assign_input() -> Vec<Operation> {
         let mut context = CubeContext::root();
-        let item = Item::new(Elem::UInt);
+        let item = Item::new(Elem::UInt(UIntKind::U32));
         let y = context.create_local(item);
 
         let mut scope = context.into_scope();
@@ -139,7 +139,7 @@ mod tests {
 
     fn inline_macro_ref_assign_mut_input() -> Vec<Operation> {
         let mut context = CubeContext::root();
-        let item = Item::new(Elem::UInt);
+        let item = Item::new(Elem::UInt(UIntKind::U32));
         let y = context.create_local(item);
 
         let mut scope = context.into_scope();
@@ -158,7 +158,7 @@ mod tests {
 
     fn inline_macro_ref_assign_vectorized() -> Vec<Operation> {
         let mut context = CubeContext::root();
-        let item = Item::vectorized(Elem::UInt, NonZero::new(4));
+        let item = Item::vectorized(Elem::UInt(UIntKind::U32), NonZero::new(4));
         let y = context.create_local(item);
 
         let mut scope = context.into_scope();
@@ -182,7 +182,7 @@ mod tests {
     fn inline_macro_ref_assign_deref() -> Vec<Operation> {
         let context = CubeContext::root();
         let mut scope = context.into_scope();
-        let y = scope.create_local(Item::new(Elem::UInt));
+        let y = scope.create_local(Item::new(Elem::UInt(UIntKind::U32)));
 
         let one: Variable = 1u32.into();
 
@@ -84,6 +84,19 @@ pub fn u32_to_bool(x: u32) {
     let _ = bool::cast_from(y) || true;
 }
 
+// From sized integers
+#[cube]
+pub fn u8_to_i16(x: u8) {
+    let y = x + u8::from_int(2);
+    let _ = i16::cast_from(y) + i16::from_int(34);
+}
+
+#[cube]
+pub fn i16_to_u64(x: i16) {
+    let y = x + i16::from_int(2);
+    let _ = u64::cast_from(y) + u64::from_int(34);
+}
+
 // From bool
 #[cube]
 #[allow(clippy::overly_complex_bool_expr)]
@@ -119,7 +132,7 @@ mod tests {
     use cubecl_core::{
         cpa,
         frontend::{CubeContext, CubePrimitive},
-        ir::{Elem, Item, Variable},
+        ir::{Elem, IntKind, Item, UIntKind, Variable},
     };
 
     macro_rules! cast_test {
@@ -159,7 +172,7 @@ mod tests {
         cube_float_to_u32_test,
         float_to_u32::expand,
         Item::new(f32::as_elem()),
-        Item::new(Elem::UInt)
+        Item::new(Elem::UInt(UIntKind::U32))
     );
 
     cast_test!(
@@ -187,7 +200,7 @@ mod tests {
         cube_int_to_u32_test,
         int_to_u32::expand,
         Item::new(i32::as_elem()),
-        Item::new(Elem::UInt)
+        Item::new(Elem::UInt(UIntKind::U32))
     );
 
     cast_test!(
@@ -200,28 +213,28 @@ mod tests {
     cast_test!(
         cube_u32_to_float_test,
         u32_to_float::expand,
-        Item::new(Elem::UInt),
+        Item::new(Elem::UInt(UIntKind::U32)),
         Item::new(f32::as_elem())
     );
 
     cast_test!(
         cube_u32_to_int_test,
         u32_to_int::expand,
-        Item::new(Elem::UInt),
+        Item::new(Elem::UInt(UIntKind::U32)),
         Item::new(i32::as_elem())
     );
 
     cast_test!(
         cube_u32_to_u32_test,
         u32_to_u32::expand,
-        Item::new(Elem::UInt),
-        Item::new(Elem::UInt)
+        Item::new(Elem::UInt(UIntKind::U32)),
+        Item::new(Elem::UInt(UIntKind::U32))
     );
 
     cast_test!(
         cube_u32_to_bool_test,
         u32_to_bool::expand,
-        Item::new(Elem::UInt),
+        Item::new(Elem::UInt(UIntKind::U32)),
         Item::new(Elem::Bool)
     );
 
@@ -243,7 +256,7 @@ mod tests {
         cube_bool_to_u32_test,
         bool_to_u32::expand,
         Item::new(Elem::Bool),
-        Item::new(Elem::UInt)
+        Item::new(Elem::UInt(UIntKind::U32))
     );
 
     cast_test!(
@@ -253,6 +266,20 @@ mod tests {
         Item::new(Elem::Bool)
     );
 
+    cast_test!(
+        cube_u8_to_i16_test,
+        u8_to_i16::expand,
+        Item::new(Elem::UInt(UIntKind::U8)),
+        Item::new(Elem::Int(IntKind::I16))
+    );
+
+    cast_test!(
+        cube_i16_to_u64_test,
+        i16_to_u64::expand,
+        Item::new(Elem::Int(IntKind::I16)),
+        Item::new(Elem::UInt(UIntKind::U64))
+    );
+
     fn inline_macro_ref_cast(from_item: Item, to_item: Item) -> String {
         let mut context = CubeContext::root();
         let x = context.create_local(from_item);
@@ -263,9 +290,12 @@ mod tests {
 
         match from_item.elem() {
             Elem::Float(_) => cpa!(scope, x = x + 2f32),
-            Elem::Int(_) => cpa!(scope, x = x + 2i32),
+            Elem::Int(_) | Elem::UInt(_) => {
+                let two = from_item.elem().constant_from_i64(2);
+                cpa!(scope, x = x + two)
+            }
+            Elem::AtomicFloat(_) => cpa!(scope, x = x + 2f32),
             Elem::AtomicInt(_) => cpa!(scope, x = x + 2i32),
-            Elem::UInt => cpa!(scope, x = x + 2u32),
             Elem::AtomicUInt => cpa!(scope, x = x + 2u32),
             Elem::Bool => cpa!(scope, x = x && false),
         }
@@ -274,9 +304,12 @@ mod tests {
 
         match to_item.elem() {
             Elem::Float(_) => cpa!(scope, y = y + 34f32),
-            Elem::Int(_) => cpa!(scope, y = y + 34i32),
+            Elem::Int(_) | Elem::UInt(_) => {
+                let thirty_four = to_item.elem().constant_from_i64(34);
+                cpa!(scope, y = y + thirty_four)
+            }
+            Elem::AtomicFloat(_) => cpa!(scope, y = y + 34f32),
             Elem::AtomicInt(_) => cpa!(scope, y = y + 34i32),
-            Elem::UInt => cpa!(scope, y = y + 34u32),
             Elem::AtomicUInt => cpa!(scope, y = y + 34u32),
             Elem::Bool => cpa!(scope, y = y || true),
         }
@@ -0,0 +1,53 @@
+use cubecl_core as cubecl;
+use cubecl_core::prelude::*;
+
+#[cube]
+pub fn debug_output(x: u32, y: u32) {
+    cube_assert!(x < y, "x should be smaller than y");
+    cube_debug_print!("x = {}, y = {}", x, y);
+    cube_assert!(x < y);
+}
+
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use cubecl_core::ir::{DebugKind, DebugOperation, Item, Operation};
+
+    #[test]
+    fn cube_debug_output_test() {
+        let mut context = CubeContext::root();
+
+        let x = context.create_local(Item::new(u32::as_elem()));
+        let y = context.create_local(Item::new(u32::as_elem()));
+
+        debug_output::expand(&mut context, x.into(), y.into());
+        let formats = context.take_debug_formats();
+        let scope = context.into_scope();
+
+        if !cfg!(debug_assertions) {
+            assert!(formats.is_empty());
+            return;
+        }
+
+        let kinds: Vec<_> = formats.iter().map(|format| format.kind).collect();
+        assert_eq!(
+            kinds,
+            [DebugKind::Assert, DebugKind::Print, DebugKind::Assert]
+        );
+        assert_eq!(formats[0].message, "x should be smaller than y");
+        assert_eq!(formats[1].args, [u32::as_elem(), u32::as_elem()]);
+        assert_eq!(formats[2].message, "assertion failed: x < y");
+
+        let debug: Vec<_> = scope
+            .operations
+            .iter()
+            .filter_map(|op| match op {
+                Operation::Debug(op) => Some(op.clone()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(debug.len(), 3);
+        assert!(matches!(debug[1], DebugOperation::Print { format: 1, .. }));
+    }
+}
@@ -5,6 +5,7 @@ mod cast_kind;
 mod comptime;
 mod constants;
 mod cube_trait;
+mod debug;
 mod for_loop;
 mod function_call;
 mod generic_kernel;
@@ -15,6 +16,7 @@ mod r#loop;
 mod module_import;
 mod ops;
 mod parenthesis;
+mod pipeline;
 mod redeclare;
 mod reuse;
 mod shared_memory;
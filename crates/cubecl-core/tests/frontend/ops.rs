@@ -91,6 +91,106 @@ pub fn recip_op<F: Float>(a: F) -> F {
     F::recip(a)
 }
 
+#[cube]
+pub fn tan_op<F: Float>(a: F) -> F {
+    F::tan(a)
+}
+
+#[cube]
+pub fn asin_op<F: Float>(a: F) -> F {
+    F::asin(a)
+}
+
+#[cube]
+pub fn acos_op<F: Float>(a: F) -> F {
+    F::acos(a)
+}
+
+#[cube]
+pub fn atan_op<F: Float>(a: F) -> F {
+    F::atan(a)
+}
+
+#[cube]
+pub fn sinh_op<F: Float>(a: F) -> F {
+    F::sinh(a)
+}
+
+#[cube]
+pub fn cosh_op<F: Float>(a: F) -> F {
+    F::cosh(a)
+}
+
+#[cube]
+pub fn exp2_op<F: Float>(a: F) -> F {
+    F::exp2(a)
+}
+
+#[cube]
+pub fn log2_op<F: Float>(a: F) -> F {
+    F::log2(a)
+}
+
+#[cube]
+pub fn trunc_op<F: Float>(a: F) -> F {
+    F::trunc(a)
+}
+
+#[cube]
+pub fn fract_op<F: Float>(a: F) -> F {
+    F::fract(a)
+}
+
+#[cube]
+pub fn sign_op<F: Float>(a: F) -> F {
+    F::sign(a)
+}
+
+#[cube]
+pub fn atan2_op<F: Float>(a: F, b: F) -> F {
+    F::atan2(a, b)
+}
+
+#[cube]
+pub fn hypot_op<F: Float>(a: F, b: F) -> F {
+    F::hypot(a, b)
+}
+
+#[cube]
+pub fn is_nan_op<F: Float>(a: F) -> bool {
+    F::is_nan(a)
+}
+
+#[cube]
+pub fn is_inf_op<F: Float>(a: F) -> bool {
+    F::is_inf(a)
+}
+
+#[cube]
+pub fn is_finite_op<F: Float>(a: F) -> bool {
+    F::is_finite(a)
+}
+
+#[cube]
+pub fn copysign_op<F: Float>(a: F, b: F) -> F {
+    F::copysign(a, b)
+}
+
+#[cube]
+pub fn next_after_op(a: f32, b: f32) -> f32 {
+    f32::next_after(a, b)
+}
+
+#[cube]
+pub fn folded_atan2_op<F: Float>() -> F {
+    F::atan2(F::new(1.0), F::new(-2.0))
+}
+
+#[cube]
+pub fn folded_fract_op<F: Float>() -> F {
+    F::fract(F::new(-2.75))
+}
+
 #[cube]
 pub fn equal_op<T: CubePrimitive>(a: T, b: T) -> bool {
     a == b
@@ -181,6 +281,31 @@ pub fn shr_op(a: u32, b: u32) -> u32 {
     a >> b
 }
 
+#[cube]
+pub fn count_ones_op<I: Int>(a: I) -> I {
+    I::count_ones(a)
+}
+
+#[cube]
+pub fn leading_zeros_op<I: Int>(a: I) -> I {
+    I::leading_zeros(a)
+}
+
+#[cube]
+pub fn trailing_zeros_op<I: Int>(a: I) -> I {
+    I::trailing_zeros(a)
+}
+
+#[cube]
+pub fn reverse_bits_op<I: Int>(a: I) -> I {
+    I::reverse_bits(a)
+}
+
+#[cube]
+pub fn mul_hi_op<I: Int>(a: I, b: I) -> I {
+    I::mul_hi(a, b)
+}
+
 #[cube]
 pub fn add_assign_op<T: Numeric>(mut a: T, b: T) {
     a += b;
@@ -233,7 +358,7 @@ pub fn shr_assign_op<T: Int>(mut a: T, b: u32) {
 
 mod tests {
     use super::*;
-    use cubecl_core::ir::{Elem, FloatKind, Item};
+    use cubecl_core::ir::{ConstantScalarValue, Elem, FloatKind, Item, UIntKind, Variable};
     use pretty_assertions::assert_eq;
 
     macro_rules! binary_test {
@@ -256,6 +381,9 @@ mod tests {
 
     macro_rules! unary_test {
         ($test_name:ident, $op_expand:expr, $op_name:expr) => {
+            unary_test!($test_name, $op_expand, $op_name, ref_ops_unary);
+        };
+        ($test_name:ident, $op_expand:expr, $op_name:expr, $func:ident) => {
             #[test]
             fn $test_name() {
                 let mut context = CubeContext::root();
@@ -265,7 +393,7 @@ mod tests {
 
                 assert_eq!(
                     format!("{:?}", context.into_scope().operations),
-                    ref_ops_unary($op_name)
+                    $func($op_name)
                 );
             }
         };
@@ -294,8 +422,8 @@ mod tests {
             #[test]
             fn $test_name() {
                 let mut context = CubeContext::root();
-                let x = context.create_local(Item::new(Elem::UInt));
-                let y = context.create_local(Item::new(Elem::UInt));
+                let x = context.create_local(Item::new(Elem::UInt(UIntKind::U32)));
+                let y = context.create_local(Item::new(Elem::UInt(UIntKind::U32)));
 
                 $op_expand(&mut context, x.into(), y.into());
 
@@ -307,6 +435,23 @@ mod tests {
         };
     }
 
+    macro_rules! unary_u32_test {
+        ($test_name:ident, $op_expand:expr, $op_name:expr) => {
+            #[test]
+            fn $test_name() {
+                let mut context = CubeContext::root();
+                let x = context.create_local(Item::new(Elem::UInt(UIntKind::U32)));
+
+                $op_expand(&mut context, x.into());
+
+                assert_eq!(
+                    format!("{:?}", context.into_scope().operations),
+                    ref_ops_unary_u32($op_name)
+                );
+            }
+        };
+    }
+
     binary_test!(cube_can_add, add_op::expand::<f32>, "Add", ref_ops_binary);
     binary_test!(cube_can_sub, sub_op::expand::<f32>, "Sub", ref_ops_binary);
     binary_test!(cube_can_mul, mul_op::expand::<f32>, "Mul", ref_ops_binary);
@@ -330,6 +475,59 @@ mod tests {
     unary_test!(cube_can_round, round_op::expand::<f32>, "Round");
     unary_test!(cube_can_floor, floor_op::expand::<f32>, "Floor");
     unary_test!(cube_can_ceil, ceil_op::expand::<f32>, "Ceil");
+    unary_test!(cube_can_tan, tan_op::expand::<f32>, "Tan");
+    unary_test!(cube_can_asin, asin_op::expand::<f32>, "Asin");
+    unary_test!(cube_can_acos, acos_op::expand::<f32>, "Acos");
+    unary_test!(cube_can_atan, atan_op::expand::<f32>, "Atan");
+    unary_test!(cube_can_sinh, sinh_op::expand::<f32>, "Sinh");
+    unary_test!(cube_can_cosh, cosh_op::expand::<f32>, "Cosh");
+    unary_test!(cube_can_exp2, exp2_op::expand::<f32>, "Exp2");
+    unary_test!(cube_can_log2, log2_op::expand::<f32>, "Log2");
+    unary_test!(cube_can_trunc, trunc_op::expand::<f32>, "Trunc");
+    unary_test!(cube_can_fract, fract_op::expand::<f32>, "Fract");
+    unary_test!(cube_can_sign, sign_op::expand::<f32>, "Sign");
+    binary_test!(
+        cube_can_atan2,
+        atan2_op::expand::<f32>,
+        "Atan2",
+        ref_ops_binary
+    );
+    binary_test!(
+        cube_can_hypot,
+        hypot_op::expand::<f32>,
+        "Hypot",
+        ref_ops_binary
+    );
+    unary_test!(
+        cube_can_is_nan,
+        is_nan_op::expand::<f32>,
+        "IsNan",
+        ref_ops_check
+    );
+    unary_test!(
+        cube_can_is_inf,
+        is_inf_op::expand::<f32>,
+        "IsInf",
+        ref_ops_check
+    );
+    unary_test!(
+        cube_can_is_finite,
+        is_finite_op::expand::<f32>,
+        "IsFinite",
+        ref_ops_check
+    );
+    binary_test!(
+        cube_can_copysign,
+        copysign_op::expand::<f32>,
+        "CopySign",
+        ref_ops_binary
+    );
+    binary_test!(
+        cube_can_next_after,
+        next_after_op::expand,
+        "NextAfter",
+        ref_ops_binary
+    );
     binary_test!(cube_can_eq, equal_op::expand::<f32>, "Equal", ref_ops_cmp);
     binary_test!(
         cube_can_ne,
@@ -426,6 +624,27 @@ mod tests {
     binary_u32_test!(cube_can_shl, shl_op::expand, "ShiftLeft");
     binary_u32_test!(cube_can_shr, shr_op::expand, "ShiftRight");
     binary_u32_test!(cube_can_mod, modulo_op::expand, "Modulo");
+    unary_u32_test!(
+        cube_can_count_ones,
+        count_ones_op::expand::<u32>,
+        "CountOnes"
+    );
+    unary_u32_test!(
+        cube_can_leading_zeros,
+        leading_zeros_op::expand::<u32>,
+        "LeadingZeros"
+    );
+    unary_u32_test!(
+        cube_can_trailing_zeros,
+        trailing_zeros_op::expand::<u32>,
+        "TrailingZeros"
+    );
+    unary_u32_test!(
+        cube_can_reverse_bits,
+        reverse_bits_op::expand::<u32>,
+        "ReverseBits"
+    );
+    binary_u32_test!(cube_can_mul_hi, mul_hi_op::expand::<u32>, "MulHi");
     binary_test!(
         cube_can_rem,
         remainder_op::expand::<f32>,
@@ -446,6 +665,34 @@ mod tests {
         );
     }
 
+    #[test]
+    fn cube_can_fold_constant_math() {
+        let mut context = CubeContext::root();
+
+        let out: ExpandElement = folded_atan2_op::expand::<f32>(&mut context).into();
+
+        assert_eq!(
+            *out,
+            Variable::ConstantScalar(ConstantScalarValue::Float(
+                1.0f64.atan2(-2.0),
+                FloatKind::F32
+            ))
+        );
+        assert_eq!(format!("{:?}", context.into_scope().operations), "[]");
+    }
+
+    #[test]
+    fn cube_can_fold_negative_fract() {
+        let mut context = CubeContext::root();
+
+        let out: ExpandElement = folded_fract_op::expand::<f32>(&mut context).into();
+
+        assert_eq!(
+            *out,
+            Variable::ConstantScalar(ConstantScalarValue::Float(-0.75, FloatKind::F32))
+        );
+    }
+
     fn ref_ops_binary(ops_name: &str) -> String {
         ref_ops_template(ops_name, "Float(F32)", "Float(F32)", true)
     }
@@ -458,6 +705,10 @@ mod tests {
         ref_ops_template(ops_name, "Float(F32)", "Bool", true)
     }
 
+    fn ref_ops_check(ops_name: &str) -> String {
+        ref_ops_template(ops_name, "Float(F32)", "Bool", false)
+    }
+
     fn ref_ops_unary_boolean(ops_name: &str) -> String {
         ref_ops_template(ops_name, "Bool", "Bool", false)
     }
@@ -467,12 +718,21 @@ mod tests {
     }
 
     fn ref_ops_binary_u32(ops_name: &str) -> String {
-        ref_ops_template(ops_name, "UInt", "UInt", true)
+        ref_ops_template(ops_name, "UInt(U32)", "UInt(U32)", true)
+    }
+
+    fn ref_ops_unary_u32(ops_name: &str) -> String {
+        ref_ops_template(ops_name, "UInt(U32)", "UInt(U32)", false)
     }
 
     fn ref_ops_template(ops_name: &str, in_type: &str, out_type: &str, binary: bool) -> String {
+        let out_number = match (in_type == out_type, binary) {
+            (true, _) => 0,
+            (false, true) => 2,
+            (false, false) => 1,
+        };
+
         if binary {
-            let out_number = if in_type == out_type { 0 } else { 2 };
             format!(
                 "[Operator({ops_name}(BinaryOperator {{ \
                 lhs: Local {{ id: 0, item: Item {{ \
@@ -496,7 +756,7 @@ mod tests {
                     elem: {in_type}, \
                     vectorization: None \
                 }}, depth: 0 }}, \
-                out: Local {{ id: 0, item: Item {{ \
+                out: Local {{ id: {out_number}, item: Item {{ \
                     elem: {out_type}, \
                     vectorization: None \
                 }}, depth: 0 }} \
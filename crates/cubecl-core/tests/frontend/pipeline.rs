@@ -0,0 +1,62 @@
+use cubecl_core as cubecl;
+use cubecl_core::prelude::*;
+
+#[cube]
+pub fn copy_global_to_shared(input: &Array<f32>) {
+    let mut shared = SharedMemory::<f32>::new(8);
+    let slice = input.slice(0, 8);
+    memcpy_async(slice.slice(0, 4), shared.slice_mut(0, 4));
+}
+
+#[cube]
+pub fn copy_shared_to_shared() {
+    let shared = SharedMemory::<f32>::new(8);
+    let mut other = SharedMemory::<f32>::new(8);
+    memcpy_async(shared.slice(0, 4), other.slice_mut(0, 4));
+}
+
+#[cube]
+pub fn copy_global_to_global(input: &Array<f32>, output: &mut Array<f32>) {
+    memcpy_async(input.slice(0, 4), output.slice_mut(0, 4));
+}
+
+mod tests {
+    use super::*;
+    use cubecl_core::ir::{Item, Operation, Operator};
+
+    #[test]
+    fn cube_memcpy_async_global_to_shared() {
+        let mut context = CubeContext::root();
+        let input = context.input(0, Item::new(f32::as_elem()));
+
+        copy_global_to_shared::expand(&mut context, input.into());
+
+        let scope = context.into_scope();
+        assert!(scope
+            .operations
+            .iter()
+            .any(|op| matches!(op, Operation::Operator(Operator::CopyAsync(_)))));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "The source of an asynchronous copy must be a slice of global memory"
+    )]
+    fn cube_memcpy_async_from_shared_fails() {
+        let mut context = CubeContext::root();
+
+        copy_shared_to_shared::expand(&mut context);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "The destination of an asynchronous copy must be a slice of shared memory"
+    )]
+    fn cube_memcpy_async_to_global_fails() {
+        let mut context = CubeContext::root();
+        let input = context.input(0, Item::new(f32::as_elem()));
+        let output = context.output(1, Item::new(f32::as_elem()));
+
+        copy_global_to_global::expand(&mut context, input.into(), output.into());
+    }
+}
@@ -19,7 +19,7 @@ mod tests {
     use super::*;
     use cubecl_core::{
         cpa,
-        ir::{Elem, Item, Operation, Variable},
+        ir::{Elem, Item, Operation, UIntKind, Variable},
     };
     use pretty_assertions::assert_eq;
 
@@ -37,8 +37,8 @@ mod tests {
         let context = CubeContext::root();
 
         let mut scope = context.into_scope();
-        let x = scope.create_local(Item::new(Elem::UInt));
-        let y = scope.create_local(Item::new(Elem::UInt));
+        let x = scope.create_local(Item::new(Elem::UInt(UIntKind::U32)));
+        let y = scope.create_local(Item::new(Elem::UInt(UIntKind::U32)));
 
         let zero: Variable = 0u32.into();
         let one: Variable = 1u32.into();
@@ -63,8 +63,8 @@ mod tests {
         let context = CubeContext::root();
 
         let mut scope = context.into_scope();
-        let a = scope.create_local(Item::new(Elem::UInt));
-        let b = scope.create_local(Item::new(Elem::UInt));
+        let a = scope.create_local(Item::new(Elem::UInt(UIntKind::U32)));
+        let b = scope.create_local(Item::new(Elem::UInt(UIntKind::U32)));
 
         let one: Variable = 1u32.into();
 
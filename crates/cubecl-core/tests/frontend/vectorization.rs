@@ -11,6 +11,27 @@ pub fn vectorization_cmp<T: Numeric>(rhs: T) {
     let _ = T::from_vec([4, 5]) > rhs;
 }
 
+#[cube]
+pub fn vectorization_dot<T: Numeric>(lhs: T, rhs: T) -> T {
+    dot(lhs, rhs)
+}
+
+#[cube]
+pub fn vectorization_reduce<T: Numeric>(value: T) -> T {
+    reduce_sum(value) + reduce_max(value) + reduce_min(value)
+}
+
+#[cube]
+pub fn vectorization_swizzle<T: Numeric>(value: T) -> T {
+    swizzle(value, [3, 2, 1, 0])
+}
+
+#[cube]
+pub fn vectorization_lanes<T: Numeric>(value: T) -> T {
+    let first = extract(value, 0);
+    insert(value, 3, first)
+}
+
 mod tests {
     use std::num::NonZero;
 
@@ -65,4 +86,69 @@ mod tests {
 
         vectorization_cmp::expand::<ElemType>(&mut context, lhs.into());
     }
+
+    #[test]
+    fn cube_vectorization_dot_is_scalar() {
+        let mut context = CubeContext::root();
+
+        let lhs = context.create_local(Item::vectorized(ElemType::as_elem(), NonZero::new(4)));
+        let rhs = context.create_local(Item::vectorized(ElemType::as_elem(), NonZero::new(4)));
+
+        let out: ExpandElement =
+            vectorization_dot::expand::<ElemType>(&mut context, lhs.into(), rhs.into()).into();
+
+        assert_eq!(out.item(), Item::new(ElemType::as_elem()));
+    }
+
+    #[test]
+    fn cube_vectorization_reduce_is_scalar() {
+        let mut context = CubeContext::root();
+
+        let value = context.create_local(Item::vectorized(ElemType::as_elem(), NonZero::new(4)));
+
+        let out: ExpandElement =
+            vectorization_reduce::expand::<ElemType>(&mut context, value.into()).into();
+
+        assert_eq!(out.item(), Item::new(ElemType::as_elem()));
+    }
+
+    #[test]
+    fn cube_vectorization_swizzle_has_one_lane_per_index() {
+        let mut context = CubeContext::root();
+
+        let value = context.create_local(Item::vectorized(ElemType::as_elem(), NonZero::new(4)));
+
+        let out: ExpandElement =
+            vectorization_swizzle::expand::<ElemType>(&mut context, value.into()).into();
+
+        assert_eq!(
+            out.item(),
+            Item::vectorized(ElemType::as_elem(), NonZero::new(4))
+        );
+    }
+
+    #[test]
+    fn cube_vectorization_insert_keeps_vectorization() {
+        let mut context = CubeContext::root();
+
+        let value = context.create_local(Item::vectorized(ElemType::as_elem(), NonZero::new(4)));
+
+        let out: ExpandElement =
+            vectorization_lanes::expand::<ElemType>(&mut context, value.into()).into();
+
+        assert_eq!(
+            out.item(),
+            Item::vectorized(ElemType::as_elem(), NonZero::new(4))
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn cube_vectorization_swizzle_out_of_bounds_lane_fails() {
+        let mut context = CubeContext::root();
+
+        let value = context.create_local(Item::vectorized(ElemType::as_elem(), NonZero::new(2)));
+
+        vectorization_swizzle::expand::<ElemType>(&mut context, value.into());
+    }
 }
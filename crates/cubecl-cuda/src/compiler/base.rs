@@ -18,6 +18,9 @@ pub struct CudaCompiler {
     wmma: bool,
     bf16: bool,
     f16: bool,
+    fp8: bool,
+    pipeline: bool,
+    sized_int: bool,
     shape: bool,
     stride: bool,
     num_inputs: usize,
@@ -25,6 +28,8 @@ pub struct CudaCompiler {
     items: HashSet<super::Item>,
     strategy: ExecutionMode,
     settings: VariableSettings,
+    bindings: String,
+    function_names: Vec<String>,
 }
 
 impl Compiler for CudaCompiler {
@@ -48,14 +53,38 @@ impl Compiler for CudaCompiler {
     fn max_shared_memory_size() -> usize {
         49152
     }
+
+    fn extension() -> &'static str {
+        "cu"
+    }
 }
 
 impl CudaCompiler {
     fn compile_shader(mut self, mut value: gpu::KernelDefinition) -> super::ComputeKernel {
+        // Debug output isn't written in unchecked mode, so the debug buffer isn't bound.
+        if let ExecutionMode::Unchecked = self.strategy {
+            value.named.retain(|(name, _)| name != "debug");
+        }
         self.num_inputs = value.inputs.len();
         self.num_outputs = value.outputs.len();
+        self.bindings = (0..self.num_inputs)
+            .map(|i| format!("input_{i}"))
+            .chain((0..self.num_outputs).map(|i| format!("output_{i}")))
+            .chain(value.named.iter().map(|(name, _)| name.clone()))
+            .collect::<Vec<_>>()
+            .join(", ");
+        self.function_names = value
+            .functions
+            .iter()
+            .map(|function| format!("{}_{}", function.name, function.id))
+            .collect();
 
         let instructions = self.compile_scope(&mut value.body);
+        let functions: Vec<_> = value
+            .functions
+            .into_iter()
+            .map(|function| self.compile_function(function))
+            .collect();
         let inputs = value
             .inputs
             .into_iter()
@@ -72,11 +101,17 @@ impl CudaCompiler {
             .map(|(name, binding)| (name, self.compile_binding(binding)))
             .collect();
 
+        // Shared memories are declared at file scope when they can be accessed from functions.
+        let (shared_memories, shared_memories_global) = match functions.is_empty() {
+            true => (self.shared_memories, Vec::new()),
+            false => (Vec::new(), self.shared_memories),
+        };
+
         let body = super::Body {
             instructions,
             stride: true,
             shape: true,
-            shared_memories: self.shared_memories,
+            shared_memories,
             local_arrays: self.local_arrays,
             rank: self.rank,
             wrap_size_checked: self.wrap_size_checked,
@@ -89,13 +124,71 @@ impl CudaCompiler {
             named,
             cube_dim: value.cube_dim,
             body,
+            functions,
+            shared_memories: shared_memories_global,
             wmma_activated: self.wmma,
             bf16: self.bf16,
             f16: self.f16,
+            fp8: self.fp8,
+            pipeline: self.pipeline,
+            sized_int: self.sized_int,
             items: self.items,
         }
     }
 
+    fn compile_function(&mut self, mut function: gpu::FunctionDefinition) -> super::Function {
+        let local_arrays = core::mem::take(&mut self.local_arrays);
+        let settings = core::mem::take(&mut self.settings);
+        let flags = (self.rank, self.wrap_size_checked);
+        (self.rank, self.wrap_size_checked) = (false, false);
+
+        let mut instructions = Vec::new();
+        let processing = function.body.process();
+
+        for var in processing.variables {
+            // Parameters are declared by the function signature.
+            if let gpu::Variable::Slice { .. } = var {
+                continue;
+            }
+            if function.params.contains(&var) {
+                continue;
+            }
+            instructions.push(Instruction::DeclareVariable {
+                var: self.compile_variable(var),
+            });
+        }
+
+        processing
+            .operations
+            .into_iter()
+            .for_each(|op| self.compile_operation(&mut instructions, op, &mut function.body));
+
+        let params = function
+            .params
+            .into_iter()
+            .map(|var| self.compile_variable(var))
+            .collect();
+        let output = function.output.map(|var| self.compile_variable(var));
+        let body = super::Body {
+            instructions,
+            stride: true,
+            shape: true,
+            shared_memories: Vec::new(),
+            local_arrays: core::mem::replace(&mut self.local_arrays, local_arrays),
+            rank: self.rank,
+            wrap_size_checked: self.wrap_size_checked,
+            settings: core::mem::replace(&mut self.settings, settings),
+        };
+        (self.rank, self.wrap_size_checked) = flags;
+
+        super::Function {
+            name: self.function_names[function.id as usize].clone(),
+            params,
+            output,
+            body,
+        }
+    }
+
     fn compile_scope(&mut self, scope: &mut gpu::Scope) -> Vec<Instruction> {
         let mut instructions = Vec::new();
         let processing = scope.process();
@@ -131,6 +224,19 @@ impl CudaCompiler {
             gpu::Operation::Synchronization(val) => match val {
                 gpu::Synchronization::SyncUnits => instructions.push(Instruction::SyncThreads),
                 gpu::Synchronization::SyncStorage => instructions.push(Instruction::SyncThreads),
+                // CUDA fences order all memory accesses, whatever the ordering.
+                gpu::Synchronization::Fence { scope, .. } => match scope {
+                    gpu::MemoryScope::Cube => instructions.push(Instruction::ThreadFenceBlock),
+                    gpu::MemoryScope::Device => instructions.push(Instruction::ThreadFence),
+                },
+                gpu::Synchronization::PipelineCommit => {
+                    self.pipeline = true;
+                    instructions.push(Instruction::PipelineCommit)
+                }
+                gpu::Synchronization::PipelineWait { pending } => {
+                    self.pipeline = true;
+                    instructions.push(Instruction::PipelineWait { pending })
+                }
             },
             gpu::Operation::Subcube(op) => {
                 self.wrap_size_checked = true;
@@ -159,6 +265,20 @@ impl CudaCompiler {
                             out: self.compile_variable(op.out),
                         }))
                     }
+                    gpu::Subcube::InclusiveSum(op) => {
+                        self.settings.thread_idx_global = true;
+                        instructions.push(Instruction::Wrap(WarpInstruction::InclusiveSum {
+                            input: self.compile_variable(op.input),
+                            out: self.compile_variable(op.out),
+                        }))
+                    }
+                    gpu::Subcube::ExclusiveSum(op) => {
+                        self.settings.thread_idx_global = true;
+                        instructions.push(Instruction::Wrap(WarpInstruction::ExclusiveSum {
+                            input: self.compile_variable(op.input),
+                            out: self.compile_variable(op.out),
+                        }))
+                    }
                     gpu::Subcube::Elect(op) => {
                         instructions.push(Instruction::Wrap(WarpInstruction::Elect {
                             out: self.compile_variable(op.out),
@@ -183,9 +303,76 @@ impl CudaCompiler {
                             out: self.compile_variable(op.out),
                         }))
                     }
+                    gpu::Subcube::Shuffle(op) => {
+                        instructions.push(Instruction::Wrap(WarpInstruction::Shuffle {
+                            input: self.compile_variable(op.lhs),
+                            lane: self.compile_variable(op.rhs),
+                            out: self.compile_variable(op.out),
+                        }))
+                    }
+                    gpu::Subcube::ShuffleXor(op) => {
+                        instructions.push(Instruction::Wrap(WarpInstruction::ShuffleXor {
+                            input: self.compile_variable(op.lhs),
+                            mask: self.compile_variable(op.rhs),
+                            out: self.compile_variable(op.out),
+                        }))
+                    }
+                    gpu::Subcube::ShuffleUp(op) => {
+                        instructions.push(Instruction::Wrap(WarpInstruction::ShuffleUp {
+                            input: self.compile_variable(op.lhs),
+                            delta: self.compile_variable(op.rhs),
+                            out: self.compile_variable(op.out),
+                        }))
+                    }
+                    gpu::Subcube::ShuffleDown(op) => {
+                        instructions.push(Instruction::Wrap(WarpInstruction::ShuffleDown {
+                            input: self.compile_variable(op.lhs),
+                            delta: self.compile_variable(op.rhs),
+                            out: self.compile_variable(op.out),
+                        }))
+                    }
+                    gpu::Subcube::Ballot(op) => {
+                        instructions.push(Instruction::Wrap(WarpInstruction::Ballot {
+                            input: self.compile_variable(op.input),
+                            out: self.compile_variable(op.out),
+                        }))
+                    }
                 }
             }
             gpu::Operation::CoopMma(cmma) => instructions.push(self.compile_cmma(cmma)),
+            gpu::Operation::Call(call) => instructions.push(Instruction::Call {
+                function: self.function_names[call.function as usize].clone(),
+                bindings: self.bindings.clone(),
+                args: call
+                    .args
+                    .into_iter()
+                    .map(|var| self.compile_variable(var))
+                    .collect(),
+                out: call.out.map(|var| self.compile_variable(var)),
+            }),
+            gpu::Operation::Debug(op) => {
+                if let ExecutionMode::Checked = self.strategy {
+                    instructions.push(self.compile_debug(op))
+                }
+            }
+        }
+    }
+
+    fn compile_debug(&mut self, op: gpu::DebugOperation) -> Instruction {
+        match op {
+            gpu::DebugOperation::Assert { condition, format } => Instruction::DebugRecord {
+                format,
+                condition: Some(self.compile_variable(condition)),
+                args: Vec::new(),
+            },
+            gpu::DebugOperation::Print { format, args } => Instruction::DebugRecord {
+                format,
+                condition: None,
+                args: args
+                    .into_iter()
+                    .map(|arg| self.compile_variable(arg))
+                    .collect(),
+            },
         }
     }
 
@@ -287,6 +474,23 @@ impl CudaCompiler {
                 instructions_if: self.compile_scope(&mut op.scope_if),
                 instructions_else: self.compile_scope(&mut op.scope_else),
             }),
+            gpu::Branch::Switch(mut op) => instructions.push(Instruction::Switch {
+                value: self.compile_variable(op.value),
+                instructions_default: self.compile_scope(&mut op.scope_default),
+                cases: op
+                    .cases
+                    .into_iter()
+                    .map(|(values, mut scope)| {
+                        (
+                            values
+                                .into_iter()
+                                .map(|value| self.compile_variable(value))
+                                .collect(),
+                            self.compile_scope(&mut scope),
+                        )
+                    })
+                    .collect(),
+            }),
             gpu::Branch::Return => instructions.push(Instruction::Return),
             gpu::Branch::Break => instructions.push(Instruction::Break),
             gpu::Branch::RangeLoop(mut range_loop) => instructions.push(Instruction::RangeLoop {
@@ -368,6 +572,13 @@ impl CudaCompiler {
                 end: self.compile_variable(op.end),
                 out: self.compile_variable(op.out),
             }),
+            gpu::Operator::CopyAsync(op) => {
+                self.pipeline = true;
+                instructions.push(Instruction::CopyAsync {
+                    input: self.compile_variable(op.input),
+                    out: self.compile_variable(op.out),
+                })
+            }
             gpu::Operator::Index(op) => {
                 if let ExecutionMode::Checked = self.strategy {
                     // Since atomics must be declared inline (for `wgpu` compatibility), we need to
@@ -446,6 +657,57 @@ impl CudaCompiler {
             }
             gpu::Operator::Sqrt(op) => instructions.push(Instruction::Sqrt(self.compile_unary(op))),
             gpu::Operator::Erf(op) => instructions.push(Instruction::Erf(self.compile_unary(op))),
+            gpu::Operator::Tan(op) => instructions.push(Instruction::Tan(self.compile_unary(op))),
+            gpu::Operator::Asin(op) => instructions.push(Instruction::Asin(self.compile_unary(op))),
+            gpu::Operator::Acos(op) => instructions.push(Instruction::Acos(self.compile_unary(op))),
+            gpu::Operator::Atan(op) => instructions.push(Instruction::Atan(self.compile_unary(op))),
+            gpu::Operator::Atan2(op) => {
+                instructions.push(Instruction::Atan2(self.compile_binary(op)))
+            }
+            gpu::Operator::Sinh(op) => instructions.push(Instruction::Sinh(self.compile_unary(op))),
+            gpu::Operator::Cosh(op) => instructions.push(Instruction::Cosh(self.compile_unary(op))),
+            gpu::Operator::Exp2(op) => instructions.push(Instruction::Exp2(self.compile_unary(op))),
+            gpu::Operator::Log2(op) => instructions.push(Instruction::Log2(self.compile_unary(op))),
+            gpu::Operator::Hypot(op) => {
+                instructions.push(Instruction::Hypot(self.compile_binary(op)))
+            }
+            gpu::Operator::Trunc(op) => {
+                instructions.push(Instruction::Trunc(self.compile_unary(op)))
+            }
+            gpu::Operator::Fract(op) => {
+                instructions.push(Instruction::Fract(self.compile_unary(op)))
+            }
+            gpu::Operator::Sign(op) => instructions.push(Instruction::Sign(self.compile_unary(op))),
+            gpu::Operator::IsNan(op) => {
+                instructions.push(Instruction::IsNan(self.compile_unary(op)))
+            }
+            gpu::Operator::IsInf(op) => {
+                instructions.push(Instruction::IsInf(self.compile_unary(op)))
+            }
+            gpu::Operator::IsFinite(op) => {
+                instructions.push(Instruction::IsFinite(self.compile_unary(op)))
+            }
+            gpu::Operator::CopySign(op) => {
+                instructions.push(Instruction::CopySign(self.compile_binary(op)))
+            }
+            gpu::Operator::NextAfter(op) => {
+                instructions.push(Instruction::NextAfter(self.compile_binary(op)))
+            }
+            gpu::Operator::CountOnes(op) => {
+                instructions.push(Instruction::CountOnes(self.compile_unary(op)))
+            }
+            gpu::Operator::LeadingZeros(op) => {
+                instructions.push(Instruction::LeadingZeros(self.compile_unary(op)))
+            }
+            gpu::Operator::TrailingZeros(op) => {
+                instructions.push(Instruction::TrailingZeros(self.compile_unary(op)))
+            }
+            gpu::Operator::ReverseBits(op) => {
+                instructions.push(Instruction::ReverseBits(self.compile_unary(op)))
+            }
+            gpu::Operator::MulHi(op) => {
+                instructions.push(Instruction::MulHi(self.compile_binary(op)))
+            }
             gpu::Operator::And(op) => instructions.push(Instruction::And(self.compile_binary(op))),
             gpu::Operator::Or(op) => instructions.push(Instruction::Or(self.compile_binary(op))),
             gpu::Operator::Not(op) => instructions.push(Instruction::Not(self.compile_unary(op))),
@@ -480,9 +742,9 @@ impl CudaCompiler {
                 let lhs = match elem {
                     gpu::Elem::Float(kind) => ConstantScalarValue::Float(1.0, kind),
                     gpu::Elem::Int(kind) => ConstantScalarValue::Int(1, kind),
-                    gpu::Elem::UInt => ConstantScalarValue::UInt(1),
+                    gpu::Elem::UInt(kind) => ConstantScalarValue::UInt(1, kind),
                     gpu::Elem::Bool => ConstantScalarValue::Bool(true),
-                    gpu::Elem::AtomicInt(_) | gpu::Elem::AtomicUInt => {
+                    gpu::Elem::AtomicFloat(_) | gpu::Elem::AtomicInt(_) | gpu::Elem::AtomicUInt => {
                         panic!("Cannot use recip with atomics")
                     }
                 };
@@ -518,6 +780,30 @@ impl CudaCompiler {
             gpu::Operator::AtomicStore(op) => {
                 instructions.push(Instruction::AtomicStore(self.compile_unary(op)))
             }
+            // Atomics are relaxed, so the ordering is given by a fence on the acquiring or
+            // releasing side of the operation.
+            gpu::Operator::AtomicLoadOrdered(op) => {
+                instructions.push(Instruction::AtomicLoad(self.compile_unary(
+                    gpu::UnaryOperator {
+                        input: op.input,
+                        out: op.out,
+                    },
+                )));
+                if op.ordering.is_acquire() {
+                    instructions.push(Instruction::ThreadFence);
+                }
+            }
+            gpu::Operator::AtomicStoreOrdered(op) => {
+                if op.ordering.is_release() {
+                    instructions.push(Instruction::ThreadFence);
+                }
+                instructions.push(Instruction::AtomicStore(self.compile_unary(
+                    gpu::UnaryOperator {
+                        input: op.input,
+                        out: op.out,
+                    },
+                )));
+            }
             gpu::Operator::AtomicSwap(op) => {
                 instructions.push(Instruction::AtomicSwap(self.compile_binary(op)))
             }
@@ -554,6 +840,27 @@ impl CudaCompiler {
             gpu::Operator::Normalize(op) => {
                 instructions.push(Instruction::Normalize(self.compile_unary(op)))
             }
+            gpu::Operator::Dot(op) => instructions.push(Instruction::Dot(self.compile_binary(op))),
+            gpu::Operator::ReduceSum(op) => {
+                instructions.push(Instruction::ReduceSum(self.compile_unary(op)))
+            }
+            gpu::Operator::ReduceMax(op) => {
+                instructions.push(Instruction::ReduceMax(self.compile_unary(op)))
+            }
+            gpu::Operator::ReduceMin(op) => {
+                instructions.push(Instruction::ReduceMin(self.compile_unary(op)))
+            }
+            gpu::Operator::Swizzle(op) => instructions.push(Instruction::Swizzle {
+                input: self.compile_variable(op.input),
+                lanes: op.lanes,
+                out: self.compile_variable(op.out),
+            }),
+            gpu::Operator::InsertLane(op) => instructions.push(Instruction::InsertLane {
+                input: self.compile_variable(op.input),
+                lane: op.lane,
+                value: self.compile_variable(op.value),
+                out: self.compile_variable(op.out),
+            }),
         };
     }
 
@@ -746,16 +1053,50 @@ impl CudaCompiler {
                 }
                 gpu::FloatKind::F32 => super::Elem::F32,
                 gpu::FloatKind::F64 => panic!("f64 isn't supported yet"),
+                gpu::FloatKind::E4M3 => {
+                    self.fp8 = true;
+                    super::Elem::E4M3
+                }
+                gpu::FloatKind::E5M2 => {
+                    self.fp8 = true;
+                    super::Elem::E5M2
+                }
             },
             gpu::Elem::Int(kind) => match kind {
+                gpu::IntKind::I8 => {
+                    self.sized_int = true;
+                    super::Elem::I8
+                }
+                gpu::IntKind::I16 => {
+                    self.sized_int = true;
+                    super::Elem::I16
+                }
                 gpu::IntKind::I32 => super::Elem::I32,
                 gpu::IntKind::I64 => panic!("i64 isn't supported yet"),
             },
+            gpu::Elem::AtomicFloat(kind) => match kind {
+                gpu::FloatKind::F32 => super::Elem::Atomic(super::AtomicKind::F32),
+                _ => panic!("atomic<{kind:?}> isn't supported yet"),
+            },
             gpu::Elem::AtomicInt(kind) => match kind {
                 gpu::IntKind::I32 => super::Elem::Atomic(super::AtomicKind::I32),
-                gpu::IntKind::I64 => panic!("atomic<i64> isn't supported yet"),
+                _ => panic!("atomic<{kind:?}> isn't supported yet"),
+            },
+            gpu::Elem::UInt(kind) => match kind {
+                gpu::UIntKind::U8 => {
+                    self.sized_int = true;
+                    super::Elem::U8
+                }
+                gpu::UIntKind::U16 => {
+                    self.sized_int = true;
+                    super::Elem::U16
+                }
+                gpu::UIntKind::U32 => super::Elem::U32,
+                gpu::UIntKind::U64 => {
+                    self.sized_int = true;
+                    super::Elem::U64
+                }
             },
-            gpu::Elem::UInt => super::Elem::U32,
             gpu::Elem::AtomicUInt => super::Elem::Atomic(super::AtomicKind::U32),
             gpu::Elem::Bool => super::Elem::Bool,
         }
@@ -110,6 +110,82 @@ function!(Powf, "powf");
 function!(Max, "max");
 function!(Min, "min");
 
+/// Same as the unary `function_float!`, for functions of two arguments.
+macro_rules! function_float {
+    ($name:ident, $func:expr) => {
+        pub struct $name;
+
+        impl Binary for $name {
+            fn unroll_vec(
+                f: &mut Formatter<'_>,
+                lhs: &Variable,
+                rhs: &Variable,
+                out: &Variable,
+            ) -> core::fmt::Result {
+                let item_out = out.item();
+
+                if item_out.vectorization == 1 {
+                    return Self::format_scalar(f, *lhs, *rhs, *out);
+                }
+
+                for i in 0..item_out.vectorization {
+                    Self::format_scalar(f, lhs.index(i), rhs.index(i), out.index(i))?;
+                }
+
+                Ok(())
+            }
+
+            fn format_scalar<Lhs: Component, Rhs: Component, Out: Component>(
+                f: &mut std::fmt::Formatter<'_>,
+                lhs: Lhs,
+                rhs: Rhs,
+                out: Out,
+            ) -> std::fmt::Result {
+                match out.elem() {
+                    elem @ (Elem::F16 | Elem::BF16) => f.write_fmt(format_args!(
+                        "{out} = {elem}({}(float({lhs}), float({rhs})));\n",
+                        $func
+                    )),
+                    _ => f.write_fmt(format_args!("{out} = {}({lhs}, {rhs});\n", $func)),
+                }
+            }
+        }
+    };
+}
+
+function_float!(Atan2, "atan2");
+function_float!(Hypot, "hypot");
+function_float!(CopySign, "copysign");
+function_float!(NextAfter, "nextafter");
+
+pub struct MulHi;
+
+impl Binary for MulHi {
+    fn format_scalar<Lhs: Component, Rhs: Component, Out: Component>(
+        f: &mut std::fmt::Formatter<'_>,
+        lhs: Lhs,
+        rhs: Rhs,
+        out: Out,
+    ) -> std::fmt::Result {
+        let elem = out.elem();
+
+        match elem {
+            Elem::I32 => f.write_fmt(format_args!("{out} = __mulhi({lhs}, {rhs});\n")),
+            Elem::U32 => f.write_fmt(format_args!("{out} = __umulhi({lhs}, {rhs});\n")),
+            Elem::U64 => f.write_fmt(format_args!("{out} = __umul64hi({lhs}, {rhs});\n")),
+            // The full product of narrow integers fits in 32 bits.
+            Elem::I8 | Elem::I16 => f.write_fmt(format_args!(
+                "{out} = {elem}((int({lhs}) * int({rhs})) >> {});\n",
+                elem.size() * 8
+            )),
+            _ => f.write_fmt(format_args!(
+                "{out} = {elem}((uint({lhs}) * uint({rhs})) >> {});\n",
+                elem.size() * 8
+            )),
+        }
+    }
+}
+
 pub struct IndexAssign;
 pub struct Index;
 
@@ -135,9 +211,8 @@ impl Binary for IndexAssign {
             for i in 0..item_out.vectorization {
                 if cast {
                     f.write_fmt(format_args!(
-                        "{var}.i_{i} = {}({});\n",
-                        item_out.elem,
-                        rhs.index(i)
+                        "{var}.i_{i} = {};\n",
+                        item_out.elem.cast(item_rhs.elem, rhs.index(i))
                     ))?;
                 } else {
                     f.write_fmt(format_args!("{var}.i_{i} = {};\n", rhs.index(i)))?;
@@ -155,7 +230,10 @@ impl Binary for IndexAssign {
             if item_out.vectorization > 1 {
                 format_vec(f, true)?;
             } else {
-                f.write_fmt(format_args!("{out}[{lhs}] = {}({rhs});\n", item_out.elem))?;
+                f.write_fmt(format_args!(
+                    "{out}[{lhs}] = {};\n",
+                    item_out.elem.cast(item_rhs.elem, &rhs)
+                ))?;
             }
             Ok(())
         } else {
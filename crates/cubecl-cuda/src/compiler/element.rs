@@ -1,4 +1,7 @@
-use cubecl_core::ir::{self as gpu, ConstantScalarValue};
+use cubecl_core::{
+    frontend::{F8E4M3, F8E5M2},
+    ir::{self as gpu, ConstantScalarValue},
+};
 use half::{bf16, f16};
 use std::fmt::Display;
 
@@ -11,14 +14,22 @@ pub enum Elem {
     F162,
     BF16,
     BF162,
+    E4M3,
+    E5M2,
+    I8,
+    I16,
     I32,
+    U8,
+    U16,
     U32,
+    U64,
     Bool,
     Atomic(AtomicKind),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Copy, Hash)]
 pub enum AtomicKind {
+    F32,
     I32,
     U32,
 }
@@ -26,6 +37,7 @@ pub enum AtomicKind {
 impl Display for AtomicKind {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
+            AtomicKind::F32 => f.write_str("float"),
             AtomicKind::I32 => f.write_str("int"),
             AtomicKind::U32 => f.write_str("uint"),
         }
@@ -46,8 +58,15 @@ impl Display for Elem {
             Elem::F32 => f.write_str("float"),
             Elem::BF16 => f.write_str("__nv_bfloat16"),
             Elem::BF162 => f.write_str("__nv_bfloat162"),
+            Elem::E4M3 => f.write_str("__nv_fp8_e4m3"),
+            Elem::E5M2 => f.write_str("__nv_fp8_e5m2"),
+            Elem::I8 => f.write_str("int8"),
+            Elem::I16 => f.write_str("int16"),
             Elem::I32 => f.write_str("int"),
+            Elem::U8 => f.write_str("uint8"),
+            Elem::U16 => f.write_str("uint16"),
             Elem::U32 => f.write_str("uint"),
+            Elem::U64 => f.write_str("uint64"),
             Elem::Bool => f.write_str("bool"),
             Elem::Atomic(inner) => inner.fmt(f),
         }
@@ -201,9 +220,23 @@ impl Display for Variable {
             // precision related problems.
             Variable::ConstantScalar(number, elem) => match number {
                 ConstantScalarValue::Int(val, kind) => match kind {
+                    gpu::IntKind::I8 => f.write_fmt(format_args!("{elem}({})", *val as i8)),
+                    gpu::IntKind::I16 => f.write_fmt(format_args!("{elem}({})", *val as i16)),
                     gpu::IntKind::I32 => f.write_fmt(format_args!("{elem}({})", *val as i32)),
                     gpu::IntKind::I64 => f.write_fmt(format_args!("{elem}({})", { *val })),
                 },
+                // C++ has no literal for infinities and NaN without including the math headers,
+                // so their bits are reinterpreted with `__uint_as_float` and `__longlong_as_double`.
+                ConstantScalarValue::Float(val, kind) if !val.is_finite() => match kind {
+                    gpu::FloatKind::F64 => f.write_fmt(format_args!(
+                        "{elem}(__longlong_as_double({:#x}ll))",
+                        val.to_bits()
+                    )),
+                    _ => f.write_fmt(format_args!(
+                        "{elem}(__uint_as_float({:#x}u))",
+                        (*val as f32).to_bits()
+                    )),
+                },
                 ConstantScalarValue::Float(val, kind) => match kind {
                     gpu::FloatKind::F16 => {
                         f.write_fmt(format_args!("{elem}({:?})", half::f16::from_f64(*val)))
@@ -213,10 +246,21 @@ impl Display for Variable {
                     }
                     gpu::FloatKind::F32 => f.write_fmt(format_args!("{elem}({:?})", *val as f32)),
                     gpu::FloatKind::F64 => f.write_fmt(format_args!("{elem}({:?})", { *val })),
+                    gpu::FloatKind::E4M3 => f.write_fmt(format_args!(
+                        "{elem}({:?})",
+                        F8E4M3::from_f64(*val).to_f32()
+                    )),
+                    gpu::FloatKind::E5M2 => f.write_fmt(format_args!(
+                        "{elem}({:?})",
+                        F8E5M2::from_f64(*val).to_f32()
+                    )),
+                },
+                ConstantScalarValue::UInt(val, kind) => match kind {
+                    gpu::UIntKind::U8 => f.write_fmt(format_args!("{elem}({})", *val as u8)),
+                    gpu::UIntKind::U16 => f.write_fmt(format_args!("{elem}({})", *val as u16)),
+                    gpu::UIntKind::U32 => f.write_fmt(format_args!("{elem}({})", *val as u32)),
+                    gpu::UIntKind::U64 => f.write_fmt(format_args!("{elem}({}ull)", { *val })),
                 },
-                ConstantScalarValue::UInt(val) => {
-                    f.write_fmt(format_args!("{elem}({})", *val as u32))
-                }
                 ConstantScalarValue::Bool(val) => f.write_fmt(format_args!("{}", val)),
             },
             Variable::SharedMemory(number, _, _) => {
@@ -477,6 +521,15 @@ impl Item {
 }
 
 impl Elem {
+    /// Convert `value`, of element `from`, to this element.
+    pub fn cast<V: Display>(&self, from: Elem, value: V) -> String {
+        match from {
+            // 8 bits floats only have native conversions to float, other casts go through it.
+            Elem::E4M3 | Elem::E5M2 if *self != Elem::F32 => format!("{self}(float({value}))"),
+            _ => format!("{self}({value})"),
+        }
+    }
+
     pub fn size(&self) -> usize {
         match self {
             Self::F16 => core::mem::size_of::<f16>(),
@@ -484,9 +537,17 @@ impl Elem {
             Self::BF162 => 2 * core::mem::size_of::<bf16>(),
             Self::BF16 => core::mem::size_of::<bf16>(),
             Self::F32 => core::mem::size_of::<f32>(),
+            Self::E4M3 => core::mem::size_of::<F8E4M3>(),
+            Self::E5M2 => core::mem::size_of::<F8E5M2>(),
+            Self::I8 => core::mem::size_of::<i8>(),
+            Self::I16 => core::mem::size_of::<i16>(),
             Self::I32 => core::mem::size_of::<i32>(),
+            Self::U8 => core::mem::size_of::<u8>(),
+            Self::U16 => core::mem::size_of::<u16>(),
             Self::U32 => core::mem::size_of::<u32>(),
+            Self::U64 => core::mem::size_of::<u64>(),
             Self::Bool => core::mem::size_of::<bool>(),
+            Self::Atomic(AtomicKind::F32) => core::mem::size_of::<f32>(),
             Self::Atomic(AtomicKind::I32) => core::mem::size_of::<i32>(),
             Self::Atomic(AtomicKind::U32) => core::mem::size_of::<u32>(),
         }
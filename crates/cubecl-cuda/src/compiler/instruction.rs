@@ -1,4 +1,7 @@
-use super::{binary::*, unary::*, Component, Elem, Variable, WarpInstruction, WmmaInstruction};
+use super::{
+    binary::*, unary::*, AtomicKind, Component, Elem, IndexedVariable, Variable, WarpInstruction,
+    WmmaInstruction,
+};
 use std::fmt::Display;
 
 #[derive(Debug, Clone)]
@@ -65,13 +68,38 @@ pub enum Instruction {
         instructions_if: Vec<Self>,
         instructions_else: Vec<Self>,
     },
+    Switch {
+        value: Variable,
+        instructions_default: Vec<Self>,
+        cases: Vec<(Vec<Variable>, Vec<Self>)>,
+    },
     Slice {
         input: Variable,
         start: Variable,
         end: Variable,
         out: Variable,
     },
+    CopyAsync {
+        input: Variable,
+        out: Variable,
+    },
+    PipelineCommit,
+    PipelineWait {
+        pending: u32,
+    },
     Return,
+    Call {
+        function: String,
+        bindings: String,
+        args: Vec<Variable>,
+        out: Option<Variable>,
+    },
+    /// Write a record to the debug buffer, only when the condition is false if there is one.
+    DebugRecord {
+        format: u32,
+        condition: Option<Variable>,
+        args: Vec<Variable>,
+    },
     Break,
     Stride {
         dim: Variable,
@@ -90,6 +118,29 @@ pub enum Instruction {
     LowerEqual(BinaryInstruction),
     GreaterEqual(BinaryInstruction),
     Erf(UnaryInstruction),
+    Tan(UnaryInstruction),
+    Asin(UnaryInstruction),
+    Acos(UnaryInstruction),
+    Atan(UnaryInstruction),
+    Atan2(BinaryInstruction),
+    Sinh(UnaryInstruction),
+    Cosh(UnaryInstruction),
+    Exp2(UnaryInstruction),
+    Log2(UnaryInstruction),
+    Hypot(BinaryInstruction),
+    Trunc(UnaryInstruction),
+    Fract(UnaryInstruction),
+    Sign(UnaryInstruction),
+    IsNan(UnaryInstruction),
+    IsInf(UnaryInstruction),
+    IsFinite(UnaryInstruction),
+    CopySign(BinaryInstruction),
+    NextAfter(BinaryInstruction),
+    CountOnes(UnaryInstruction),
+    LeadingZeros(UnaryInstruction),
+    TrailingZeros(UnaryInstruction),
+    ReverseBits(UnaryInstruction),
+    MulHi(BinaryInstruction),
     BitwiseOr(BinaryInstruction),
     BitwiseAnd(BinaryInstruction),
     BitwiseXor(BinaryInstruction),
@@ -117,6 +168,7 @@ pub enum Instruction {
     },
     SyncThreads,
     ThreadFence,
+    ThreadFenceBlock,
     Round(UnaryInstruction),
     Ceil(UnaryInstruction),
     Floor(UnaryInstruction),
@@ -141,12 +193,110 @@ pub enum Instruction {
     },
     Negate(UnaryInstruction),
     Normalize(UnaryInstruction),
+    Dot(BinaryInstruction),
+    ReduceSum(UnaryInstruction),
+    ReduceMax(UnaryInstruction),
+    ReduceMin(UnaryInstruction),
+    Swizzle {
+        input: Variable,
+        lanes: Vec<u32>,
+        out: Variable,
+    },
+    InsertLane {
+        input: Variable,
+        lane: u32,
+        value: Variable,
+        out: Variable,
+    },
 }
 
 impl Display for Instruction {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Instruction::Return => f.write_str("return;"),
+            Instruction::Call {
+                function,
+                bindings,
+                args,
+                out,
+            } => {
+                // Slices are passed along with their length.
+                let args = args.iter().map(|arg| match arg {
+                    Variable::Slice { .. } => format!("{arg}, {arg}_length"),
+                    arg => arg.to_string(),
+                });
+                let args = core::iter::once(bindings.clone())
+                    .chain(args)
+                    .filter(|arg| !arg.is_empty())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                match out {
+                    Some(out) => f.write_fmt(format_args!("{out} = {function}({args});\n")),
+                    None => f.write_fmt(format_args!("{function}({args});\n")),
+                }
+            }
+            Instruction::DebugRecord {
+                format,
+                condition,
+                args,
+            } => {
+                let mut words = vec![format!("{}", format + 1)];
+                for arg in args {
+                    let item = arg.item();
+                    let lanes = (0..item.vectorization).map(|i| arg.index(i).to_string());
+                    let lanes: Vec<_> = match item.elem() {
+                        // Packed halves are written as two lanes.
+                        Elem::F162 | Elem::BF162 => lanes
+                            .flat_map(|lane| [format!("{lane}.x"), format!("{lane}.y")])
+                            .collect(),
+                        _ => lanes.collect(),
+                    };
+                    words.push(format!("{}", lanes.len()));
+                    for lane in lanes {
+                        words.push(match item.elem() {
+                            Elem::F32
+                            | Elem::F16
+                            | Elem::F162
+                            | Elem::BF16
+                            | Elem::BF162
+                            | Elem::E4M3
+                            | Elem::E5M2 => {
+                                format!("__float_as_uint(float({lane}))")
+                            }
+                            Elem::I8 | Elem::I16 | Elem::I32 => format!("uint(int({lane}))"),
+                            Elem::U8 | Elem::U16 | Elem::U32 | Elem::Bool => {
+                                format!("uint({lane})")
+                            }
+                            Elem::U64 => {
+                                panic!("64 bits integers can't be written to the debug buffer")
+                            }
+                            Elem::Atomic(_) => {
+                                panic!("Atomics can't be written to the debug buffer")
+                            }
+                        });
+                    }
+                }
+                let len = words.len();
+
+                if let Some(condition) = condition {
+                    let item = condition.item();
+                    let all = (0..item.vectorization)
+                        .map(|i| condition.index(i).to_string())
+                        .collect::<Vec<_>>()
+                        .join(" && ");
+                    f.write_fmt(format_args!("if (!({all})) {{\n"))?;
+                } else {
+                    f.write_str("{\n")?;
+                }
+                f.write_fmt(format_args!(
+                    "uint debug_offset = atomicAdd(&debug[0], {len});
+if (debug_offset + {len} <= debug[1]) {{\n"
+                ))?;
+                for (i, word) in words.iter().enumerate() {
+                    f.write_fmt(format_args!("debug[debug_offset + {}] = {word};\n", i + 2))?;
+                }
+                f.write_str("}\n}\n")
+            }
             Instruction::Break => f.write_str("break;"),
             Instruction::DeclareVariable { var } => match var {
                 Variable::WmmaFragment {
@@ -170,6 +320,28 @@ impl Display for Instruction {
                 f.write_fmt(format_args!("uint {out}_length = {end} - {start};\n"))?;
                 f.write_fmt(format_args!("{item} *{out} = {input} + {start};\n"))
             }
+            Instruction::CopyAsync { input, out } => {
+                let item = out.item();
+                let size = item.elem.size() * item.vectorization;
+
+                f.write_fmt(format_args!(
+                    "
+for (uint i = 0; i < min({input}_length, {out}_length); ++i) {{
+"
+                ))?;
+                match size {
+                    // Asynchronous copies are limited to 4, 8 and 16 bytes per element.
+                    4 | 8 | 16 => f.write_fmt(format_args!(
+                        "__pipeline_memcpy_async({out} + i, {input} + i, {size});\n"
+                    ))?,
+                    _ => f.write_fmt(format_args!("{out}[i] = {input}[i];\n"))?,
+                }
+                f.write_str("}\n")
+            }
+            Instruction::PipelineCommit => f.write_str("__pipeline_commit();\n"),
+            Instruction::PipelineWait { pending } => {
+                f.write_fmt(format_args!("__pipeline_wait_prior({pending});\n"))
+            }
             Instruction::Mul(it) => Mul::format(f, &it.lhs, &it.rhs, &it.out),
             Instruction::Div(it) => Div::format(f, &it.lhs, &it.rhs, &it.out),
             Instruction::Sub(it) => Sub::format(f, &it.lhs, &it.rhs, &it.out),
@@ -211,6 +383,28 @@ for ({i_ty} {i} = {start}; {i} {cmp} {end}; {increment}) {{
                 f.write_str("}\n")
             }
 
+            Instruction::Switch {
+                value,
+                instructions_default,
+                cases,
+            } => {
+                f.write_fmt(format_args!("switch ({value}) {{\n"))?;
+                for (values, instructions) in cases {
+                    for value in values {
+                        f.write_fmt(format_args!("case {value}:\n"))?;
+                    }
+                    f.write_str("{\n")?;
+                    for i in instructions {
+                        f.write_fmt(format_args!("{i}"))?;
+                    }
+                    f.write_str("}\nbreak;\n")?;
+                }
+                f.write_str("default: {\n")?;
+                for i in instructions_default {
+                    f.write_fmt(format_args!("{i}"))?;
+                }
+                f.write_str("}\n}\n")
+            }
             Instruction::Loop { instructions } => {
                 f.write_fmt(format_args!("while (true) {{\n"))?;
                 for i in instructions {
@@ -253,6 +447,29 @@ for ({i_ty} {i} = {start}; {i} {cmp} {end}; {increment}) {{
             Instruction::LowerEqual(it) => LowerEqual::format(f, &it.lhs, &it.rhs, &it.out),
             Instruction::GreaterEqual(it) => GreaterEqual::format(f, &it.lhs, &it.rhs, &it.out),
             Instruction::Erf(it) => Erf::format(f, &it.input, &it.out),
+            Instruction::Tan(it) => Tan::format(f, &it.input, &it.out),
+            Instruction::Asin(it) => Asin::format(f, &it.input, &it.out),
+            Instruction::Acos(it) => Acos::format(f, &it.input, &it.out),
+            Instruction::Atan(it) => Atan::format(f, &it.input, &it.out),
+            Instruction::Atan2(it) => Atan2::format(f, &it.lhs, &it.rhs, &it.out),
+            Instruction::Sinh(it) => Sinh::format(f, &it.input, &it.out),
+            Instruction::Cosh(it) => Cosh::format(f, &it.input, &it.out),
+            Instruction::Exp2(it) => Exp2::format(f, &it.input, &it.out),
+            Instruction::Log2(it) => Log2::format(f, &it.input, &it.out),
+            Instruction::Hypot(it) => Hypot::format(f, &it.lhs, &it.rhs, &it.out),
+            Instruction::Trunc(it) => Trunc::format(f, &it.input, &it.out),
+            Instruction::Fract(it) => Fract::format(f, &it.input, &it.out),
+            Instruction::Sign(it) => Sign::format(f, &it.input, &it.out),
+            Instruction::IsNan(it) => IsNan::format(f, &it.input, &it.out),
+            Instruction::IsInf(it) => IsInf::format(f, &it.input, &it.out),
+            Instruction::IsFinite(it) => IsFinite::format(f, &it.input, &it.out),
+            Instruction::CopySign(it) => CopySign::format(f, &it.lhs, &it.rhs, &it.out),
+            Instruction::NextAfter(it) => NextAfter::format(f, &it.lhs, &it.rhs, &it.out),
+            Instruction::CountOnes(it) => CountOnes::format(f, &it.input, &it.out),
+            Instruction::LeadingZeros(it) => LeadingZeros::format(f, &it.input, &it.out),
+            Instruction::TrailingZeros(it) => TrailingZeros::format(f, &it.input, &it.out),
+            Instruction::ReverseBits(it) => ReverseBits::format(f, &it.input, &it.out),
+            Instruction::MulHi(it) => MulHi::format(f, &it.lhs, &it.rhs, &it.out),
             Instruction::Abs(it) => Abs::format(f, &it.input, &it.out),
             Instruction::Exp(it) => Exp::format(f, &it.input, &it.out),
             Instruction::Log(it) => Log::format(f, &it.input, &it.out),
@@ -275,6 +492,7 @@ for ({i_ty} {i} = {start}; {i} {cmp} {end}; {increment}) {{
             } => Clamp::format(f, input, min_value, max_value, out),
             Instruction::SyncThreads => f.write_str("__syncthreads();\n"),
             Instruction::ThreadFence => f.write_str("__threadfence();\n"),
+            Instruction::ThreadFenceBlock => f.write_str("__threadfence_block();\n"),
             Instruction::Round(it) => Round::format(f, &it.input, &it.out),
             Instruction::Ceil(it) => Ceil::format(f, &it.input, &it.out),
             Instruction::Floor(it) => Floor::format(f, &it.input, &it.out),
@@ -354,7 +572,17 @@ for ({i_ty} {i} = {start}; {i} {cmp} {end}; {increment}) {{
                 cmp,
                 val,
                 out,
-            } => f.write_fmt(format_args!("{out} = atomicCAS({input}, {cmp}, {val});\n")),
+            } => {
+                if AtomicFloat::is(input) {
+                    // There is no float overload of `atomicCAS`, so the bits are compared instead.
+                    f.write_fmt(format_args!(
+                        "{out} = __uint_as_float(atomicCAS((uint*){input}, \
+                         __float_as_uint({cmp}), __float_as_uint({val})));\n"
+                    ))
+                } else {
+                    f.write_fmt(format_args!("{out} = atomicCAS({input}, {cmp}, {val});\n"))
+                }
+            }
             Instruction::AtomicSwap(BinaryInstruction { lhs, rhs, out }) => {
                 f.write_fmt(format_args!("{out} = atomicExch({lhs}, {rhs});\n"))
             }
@@ -362,21 +590,45 @@ for ({i_ty} {i} = {start}; {i} {cmp} {end}; {increment}) {{
                 f.write_fmt(format_args!("{out} = atomicAdd({lhs}, {rhs});\n"))
             }
             Instruction::AtomicSub(BinaryInstruction { lhs, rhs, out }) => {
-                f.write_fmt(format_args!("{out} = atomicSub({lhs}, {rhs});\n"))
+                if AtomicFloat::is(lhs) {
+                    f.write_fmt(format_args!("{out} = atomicAdd({lhs}, -{rhs});\n"))
+                } else {
+                    f.write_fmt(format_args!("{out} = atomicSub({lhs}, {rhs});\n"))
+                }
             }
             Instruction::AtomicMax(BinaryInstruction { lhs, rhs, out }) => {
-                f.write_fmt(format_args!("{out} = atomicMax({lhs}, {rhs});\n"))
+                if AtomicFloat::is(lhs) {
+                    AtomicFloat::update(f, lhs, out, |current| format!("max({current}, {rhs})"))
+                } else {
+                    f.write_fmt(format_args!("{out} = atomicMax({lhs}, {rhs});\n"))
+                }
             }
             Instruction::AtomicMin(BinaryInstruction { lhs, rhs, out }) => {
-                f.write_fmt(format_args!("{out} = atomicMin({lhs}, {rhs});\n"))
+                if AtomicFloat::is(lhs) {
+                    AtomicFloat::update(f, lhs, out, |current| format!("min({current}, {rhs})"))
+                } else {
+                    f.write_fmt(format_args!("{out} = atomicMin({lhs}, {rhs});\n"))
+                }
             }
             Instruction::AtomicAnd(BinaryInstruction { lhs, rhs, out }) => {
+                assert!(
+                    !AtomicFloat::is(lhs),
+                    "Can't use bitwise and on float atomics"
+                );
                 f.write_fmt(format_args!("{out} = atomicAnd({lhs}, {rhs});\n"))
             }
             Instruction::AtomicOr(BinaryInstruction { lhs, rhs, out }) => {
+                assert!(
+                    !AtomicFloat::is(lhs),
+                    "Can't use bitwise or on float atomics"
+                );
                 f.write_fmt(format_args!("{out} = atomicOr({lhs}, {rhs});\n"))
             }
             Instruction::AtomicXor(BinaryInstruction { lhs, rhs, out }) => {
+                assert!(
+                    !AtomicFloat::is(lhs),
+                    "Can't use bitwise xor on float atomics"
+                );
                 f.write_fmt(format_args!("{out} = atomicXor({lhs}, {rhs});\n"))
             }
             Instruction::AtomicLoad(UnaryInstruction { input, out }) => {
@@ -390,6 +642,37 @@ for ({i_ty} {i} = {start}; {i} {cmp} {end}; {increment}) {{
                 f.write_fmt(format_args!("{out} = !{input};\n"))
             }
             Instruction::Normalize(inst) => Normalize::format(f, &inst.input, &inst.out),
+            Instruction::Dot(it) => Dot::format(f, &it.lhs, &it.rhs, &it.out),
+            Instruction::ReduceSum(it) => Reduce::format(f, &it.input, &it.out, |f, lane, out| {
+                f.write_fmt(format_args!("{out} += {lane};\n"))
+            }),
+            Instruction::ReduceMax(it) => Reduce::format(f, &it.input, &it.out, |f, lane, out| {
+                f.write_fmt(format_args!("{out} = {lane} > {out} ? {lane} : {out};\n"))
+            }),
+            Instruction::ReduceMin(it) => Reduce::format(f, &it.input, &it.out, |f, lane, out| {
+                f.write_fmt(format_args!("{out} = {lane} < {out} ? {lane} : {out};\n"))
+            }),
+            Instruction::Swizzle { input, lanes, out } => {
+                for (i, lane) in lanes.iter().enumerate() {
+                    let outi = out.index(i);
+                    let inputi = input.index(*lane as usize);
+
+                    f.write_fmt(format_args!("{outi} = {inputi};\n"))?;
+                }
+
+                Ok(())
+            }
+            Instruction::InsertLane {
+                input,
+                lane,
+                value,
+                out,
+            } => {
+                f.write_fmt(format_args!("{out} = {input};\n"))?;
+
+                let outi = out.index(*lane as usize);
+                f.write_fmt(format_args!("{outi} = {value};\n"))
+            }
         }
     }
 }
@@ -419,6 +702,43 @@ impl Fma {
     }
 }
 
+struct Dot;
+
+impl Dot {
+    fn format(
+        f: &mut core::fmt::Formatter<'_>,
+        lhs: &Variable,
+        rhs: &Variable,
+        out: &Variable,
+    ) -> core::fmt::Result {
+        let products = (0..lhs.item().vectorization)
+            .map(|i| format!("{} * {}", lhs.index(i), rhs.index(i)))
+            .collect::<Vec<_>>();
+
+        f.write_fmt(format_args!("{out} = {};\n", products.join(" + ")))
+    }
+}
+
+/// Combines the lanes of a vectorized value one after the other.
+struct Reduce;
+
+impl Reduce {
+    fn format(
+        f: &mut core::fmt::Formatter<'_>,
+        input: &Variable,
+        out: &Variable,
+        combine: impl Fn(&mut core::fmt::Formatter<'_>, IndexedVariable, &Variable) -> core::fmt::Result,
+    ) -> core::fmt::Result {
+        f.write_fmt(format_args!("{out} = {};\n", input.index(0)))?;
+
+        for i in 1..input.item().vectorization {
+            combine(f, input.index(i), out)?;
+        }
+
+        Ok(())
+    }
+}
+
 struct Clamp;
 
 impl Clamp {
@@ -510,3 +830,38 @@ impl Normalize {
         f.write_fmt(format_args!("}}\n"))
     }
 }
+
+/// Float atomics, which only have native support for exchanges and additions.
+struct AtomicFloat;
+
+impl AtomicFloat {
+    fn is(pointer: &Variable) -> bool {
+        pointer.elem() == Elem::Atomic(AtomicKind::F32)
+    }
+
+    /// Atomically replaces the value at the pointer with the one computed from the current value,
+    /// retrying with a compare and swap until no other unit wrote in between.
+    fn update(
+        f: &mut core::fmt::Formatter<'_>,
+        pointer: &Variable,
+        out: &Variable,
+        value: impl Fn(&str) -> String,
+    ) -> core::fmt::Result {
+        let value = value("__uint_as_float(assumed)");
+
+        f.write_fmt(format_args!(
+            "
+{{
+    uint* bits = (uint*){pointer};
+    uint old = *bits;
+    uint assumed;
+    do {{
+        assumed = old;
+        old = atomicCAS(bits, assumed, __float_as_uint({value}));
+    }} while (assumed != old);
+    {out} = __uint_as_float(old);
+}}
+"
+        ))
+    }
+}
@@ -1,4 +1,4 @@
-use super::{Body, Item};
+use super::{Body, Component, Item, Variable};
 use cubecl_core::{ir::CubeDim, CompilerRepresentation};
 use std::{collections::HashSet, fmt::Display, io::Write, process::Command};
 
@@ -40,6 +40,14 @@ impl SharedMemory {
     }
 }
 
+#[derive(Debug, Clone)]
+pub struct Function {
+    pub name: String,
+    pub params: Vec<Variable>,
+    pub output: Option<Variable>,
+    pub body: Body,
+}
+
 #[derive(Debug, Clone)]
 pub struct ComputeKernel {
     pub inputs: Vec<Binding>,
@@ -47,9 +55,14 @@ pub struct ComputeKernel {
     pub named: Vec<(String, Binding)>,
     pub cube_dim: CubeDim,
     pub body: Body,
+    pub functions: Vec<Function>,
+    pub shared_memories: Vec<SharedMemory>,
     pub wmma_activated: bool,
     pub bf16: bool,
     pub f16: bool,
+    pub fp8: bool,
+    pub pipeline: bool,
+    pub sized_int: bool,
     pub items: HashSet<super::Item>,
 }
 
@@ -57,7 +70,12 @@ impl CompilerRepresentation for ComputeKernel {
     fn shared_memory_size(&self) -> usize {
         let mut current = 0usize;
 
-        for var in self.body.shared_memories.iter() {
+        for var in self
+            .body
+            .shared_memories
+            .iter()
+            .chain(self.shared_memories.iter())
+        {
             let factor = var.item.vectorization;
             let elem_size_bytes = var.item.elem().size();
             current += (var.size as usize) * factor * elem_size_bytes;
@@ -80,12 +98,28 @@ impl Display for ComputeKernel {
             f.write_str("#include <cuda_fp16.h>\n")?;
         }
 
+        if self.fp8 {
+            f.write_str("#include <cuda_fp8.h>\n")?;
+        }
+
+        if self.pipeline {
+            f.write_str("#include <cuda_pipeline.h>\n")?;
+        }
+
         if self.wmma_activated {
             f.write_str("using namespace nvcuda;\n")?;
         }
 
         f.write_str("typedef unsigned int uint;\n")?;
 
+        if self.sized_int {
+            f.write_str("typedef signed char int8;\n")?;
+            f.write_str("typedef short int16;\n")?;
+            f.write_str("typedef unsigned char uint8;\n")?;
+            f.write_str("typedef unsigned short uint16;\n")?;
+            f.write_str("typedef unsigned long long int uint64;\n")?;
+        }
+
         for item in self.items.iter() {
             let elem = item.elem;
             let size = item.vectorization;
@@ -107,6 +141,18 @@ struct __align__({alignment}) {item} {{"
             }
         }
 
+        for shared in self.shared_memories.iter() {
+            f.write_fmt(format_args!(
+                "__shared__ {} shared_memory_{}[{}];\n",
+                shared.item, shared.index, shared.size
+            ))?;
+        }
+
+        // Functions are declared before their callers, which always have a lower id.
+        for function in self.functions.iter().rev() {
+            self.format_function(f, function)?;
+        }
+
         f.write_fmt(format_args!(
             "
 
@@ -114,6 +160,19 @@ extern \"C\" __global__ void kernel(
 ",
         ))?;
 
+        self.format_bindings(f)?;
+
+        f.write_str("\n) {\n")?;
+
+        f.write_fmt(format_args!("{}", self.body))?;
+        f.write_str("\n}")?;
+
+        Ok(())
+    }
+}
+
+impl ComputeKernel {
+    fn format_bindings(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let num_bindings = self.inputs.len() + self.outputs.len() + self.named.len();
         let mut binding_index = 0;
         for (index, binding) in self.inputs.iter().enumerate() {
@@ -139,12 +198,40 @@ extern \"C\" __global__ void kernel(
             }
         }
 
+        Ok(())
+    }
+
+    /// Functions receive all the bindings of the kernel, followed by their own parameters.
+    fn format_function(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+        function: &Function,
+    ) -> std::fmt::Result {
+        let output = match &function.output {
+            Some(output) => output.item().to_string(),
+            None => "void".to_string(),
+        };
+        f.write_fmt(format_args!("\n__device__ {output} {}(\n", function.name))?;
+        self.format_bindings(f)?;
+
+        for param in function.params.iter() {
+            let item = param.item();
+            match param {
+                Variable::Slice { .. } => {
+                    f.write_fmt(format_args!(",{item} *{param}, uint {param}_length"))?
+                }
+                _ => f.write_fmt(format_args!(",{item} {param}"))?,
+            }
+        }
+
         f.write_str("\n) {\n")?;
+        f.write_fmt(format_args!("{}", function.body))?;
 
-        f.write_fmt(format_args!("{}", self.body))?;
-        f.write_str("\n}")?;
+        if let Some(output) = &function.output {
+            f.write_fmt(format_args!("return {output};\n"))?;
+        }
 
-        Ok(())
+        f.write_str("}\n")
     }
 }
 
@@ -104,6 +104,266 @@ function!(Erf, "erf");
 function!(Ceil, "ceil");
 function!(Floor, "floor");
 function!(Round, "rint");
+function!(Exp2, "exp2");
+function!(Log2, "log2");
+function!(Trunc, "trunc");
+
+/// Functions without half precision intrinsics, computed in f32 for half precision floats.
+///
+/// Packed half2 values can't be converted to float, so vectorized items are always unrolled.
+macro_rules! function_float {
+    ($name:ident, $func:expr) => {
+        pub struct $name;
+
+        impl Unary for $name {
+            fn unroll_vec(
+                f: &mut std::fmt::Formatter<'_>,
+                input: &Variable,
+                out: &Variable,
+                elem: Elem,
+                index: usize,
+            ) -> std::fmt::Result {
+                for i in 0..index {
+                    Self::format_scalar(f, input.index(i), out.index(i), elem)?;
+                }
+
+                Ok(())
+            }
+
+            fn format_scalar<Input: Display, Out: Display>(
+                f: &mut std::fmt::Formatter<'_>,
+                input: Input,
+                out: Out,
+                elem: Elem,
+            ) -> std::fmt::Result {
+                match elem {
+                    Elem::F16 | Elem::BF16 => {
+                        f.write_fmt(format_args!("{out} = {elem}({}(float({input})));\n", $func))
+                    }
+                    _ => f.write_fmt(format_args!("{out} = {}({input});\n", $func)),
+                }
+            }
+        }
+    };
+}
+
+function_float!(Tan, "tan");
+function_float!(Asin, "asin");
+function_float!(Acos, "acos");
+function_float!(Atan, "atan");
+function_float!(Sinh, "sinh");
+function_float!(Cosh, "cosh");
+
+pub struct Fract;
+
+impl Unary for Fract {
+    fn format_scalar<Input, Out>(
+        f: &mut std::fmt::Formatter<'_>,
+        input: Input,
+        out: Out,
+        elem: Elem,
+    ) -> std::fmt::Result
+    where
+        Input: Component,
+        Out: Component,
+    {
+        f.write_fmt(format_args!(
+            "{out} = {input} - {}({input});\n",
+            Trunc::function_name(elem)
+        ))
+    }
+}
+
+pub struct Sign;
+
+impl Unary for Sign {
+    fn unroll_vec(
+        f: &mut std::fmt::Formatter<'_>,
+        input: &Variable,
+        out: &Variable,
+        elem: Elem,
+        index: usize,
+    ) -> std::fmt::Result {
+        for i in 0..index {
+            Self::format_scalar(f, input.index(i), out.index(i), elem)?;
+        }
+
+        Ok(())
+    }
+
+    fn format_scalar<Input, Out>(
+        f: &mut std::fmt::Formatter<'_>,
+        input: Input,
+        out: Out,
+        elem: Elem,
+    ) -> std::fmt::Result
+    where
+        Input: Component,
+        Out: Component,
+    {
+        f.write_fmt(format_args!(
+            "{out} = {elem}(({input} > {elem}(0)) - ({input} < {elem}(0)));\n"
+        ))
+    }
+}
+
+macro_rules! function_check {
+    ($name:ident, $func:expr) => {
+        pub struct $name;
+
+        impl Unary for $name {
+            fn format(
+                f: &mut std::fmt::Formatter<'_>,
+                input: &Variable,
+                out: &Variable,
+            ) -> std::fmt::Result {
+                // The output is a boolean, so the input decides how the value is checked.
+                Self::unroll_vec(f, input, out, input.elem(), out.item().vectorization)
+            }
+
+            fn unroll_vec(
+                f: &mut std::fmt::Formatter<'_>,
+                input: &Variable,
+                out: &Variable,
+                elem: Elem,
+                index: usize,
+            ) -> std::fmt::Result {
+                for i in 0..index {
+                    Self::format_scalar(f, input.index(i), out.index(i), elem)?;
+                }
+
+                Ok(())
+            }
+
+            fn format_scalar<Input: Display, Out: Display>(
+                f: &mut std::fmt::Formatter<'_>,
+                input: Input,
+                out: Out,
+                elem: Elem,
+            ) -> std::fmt::Result {
+                match elem {
+                    Elem::F16 | Elem::BF16 => {
+                        f.write_fmt(format_args!("{out} = {}(float({input}));\n", $func))
+                    }
+                    _ => f.write_fmt(format_args!("{out} = {}({input});\n", $func)),
+                }
+            }
+        }
+    };
+}
+
+function_check!(IsNan, "isnan");
+function_check!(IsInf, "isinf");
+function_check!(IsFinite, "isfinite");
+
+/// The number of bits of an integer element, narrow integers being promoted to 32 bits by the
+/// bit manipulation intrinsics, so their extra bits are masked or shifted away.
+fn int_bits(elem: Elem) -> usize {
+    elem.size() * 8
+}
+
+pub struct CountOnes;
+
+impl Unary for CountOnes {
+    fn format_scalar<Input, Out>(
+        f: &mut std::fmt::Formatter<'_>,
+        input: Input,
+        out: Out,
+        elem: Elem,
+    ) -> std::fmt::Result
+    where
+        Input: Component,
+        Out: Component,
+    {
+        match elem {
+            Elem::U64 => f.write_fmt(format_args!("{out} = {elem}(__popcll({input}));\n")),
+            Elem::I32 | Elem::U32 => {
+                f.write_fmt(format_args!("{out} = {elem}(__popc({input}));\n"))
+            }
+            _ => f.write_fmt(format_args!(
+                "{out} = {elem}(__popc(uint({input}) & {:#x}u));\n",
+                u32::MAX >> (32 - int_bits(elem))
+            )),
+        }
+    }
+}
+
+pub struct LeadingZeros;
+
+impl Unary for LeadingZeros {
+    fn format_scalar<Input, Out>(
+        f: &mut std::fmt::Formatter<'_>,
+        input: Input,
+        out: Out,
+        elem: Elem,
+    ) -> std::fmt::Result
+    where
+        Input: Component,
+        Out: Component,
+    {
+        match elem {
+            Elem::U64 => f.write_fmt(format_args!("{out} = {elem}(__clzll({input}));\n")),
+            Elem::I32 | Elem::U32 => f.write_fmt(format_args!("{out} = {elem}(__clz({input}));\n")),
+            _ => f.write_fmt(format_args!(
+                "{out} = {elem}(__clz(int(uint({input}) & {:#x}u)) - {});\n",
+                u32::MAX >> (32 - int_bits(elem)),
+                32 - int_bits(elem)
+            )),
+        }
+    }
+}
+
+pub struct TrailingZeros;
+
+impl Unary for TrailingZeros {
+    fn format_scalar<Input, Out>(
+        f: &mut std::fmt::Formatter<'_>,
+        input: Input,
+        out: Out,
+        elem: Elem,
+    ) -> std::fmt::Result
+    where
+        Input: Component,
+        Out: Component,
+    {
+        // The position of the first set bit is 1 based, and 0 when no bit is set.
+        match elem {
+            Elem::U64 => f.write_fmt(format_args!(
+                "{out} = {elem}({input} == 0 ? 64 : __ffsll({input}) - 1);\n"
+            )),
+            _ => f.write_fmt(format_args!(
+                "{out} = {elem}({input} == 0 ? {} : __ffs(int({input})) - 1);\n",
+                int_bits(elem)
+            )),
+        }
+    }
+}
+
+pub struct ReverseBits;
+
+impl Unary for ReverseBits {
+    fn format_scalar<Input, Out>(
+        f: &mut std::fmt::Formatter<'_>,
+        input: Input,
+        out: Out,
+        elem: Elem,
+    ) -> std::fmt::Result
+    where
+        Input: Component,
+        Out: Component,
+    {
+        match elem {
+            Elem::U64 => f.write_fmt(format_args!("{out} = __brevll({input});\n")),
+            Elem::I32 | Elem::U32 => {
+                f.write_fmt(format_args!("{out} = {elem}(__brev({input}));\n"))
+            }
+            _ => f.write_fmt(format_args!(
+                "{out} = {elem}(__brev(uint({input})) >> {});\n",
+                32 - int_bits(elem)
+            )),
+        }
+    }
+}
 
 pub struct Not;
 
@@ -137,7 +397,10 @@ impl Unary for Assign {
     {
         // Cast only when necessary.
         if elem != input.elem() {
-            f.write_fmt(format_args!("{out} = {elem}({input});\n"))
+            f.write_fmt(format_args!(
+                "{out} = {};\n",
+                elem.cast(input.elem(), &input)
+            ))
         } else {
             f.write_fmt(format_args!("{out} = {input};\n"))
         }
@@ -1,6 +1,6 @@
 use std::fmt::Display;
 
-use super::Variable;
+use super::{Component, Variable};
 
 #[derive(Clone, Debug)]
 pub enum WarpInstruction {
@@ -20,6 +20,14 @@ pub enum WarpInstruction {
         input: Variable,
         out: Variable,
     },
+    InclusiveSum {
+        input: Variable,
+        out: Variable,
+    },
+    ExclusiveSum {
+        input: Variable,
+        out: Variable,
+    },
     Elect {
         out: Variable,
     },
@@ -36,6 +44,30 @@ pub enum WarpInstruction {
         id: Variable,
         out: Variable,
     },
+    Shuffle {
+        input: Variable,
+        lane: Variable,
+        out: Variable,
+    },
+    ShuffleXor {
+        input: Variable,
+        mask: Variable,
+        out: Variable,
+    },
+    ShuffleUp {
+        input: Variable,
+        delta: Variable,
+        out: Variable,
+    },
+    ShuffleDown {
+        input: Variable,
+        delta: Variable,
+        out: Variable,
+    },
+    Ballot {
+        input: Variable,
+        out: Variable,
+    },
 }
 
 impl Display for WarpInstruction {
@@ -63,6 +95,22 @@ for (int offset = warpSizeChecked / 2; offset > 0; offset /= 2) {{
 }}
                     "
             )),
+            WarpInstruction::InclusiveSum { input, out } => inclusive_sum(f, input, out),
+            WarpInstruction::ExclusiveSum { input, out } => {
+                inclusive_sum(f, input, out)?;
+                let elem = out.item().elem;
+                f.write_str("{\n")?;
+                for i in 0..out.item().vectorization {
+                    let out = out.index(i);
+                    f.write_fmt(format_args!(
+                        "
+    auto shifted_{i} = __shfl_up_sync(0xFFFFFFFF, {out}, 1);
+    {out} = threadIdxGlobal % warpSize == 0 ? {elem}(0) : shifted_{i};
+"
+                    ))?;
+                }
+                f.write_str("}\n")
+            }
             WarpInstruction::Elect { out } => f.write_fmt(format_args!(
                 "
 unsigned int mask = __activemask();
@@ -91,6 +139,21 @@ unsigned int leader = __ffs(mask) - 1;
 {out} = __shfl_sync(0xFFFFFFFF, {input}, {id});
             "
             )),
+            WarpInstruction::Shuffle { input, lane, out } => {
+                shuffle(f, input, lane, out, "__shfl_sync")
+            }
+            WarpInstruction::ShuffleXor { input, mask, out } => {
+                shuffle(f, input, mask, out, "__shfl_xor_sync")
+            }
+            WarpInstruction::ShuffleUp { input, delta, out } => {
+                shuffle(f, input, delta, out, "__shfl_up_sync")
+            }
+            WarpInstruction::ShuffleDown { input, delta, out } => {
+                shuffle(f, input, delta, out, "__shfl_down_sync")
+            }
+            WarpInstruction::Ballot { input, out } => f.write_fmt(format_args!(
+                "{out} = __ballot_sync(0xFFFFFFFF, {input});\n"
+            )),
         }
     }
 }
@@ -112,3 +175,54 @@ fn reduce_operator(
 "
     ))
 }
+
+/// The shuffle intrinsics only take scalars, so vectorized items are shuffled one lane at a time.
+fn shuffle(
+    f: &mut core::fmt::Formatter<'_>,
+    input: &Variable,
+    other: &Variable,
+    out: &Variable,
+    func: &str,
+) -> core::fmt::Result {
+    for i in 0..out.item().vectorization {
+        let input = input.index(i);
+        let out = out.index(i);
+        f.write_fmt(format_args!(
+            "{out} = {func}(0xFFFFFFFF, {input}, {other});\n"
+        ))?;
+    }
+
+    Ok(())
+}
+
+fn inclusive_sum(
+    f: &mut core::fmt::Formatter<'_>,
+    input: &Variable,
+    out: &Variable,
+) -> core::fmt::Result {
+    f.write_fmt(format_args!(
+        "
+    {out} = {input};
+{{
+    int laneId = threadIdxGlobal % warpSize;
+    for (int offset = 1; offset < warpSizeChecked; offset *= 2) {{
+"
+    ))?;
+    for i in 0..out.item().vectorization {
+        let out = out.index(i);
+        f.write_fmt(format_args!(
+            "
+        auto value_{i} = __shfl_up_sync(0xFFFFFFFF, {out}, offset);
+        if (laneId >= offset) {{
+            {out} += value_{i};
+        }}
+"
+        ))?;
+    }
+    f.write_str(
+        "
+    }
+}
+",
+    )
+}
@@ -8,6 +8,7 @@ use cubecl_core::compute::DebugInformation;
 use cubecl_core::ir::CubeDim;
 use cubecl_core::{prelude::*, KernelId};
 use cubecl_core::{FeatureSet, Properties};
+use cubecl_runtime::compilation_cache::CompilationCache;
 use cubecl_runtime::debug::DebugLogger;
 use cubecl_runtime::ExecutionMode;
 use cubecl_runtime::{
@@ -49,6 +50,7 @@ pub(crate) struct CudaContext<MM: MemoryManagement<CudaStorage>> {
     stream: cudarc::driver::sys::CUstream,
     memory_management: MM,
     module_names: HashMap<KernelId, CompiledKernel>,
+    compilation_cache: CompilationCache,
     pub(crate) arch: u32,
 }
 
@@ -128,6 +130,15 @@ impl<MM: MemoryManagement<CudaStorage>> ComputeServer for CudaServer<MM> {
         bindings: Vec<server::Binding<Self>>,
         mode: ExecutionMode,
     ) {
+        if let Some(num_bindings) = kernel.num_bindings() {
+            assert_eq!(
+                bindings.len(),
+                num_bindings,
+                "The kernel expects {num_bindings} bindings, but {} were given",
+                bindings.len()
+            );
+        }
+
         let mut kernel_id = kernel.id();
         kernel_id.mode(mode);
 
@@ -200,6 +211,7 @@ impl<MM: MemoryManagement<CudaStorage>> CudaContext<MM> {
             context,
             memory_management,
             module_names: HashMap::new(),
+            compilation_cache: compilation_cache(arch),
             stream,
             arch,
         }
@@ -239,25 +251,31 @@ impl<MM: MemoryManagement<CudaStorage>> CudaContext<MM> {
 
         let kernel_compiled = logger.debug(kernel_compiled);
 
-        let ptx = unsafe {
-            let program = cudarc::nvrtc::result::create_program(kernel_compiled.source).unwrap();
-            if cudarc::nvrtc::result::compile_program(program, options).is_err() {
-                let log_raw = cudarc::nvrtc::result::get_program_log(program).unwrap();
-                let log_ptr = log_raw.as_ptr();
-                let log = CStr::from_ptr(log_ptr).to_str().unwrap();
-                let mut message = "[Compilation Error] ".to_string();
-                for line in log.split('\n') {
-                    if !line.is_empty() {
-                        message += format!("\n    {line}").as_str();
-                    }
-                }
-                let source = kernel.compile(mode).source;
-                panic!("{message}\n[Source]  \n{source}");
-            };
-            cudarc::nvrtc::result::get_ptx(program).unwrap()
+        // The PTX only depends on the source and the architecture, so it can be reused from a
+        // previous process as long as the generated source didn't change.
+        let cache_key = format!("{}-{mode:?}", kernel.stable_id());
+        let ptx = match self
+            .compilation_cache
+            .get(&cache_key, &kernel_compiled.source)
+        {
+            Some(ptx) => ptx,
+            None => {
+                let ptx = compile_ptx(&kernel_compiled.source, options, || {
+                    kernel.compile(mode).source
+                });
+                self.compilation_cache
+                    .insert(&cache_key, &kernel_compiled.source, &ptx);
+                ptx
+            }
         };
 
-        let func_name = CString::new("kernel".to_string()).unwrap();
+        let func_name = CString::new(
+            kernel_compiled
+                .entry_point
+                .clone()
+                .unwrap_or_else(|| "kernel".to_string()),
+        )
+        .unwrap();
         let func = unsafe {
             let module =
                 cudarc::driver::result::module::load_data(ptx.as_ptr() as *const _).unwrap();
@@ -334,6 +352,44 @@ impl<MM: MemoryManagement<CudaStorage>> CudaServer<MM> {
     }
 }
 
+/// Compile the source to PTX with NVRTC, returning the PTX with its trailing nul byte.
+fn compile_ptx(source: &str, options: &[&str], full_source: impl FnOnce() -> String) -> Vec<u8> {
+    unsafe {
+        let program = cudarc::nvrtc::result::create_program(source).unwrap();
+        if cudarc::nvrtc::result::compile_program(program, options).is_err() {
+            let log_raw = cudarc::nvrtc::result::get_program_log(program).unwrap();
+            let log_ptr = log_raw.as_ptr();
+            let log = CStr::from_ptr(log_ptr).to_str().unwrap();
+            let mut message = "[Compilation Error] ".to_string();
+            for line in log.split('\n') {
+                if !line.is_empty() {
+                    message += format!("\n    {line}").as_str();
+                }
+            }
+            let source = full_source();
+            panic!("{message}\n[Source]  \n{source}");
+        };
+        let ptx = cudarc::nvrtc::result::get_ptx(program).unwrap();
+        ptx.into_iter().map(|c| c as u8).collect()
+    }
+}
+
+/// The persistent cache of the PTX compiled for the architecture, invalidated when the NVRTC
+/// version changes.
+fn compilation_cache(arch: u32) -> CompilationCache {
+    let (major, minor) = unsafe {
+        let mut major = 0;
+        let mut minor = 0;
+        cudarc::nvrtc::sys::lib().nvrtcVersion(&mut major, &mut minor);
+        (major, minor)
+    };
+
+    CompilationCache::new(
+        &format!("cuda-nvrtc-{major}.{minor}"),
+        &format!("sm_{arch}"),
+    )
+}
+
 fn include_path() -> PathBuf {
     let mut path = cuda_path().expect("
         CUDA installation not found.
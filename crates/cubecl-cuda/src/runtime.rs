@@ -70,7 +70,7 @@ impl Runtime for CudaRuntime {
 
         RUNTIME.client(device, move || {
             let mut server = CudaServer::new(device.index, Box::new(init));
-            let mut features = FeatureSet::new(&[Feature::Subcube]);
+            let mut features = FeatureSet::new(&[Feature::Subcube, Feature::DeviceFence]);
 
             register_wmma_features(&mut features, server.arch_version());
             ComputeClient::new(
@@ -0,0 +1,219 @@
+use cubecl_core::cube;
+use cubecl_core::{self as cubecl, prelude::*};
+
+use crate::matmul::cmma::base::Ids;
+
+use super::compute::{compute_scores, compute_values, tile_index};
+use super::config::ComptimeAttentionInfo;
+use super::load::load_block;
+
+#[cube(launch_unchecked)]
+/// Computes `softmax(scale * Q·Kᵀ + mask)·V` for one block of query rows, iterating over
+/// blocks of keys and values with an online softmax so the scores are never written
+/// to global memory.
+///
+/// The mask is only read when the comptime info has one, otherwise any tensor can be given.
+///
+/// Each coop handles one row of 16 query rows, with two units per row: both units keep
+/// the running max and sum of the row, and each one owns half of the output columns.
+pub fn attention_kernel<F: Float, FC: Float>(
+    query: &Tensor<F>,
+    key: &Tensor<F>,
+    value: &Tensor<F>,
+    mask: &Tensor<F>,
+    out: &mut Tensor<F>,
+    scale: f32,
+    #[comptime] info: ComptimeAttentionInfo,
+) {
+    let block_size_q = info.block_size_q;
+    let block_size_kv = info.block_size_kv;
+    let head_dim = info.head_dim;
+    let tile_size = info.tile_size;
+    let causal = info.causal;
+    let check_q_bounds = info.check_q_bounds;
+    let check_kv_bounds = info.check_kv_bounds;
+    let num_coops = info.num_coops;
+    let scores_stride = info.scores_stride;
+    let coop_dim = info.coop_dim;
+    let unroll = info.unroll;
+
+    let units_per_row = coop_dim / tile_size;
+    let cols_per_unit = block_size_kv / units_per_row;
+    let dims_per_unit = head_dim / units_per_row;
+
+    let ids = Ids {
+        coop: UNIT_POS_Y,
+        lane: UNIT_POS_X,
+    };
+
+    let rank = query.rank();
+    let seq_q = query.shape(rank - 2);
+    let seq_kv = key.shape(rank - 2);
+
+    let batch_q = CUBE_POS_Z * seq_q * head_dim;
+    let batch_kv = CUBE_POS_Z * seq_kv * head_dim;
+    let q_start = CUBE_POS_X * block_size_q;
+
+    let row = ids.lane / units_per_row;
+    let part = ids.lane % units_per_row;
+    let q_row = q_start + ids.coop * tile_size + row;
+    let scores_offset = ids.coop * scores_stride;
+    let probs_offset = ids.coop * tile_size * block_size_kv;
+
+    let mut shared_query = SharedMemory::<FC>::new(block_size_q * head_dim);
+    let mut shared_key = SharedMemory::<FC>::new(block_size_kv * head_dim);
+    let mut shared_value = SharedMemory::<FC>::new(block_size_kv * head_dim);
+    let scores = SharedMemory::<f32>::new(num_coops * scores_stride);
+    let mut probs = SharedMemory::<FC>::new(block_size_q * block_size_kv);
+
+    load_block::<F, FC>(
+        query,
+        &mut shared_query,
+        batch_q,
+        q_start,
+        seq_q,
+        ids,
+        block_size_q,
+        check_q_bounds,
+        info,
+    );
+
+    let mut row_max = f32::new(-1e30);
+    let mut row_sum = f32::new(0.0);
+    let mut acc = Array::<f32>::new(dims_per_unit);
+
+    #[unroll(unroll)]
+    for i in 0..dims_per_unit {
+        acc[i] = f32::new(0.0);
+    }
+
+    // Keys after the last query row of the block are all masked
+    let mut kv_end = seq_kv;
+    if causal {
+        kv_end = Min::min(seq_kv, q_start + block_size_q);
+    }
+    let num_kv_blocks = ((kv_end - 1) / block_size_kv) + 1;
+
+    for kv_block in 0..num_kv_blocks {
+        let kv_start = kv_block * block_size_kv;
+
+        load_block::<F, FC>(
+            key,
+            &mut shared_key,
+            batch_kv,
+            kv_start,
+            seq_kv,
+            ids,
+            block_size_kv,
+            check_kv_bounds,
+            info,
+        );
+        load_block::<F, FC>(
+            value,
+            &mut shared_value,
+            batch_kv,
+            kv_start,
+            seq_kv,
+            ids,
+            block_size_kv,
+            check_kv_bounds,
+            info,
+        );
+
+        sync_units();
+
+        compute_scores::<FC>(shared_query, shared_key, scores, ids, info);
+
+        sync_units();
+
+        let mut new_max = row_max;
+        let mut row_scores = Array::<f32>::new(block_size_kv);
+        #[unroll]
+        for col in 0..block_size_kv {
+            if is_key_valid(kv_start + col, q_row, seq_kv, info) {
+                let score = scores[scores_offset + tile_index(row, col, tile_size)] * scale
+                    + mask_value::<F>(mask, kv_start + col, q_row, seq_q, seq_kv, info);
+                row_scores[col] = score;
+                new_max = Max::max(new_max, score);
+            }
+        }
+
+        let alpha = f32::exp(row_max - new_max);
+        let mut block_sum = f32::new(0.0);
+        #[unroll]
+        for col in 0..block_size_kv {
+            let mut prob = f32::new(0.0);
+            if is_key_valid(kv_start + col, q_row, seq_kv, info) {
+                prob = f32::exp(row_scores[col] - new_max);
+            }
+            block_sum += prob;
+
+            if col / cols_per_unit == part {
+                probs[probs_offset + tile_index(row, col, tile_size)] = FC::cast_from(prob);
+            }
+        }
+
+        row_sum = row_sum * alpha + block_sum;
+        row_max = new_max;
+
+        sync_units();
+
+        compute_values::<FC>(probs, shared_value, scores, ids, info);
+
+        sync_units();
+
+        #[unroll(unroll)]
+        for i in 0..dims_per_unit {
+            let col = part * dims_per_unit + i;
+            acc[i] = acc[i] * alpha + scores[scores_offset + tile_index(row, col, tile_size)];
+        }
+    }
+
+    if q_row < seq_q {
+        let write_offset = batch_q + q_row * head_dim + part * dims_per_unit;
+
+        #[unroll(unroll)]
+        for i in 0..dims_per_unit {
+            out[write_offset + i] = F::cast_from(acc[i] / row_sum);
+        }
+    }
+}
+
+#[cube]
+fn is_key_valid(
+    key_row: u32,
+    q_row: u32,
+    seq_kv: u32,
+    #[comptime] info: ComptimeAttentionInfo,
+) -> bool {
+    let causal = info.causal;
+
+    let mut valid = key_row < seq_kv;
+    if causal {
+        valid = valid && key_row <= q_row;
+    }
+    valid
+}
+
+#[cube]
+/// The additive mask of the score of a valid key for the query row, zero without a mask.
+fn mask_value<F: Float>(
+    mask: &Tensor<F>,
+    key_row: u32,
+    q_row: u32,
+    seq_q: u32,
+    seq_kv: u32,
+    #[comptime] info: ComptimeAttentionInfo,
+) -> f32 {
+    let has_mask = info.has_mask;
+
+    let mut value = f32::new(0.0);
+    if has_mask {
+        // Rows past the end of the query sequence are computed but never written.
+        if q_row < seq_q {
+            let offset = CUBE_POS_Z * seq_q * seq_kv + q_row * seq_kv + key_row;
+            value = f32::cast_from(mask[offset]);
+        }
+    }
+    value
+}
@@ -0,0 +1,148 @@
+use cubecl_core as cubecl;
+use cubecl_core::prelude::*;
+
+use crate::matmul::cmma::base::Ids;
+
+use super::config::ComptimeAttentionInfo;
+
+#[cube]
+/// Position of element (`row`, `col`) in a row of 16x16 tiles stored one after the other.
+pub(crate) fn tile_index(row: u32, col: u32, #[comptime] tile_size: u32) -> u32 {
+    (col / tile_size) * tile_size * tile_size + row * tile_size + col % tile_size
+}
+
+#[cube]
+/// Compute `Q·Kᵀ` for the query rows of the coop, and store it in its scores slot.
+pub(crate) fn compute_scores<FC: Float>(
+    shared_query: SharedMemory<FC>,
+    shared_key: SharedMemory<FC>,
+    scores: SharedMemory<f32>,
+    ids: Ids,
+    #[comptime] info: ComptimeAttentionInfo,
+) {
+    let tile_size = info.tile_size;
+    let head_dim = info.head_dim;
+    let block_size_kv = info.block_size_kv;
+    let scores_stride = info.scores_stride;
+    let unroll = info.unroll;
+
+    let num_head_tiles = head_dim / tile_size;
+    let num_kv_tiles = block_size_kv / tile_size;
+    let smem_stride = tile_size * tile_size;
+    let scores_offset = ids.coop * scores_stride;
+
+    let query = cmma::Matrix::<FC>::new(
+        cmma::MatrixIdent::A,
+        16,
+        16,
+        16,
+        cmma::MatrixLayout::RowMajor,
+    );
+    // Key tiles are stored row major, which is column major for their transpose
+    let key = cmma::Matrix::<FC>::new(
+        cmma::MatrixIdent::B,
+        16,
+        16,
+        16,
+        cmma::MatrixLayout::ColMajor,
+    );
+    let accumulator = cmma::Matrix::<f32>::new(
+        cmma::MatrixIdent::Accumulator,
+        16,
+        16,
+        16,
+        cmma::MatrixLayout::Undefined,
+    );
+
+    #[unroll]
+    for kv_tile in 0..num_kv_tiles {
+        cmma::fill::<f32>(&accumulator, f32::new(0.0));
+
+        #[unroll(unroll)]
+        for head_tile in 0..num_head_tiles {
+            let query_pos = (ids.coop * num_head_tiles + head_tile) * smem_stride;
+            let key_pos = (kv_tile * num_head_tiles + head_tile) * smem_stride;
+
+            let query_slice = shared_query.slice(query_pos, query_pos + smem_stride);
+            let key_slice = shared_key.slice(key_pos, key_pos + smem_stride);
+
+            cmma::load::<FC>(&query, query_slice, 16);
+            cmma::load::<FC>(&key, key_slice, 16);
+
+            cmma::execute::<FC, FC, f32, f32>(&query, &key, &accumulator, &accumulator);
+        }
+
+        let scores_pos = scores_offset + kv_tile * smem_stride;
+        let scores_slice = scores.slice_mut_unsafe(scores_pos, scores_pos + smem_stride);
+
+        cmma::store::<f32>(scores_slice, &accumulator, 16, cmma::MatrixLayout::RowMajor);
+    }
+}
+
+#[cube]
+/// Compute `P·V` for the query rows of the coop, and store it in its scores slot.
+pub(crate) fn compute_values<FC: Float>(
+    probs: SharedMemory<FC>,
+    shared_value: SharedMemory<FC>,
+    scores: SharedMemory<f32>,
+    ids: Ids,
+    #[comptime] info: ComptimeAttentionInfo,
+) {
+    let tile_size = info.tile_size;
+    let head_dim = info.head_dim;
+    let block_size_kv = info.block_size_kv;
+    let scores_stride = info.scores_stride;
+    let unroll = info.unroll;
+
+    let num_head_tiles = head_dim / tile_size;
+    let num_kv_tiles = block_size_kv / tile_size;
+    let smem_stride = tile_size * tile_size;
+    let scores_offset = ids.coop * scores_stride;
+    let probs_offset = ids.coop * tile_size * block_size_kv;
+
+    let prob = cmma::Matrix::<FC>::new(
+        cmma::MatrixIdent::A,
+        16,
+        16,
+        16,
+        cmma::MatrixLayout::RowMajor,
+    );
+    let value = cmma::Matrix::<FC>::new(
+        cmma::MatrixIdent::B,
+        16,
+        16,
+        16,
+        cmma::MatrixLayout::RowMajor,
+    );
+    let accumulator = cmma::Matrix::<f32>::new(
+        cmma::MatrixIdent::Accumulator,
+        16,
+        16,
+        16,
+        cmma::MatrixLayout::Undefined,
+    );
+
+    #[unroll(unroll)]
+    for head_tile in 0..num_head_tiles {
+        cmma::fill::<f32>(&accumulator, f32::new(0.0));
+
+        #[unroll]
+        for kv_tile in 0..num_kv_tiles {
+            let prob_pos = probs_offset + kv_tile * smem_stride;
+            let value_pos = (kv_tile * num_head_tiles + head_tile) * smem_stride;
+
+            let prob_slice = probs.slice(prob_pos, prob_pos + smem_stride);
+            let value_slice = shared_value.slice(value_pos, value_pos + smem_stride);
+
+            cmma::load::<FC>(&prob, prob_slice, 16);
+            cmma::load::<FC>(&value, value_slice, 16);
+
+            cmma::execute::<FC, FC, f32, f32>(&prob, &value, &accumulator, &accumulator);
+        }
+
+        let scores_pos = scores_offset + head_tile * smem_stride;
+        let scores_slice = scores.slice_mut_unsafe(scores_pos, scores_pos + smem_stride);
+
+        cmma::store::<f32>(scores_slice, &accumulator, 16, cmma::MatrixLayout::RowMajor);
+    }
+}
@@ -0,0 +1,132 @@
+use cubecl_core::prelude::*;
+
+use crate::matmul::cmma::config::{ComptimeCmmaInfo, CMMA_COOP_DIM, CMMA_TILE_SIZE};
+
+/// Number of rows of query, key and value loaded per block.
+pub(crate) const ATTENTION_BLOCK_SIZE: usize = 32;
+
+/// Configuration of the fused attention kernel.
+#[derive(Default)]
+pub struct AttentionConfig {
+    /// Whether a query may only attend to keys at the same or an earlier position
+    pub causal: bool,
+    /// Factor applied to the scores before the softmax. Defaults to `1 / sqrt(head_dim)`
+    pub scale: Option<f32>,
+    /// Whether to unroll loops over the head dimension
+    pub unroll: bool,
+}
+
+impl AttentionConfig {
+    pub(crate) fn scale(&self, head_dim: usize) -> f32 {
+        self.scale
+            .unwrap_or_else(|| 1.0 / f32::sqrt(head_dim as f32))
+    }
+
+    pub(crate) fn comptime_info(
+        &self,
+        seq_q: usize,
+        seq_kv: usize,
+        head_dim: usize,
+        has_mask: bool,
+    ) -> ComptimeAttentionInfo {
+        assert!(
+            head_dim == 64 || head_dim == 128,
+            "Only head dimensions of 64 and 128 are supported, got {head_dim}."
+        );
+
+        let num_coops = ATTENTION_BLOCK_SIZE / CMMA_TILE_SIZE;
+        let check_q_bounds = seq_q % ATTENTION_BLOCK_SIZE != 0;
+        let check_kv_bounds = seq_kv % ATTENTION_BLOCK_SIZE != 0;
+
+        ComptimeAttentionInfo {
+            block_size_q: ATTENTION_BLOCK_SIZE as u32,
+            block_size_kv: ATTENTION_BLOCK_SIZE as u32,
+            head_dim: head_dim as u32,
+            tile_size: CMMA_TILE_SIZE as u32,
+            causal: self.causal,
+            has_mask,
+            check_q_bounds,
+            check_kv_bounds,
+            unroll: self.unroll,
+            coop_dim: CMMA_COOP_DIM as u32,
+            num_coops: num_coops as u32,
+            scores_stride: (CMMA_TILE_SIZE * usize::max(ATTENTION_BLOCK_SIZE, head_dim)) as u32,
+            cmma: ComptimeCmmaInfo {
+                block_size_m: ATTENTION_BLOCK_SIZE as u32,
+                block_size_k: head_dim as u32,
+                block_size_n: ATTENTION_BLOCK_SIZE as u32,
+                tile_size: CMMA_TILE_SIZE as u32,
+                check_m_bounds: check_q_bounds,
+                check_k_bounds: false,
+                check_n_bounds: check_kv_bounds,
+                unroll: self.unroll,
+                coop_dim: CMMA_COOP_DIM as u32,
+                num_coops: num_coops as u32,
+                num_accumulators: 1,
+                write_out_reuse_smem: true,
+            },
+        }
+    }
+
+    pub(crate) fn cube_count<R: Runtime>(
+        &self,
+        output_shape: &[usize],
+    ) -> CubeCount<<R as Runtime>::Server> {
+        let rank = output_shape.len();
+        let seq_q = output_shape[rank - 2];
+
+        let cubes_x = f32::ceil(seq_q as f32 / ATTENTION_BLOCK_SIZE as f32) as u32;
+
+        let mut num_iter = 1;
+        for shape in output_shape.iter().take(rank - 2) {
+            num_iter *= shape;
+        }
+
+        CubeCount::Static(cubes_x, 1, num_iter as u32)
+    }
+
+    pub(crate) fn cube_dim(&self) -> CubeDim {
+        CubeDim {
+            x: CMMA_COOP_DIM as u32,
+            y: (ATTENTION_BLOCK_SIZE / CMMA_TILE_SIZE) as u32,
+            z: 1,
+        }
+    }
+}
+
+impl Init for ComptimeAttentionInfo {
+    fn init(self, _context: &mut CubeContext) -> Self {
+        self
+    }
+}
+
+#[derive(Clone, Copy, Hash, PartialEq, Eq, Debug)]
+/// Fused attention parameters
+pub struct ComptimeAttentionInfo {
+    /// Number of query rows handled by one cube
+    pub block_size_q: u32,
+    /// Number of key and value rows loaded per iteration
+    pub block_size_kv: u32,
+    /// Dimension of one head, shared by query, key and value
+    pub head_dim: u32,
+    /// Tile size (dimension of one side). Should correspond to cmma supported tile size
+    pub tile_size: u32,
+    /// Causal masking
+    pub causal: bool,
+    /// Whether an additive mask is added to the scores
+    pub has_mask: bool,
+    /// Bounds must be checked on the query sequence
+    pub check_q_bounds: bool,
+    /// Bounds must be checked on the key/value sequence
+    pub check_kv_bounds: bool,
+    /// Unroll
+    pub unroll: bool,
+    /// The number of units that can collaborate
+    pub coop_dim: u32,
+    /// The number of collaboration groups, one per row of tiles of the query block
+    pub num_coops: u32,
+    /// Size of the scores shared memory reserved for each collaboration group
+    pub scores_stride: u32,
+    /// Parameters used when loading tiles through the cmma loaders
+    pub cmma: ComptimeCmmaInfo,
+}
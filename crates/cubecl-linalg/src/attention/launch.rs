@@ -0,0 +1,171 @@
+use cubecl_core::{
+    client::ComputeClient,
+    frontend::{Float, ScalarArg, TensorArg, TensorHandleRef},
+    tensor_vectorization_factor, Runtime,
+};
+use half::f16;
+
+use crate::{
+    attention::{base::attention_kernel, config::AttentionConfig},
+    matmul::cmma::{self, launch::UnavailabilityReason},
+    tensor::{into_contiguous, matrix_layout, MatrixLayout, TensorHandle},
+};
+
+/// Fused attention `softmax(scale * Q·Kᵀ + mask)·V` using
+/// [cooperative matrix-multiply and accumulate operations](cubecl_core::cmma).
+///
+/// Query, key and value are expected to be of shape `[.., seq, head_dim]`, with the
+/// same batch dimensions. The optional additive mask is of shape `[.., seq_q, seq_kv]`,
+/// and is applied on top of the causal masking of the config.
+pub fn flash_attention<R: Runtime, F: Float>(
+    client: &ComputeClient<R::Server, R::Channel>,
+    query: TensorHandle<R, F>,
+    key: TensorHandle<R, F>,
+    value: TensorHandle<R, F>,
+    mask: Option<TensorHandle<R, F>>,
+    out: TensorHandle<R, F>,
+    config: AttentionConfig,
+) -> TensorHandle<R, F> {
+    flash_attention_ref::<R, F>(
+        client,
+        query.as_ref(),
+        key.as_ref(),
+        value.as_ref(),
+        mask.as_ref().map(|mask| mask.as_ref()),
+        out.as_ref(),
+        config,
+    );
+    out
+}
+
+/// Checks if the fused attention can be used.
+pub fn check_attention_availability<R: Runtime, F: Float>(
+    client: &ComputeClient<R::Server, R::Channel>,
+) -> Result<(), UnavailabilityReason> {
+    cmma::is_available::<R, F>(client)
+}
+
+/// Fused attention `softmax(scale * Q·Kᵀ + mask)·V` using
+/// [cooperative matrix-multiply and accumulate operations](cubecl_core::cmma).
+///
+/// Query, key and value are expected to be of shape `[.., seq, head_dim]`, with the
+/// same batch dimensions. The optional additive mask is of shape `[.., seq_q, seq_kv]`,
+/// and is applied on top of the causal masking of the config.
+pub fn flash_attention_ref<R: Runtime, F: Float>(
+    client: &ComputeClient<R::Server, R::Channel>,
+    query: TensorHandleRef<'_, R>,
+    key: TensorHandleRef<'_, R>,
+    value: TensorHandleRef<'_, R>,
+    mask: Option<TensorHandleRef<'_, R>>,
+    out: TensorHandleRef<'_, R>,
+    config: AttentionConfig,
+) {
+    let make_contiguous = |tensor: &TensorHandleRef<'_, R>| match matrix_layout(tensor.strides) {
+        MatrixLayout::Contiguous => None,
+        _ => Some(into_contiguous::<R, F>(
+            client,
+            TensorHandleRef {
+                handle: tensor.handle,
+                strides: tensor.strides,
+                shape: tensor.shape,
+            },
+        )),
+    };
+
+    let query_contiguous = make_contiguous(&query);
+    let key_contiguous = make_contiguous(&key);
+    let value_contiguous = make_contiguous(&value);
+    let mask_contiguous = mask.as_ref().and_then(make_contiguous);
+
+    flash_attention_ref_no_check::<R, F>(
+        client,
+        query_contiguous.as_ref().map_or(query, |t| t.as_ref()),
+        key_contiguous.as_ref().map_or(key, |t| t.as_ref()),
+        value_contiguous.as_ref().map_or(value, |t| t.as_ref()),
+        mask.map(|mask| mask_contiguous.as_ref().map_or(mask, |t| t.as_ref())),
+        out,
+        config,
+    );
+}
+
+fn flash_attention_ref_no_check<R: Runtime, F: Float>(
+    client: &ComputeClient<R::Server, R::Channel>,
+    query: TensorHandleRef<'_, R>,
+    key: TensorHandleRef<'_, R>,
+    value: TensorHandleRef<'_, R>,
+    mask: Option<TensorHandleRef<'_, R>>,
+    out: TensorHandleRef<'_, R>,
+    config: AttentionConfig,
+) {
+    let rank = query.strides.len();
+
+    let seq_q = query.shape[rank - 2];
+    let seq_kv = key.shape[rank - 2];
+    let head_dim = query.shape[rank - 1];
+
+    assert!(seq_kv > 0, "Key and value must contain at least one row.");
+    assert_eq!(key.shape[rank - 1], head_dim, "Key head dim mismatch.");
+    assert_eq!(value.shape[rank - 1], head_dim, "Value head dim mismatch.");
+    assert_eq!(
+        value.shape[rank - 2],
+        seq_kv,
+        "Key and value lengths differ."
+    );
+    assert_eq!(
+        query.shape, out.shape,
+        "Output must be of the same shape as query."
+    );
+    if let Some(mask) = &mask {
+        assert_eq!(
+            mask.shape[..rank - 1],
+            query.shape[..rank - 1],
+            "Mask must have the batch dimensions and query length of query."
+        );
+        assert_eq!(mask.shape[rank - 1], seq_kv, "Mask key length mismatch.");
+    }
+
+    let available_vectorizations = [4, 2];
+    let query_vectorization = tensor_vectorization_factor(
+        &available_vectorizations,
+        query.shape,
+        query.strides,
+        rank - 1,
+    );
+    let key_vectorization =
+        tensor_vectorization_factor(&available_vectorizations, key.shape, key.strides, rank - 1);
+    let value_vectorization = tensor_vectorization_factor(
+        &available_vectorizations,
+        value.shape,
+        value.strides,
+        rank - 1,
+    );
+
+    unsafe {
+        attention_kernel::launch_unchecked::<F, f16, R>(
+            client,
+            config.cube_count::<R>(out.shape),
+            config.cube_dim(),
+            TensorArg::from_raw_parts(
+                query.handle,
+                query.strides,
+                query.shape,
+                query_vectorization,
+            ),
+            TensorArg::from_raw_parts(key.handle, key.strides, key.shape, key_vectorization),
+            TensorArg::from_raw_parts(
+                value.handle,
+                value.strides,
+                value.shape,
+                value_vectorization,
+            ),
+            // Without a mask, the query is bound in its place and never read.
+            match &mask {
+                Some(mask) => TensorArg::from_raw_parts(mask.handle, mask.strides, mask.shape, 1),
+                None => TensorArg::from_raw_parts(query.handle, query.strides, query.shape, 1),
+            },
+            TensorArg::from_raw_parts(out.handle, out.strides, out.shape, 1),
+            ScalarArg::new(config.scale(head_dim)),
+            config.comptime_info(seq_q, seq_kv, head_dim, mask.is_some()),
+        );
+    }
+}
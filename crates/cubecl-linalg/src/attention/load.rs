@@ -0,0 +1,75 @@
+use cubecl_core as cubecl;
+use cubecl_core::prelude::*;
+
+use crate::matmul::cmma::{
+    base::Ids,
+    block_io::{unchecked_block::UncheckedBlockIO, whole_block_check::WholeCheckBlockIO},
+    load_shared_memory::load_tile,
+};
+
+use super::config::ComptimeAttentionInfo;
+
+#[cube]
+/// Load the rows `block_start..block_start + block_size` of a `[seq_len, head_dim]` matrix
+/// into shared memory, one 16x16 tile after the other.
+pub(crate) fn load_block<F: Float, FC: Float>(
+    tensor: &Tensor<F>,
+    shared_memory: &mut SharedMemory<FC>,
+    batch_offset: u32,
+    block_start: u32,
+    seq_len: u32,
+    ids: Ids,
+    #[comptime] block_size: u32,
+    #[comptime] check_bounds: bool,
+    #[comptime] info: ComptimeAttentionInfo,
+) {
+    let head_dim = info.head_dim;
+    let tile_size = info.tile_size;
+    let num_coops = info.num_coops;
+    let cmma_info = info.cmma;
+
+    let num_head_tiles = head_dim / tile_size;
+    let num_tiles = (block_size / tile_size) * num_head_tiles;
+    let num_tiles_per_coop = num_tiles / num_coops;
+
+    #[unroll]
+    for i in 0..num_tiles_per_coop {
+        let slot = ids.coop + i * num_coops;
+        let tile_row = slot / num_head_tiles;
+        let tile_col = slot % num_head_tiles;
+        let slot_ids = Ids {
+            coop: slot,
+            lane: ids.lane,
+        };
+
+        if check_bounds {
+            load_tile::<F, FC, WholeCheckBlockIO>(
+                tensor,
+                shared_memory,
+                batch_offset,
+                tile_row,
+                tile_col,
+                seq_len,
+                head_dim,
+                block_start,
+                0,
+                slot_ids,
+                cmma_info,
+            );
+        } else {
+            load_tile::<F, FC, UncheckedBlockIO>(
+                tensor,
+                shared_memory,
+                batch_offset,
+                tile_row,
+                tile_col,
+                seq_len,
+                head_dim,
+                block_start,
+                0,
+                slot_ids,
+                cmma_info,
+            );
+        }
+    }
+}
@@ -0,0 +1,13 @@
+mod base;
+mod compute;
+mod config;
+mod launch;
+mod load;
+
+#[cfg(feature = "export_tests")]
+pub mod tests;
+
+pub use config::AttentionConfig;
+pub use launch::check_attention_availability as is_available;
+pub use launch::flash_attention as launch;
+pub use launch::flash_attention_ref as launch_ref;
@@ -0,0 +1,234 @@
+use cubecl_core::{CubeElement, Runtime};
+
+use crate::{
+    attention::{launch, AttentionConfig},
+    matmul::tests::test_utils::{assert_equals_approx, cmma_available, create_empty},
+    tensor::TensorHandle,
+};
+
+pub fn test_attention_head_dim_64<R: Runtime>(device: &R::Device) {
+    AttentionTestCase {
+        batch: 1,
+        seq_q: 64,
+        seq_kv: 64,
+        head_dim: 64,
+        causal: false,
+        mask: false,
+        epsilon: 0.01,
+    }
+    .test::<R>(device);
+}
+
+pub fn test_attention_head_dim_128<R: Runtime>(device: &R::Device) {
+    AttentionTestCase {
+        batch: 1,
+        seq_q: 64,
+        seq_kv: 64,
+        head_dim: 128,
+        causal: false,
+        mask: false,
+        epsilon: 0.01,
+    }
+    .test::<R>(device);
+}
+
+pub fn test_attention_causal<R: Runtime>(device: &R::Device) {
+    AttentionTestCase {
+        batch: 1,
+        seq_q: 96,
+        seq_kv: 96,
+        head_dim: 64,
+        causal: true,
+        mask: false,
+        epsilon: 0.01,
+    }
+    .test::<R>(device);
+}
+
+pub fn test_attention_with_check_bounds<R: Runtime>(device: &R::Device) {
+    AttentionTestCase {
+        batch: 1,
+        seq_q: 50,
+        seq_kv: 70,
+        head_dim: 64,
+        causal: false,
+        mask: false,
+        epsilon: 0.01,
+    }
+    .test::<R>(device);
+}
+
+pub fn test_attention_causal_with_check_bounds<R: Runtime>(device: &R::Device) {
+    AttentionTestCase {
+        batch: 1,
+        seq_q: 45,
+        seq_kv: 45,
+        head_dim: 128,
+        causal: true,
+        mask: false,
+        epsilon: 0.01,
+    }
+    .test::<R>(device);
+}
+
+pub fn test_attention_with_batches<R: Runtime>(device: &R::Device) {
+    AttentionTestCase {
+        batch: 3,
+        seq_q: 32,
+        seq_kv: 64,
+        head_dim: 64,
+        causal: false,
+        mask: false,
+        epsilon: 0.01,
+    }
+    .test::<R>(device);
+}
+
+pub fn test_attention_with_mask<R: Runtime>(device: &R::Device) {
+    AttentionTestCase {
+        batch: 2,
+        seq_q: 50,
+        seq_kv: 70,
+        head_dim: 64,
+        causal: false,
+        mask: true,
+        epsilon: 0.01,
+    }
+    .test::<R>(device);
+}
+
+pub fn test_attention_causal_with_mask<R: Runtime>(device: &R::Device) {
+    AttentionTestCase {
+        batch: 1,
+        seq_q: 64,
+        seq_kv: 64,
+        head_dim: 64,
+        causal: true,
+        mask: true,
+        epsilon: 0.01,
+    }
+    .test::<R>(device);
+}
+
+struct AttentionTestCase {
+    batch: usize,
+    seq_q: usize,
+    seq_kv: usize,
+    head_dim: usize,
+    causal: bool,
+    mask: bool,
+    epsilon: f32,
+}
+
+impl AttentionTestCase {
+    fn test<R: Runtime>(&self, device: &R::Device) {
+        if !cmma_available::<R>(device) {
+            // We can't execute the test, skip.
+            return;
+        }
+
+        let client = R::client(device);
+
+        let query = self.data(self.seq_q, 3);
+        let key = self.data(self.seq_kv, 5);
+        let value = self.data(self.seq_kv, 7);
+        let mask = self.mask_data();
+        let expected = self.attention_cpu(&query, &key, &value, mask.as_deref());
+
+        let create = |data: &[f32], seq: usize| {
+            TensorHandle::<R, f32>::new_contiguous(
+                vec![self.batch, seq, self.head_dim],
+                client.create(f32::as_bytes(data)),
+            )
+        };
+        let query = create(&query, self.seq_q);
+        let key = create(&key, self.seq_kv);
+        let value = create(&value, self.seq_kv);
+        let mask = mask.map(|mask| {
+            TensorHandle::<R, f32>::new_contiguous(
+                vec![self.batch, self.seq_q, self.seq_kv],
+                client.create(f32::as_bytes(&mask)),
+            )
+        });
+        let out = TensorHandle::new_contiguous(
+            vec![self.batch, self.seq_q, self.head_dim],
+            create_empty::<R>(&client, self.batch * self.seq_q, self.head_dim),
+        );
+
+        let config = AttentionConfig {
+            causal: self.causal,
+            ..Default::default()
+        };
+        let out = launch::<R, f32>(&client, query, key, value, mask, out, config);
+
+        assert_equals_approx::<R>(&client, out.handle, &expected, self.epsilon);
+    }
+
+    fn data(&self, seq: usize, seed: usize) -> Vec<f32> {
+        (0..self.batch * seq * self.head_dim)
+            .map(|i| ((i * seed) % 11) as f32 / 10. - 0.5)
+            .collect()
+    }
+
+    fn mask_data(&self) -> Option<Vec<f32>> {
+        self.mask.then(|| {
+            (0..self.batch * self.seq_q * self.seq_kv)
+                .map(|i| match i % 5 {
+                    0 => -1e4,
+                    i => i as f32 * -0.25,
+                })
+                .collect()
+        })
+    }
+
+    fn attention_cpu(
+        &self,
+        query: &[f32],
+        key: &[f32],
+        value: &[f32],
+        mask: Option<&[f32]>,
+    ) -> Vec<f32> {
+        let d = self.head_dim;
+        let scale = 1.0 / f32::sqrt(d as f32);
+        let mut out = vec![0.; self.batch * self.seq_q * d];
+
+        for b in 0..self.batch {
+            let query = &query[b * self.seq_q * d..];
+            let key = &key[b * self.seq_kv * d..];
+            let value = &value[b * self.seq_kv * d..];
+
+            for i in 0..self.seq_q {
+                let num_keys = match self.causal {
+                    true => usize::min(i + 1, self.seq_kv),
+                    false => self.seq_kv,
+                };
+
+                let scores = (0..num_keys)
+                    .map(|j| {
+                        (0..d)
+                            .map(|k_| query[i * d + k_] * key[j * d + k_])
+                            .sum::<f32>()
+                            * scale
+                            + mask.map_or(0., |mask| mask[(b * self.seq_q + i) * self.seq_kv + j])
+                    })
+                    .collect::<Vec<f32>>();
+                let max = scores.iter().cloned().fold(f32::MIN, f32::max);
+                let probs = scores
+                    .iter()
+                    .map(|s| f32::exp(s - max))
+                    .collect::<Vec<f32>>();
+                let sum = probs.iter().sum::<f32>();
+
+                for k_ in 0..d {
+                    out[(b * self.seq_q + i) * d + k_] = probs
+                        .iter()
+                        .enumerate()
+                        .map(|(j, p)| p * value[j * d + k_])
+                        .sum::<f32>()
+                        / sum;
+                }
+            }
+        }
+        out
+    }
+}
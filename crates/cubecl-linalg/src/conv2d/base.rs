@@ -0,0 +1,130 @@
+use cubecl_core as cubecl;
+use cubecl_core::prelude::*;
+
+use crate::matmul::tiling2d::{
+    base::{calculate_coordinates, make_shared_memories, Dimensions},
+    block_loop::init_results,
+    compute_loop::compute_loop,
+    config::CubeTiling2dConfig,
+    load_shared_memory::{
+        load_lhs_plain, load_lhs_transposed, load_rhs_plain, load_rhs_transposed, LoadInfo,
+    },
+    tile::{loader::TileLoader, writer::TileWriter},
+    write_output::write_to_output_strided,
+};
+
+use super::load::Im2colLoader;
+
+#[cube(launch_unchecked)]
+/// 2D convolution as an implicit GEMM over one batch and one group per `CUBE_POS_Z`.
+///
+/// For NCHW, `out[oc, pixel] = weight[oc, k] · im2col[k, pixel]`; for NHWC,
+/// `out[pixel, oc] = im2col[k, pixel]ᵀ · weight[oc, k]ᵀ`. The weight goes through the
+/// regular tiling 2d loader, while the im2col matrix is gathered from the input on the fly
+/// by the [im2col loader](Im2colLoader).
+pub fn conv2d_kernel<F: Float>(
+    input: &Tensor<F>,
+    weight: &Tensor<F>,
+    out: &mut Tensor<F>,
+    #[comptime] config: CubeTiling2dConfig,
+) {
+    let channels_last = config.im2col.unwrap().channels_last;
+    let groups = config.im2col.unwrap().groups;
+    let block_size_k = config.block_size_k;
+
+    let mut dim_h = 2;
+    let mut dim_w = 3;
+    if channels_last {
+        dim_h = 1;
+        dim_w = 2;
+    }
+
+    let out_channels = weight.shape(0);
+    let gemm_k = weight.shape(1) * weight.shape(2) * weight.shape(3);
+    let out_channels_per_group = out_channels / groups;
+    let batch = CUBE_POS_Z / groups;
+    let group = CUBE_POS_Z % groups;
+    let num_pixels = out.shape(dim_h) * out.shape(dim_w);
+
+    let mut dim_m = out_channels_per_group;
+    let mut dim_n = num_pixels;
+    let mut offset_out = (batch * out_channels + group * out_channels_per_group) * num_pixels;
+    let mut out_stride = num_pixels;
+    if channels_last {
+        dim_m = num_pixels;
+        dim_n = out_channels_per_group;
+        offset_out = batch * num_pixels * out_channels + group * out_channels_per_group;
+        out_stride = out_channels;
+    }
+    let dims = Dimensions {
+        m: dim_m,
+        k: gemm_k,
+        n: dim_n,
+    };
+    let offset_weight = group * out_channels_per_group * gemm_k;
+
+    let coordinates = calculate_coordinates(CUBE_POS_X, CUBE_POS_Y, UNIT_POS, config);
+    let shared = make_shared_memories::<F>(config);
+    let mut results = init_results::<F>(config);
+
+    let n_loops = ((gemm_k - 1) / block_size_k) + 1;
+
+    for block in 0..n_loops {
+        let k = block * block_size_k;
+
+        // The im2col loader finds the batch and group of the cube by itself.
+        if channels_last {
+            let im2col_info = LoadInfo::<F> {
+                coordinates,
+                k,
+                batch_offset: 0,
+                shared_memory: shared.lhs,
+                dims,
+            };
+            load_lhs_plain::<F, Im2colLoader<F>>(input, im2col_info, config);
+
+            let weight_info = LoadInfo::<F> {
+                coordinates,
+                k,
+                batch_offset: offset_weight,
+                shared_memory: shared.rhs,
+                dims,
+            };
+            load_rhs_transposed::<F, TileLoader<F>>(weight, weight_info, config);
+        } else {
+            let weight_info = LoadInfo::<F> {
+                coordinates,
+                k,
+                batch_offset: offset_weight,
+                shared_memory: shared.lhs,
+                dims,
+            };
+            load_lhs_transposed::<F, TileLoader<F>>(weight, weight_info, config);
+
+            let im2col_info = LoadInfo::<F> {
+                coordinates,
+                k,
+                batch_offset: 0,
+                shared_memory: shared.rhs,
+                dims,
+            };
+            load_rhs_plain::<F, Im2colLoader<F>>(input, im2col_info, config);
+        }
+
+        sync_units();
+
+        compute_loop::<F>(coordinates, shared.lhs, shared.rhs, &mut results, config);
+
+        sync_units();
+    }
+
+    write_to_output_strided::<F, TileWriter<F>>(
+        out,
+        &results,
+        coordinates,
+        offset_out,
+        out_stride,
+        dims,
+        config,
+    );
+}
@@ -0,0 +1,143 @@
+use cubecl_core::{self as cubecl, prelude::*};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Memory layout of the input and output of a 2D convolution.
+pub enum Conv2dLayout {
+    /// `[batch, channels, height, width]`
+    Nchw,
+    /// `[batch, height, width, channels]`
+    Nhwc,
+}
+
+#[derive(Debug, Clone)]
+/// Parameters of a 2D convolution.
+///
+/// The weight is always `[out_channels, in_channels / groups, kernel_height, kernel_width]`.
+pub struct Conv2dOptions {
+    /// Stride along height and width
+    pub stride: [usize; 2],
+    /// Zero padding added on both sides of height and width
+    pub padding: [usize; 2],
+    /// Spacing between kernel elements along height and width
+    pub dilation: [usize; 2],
+    /// Number of groups the input and output channels are split into
+    pub groups: usize,
+    /// Layout of both input and output
+    pub layout: Conv2dLayout,
+}
+
+impl Default for Conv2dOptions {
+    fn default() -> Self {
+        Self {
+            stride: [1, 1],
+            padding: [0, 0],
+            dilation: [1, 1],
+            groups: 1,
+            layout: Conv2dLayout::Nchw,
+        }
+    }
+}
+
+impl Conv2dOptions {
+    /// Size of the output along height and width.
+    ///
+    /// # Panics
+    ///
+    /// If the input, padded on both sides, is smaller than the dilated kernel.
+    pub fn output_size(&self, input_size: [usize; 2], kernel_size: [usize; 2]) -> [usize; 2] {
+        let dim = |i: usize| {
+            let padded_input = input_size[i] + 2 * self.padding[i];
+            let dilated_kernel = self.dilation[i] * (kernel_size[i] - 1) + 1;
+            let remaining = padded_input.checked_sub(dilated_kernel).unwrap_or_else(|| {
+                panic!(
+                    "The padded input ({padded_input}) is smaller than the dilated kernel \
+                     ({dilated_kernel}) along dimension {i}."
+                )
+            });
+
+            remaining / self.stride[i] + 1
+        };
+
+        [dim(0), dim(1)]
+    }
+}
+
+#[derive(Clone, Copy, Hash, PartialEq, Eq, Debug, CubeType)]
+/// Parameters of the implicit im2col matrix read by the convolution
+pub struct Im2colConfig {
+    /// Kernel height
+    pub kernel_h: u32,
+    /// Kernel width
+    pub kernel_w: u32,
+    /// Stride along height
+    pub stride_h: u32,
+    /// Stride along width
+    pub stride_w: u32,
+    /// Padding along height
+    pub padding_h: u32,
+    /// Padding along width
+    pub padding_w: u32,
+    /// Dilation along height
+    pub dilation_h: u32,
+    /// Dilation along width
+    pub dilation_w: u32,
+    /// Number of groups the channels are split into
+    pub groups: u32,
+    /// Input and output are `[batch, height, width, channels]`
+    pub channels_last: bool,
+}
+
+impl Init for Im2colConfig {
+    fn init(self, _context: &mut CubeContext) -> Self {
+        self
+    }
+}
+
+impl Im2colConfig {
+    pub(crate) fn new(kernel_size: [usize; 2], options: &Conv2dOptions) -> Self {
+        Self {
+            kernel_h: kernel_size[0] as u32,
+            kernel_w: kernel_size[1] as u32,
+            stride_h: options.stride[0] as u32,
+            stride_w: options.stride[1] as u32,
+            padding_h: options.padding[0] as u32,
+            padding_w: options.padding[1] as u32,
+            dilation_h: options.dilation[0] as u32,
+            dilation_w: options.dilation[1] as u32,
+            groups: options.groups as u32,
+            channels_last: options.layout == Conv2dLayout::Nhwc,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn output_size_with_stride_padding_dilation() {
+        let options = Conv2dOptions {
+            stride: [2, 1],
+            padding: [1, 2],
+            dilation: [1, 2],
+            ..Default::default()
+        };
+        assert_eq!(options.output_size([17, 12], [3, 5]), [9, 8]);
+    }
+
+    #[test]
+    fn output_size_of_kernel_filling_input() {
+        let options = Conv2dOptions::default();
+        assert_eq!(options.output_size([3, 5], [3, 5]), [1, 1]);
+    }
+
+    #[test]
+    #[should_panic = "smaller than the dilated kernel"]
+    fn output_size_of_input_smaller_than_kernel() {
+        let options = Conv2dOptions {
+            dilation: [2, 2],
+            ..Default::default()
+        };
+        options.output_size([4, 8], [3, 3]);
+    }
+}
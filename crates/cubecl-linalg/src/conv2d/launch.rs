@@ -0,0 +1,154 @@
+use std::cmp::max;
+
+use cubecl_core::{prelude::*, Compiler};
+
+use crate::{
+    conv2d::{
+        base::conv2d_kernel,
+        config::{Conv2dLayout, Conv2dOptions, Im2colConfig},
+    },
+    matmul::tiling2d::config::{tiling2d_cube_dim, CubeTiling2dConfig, Tiling2dConfig},
+    tensor::{into_contiguous, matrix_layout, MatrixLayout, TensorHandle},
+};
+
+/// 2D convolution using an implicit GEMM on top of the tiling 2d algorithm.
+///
+/// The weight must be `[out_channels, in_channels / groups, kernel_height, kernel_width]`,
+/// while the input and output follow `options.layout`.
+pub fn conv2d<R: Runtime, F: Float>(
+    client: &ComputeClient<R::Server, R::Channel>,
+    input: TensorHandle<R, F>,
+    weight: TensorHandle<R, F>,
+    out: TensorHandle<R, F>,
+    options: Conv2dOptions,
+    config: Tiling2dConfig,
+) -> TensorHandle<R, F> {
+    conv2d_ref::<R, F>(
+        client,
+        input.as_ref(),
+        weight.as_ref(),
+        out.as_ref(),
+        options,
+        config,
+    );
+
+    out
+}
+
+/// 2D convolution using an implicit GEMM on top of the tiling 2d algorithm.
+///
+/// The weight must be `[out_channels, in_channels / groups, kernel_height, kernel_width]`,
+/// while the input and output follow `options.layout`.
+pub fn conv2d_ref<R: Runtime, F: Float>(
+    client: &ComputeClient<R::Server, R::Channel>,
+    input: TensorHandleRef<'_, R>,
+    weight: TensorHandleRef<'_, R>,
+    out: TensorHandleRef<'_, R>,
+    options: Conv2dOptions,
+    config: Tiling2dConfig,
+) {
+    // The input is read through its strides, but the weight is read as a plain matrix.
+    match matrix_layout(weight.strides) {
+        MatrixLayout::Contiguous => {
+            conv2d_ref_no_check::<R, F>(client, input, weight, out, options, config)
+        }
+        _ => conv2d_ref_no_check::<R, F>(
+            client,
+            input,
+            into_contiguous::<R, F>(client, weight).as_ref(),
+            out,
+            options,
+            config,
+        ),
+    }
+}
+
+fn conv2d_ref_no_check<R: Runtime, F: Float>(
+    client: &ComputeClient<R::Server, R::Channel>,
+    input: TensorHandleRef<'_, R>,
+    weight: TensorHandleRef<'_, R>,
+    out: TensorHandleRef<'_, R>,
+    options: Conv2dOptions,
+    config: Tiling2dConfig,
+) {
+    assert_eq!(input.shape.len(), 4, "Input must be of rank 4.");
+    assert_eq!(weight.shape.len(), 4, "Weight must be of rank 4.");
+    assert_eq!(out.shape.len(), 4, "Output must be of rank 4.");
+    assert_eq!(
+        matrix_layout(out.strides),
+        MatrixLayout::Contiguous,
+        "Output must be contiguous."
+    );
+    assert!(
+        F::as_elem().size() * config.block_size_k * max(config.block_size_m, config.block_size_n)
+            <= <R::Compiler as Compiler>::max_shared_memory_size(),
+        "Shared memory limit will be busted. "
+    );
+
+    let (dim_c, dim_h, dim_w) = match options.layout {
+        Conv2dLayout::Nchw => (1, 2, 3),
+        Conv2dLayout::Nhwc => (3, 1, 2),
+    };
+
+    let batch_size = input.shape[0];
+    let in_channels = input.shape[dim_c];
+    let out_channels = weight.shape[0];
+    let channels_per_group = weight.shape[1];
+    let kernel_size = [weight.shape[2], weight.shape[3]];
+    let groups = options.groups;
+
+    assert_eq!(
+        in_channels,
+        channels_per_group * groups,
+        "Input channels must equal weight channels times groups."
+    );
+    assert_eq!(
+        out_channels % groups,
+        0,
+        "Output channels must be divisible by groups."
+    );
+
+    let [out_height, out_width] =
+        options.output_size([input.shape[dim_h], input.shape[dim_w]], kernel_size);
+    assert_eq!(out.shape[0], batch_size, "Output batch size mismatch.");
+    assert_eq!(out.shape[dim_c], out_channels, "Output channels mismatch.");
+    assert_eq!(out.shape[dim_h], out_height, "Output height mismatch.");
+    assert_eq!(out.shape[dim_w], out_width, "Output width mismatch.");
+
+    let num_pixels = out_height * out_width;
+    let gemm_k = channels_per_group * kernel_size[0] * kernel_size[1];
+    let out_channels_per_group = out_channels / groups;
+
+    let (m, n) = match options.layout {
+        Conv2dLayout::Nchw => (out_channels_per_group, num_pixels),
+        Conv2dLayout::Nhwc => (num_pixels, out_channels_per_group),
+    };
+    let rhs_transposed = options.layout == Conv2dLayout::Nhwc;
+
+    let out_vectorization = [4, 2]
+        .into_iter()
+        .filter(|v| n % v == 0)
+        .map(|v| v as u8)
+        .next()
+        .unwrap_or(1);
+
+    let cubes_x = f32::ceil(m as f32 / config.block_size_m as f32) as u32;
+    let cubes_y = f32::ceil(n as f32 / config.block_size_n as f32) as u32;
+    let cube_count = CubeCount::Static(cubes_x, cubes_y, (batch_size * groups) as u32);
+    let cube_dim = tiling2d_cube_dim(&config);
+
+    let mut cube_config = CubeTiling2dConfig::new(&config, m, gemm_k, n, false, rhs_transposed);
+    cube_config.im2col = Some(Im2colConfig::new(kernel_size, &options));
+
+    unsafe {
+        conv2d_kernel::launch_unchecked::<F, R>(
+            client,
+            cube_count,
+            cube_dim,
+            TensorArg::from_raw_parts(input.handle, input.strides, input.shape, 1),
+            TensorArg::from_raw_parts(weight.handle, weight.strides, weight.shape, 1),
+            TensorArg::from_raw_parts(out.handle, out.strides, out.shape, out_vectorization),
+            cube_config,
+        );
+    }
+}
@@ -0,0 +1,246 @@
+use cubecl_core as cubecl;
+use cubecl_core::prelude::*;
+use std::marker::PhantomData;
+
+use crate::matmul::tiling2d::{
+    config::CubeTiling2dConfig,
+    load_shared_memory::{LoadInfo, Loader},
+    tile::{
+        block_io::base::BlockLoader,
+        loader::{CheckBounds, ReadTileInfo},
+        memory_access::{ContiguousAccess, WritePositions},
+    },
+};
+
+/// Loads the implicit im2col matrix of the input into shared memory, without ever
+/// materializing it in global memory.
+///
+/// The matrix is `[gemm_k, num_pixels]`: element (`k`, `pixel`) is the input value seen by
+/// kernel position `k` when computing output `pixel`, or zero when it falls in the padding.
+/// It is the rhs of the NCHW convolution and the lhs of the NHWC one, and since it is virtual,
+/// it is loaded the same way whether or not it's flagged as transposed.
+pub(crate) struct Im2colLoader<F: Float> {
+    _f: PhantomData<F>,
+}
+
+/// Gathers the values of the im2col matrix from the input, for the batch and group of
+/// `CUBE_POS_Z`. Positions are relative to the `[gemm_k, num_pixels]` matrix.
+pub(crate) struct Im2colAccess;
+
+#[cube]
+impl<F: Float> Loader<F> for Im2colLoader<F> {
+    fn load_lhs_plain<B: BlockLoader<F>>(
+        input: &Tensor<F>,
+        load_info: LoadInfo<F>,
+        #[comptime] config: CubeTiling2dConfig,
+    ) {
+        let coordinates = load_info.coordinates;
+        let dims = load_info.dims;
+
+        // Pixels are along m, so units are transposed compared to the rhs.
+        let check_bounds = CheckBounds {
+            dim_vertical: dims.k,
+            dim_horizontal: dims.m,
+            skip_row: load_info.k,
+            skip_col: coordinates.skip_row,
+        };
+
+        load_im2col::<F, B>(
+            input,
+            load_info,
+            coordinates.unit_col,
+            coordinates.unit_row,
+            config.block_size_m,
+            check_bounds,
+            config,
+        );
+    }
+
+    fn load_lhs_transposed<B: BlockLoader<F>>(
+        input: &Tensor<F>,
+        load_info: LoadInfo<F>,
+        #[comptime] config: CubeTiling2dConfig,
+    ) {
+        Self::load_lhs_plain::<B>(input, load_info, config);
+    }
+
+    fn load_rhs_plain<B: BlockLoader<F>>(
+        input: &Tensor<F>,
+        load_info: LoadInfo<F>,
+        #[comptime] config: CubeTiling2dConfig,
+    ) {
+        let coordinates = load_info.coordinates;
+        let dims = load_info.dims;
+
+        let check_bounds = CheckBounds {
+            dim_vertical: dims.k,
+            dim_horizontal: dims.n,
+            skip_row: load_info.k,
+            skip_col: coordinates.skip_col,
+        };
+
+        load_im2col::<F, B>(
+            input,
+            load_info,
+            coordinates.unit_row,
+            coordinates.unit_col,
+            config.block_size_n,
+            check_bounds,
+            config,
+        );
+    }
+
+    fn load_rhs_transposed<B: BlockLoader<F>>(
+        input: &Tensor<F>,
+        load_info: LoadInfo<F>,
+        #[comptime] config: CubeTiling2dConfig,
+    ) {
+        Self::load_rhs_plain::<B>(input, load_info, config);
+    }
+}
+
+#[cube]
+/// Load the tile of the unit at (`read_row`, `read_col`) of the block, like a plain matrix of
+/// `check_bounds.dim_horizontal` columns.
+fn load_im2col<F: Float, B: BlockLoader<F>>(
+    input: &Tensor<F>,
+    load_info: LoadInfo<F>,
+    read_row: u32,
+    read_col: u32,
+    #[comptime] sm_stride: u32,
+    check_bounds: CheckBounds,
+    #[comptime] config: CubeTiling2dConfig,
+) {
+    let gm_stride = check_bounds.dim_horizontal;
+    let offset = check_bounds.skip_col + check_bounds.skip_row * gm_stride;
+
+    let read_tile_info = ReadTileInfo {
+        read_row,
+        read_col,
+        gm_position_base: read_row * gm_stride + read_col + offset,
+        sm_position_base: read_row * sm_stride + read_col,
+        gm_stride,
+        sm_stride,
+    };
+    let mut sm = load_info.shared_memory;
+
+    if read_row < config.block_size_k {
+        B::load_tile_plain::<Im2colAccess>(input, &mut sm, read_tile_info, config, check_bounds);
+    }
+}
+
+#[cube]
+impl<F: Float> ContiguousAccess<F> for Im2colAccess {
+    fn read_contiguous_unchecked(
+        input: &Tensor<F>,
+        gm_position: u32,
+        #[comptime] config: CubeTiling2dConfig,
+    ) -> F {
+        read_im2col::<F>(input, gm_position, config.tile_size, config)
+    }
+
+    fn read_contiguous_checked(
+        input: &Tensor<F>,
+        gm_position: u32,
+        check_bounds: CheckBounds,
+        read_info: ReadTileInfo,
+        #[comptime] config: CubeTiling2dConfig,
+    ) -> F {
+        let col = check_bounds.skip_col + read_info.read_col;
+        let num_reads = Min::min(check_bounds.dim_horizontal - col, config.tile_size);
+
+        read_im2col::<F>(input, gm_position, num_reads, config)
+    }
+
+    fn write_contiguous_unchecked(
+        _out: &mut Tensor<F>,
+        _results: &Array<F>,
+        _positions: WritePositions,
+        #[comptime] _config: CubeTiling2dConfig,
+    ) {
+        // The im2col matrix is only read.
+    }
+
+    fn write_contiguous_checked(
+        _out: &mut Tensor<F>,
+        _results: &Array<F>,
+        _positions: WritePositions,
+        _check_bounds: CheckBounds,
+        _write_col: u32,
+        #[comptime] _config: CubeTiling2dConfig,
+    ) {
+        // The im2col matrix is only read.
+    }
+}
+
+#[cube]
+/// Gather `num_reads` consecutive pixels of one row of the im2col matrix, the remaining
+/// values of the tile being zero.
+fn read_im2col<F: Float>(
+    input: &Tensor<F>,
+    position: u32,
+    num_reads: u32,
+    #[comptime] config: CubeTiling2dConfig,
+) -> F {
+    let im2col = config.im2col.unwrap();
+    let tile_size = config.tile_size;
+    let kernel_w = im2col.kernel_w;
+    let kernel_size = im2col.kernel_h * kernel_w;
+    let padding_h = im2col.padding_h;
+    let padding_w = im2col.padding_w;
+
+    let mut dim_c = 1;
+    let mut dim_h = 2;
+    let mut dim_w = 3;
+    if im2col.channels_last {
+        dim_c = 3;
+        dim_h = 1;
+        dim_w = 2;
+    }
+
+    let in_height = input.shape(dim_h);
+    let in_width = input.shape(dim_w);
+    let out_height = (in_height + 2 * padding_h - im2col.dilation_h * (im2col.kernel_h - 1) - 1)
+        / im2col.stride_h
+        + 1;
+    let out_width =
+        (in_width + 2 * padding_w - im2col.dilation_w * (kernel_w - 1) - 1) / im2col.stride_w + 1;
+    let num_pixels = out_height * out_width;
+    let channels_per_group = input.shape(dim_c) / im2col.groups;
+
+    let k = position / num_pixels;
+    let pixel_start = position % num_pixels;
+
+    let batch = CUBE_POS_Z / im2col.groups;
+    let group = CUBE_POS_Z % im2col.groups;
+    let channel = group * channels_per_group + k / kernel_size;
+    let kernel_pos = k % kernel_size;
+    let kernel_row = kernel_pos / kernel_w;
+    let kernel_col = kernel_pos % kernel_w;
+    let channel_position = batch * input.stride(0) + channel * input.stride(dim_c);
+
+    let mut vector = F::vectorized(0., tile_size);
+
+    for j in 0..num_reads {
+        let pixel = pixel_start + j;
+        let out_row = pixel / out_width;
+        let out_col = pixel % out_width;
+
+        // Coordinates in the padded input, to stay unsigned
+        let row = out_row * im2col.stride_h + kernel_row * im2col.dilation_h;
+        let col = out_col * im2col.stride_w + kernel_col * im2col.dilation_w;
+
+        if row >= padding_h
+            && row < in_height + padding_h
+            && col >= padding_w
+            && col < in_width + padding_w
+        {
+            let position = channel_position
+                + (row - padding_h) * input.stride(dim_h)
+                + (col - padding_w) * input.stride(dim_w);
+            vector[j] = input[position];
+        }
+    }
+
+    vector
+}
@@ -0,0 +1,12 @@
+mod base;
+mod config;
+mod launch;
+mod load;
+
+#[cfg(feature = "export_tests")]
+pub mod tests;
+
+pub(crate) use config::Im2colConfig;
+pub use config::{Conv2dLayout, Conv2dOptions};
+pub use launch::conv2d as launch;
+pub use launch::conv2d_ref as launch_ref;
@@ -0,0 +1,253 @@
+use cubecl_core::{CubeElement, Runtime};
+
+use crate::{
+    conv2d::{launch, Conv2dLayout, Conv2dOptions},
+    matmul::tests::test_utils::{assert_equals_approx, create_empty},
+    tensor::TensorHandle,
+};
+
+pub fn test_conv2d_nchw<R: Runtime>(device: &R::Device) {
+    Conv2dTestCase {
+        batch: 2,
+        in_channels: 4,
+        out_channels: 8,
+        size: [9, 9],
+        kernel_size: [3, 3],
+        options: Conv2dOptions::default(),
+        epsilon: 0.01,
+    }
+    .test::<R>(device);
+}
+
+pub fn test_conv2d_nchw_stride_padding<R: Runtime>(device: &R::Device) {
+    Conv2dTestCase {
+        batch: 1,
+        in_channels: 3,
+        out_channels: 5,
+        size: [17, 12],
+        kernel_size: [3, 5],
+        options: Conv2dOptions {
+            stride: [2, 1],
+            padding: [1, 2],
+            ..Default::default()
+        },
+        epsilon: 0.01,
+    }
+    .test::<R>(device);
+}
+
+pub fn test_conv2d_nchw_dilation<R: Runtime>(device: &R::Device) {
+    Conv2dTestCase {
+        batch: 1,
+        in_channels: 4,
+        out_channels: 4,
+        size: [16, 16],
+        kernel_size: [3, 3],
+        options: Conv2dOptions {
+            dilation: [2, 3],
+            padding: [1, 1],
+            ..Default::default()
+        },
+        epsilon: 0.01,
+    }
+    .test::<R>(device);
+}
+
+pub fn test_conv2d_nchw_groups<R: Runtime>(device: &R::Device) {
+    Conv2dTestCase {
+        batch: 2,
+        in_channels: 6,
+        out_channels: 4,
+        size: [10, 10],
+        kernel_size: [3, 3],
+        options: Conv2dOptions {
+            groups: 2,
+            padding: [1, 1],
+            ..Default::default()
+        },
+        epsilon: 0.01,
+    }
+    .test::<R>(device);
+}
+
+pub fn test_conv2d_nhwc<R: Runtime>(device: &R::Device) {
+    Conv2dTestCase {
+        batch: 2,
+        in_channels: 4,
+        out_channels: 8,
+        size: [9, 9],
+        kernel_size: [3, 3],
+        options: Conv2dOptions {
+            layout: Conv2dLayout::Nhwc,
+            ..Default::default()
+        },
+        epsilon: 0.01,
+    }
+    .test::<R>(device);
+}
+
+pub fn test_conv2d_nhwc_all_options<R: Runtime>(device: &R::Device) {
+    Conv2dTestCase {
+        batch: 2,
+        in_channels: 8,
+        out_channels: 12,
+        size: [13, 11],
+        kernel_size: [3, 2],
+        options: Conv2dOptions {
+            stride: [2, 2],
+            padding: [2, 1],
+            dilation: [1, 2],
+            groups: 4,
+            layout: Conv2dLayout::Nhwc,
+        },
+        epsilon: 0.01,
+    }
+    .test::<R>(device);
+}
+
+struct Conv2dTestCase {
+    batch: usize,
+    in_channels: usize,
+    out_channels: usize,
+    size: [usize; 2],
+    kernel_size: [usize; 2],
+    options: Conv2dOptions,
+    epsilon: f32,
+}
+
+impl Conv2dTestCase {
+    fn test<R: Runtime>(self, device: &R::Device) {
+        let client = R::client(device);
+        let channels_per_group = self.in_channels / self.options.groups;
+        let [out_height, out_width] = self.options.output_size(self.size, self.kernel_size);
+
+        let input = (0..self.batch * self.in_channels * self.size[0] * self.size[1])
+            .map(|i| (i % 13) as f32 / 12. - 0.5)
+            .collect::<Vec<f32>>();
+        let weight =
+            (0..self.out_channels * channels_per_group * self.kernel_size[0] * self.kernel_size[1])
+                .map(|i| (i % 7) as f32 / 6. - 0.5)
+                .collect::<Vec<f32>>();
+        let expected = self.conv2d_cpu(&input, &weight, [out_height, out_width]);
+
+        let input_shape = self.shape(self.in_channels, self.size);
+        let out_shape = self.shape(self.out_channels, [out_height, out_width]);
+
+        let input = TensorHandle::<R, f32>::new_contiguous(
+            input_shape,
+            client.create(f32::as_bytes(&input)),
+        );
+        let weight = TensorHandle::<R, f32>::new_contiguous(
+            vec![
+                self.out_channels,
+                channels_per_group,
+                self.kernel_size[0],
+                self.kernel_size[1],
+            ],
+            client.create(f32::as_bytes(&weight)),
+        );
+        let out = TensorHandle::new_contiguous(
+            out_shape,
+            create_empty::<R>(
+                &client,
+                self.batch * self.out_channels,
+                out_height * out_width,
+            ),
+        );
+
+        let out = launch::<R, f32>(
+            &client,
+            input,
+            weight,
+            out,
+            self.options.clone(),
+            Default::default(),
+        );
+
+        assert_equals_approx::<R>(&client, out.handle, &expected, self.epsilon);
+    }
+
+    fn shape(&self, channels: usize, size: [usize; 2]) -> Vec<usize> {
+        match self.options.layout {
+            Conv2dLayout::Nchw => vec![self.batch, channels, size[0], size[1]],
+            Conv2dLayout::Nhwc => vec![self.batch, size[0], size[1], channels],
+        }
+    }
+
+    fn index(
+        &self,
+        channels: usize,
+        size: [usize; 2],
+        b: usize,
+        c: usize,
+        h: usize,
+        w: usize,
+    ) -> usize {
+        match self.options.layout {
+            Conv2dLayout::Nchw => ((b * channels + c) * size[0] + h) * size[1] + w,
+            Conv2dLayout::Nhwc => ((b * size[0] + h) * size[1] + w) * channels + c,
+        }
+    }
+
+    fn conv2d_cpu(&self, input: &[f32], weight: &[f32], out_size: [usize; 2]) -> Vec<f32> {
+        let options = &self.options;
+        let channels_per_group = self.in_channels / options.groups;
+        let out_channels_per_group = self.out_channels / options.groups;
+        let [kernel_h, kernel_w] = self.kernel_size;
+
+        let mut out = vec![0.; self.batch * self.out_channels * out_size[0] * out_size[1]];
+
+        for b in 0..self.batch {
+            for oc in 0..self.out_channels {
+                let group = oc / out_channels_per_group;
+
+                for oh in 0..out_size[0] {
+                    for ow in 0..out_size[1] {
+                        let mut sum = 0.;
+
+                        for ic in 0..channels_per_group {
+                            for kh in 0..kernel_h {
+                                for kw in 0..kernel_w {
+                                    let h = (oh * options.stride[0] + kh * options.dilation[0])
+                                        as isize
+                                        - options.padding[0] as isize;
+                                    let w = (ow * options.stride[1] + kw * options.dilation[1])
+                                        as isize
+                                        - options.padding[1] as isize;
+
+                                    if h < 0
+                                        || w < 0
+                                        || h as usize >= self.size[0]
+                                        || w as usize >= self.size[1]
+                                    {
+                                        continue;
+                                    }
+
+                                    let channel = group * channels_per_group + ic;
+                                    let input_value = input[self.index(
+                                        self.in_channels,
+                                        self.size,
+                                        b,
+                                        channel,
+                                        h as usize,
+                                        w as usize,
+                                    )];
+                                    let weight_value =
+                                        weight[((oc * channels_per_group + ic) * kernel_h + kh)
+                                            * kernel_w
+                                            + kw];
+
+                                    sum += input_value * weight_value;
+                                }
+                            }
+                        }
+
+                        out[self.index(self.out_channels, out_size, b, oc, oh, ow)] = sum;
+                    }
+                }
+            }
+        }
+
+        out
+    }
+}
@@ -1,5 +1,11 @@
+/// Fused attention components.
+pub mod attention;
+/// 2D convolution components.
+pub mod conv2d;
 /// Matrix multiplication components.
 pub mod matmul;
+/// Prefix sum components.
+pub mod scan;
 /// Contains basic tensor helpers.
 pub mod tensor;
 mod tests;
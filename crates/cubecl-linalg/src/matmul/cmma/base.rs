@@ -4,22 +4,36 @@ use cubecl_core::{self as cubecl, prelude::*};
 use super::block_loop::block_loop;
 use super::config::ComptimeCmmaInfo;
 
+/// Element types that can be read from the inputs of the cmma matmul, to be cast to the compute
+/// float in shared memory.
+pub trait CmmaInput:
+    CubePrimitive + CubeIndex<u32, Output = Self> + CubeIndex<ExpandElementTyped<u32>, Output = Self>
+{
+}
+
+impl<E> CmmaInput for E where
+    E: CubePrimitive
+        + CubeIndex<u32, Output = Self>
+        + CubeIndex<ExpandElementTyped<u32>, Output = Self>
+{
+}
+
 #[cube(launch_unchecked)]
 #[allow(unused_mut)]
-pub fn cmma_kernel<F: Float, FC: Float>(
-    lhs: &Tensor<F>,
-    rhs: &Tensor<F>,
+pub fn cmma_kernel<E: CmmaInput, F: Float, FC: Float>(
+    lhs: &Tensor<E>,
+    rhs: &Tensor<E>,
     out: &mut Tensor<F>,
     #[comptime] comptime_info: ComptimeCmmaInfo,
 ) {
     let ids = get_ids();
-    let dims = get_dims::<F>(lhs, rhs);
-    let offsets = calculate_offsets::<F>(lhs, rhs, out, comptime_info);
+    let dims = get_dims::<E>(lhs, rhs);
+    let offsets = calculate_offsets::<E, F>(lhs, rhs, out, comptime_info);
     let runtime_info = RuntimeCmmaInfo { ids, dims, offsets };
 
     let shared_memories = make_shared_memories::<FC>(comptime_info);
     let cmma_matrices = make_cmma_matrices::<F, FC>(comptime_info);
-    block_loop::<F, FC>(
+    block_loop::<E, F, FC>(
         lhs,
         rhs,
         out,
@@ -76,7 +90,7 @@ pub(crate) struct CmmaMatrices<F: Float, FC: Float> {
 }
 
 #[cube]
-fn get_dims<F: Float>(lhs: &Tensor<F>, rhs: &Tensor<F>) -> Dimensions {
+fn get_dims<E: CmmaInput>(lhs: &Tensor<E>, rhs: &Tensor<E>) -> Dimensions {
     let rank = lhs.rank();
     let first_dim = rank - 2;
     let second_dim = rank - 1;
@@ -88,9 +102,9 @@ fn get_dims<F: Float>(lhs: &Tensor<F>, rhs: &Tensor<F>) -> Dimensions {
 }
 
 #[cube]
-fn calculate_offsets<F: Float>(
-    lhs: &Tensor<F>,
-    rhs: &Tensor<F>,
+fn calculate_offsets<E: CmmaInput, F: Float>(
+    lhs: &Tensor<E>,
+    rhs: &Tensor<E>,
     out: &Tensor<F>,
     #[comptime] config: ComptimeCmmaInfo,
 ) -> Offsets {
@@ -1,11 +1,11 @@
-use crate::matmul::cmma::base::Dimensions;
+use crate::matmul::cmma::base::{CmmaInput, Dimensions};
 use cubecl_core as cubecl;
 use cubecl_core::prelude::*;
 
 #[cube]
-pub(crate) trait BlockLoader<F: Float, FC: Float> {
+pub(crate) trait BlockLoader<E: CmmaInput, FC: Float> {
     fn load_tile(
-        tensor: &Tensor<F>,
+        tensor: &Tensor<E>,
         shared_memory: &mut SharedMemory<FC>,
         batch_offset: u32,
         read_row: u32,
@@ -1,16 +1,16 @@
 use cubecl_core as cubecl;
 use cubecl_core::prelude::*;
 
-use crate::matmul::cmma::base::Dimensions;
+use crate::matmul::cmma::base::{CmmaInput, Dimensions};
 
 use super::base::{BlockLoader, BlockWriter};
 
 pub(crate) struct HorizontalCheckBlockIO;
 
 #[cube]
-impl<F: Float, FC: Float> BlockLoader<F, FC> for HorizontalCheckBlockIO {
+impl<E: CmmaInput, FC: Float> BlockLoader<E, FC> for HorizontalCheckBlockIO {
     fn load_tile(
-        tensor: &Tensor<F>,
+        tensor: &Tensor<E>,
         shared_memory: &mut SharedMemory<FC>,
         batch_offset: u32,
         read_row: u32,
@@ -1,4 +1,4 @@
-use crate::matmul::cmma::base::Dimensions;
+use crate::matmul::cmma::base::{CmmaInput, Dimensions};
 use cubecl_core as cubecl;
 use cubecl_core::prelude::*;
 
@@ -7,9 +7,9 @@ use super::base::{BlockLoader, BlockWriter};
 pub(crate) struct WholeCheckBlockIO;
 
 #[cube]
-impl<F: Float, FC: Float> BlockLoader<F, FC> for WholeCheckBlockIO {
+impl<E: CmmaInput, FC: Float> BlockLoader<E, FC> for WholeCheckBlockIO {
     fn load_tile(
-        tensor: &Tensor<F>,
+        tensor: &Tensor<E>,
         shared_memory: &mut SharedMemory<FC>,
         batch_offset: u32,
         read_row: u32,
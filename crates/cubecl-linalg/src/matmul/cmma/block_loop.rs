@@ -2,7 +2,7 @@ use cubecl_core as cubecl;
 use cubecl_core::prelude::*;
 
 use super::{
-    base::{CmmaMatrices, RuntimeCmmaInfo, SharedMemories},
+    base::{CmmaInput, CmmaMatrices, RuntimeCmmaInfo, SharedMemories},
     compute_loop::compute_loop,
     config::ComptimeCmmaInfo,
     load_shared_memory::load_to_shared_memories,
@@ -10,9 +10,9 @@ use super::{
 };
 
 #[cube]
-pub(crate) fn block_loop<F: Float, FC: Float>(
-    lhs: &Tensor<F>,
-    rhs: &Tensor<F>,
+pub(crate) fn block_loop<E: CmmaInput, F: Float, FC: Float>(
+    lhs: &Tensor<E>,
+    rhs: &Tensor<E>,
     out: &mut Tensor<F>,
     shared_memories: SharedMemories<FC>,
     mut cmma_matrices: CmmaMatrices<F, FC>,
@@ -29,7 +29,7 @@ pub(crate) fn block_loop<F: Float, FC: Float>(
     for block in 0..num_loops {
         let k_offset = block * block_size_k;
 
-        load_to_shared_memories::<F, FC>(
+        load_to_shared_memories::<E, FC>(
             lhs,
             rhs,
             k_offset,
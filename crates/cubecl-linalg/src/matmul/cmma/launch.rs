@@ -7,7 +7,10 @@ use cubecl_core::{
 use half::f16;
 
 use crate::{
-    matmul::cmma::{base::cmma_kernel, config::CmmaConfig},
+    matmul::cmma::{
+        base::{cmma_kernel, CmmaInput},
+        config::CmmaConfig,
+    },
     tensor::{into_contiguous, matrix_layout, MatrixLayout, TensorHandle},
 };
 
@@ -61,6 +64,40 @@ pub fn matmul_cmma_ref<R: Runtime, F: Float>(
     rhs: TensorHandleRef<'_, R>,
     out: TensorHandleRef<'_, R>,
     block_config: CmmaConfig,
+) {
+    matmul_cmma_mixed_ref::<R, F, F>(client, lhs, rhs, out, block_config);
+}
+
+/// Matrix multiplication using [cooperative matrix-multiply and accumulate operations](cubecl_core::cmma),
+/// with inputs of a different element type than the output.
+///
+/// Inputs are upcast to f16 when loaded to shared memory, so they can be stored in a smaller
+/// format such as [FP8](cubecl_core::frontend::F8E4M3).
+pub fn matmul_cmma_mixed<R: Runtime, E: CmmaInput, F: Float>(
+    client: &ComputeClient<R::Server, R::Channel>,
+    lhs: TensorHandle<R, E>,
+    rhs: TensorHandle<R, E>,
+    out: TensorHandle<R, F>,
+    block_config: CmmaConfig,
+) -> TensorHandle<R, F> {
+    matmul_cmma_mixed_ref::<R, E, F>(
+        client,
+        lhs.as_ref(),
+        rhs.as_ref(),
+        out.as_ref(),
+        block_config,
+    );
+    out
+}
+
+/// Matrix multiplication using [cooperative matrix-multiply and accumulate operations](cubecl_core::cmma),
+/// with inputs of a different element type than the output.
+pub fn matmul_cmma_mixed_ref<R: Runtime, E: CmmaInput, F: Float>(
+    client: &ComputeClient<R::Server, R::Channel>,
+    lhs: TensorHandleRef<'_, R>,
+    rhs: TensorHandleRef<'_, R>,
+    out: TensorHandleRef<'_, R>,
+    block_config: CmmaConfig,
 ) {
     let check_layout = |tensor: &TensorHandleRef<'_, R>| match matrix_layout(tensor.strides) {
         MatrixLayout::Contiguous => true,
@@ -75,32 +112,32 @@ pub fn matmul_cmma_ref<R: Runtime, F: Float>(
     let rhs_correct_layout = check_layout(&rhs);
 
     match (lhs_correct_layout, rhs_correct_layout) {
-        (true, true) => matmul_cmma_ref_no_check::<R, F>(client, lhs, rhs, out, block_config),
-        (true, false) => matmul_cmma_ref_no_check::<R, F>(
+        (true, true) => matmul_cmma_ref_no_check::<R, E, F>(client, lhs, rhs, out, block_config),
+        (true, false) => matmul_cmma_ref_no_check::<R, E, F>(
             client,
             lhs,
-            into_contiguous::<R, F>(client, rhs).as_ref(),
+            into_contiguous::<R, E>(client, rhs).as_ref(),
             out,
             block_config,
         ),
-        (false, true) => matmul_cmma_ref_no_check::<R, F>(
+        (false, true) => matmul_cmma_ref_no_check::<R, E, F>(
             client,
-            into_contiguous::<R, F>(client, lhs).as_ref(),
+            into_contiguous::<R, E>(client, lhs).as_ref(),
             rhs,
             out,
             block_config,
         ),
-        (false, false) => matmul_cmma_ref_no_check::<R, F>(
+        (false, false) => matmul_cmma_ref_no_check::<R, E, F>(
             client,
-            into_contiguous::<R, F>(client, lhs).as_ref(),
-            into_contiguous::<R, F>(client, rhs).as_ref(),
+            into_contiguous::<R, E>(client, lhs).as_ref(),
+            into_contiguous::<R, E>(client, rhs).as_ref(),
             out,
             block_config,
         ),
     }
 }
 
-fn matmul_cmma_ref_no_check<R: Runtime, F: Float>(
+fn matmul_cmma_ref_no_check<R: Runtime, E: CmmaInput, F: Float>(
     client: &ComputeClient<R::Server, R::Channel>,
     lhs: TensorHandleRef<'_, R>,
     rhs: TensorHandleRef<'_, R>,
@@ -122,7 +159,7 @@ fn matmul_cmma_ref_no_check<R: Runtime, F: Float>(
         tensor_vectorization_factor(&available_vectorizations, out.shape, out.strides, rank - 1);
 
     unsafe {
-        cmma_kernel::launch_unchecked::<F, f16, R>(
+        cmma_kernel::launch_unchecked::<E, F, f16, R>(
             client,
             cmma_config.cube_count::<R>(out.shape),
             cmma_config.cube_dim(),
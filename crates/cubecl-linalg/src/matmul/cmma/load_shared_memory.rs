@@ -2,7 +2,7 @@ use cubecl_core as cubecl;
 use cubecl_core::prelude::*;
 
 use super::{
-    base::{RuntimeCmmaInfo, SharedMemories},
+    base::{CmmaInput, Ids, RuntimeCmmaInfo, SharedMemories},
     config::ComptimeCmmaInfo,
 };
 
@@ -13,9 +13,9 @@ use crate::matmul::cmma::block_io::{
 };
 
 #[cube]
-pub(crate) fn load_to_shared_memories<F: Float, FC: Float>(
-    lhs: &Tensor<F>,
-    rhs: &Tensor<F>,
+pub(crate) fn load_to_shared_memories<E: CmmaInput, FC: Float>(
+    lhs: &Tensor<E>,
+    rhs: &Tensor<E>,
     k_offset: u32,
     mut shared: SharedMemories<FC>,
     runtime_info: RuntimeCmmaInfo,
@@ -44,8 +44,8 @@ pub(crate) fn load_to_shared_memories<F: Float, FC: Float>(
 }
 
 #[cube]
-pub(crate) fn load_lhs<F: Float, FC: Float>(
-    lhs: &Tensor<F>,
+pub(crate) fn load_lhs<E: CmmaInput, FC: Float>(
+    lhs: &Tensor<E>,
     shared_lhs: &mut SharedMemory<FC>,
     num_tiles_in_k: u32,
     k_offset: u32,
@@ -63,7 +63,7 @@ pub(crate) fn load_lhs<F: Float, FC: Float>(
 
     if check_m_bounds {
         if check_k_bounds {
-            load_tile::<F, FC, WholeCheckBlockIO>(
+            load_tile::<E, FC, WholeCheckBlockIO>(
                 lhs,
                 shared_lhs,
                 offsets.batch_lhs,
@@ -73,11 +73,11 @@ pub(crate) fn load_lhs<F: Float, FC: Float>(
                 dims.k,
                 offsets.cube_row,
                 k_offset,
-                runtime_info,
+                ids,
                 comptime_info,
             );
         } else {
-            load_tile::<F, FC, VerticalCheckBlockIO>(
+            load_tile::<E, FC, VerticalCheckBlockIO>(
                 lhs,
                 shared_lhs,
                 offsets.batch_lhs,
@@ -87,12 +87,12 @@ pub(crate) fn load_lhs<F: Float, FC: Float>(
                 dims.k,
                 offsets.cube_row,
                 k_offset,
-                runtime_info,
+                ids,
                 comptime_info,
             );
         }
     } else if check_k_bounds {
-        load_tile::<F, FC, HorizontalCheckBlockIO>(
+        load_tile::<E, FC, HorizontalCheckBlockIO>(
             lhs,
             shared_lhs,
             offsets.batch_lhs,
@@ -102,11 +102,11 @@ pub(crate) fn load_lhs<F: Float, FC: Float>(
             dims.k,
             offsets.cube_row,
             k_offset,
-            runtime_info,
+            ids,
             comptime_info,
         );
     } else {
-        load_tile::<F, FC, UncheckedBlockIO>(
+        load_tile::<E, FC, UncheckedBlockIO>(
             lhs,
             shared_lhs,
             offsets.batch_lhs,
@@ -116,15 +116,15 @@ pub(crate) fn load_lhs<F: Float, FC: Float>(
             dims.k,
             offsets.cube_row,
             k_offset,
-            runtime_info,
+            ids,
             comptime_info,
         );
     }
 }
 
 #[cube]
-pub(crate) fn load_rhs<F: Float, FC: Float>(
-    rhs: &Tensor<F>,
+pub(crate) fn load_rhs<E: CmmaInput, FC: Float>(
+    rhs: &Tensor<E>,
     shared_rhs: &mut SharedMemory<FC>,
     num_tiles_in_k: u32,
     k_offset: u32,
@@ -142,7 +142,7 @@ pub(crate) fn load_rhs<F: Float, FC: Float>(
 
     if check_k_bounds {
         if check_n_bounds {
-            load_tile::<F, FC, WholeCheckBlockIO>(
+            load_tile::<E, FC, WholeCheckBlockIO>(
                 rhs,
                 shared_rhs,
                 offsets.batch_rhs,
@@ -152,11 +152,11 @@ pub(crate) fn load_rhs<F: Float, FC: Float>(
                 dims.n,
                 k_offset,
                 offsets.cube_col,
-                runtime_info,
+                ids,
                 comptime_info,
             );
         } else {
-            load_tile::<F, FC, VerticalCheckBlockIO>(
+            load_tile::<E, FC, VerticalCheckBlockIO>(
                 rhs,
                 shared_rhs,
                 offsets.batch_rhs,
@@ -166,12 +166,12 @@ pub(crate) fn load_rhs<F: Float, FC: Float>(
                 dims.n,
                 k_offset,
                 offsets.cube_col,
-                runtime_info,
+                ids,
                 comptime_info,
             );
         }
     } else if check_n_bounds {
-        load_tile::<F, FC, HorizontalCheckBlockIO>(
+        load_tile::<E, FC, HorizontalCheckBlockIO>(
             rhs,
             shared_rhs,
             offsets.batch_rhs,
@@ -181,11 +181,11 @@ pub(crate) fn load_rhs<F: Float, FC: Float>(
             dims.n,
             k_offset,
             offsets.cube_col,
-            runtime_info,
+            ids,
             comptime_info,
         );
     } else {
-        load_tile::<F, FC, UncheckedBlockIO>(
+        load_tile::<E, FC, UncheckedBlockIO>(
             rhs,
             shared_rhs,
             offsets.batch_rhs,
@@ -195,14 +195,16 @@ pub(crate) fn load_rhs<F: Float, FC: Float>(
             dims.n,
             k_offset,
             offsets.cube_col,
-            runtime_info,
+            ids,
             comptime_info,
         );
     }
 }
+/// Load the tile at (`tile_row`, `tile_col`) of the block starting at (`skip_row`, `skip_col`),
+/// into the shared memory slot `ids.coop`.
 #[cube]
-fn load_tile<F: Float, FC: Float, L: BlockLoader<F, FC>>(
-    tensor: &Tensor<F>,
+pub(crate) fn load_tile<E: CmmaInput, FC: Float, L: BlockLoader<E, FC>>(
+    tensor: &Tensor<E>,
     shared_memory: &mut SharedMemory<FC>,
     batch_offset: u32,
     tile_row: u32,
@@ -211,12 +213,11 @@ fn load_tile<F: Float, FC: Float, L: BlockLoader<F, FC>>(
     dim_horizontal: u32,
     skip_row: u32,
     skip_col: u32,
-    runtime_info: RuntimeCmmaInfo,
+    ids: Ids,
     #[comptime] comptime_info: ComptimeCmmaInfo,
 ) {
     let tile_size = comptime_info.tile_size;
     let tensor_vec = vectorization_of(tensor);
-    let ids = runtime_info.ids;
 
     // Must equal SUBCUBE_DIM, but must be known comptime too
     let coop_dim = comptime_info.coop_dim;
@@ -1,12 +1,15 @@
 pub(crate) mod base;
-mod block_io;
+pub(crate) mod block_io;
 mod block_loop;
 pub(crate) mod compute_loop;
 pub(crate) mod config;
-mod launch;
+pub(crate) mod launch;
 pub(crate) mod load_shared_memory;
 pub(crate) mod write_output;
 
+pub use base::CmmaInput;
 pub use launch::check_cmma_availability as is_available;
 pub use launch::matmul_cmma as launch;
+pub use launch::matmul_cmma_mixed as launch_mixed;
+pub use launch::matmul_cmma_mixed_ref as launch_mixed_ref;
 pub use launch::matmul_cmma_ref as launch_ref;
@@ -7,6 +7,9 @@ pub mod cmma;
 /// available.
 pub mod tiling2d;
 
+/// Contains algorithms for matrix multiplication with int8/int4 quantized weights.
+pub mod quantized;
+
 #[cfg(feature = "export_tests")]
 pub mod tests;
 
@@ -0,0 +1,134 @@
+use cubecl_core as cubecl;
+use cubecl_core::prelude::*;
+
+use crate::matmul::tiling2d::{
+    base::{calculate_batch_offsets, calculate_coordinates, get_dims, Coordinates, Dimensions},
+    block_loop::init_results,
+    config::CubeTiling2dConfig,
+    load_shared_memory::{load_lhs_plain, load_lhs_transposed, LoadInfo},
+    tile::{loader::TileLoader, writer::TileWriter},
+    write_output::write_to_output,
+};
+
+use super::{
+    compute_loop::dequantize_compute_loop, config::CubeQuantizedConfig, load_weights::load_weights,
+};
+
+#[cube(launch_unchecked)]
+pub fn quantized_matmul_kernel<F: Float>(
+    lhs: &Tensor<F>,
+    weights: &Tensor<u32>,
+    scales: &Tensor<F>,
+    zero_points: &Tensor<F>,
+    out: &mut Tensor<F>,
+    #[comptime] config: CubeQuantizedConfig,
+) {
+    let tiling = config.tiling;
+    let tile_size = tiling.tile_size;
+    let block_size_m = tiling.block_size_m;
+    let block_size_k = tiling.block_size_k;
+    let block_size_n = tiling.block_size_n;
+    let pack_factor = 32 / config.bits;
+
+    let dims = get_dims::<F>(lhs, out);
+    let coordinates = calculate_coordinates(CUBE_POS_X, CUBE_POS_Y, UNIT_POS, tiling);
+    let offsets = calculate_batch_offsets::<F, u32>(lhs, weights, out, CUBE_POS_Z);
+
+    let shared_lhs =
+        SharedMemory::<F>::vectorized(block_size_k * block_size_m / tile_size, tile_size);
+    let mut shared_weights = SharedMemory::<u32>::new(block_size_k * block_size_n / pack_factor);
+
+    // Scales and zero points only depend on the output column, they stay in registers.
+    let scale = load_channel_params::<F>(scales, coordinates, dims, tiling);
+    let zero_point = load_channel_params::<F>(zero_points, coordinates, dims, tiling);
+
+    let mut results = init_results::<f32>(tiling);
+    let n_loops = ((dims.k - 1) / block_size_k) + 1;
+
+    for k in 0..n_loops {
+        let k = k * block_size_k;
+
+        let load_info = LoadInfo::<F> {
+            coordinates,
+            k,
+            batch_offset: offsets.lhs,
+            shared_memory: shared_lhs,
+            dims,
+        };
+
+        // Lhs must be loaded as transposed. If it already is transposed in global memory, we load as plain.
+        if tiling.lhs_transposed {
+            load_lhs_plain::<F, TileLoader<F>>(lhs, load_info, tiling);
+        } else {
+            load_lhs_transposed::<F, TileLoader<F>>(lhs, load_info, tiling);
+        }
+        load_weights(
+            weights,
+            &mut shared_weights,
+            coordinates,
+            k,
+            offsets.rhs,
+            dims,
+            config,
+        );
+
+        sync_units();
+
+        dequantize_compute_loop::<F>(
+            coordinates,
+            shared_lhs,
+            shared_weights,
+            scale,
+            zero_point,
+            &mut results,
+            config,
+        );
+
+        sync_units();
+    }
+
+    let output = cast_results::<F>(&results, tiling);
+    write_to_output::<F, TileWriter<F>>(out, &output, coordinates, offsets.out, dims, tiling);
+}
+
+/// Read the per-channel parameters of the columns handled by the unit, upcasted to f32.
+#[cube]
+fn load_channel_params<F: Float>(
+    params: &Tensor<F>,
+    coordinates: Coordinates,
+    dims: Dimensions,
+    #[comptime] config: CubeTiling2dConfig,
+) -> f32 {
+    let tile_size = config.tile_size;
+    let unroll = config.unroll_tile;
+    let col_base = coordinates.skip_col + coordinates.unit_col;
+
+    let mut register = f32::vectorized(0., tile_size);
+
+    #[unroll(unroll)]
+    for i in 0..tile_size {
+        let col = col_base + i;
+        if col < dims.n {
+            register[i] = f32::cast_from(params[col]);
+        }
+    }
+
+    register
+}
+
+#[cube]
+fn cast_results<F: Float>(
+    results: &Array<f32>,
+    #[comptime] config: CubeTiling2dConfig,
+) -> Array<F> {
+    let tile_size = config.tile_size;
+    let unroll = config.unroll_tile;
+
+    let mut output = Array::<F>::new(tile_size * tile_size);
+    #[unroll(unroll)]
+    for i in 0..tile_size * tile_size {
+        output[i] = F::cast_from(results[i]);
+    }
+
+    output
+}
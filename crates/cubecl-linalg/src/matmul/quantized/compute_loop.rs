@@ -0,0 +1,73 @@
+use cubecl_core as cubecl;
+use cubecl_core::prelude::*;
+
+use crate::matmul::tiling2d::{base::Coordinates, outer_product::tile_outer_product};
+
+use super::config::CubeQuantizedConfig;
+
+#[cube]
+#[allow(unused_mut)]
+pub(crate) fn dequantize_compute_loop<F: Float>(
+    coordinates: Coordinates,
+    shared_lhs: SharedMemory<F>,
+    shared_weights: SharedMemory<u32>,
+    scale: f32,
+    zero_point: f32,
+    results: &mut Array<f32>,
+    #[comptime] config: CubeQuantizedConfig,
+) {
+    let tiling = config.tiling;
+    let tile_size = tiling.tile_size;
+    let block_size_m = tiling.block_size_m;
+    let block_size_k = tiling.block_size_k;
+    let block_size_n = tiling.block_size_n;
+    let unroll = tiling.unroll_compute;
+
+    let unit_row = coordinates.unit_row;
+    let unit_col = coordinates.unit_col;
+
+    #[unroll(unroll)]
+    for dot_index in 0..block_size_k {
+        let register_m =
+            f32::cast_from(shared_lhs[(unit_row + dot_index * block_size_m) / tile_size]);
+        let register_n = dequantize_tile(
+            shared_weights,
+            unit_col + dot_index * block_size_n,
+            scale,
+            zero_point,
+            config,
+        );
+
+        tile_outer_product::<f32>(register_m, register_n, results, tiling);
+    }
+}
+
+/// Unpack `tile_size` consecutive quantized values starting at `position` and dequantize them
+/// with `(value - zero_point) * scale`.
+#[cube]
+fn dequantize_tile(
+    shared_weights: SharedMemory<u32>,
+    position: u32,
+    scale: f32,
+    zero_point: f32,
+    #[comptime] config: CubeQuantizedConfig,
+) -> f32 {
+    let tile_size = config.tiling.tile_size;
+    let unroll = config.tiling.unroll_tile;
+    let bits = config.bits;
+    let pack_factor = 32 / bits;
+    let mask = (1u32 << bits) - 1;
+
+    let mut register = f32::vectorized_empty(tile_size);
+
+    #[unroll(unroll)]
+    for i in 0..tile_size {
+        let index = position + i;
+        let word = shared_weights[index / pack_factor];
+        let quantized = (word >> ((index % pack_factor) * bits)) & mask;
+
+        register[i] = (f32::cast_from(quantized) - zero_point[i]) * scale[i];
+    }
+
+    register
+}
@@ -0,0 +1,63 @@
+use cubecl_core::{
+    self as cubecl,
+    prelude::{CubeContext, Init},
+    CubeType,
+};
+
+use crate::matmul::tiling2d::config::CubeTiling2dConfig;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Storage format of quantized weights.
+///
+/// Values are stored unsigned and packed along the output dimension into `u32` words, the
+/// lowest bits holding the first column. The signed value is recovered with the zero point.
+pub enum QuantizationKind {
+    /// Four 8-bit values per `u32`
+    Int8,
+    /// Eight 4-bit values per `u32`
+    Int4,
+}
+
+impl QuantizationKind {
+    /// Number of bits used by a single quantized value.
+    pub fn bits(&self) -> usize {
+        match self {
+            QuantizationKind::Int8 => 8,
+            QuantizationKind::Int4 => 4,
+        }
+    }
+
+    /// Number of quantized values packed in a single `u32`.
+    pub fn pack_factor(&self) -> usize {
+        32 / self.bits()
+    }
+}
+
+#[derive(Clone, Copy, Hash, PartialEq, Eq, Debug, CubeType)]
+/// Quantized matmul parameters
+pub struct CubeQuantizedConfig {
+    /// Tiling 2D parameters used for the float side and the output
+    pub tiling: CubeTiling2dConfig,
+    /// Number of bits of a quantized value
+    pub bits: u32,
+}
+
+impl Init for CubeQuantizedConfig {
+    fn init(self, _context: &mut CubeContext) -> Self {
+        self
+    }
+}
+
+impl CubeQuantizedConfig {
+    pub fn new(tiling: CubeTiling2dConfig, kind: QuantizationKind) -> Self {
+        assert!(
+            tiling.block_size_n % kind.pack_factor() as u32 == 0,
+            "Block size in n must be a multiple of the number of packed values per word."
+        );
+
+        CubeQuantizedConfig {
+            tiling,
+            bits: kind.bits() as u32,
+        }
+    }
+}
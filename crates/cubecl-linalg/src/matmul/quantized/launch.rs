@@ -0,0 +1,192 @@
+use std::cmp::max;
+
+use cubecl_core::{prelude::*, Compiler};
+
+use crate::{
+    matmul::tiling2d::config::{
+        tiling2d_cube_count, tiling2d_cube_dim, CubeTiling2dConfig, Tiling2dConfig,
+    },
+    tensor::{into_contiguous, matrix_layout, MatrixLayout, TensorHandle},
+};
+
+use super::{
+    base::quantized_matmul_kernel,
+    config::{CubeQuantizedConfig, QuantizationKind},
+};
+
+/// Matrix multiplication of float lhs with quantized weights.
+///
+/// Weights have shape `[.., k, n / pack_factor]`, scales and zero points have shape `[n]`.
+#[allow(clippy::too_many_arguments)]
+pub fn matmul_quantized<R: Runtime, F: Float>(
+    client: &ComputeClient<R::Server, R::Channel>,
+    lhs: TensorHandle<R, F>,
+    weights: TensorHandle<R, u32>,
+    scales: TensorHandle<R, F>,
+    zero_points: TensorHandle<R, F>,
+    out: TensorHandle<R, F>,
+    kind: QuantizationKind,
+    config: Tiling2dConfig,
+) -> TensorHandle<R, F> {
+    matmul_quantized_ref::<R, F>(
+        client,
+        lhs.as_ref(),
+        weights.as_ref(),
+        scales.as_ref(),
+        zero_points.as_ref(),
+        out.as_ref(),
+        kind,
+        config,
+    );
+
+    out
+}
+
+/// Matrix multiplication of float lhs with quantized weights.
+///
+/// Weights have shape `[.., k, n / pack_factor]`, scales and zero points have shape `[n]`.
+#[allow(clippy::too_many_arguments)]
+pub fn matmul_quantized_ref<R: Runtime, F: Float>(
+    client: &ComputeClient<R::Server, R::Channel>,
+    lhs: TensorHandleRef<'_, R>,
+    weights: TensorHandleRef<'_, R>,
+    scales: TensorHandleRef<'_, R>,
+    zero_points: TensorHandleRef<'_, R>,
+    out: TensorHandleRef<'_, R>,
+    kind: QuantizationKind,
+    config: Tiling2dConfig,
+) {
+    assert!(
+        F::as_elem().size() * config.block_size_k * max(config.block_size_m, config.block_size_n)
+            <= <R::Compiler as Compiler>::max_shared_memory_size(),
+        "Shared memory limit will be busted. "
+    );
+
+    let rank = out.shape.len();
+    let n = out.shape[rank - 1];
+    assert_eq!(
+        weights.shape[rank - 1] * kind.pack_factor(),
+        n,
+        "Packed weights don't match the output dimension."
+    );
+    assert_eq!(scales.shape, &[n], "Expected one scale per output channel.");
+    assert_eq!(
+        zero_points.shape,
+        &[n],
+        "Expected one zero point per output channel."
+    );
+
+    let lhs_correct_layout = !matches!(matrix_layout(lhs.strides), MatrixLayout::HighlyPermuted);
+    let weights_correct_layout = matches!(matrix_layout(weights.strides), MatrixLayout::Contiguous);
+
+    match (lhs_correct_layout, weights_correct_layout) {
+        (true, true) => matmul_quantized_ref_no_check::<R, F>(
+            client,
+            lhs,
+            weights,
+            scales,
+            zero_points,
+            out,
+            kind,
+            config,
+        ),
+        (true, false) => matmul_quantized_ref_no_check::<R, F>(
+            client,
+            lhs,
+            into_contiguous::<R, u32>(client, weights).as_ref(),
+            scales,
+            zero_points,
+            out,
+            kind,
+            config,
+        ),
+        (false, true) => matmul_quantized_ref_no_check::<R, F>(
+            client,
+            into_contiguous::<R, F>(client, lhs).as_ref(),
+            weights,
+            scales,
+            zero_points,
+            out,
+            kind,
+            config,
+        ),
+        (false, false) => matmul_quantized_ref_no_check::<R, F>(
+            client,
+            into_contiguous::<R, F>(client, lhs).as_ref(),
+            into_contiguous::<R, u32>(client, weights).as_ref(),
+            scales,
+            zero_points,
+            out,
+            kind,
+            config,
+        ),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn matmul_quantized_ref_no_check<R: Runtime, F: Float>(
+    client: &ComputeClient<R::Server, R::Channel>,
+    lhs: TensorHandleRef<'_, R>,
+    weights: TensorHandleRef<'_, R>,
+    scales: TensorHandleRef<'_, R>,
+    zero_points: TensorHandleRef<'_, R>,
+    out: TensorHandleRef<'_, R>,
+    kind: QuantizationKind,
+    config: Tiling2dConfig,
+) {
+    let rank = lhs.strides.len();
+
+    let m = lhs.shape[rank - 2];
+    let k = lhs.shape[rank - 1];
+    let n = out.shape[rank - 1];
+
+    let lhs_transposed = match matrix_layout(lhs.strides) {
+        MatrixLayout::Contiguous => false,
+        MatrixLayout::MildlyPermuted {
+            transposed,
+            batch_swap: _,
+        } => transposed,
+        MatrixLayout::HighlyPermuted => {
+            panic!("Can't run on highly permuted tensor")
+        }
+    };
+
+    let vectorization = |shape: usize| {
+        [4, 2]
+            .into_iter()
+            .filter(|v| shape % v == 0)
+            .map(|v| v as u8)
+            .next()
+            .unwrap_or(1)
+    };
+
+    let lhs_vectorization = match lhs_transposed {
+        true => vectorization(m),
+        false => 1,
+    };
+    let out_vectorization = vectorization(n);
+
+    let cube_count = tiling2d_cube_count::<R>(out.shape, &config);
+    let cube_dim = tiling2d_cube_dim(&config);
+    let tiling = CubeTiling2dConfig::new(&config, m, k, n, lhs_transposed, false);
+    let cube_config = CubeQuantizedConfig::new(tiling, kind);
+
+    unsafe {
+        quantized_matmul_kernel::launch_unchecked::<F, R>(
+            client,
+            cube_count,
+            cube_dim,
+            TensorArg::from_raw_parts(lhs.handle, lhs.strides, lhs.shape, lhs_vectorization),
+            TensorArg::from_raw_parts(weights.handle, weights.strides, weights.shape, 1),
+            TensorArg::from_raw_parts(scales.handle, scales.strides, scales.shape, 1),
+            TensorArg::from_raw_parts(
+                zero_points.handle,
+                zero_points.strides,
+                zero_points.shape,
+                1,
+            ),
+            TensorArg::from_raw_parts(out.handle, out.strides, out.shape, out_vectorization),
+            cube_config,
+        );
+    }
+}
@@ -0,0 +1,47 @@
+use cubecl_core as cubecl;
+use cubecl_core::prelude::*;
+
+use crate::matmul::tiling2d::base::{Coordinates, Dimensions};
+
+use super::config::CubeQuantizedConfig;
+
+/// Copy the packed weights of the current block to shared memory, without unpacking them.
+/// Words are distributed over all units of the cube, out of bounds words are zero.
+#[cube]
+pub(crate) fn load_weights(
+    weights: &Tensor<u32>,
+    shared_weights: &mut SharedMemory<u32>,
+    coordinates: Coordinates,
+    k: u32,
+    batch_offset: u32,
+    dims: Dimensions,
+    #[comptime] config: CubeQuantizedConfig,
+) {
+    let tiling = config.tiling;
+    let unroll = tiling.unroll_tile;
+    let pack_factor = 32 / config.bits;
+    let words_per_row = tiling.block_size_n / pack_factor;
+    let num_words = tiling.block_size_k * words_per_row;
+    let num_units =
+        (tiling.block_size_m / tiling.tile_size) * (tiling.block_size_n / tiling.tile_size);
+    let words_per_unit = ((num_words - 1) / num_units) + 1;
+
+    let gm_stride = dims.n / pack_factor;
+    let col_offset = coordinates.skip_col / pack_factor;
+
+    #[unroll(unroll)]
+    for i in 0..words_per_unit {
+        let sm_position = UNIT_POS + i * num_units;
+
+        if sm_position < num_words {
+            let row = k + sm_position / words_per_row;
+            let col = col_offset + sm_position % words_per_row;
+
+            let mut word = 0u32;
+            if row < dims.k && col < gm_stride {
+                word = weights[batch_offset + row * gm_stride + col];
+            }
+            shared_weights[sm_position] = word;
+        }
+    }
+}
@@ -0,0 +1,9 @@
+mod base;
+mod compute_loop;
+mod config;
+mod launch;
+mod load_weights;
+
+pub use config::QuantizationKind;
+pub use launch::matmul_quantized as launch;
+pub use launch::matmul_quantized_ref as launch_ref;
@@ -1,11 +1,11 @@
-use cubecl_core::{CubeElement, Runtime};
+use cubecl_core::{frontend::F8E4M3, CubeElement, Runtime};
 use half::f16;
 
 use crate::{
     matmul::{
         cmma::{
             config::{CmmaConfig, WriteOutStrategy},
-            launch,
+            launch, launch_mixed,
         },
         tiling2d,
     },
@@ -123,6 +123,19 @@ pub fn test_matmul_cmma_vec2_shapes<R: Runtime>(device: &R::Device) {
     .test_cmma::<R>(CmmaConfig::default(), device);
 }
 
+pub fn test_matmul_cmma_e4m3_inputs<R: Runtime>(device: &R::Device) {
+    MatmulTestCase {
+        m: 64,
+        k: 64,
+        n: 64,
+        batch: 1,
+        factor: 1000.,
+        epsilon: 0.1,
+        compute_f16: true,
+    }
+    .test_cmma_e4m3::<R>(CmmaConfig::default(), device);
+}
+
 pub fn test_matmul_tiling2d_one_cube<R: Runtime>(device: &R::Device) {
     MatmulTestCase {
         m: 64,
@@ -233,6 +246,38 @@ impl MatmulTestCase {
         assert_equals_approx::<R>(&client, out.handle, &expected, self.epsilon);
     }
 
+    fn test_cmma_e4m3<R: Runtime>(&self, config: CmmaConfig, device: &R::Device) {
+        if !cmma_available::<R>(device) {
+            // We can't execute the test, skip.
+            return;
+        }
+
+        let client = R::client(device);
+        let e4m3_tensor = |x: usize, y: usize| {
+            let data: Vec<F8E4M3> = (0..self.batch * x * y)
+                .map(|i| F8E4M3::from_f32(i as f32 / self.factor))
+                .collect();
+            let handle = client.create(F8E4M3::as_bytes(&data));
+
+            (
+                TensorHandle::<R, F8E4M3>::new_contiguous(vec![self.batch, x, y], handle),
+                data.iter().map(|value| value.to_f32()).collect::<Vec<_>>(),
+            )
+        };
+        let (tensor_1, data_1) = e4m3_tensor(self.m, self.k);
+        let (tensor_2, data_2) = e4m3_tensor(self.k, self.n);
+        let out = TensorHandle::new_contiguous(
+            vec![self.batch, self.m, self.n],
+            create_empty::<R>(&client, self.batch * self.m, self.n),
+        );
+
+        let expected = self.matmul_cpu(&data_1, &data_2);
+
+        let out = launch_mixed::<R, F8E4M3, f32>(&client, tensor_1, tensor_2, out, config);
+
+        assert_equals_approx::<R>(&client, out.handle, &expected, self.epsilon);
+    }
+
     fn matmul_cpu(&self, lhs: &[f32], rhs: &[f32]) -> Vec<f32> {
         let mut out = vec![0.; self.batch * self.m * self.n];
         let lhs_batch_offset = self.m * self.k;
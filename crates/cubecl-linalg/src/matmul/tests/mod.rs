@@ -1,4 +1,5 @@
 pub mod cmma;
 pub mod matmul_tests;
-mod test_utils;
+pub mod quantized;
+pub(crate) mod test_utils;
 pub mod tiling2d;
@@ -0,0 +1,181 @@
+use bytemuck::cast_slice;
+use cubecl_core::{CubeElement, Runtime};
+
+use crate::{
+    matmul::quantized::{launch, QuantizationKind},
+    tensor::TensorHandle,
+};
+
+use super::test_utils::{assert_equals_approx, create_empty};
+
+pub fn test_matmul_quantized_int8_one_cube<R: Runtime>(device: &R::Device) {
+    QuantizedMatmulTestCase {
+        m: 64,
+        k: 64,
+        n: 64,
+        batch: 1,
+        kind: QuantizationKind::Int8,
+        epsilon: 0.01,
+    }
+    .test::<R>(device);
+}
+
+pub fn test_matmul_quantized_int4_one_cube<R: Runtime>(device: &R::Device) {
+    QuantizedMatmulTestCase {
+        m: 64,
+        k: 64,
+        n: 64,
+        batch: 1,
+        kind: QuantizationKind::Int4,
+        epsilon: 0.01,
+    }
+    .test::<R>(device);
+}
+
+pub fn test_matmul_quantized_several_cubes<R: Runtime>(device: &R::Device) {
+    QuantizedMatmulTestCase {
+        m: 256,
+        k: 256,
+        n: 256,
+        batch: 1,
+        kind: QuantizationKind::Int8,
+        epsilon: 0.01,
+    }
+    .test::<R>(device);
+}
+
+pub fn test_matmul_quantized_with_check_bounds<R: Runtime>(device: &R::Device) {
+    QuantizedMatmulTestCase {
+        m: 60,
+        k: 60,
+        n: 56,
+        batch: 1,
+        kind: QuantizationKind::Int4,
+        epsilon: 0.01,
+    }
+    .test::<R>(device);
+}
+
+pub fn test_matmul_quantized_with_batches<R: Runtime>(device: &R::Device) {
+    QuantizedMatmulTestCase {
+        m: 64,
+        k: 64,
+        n: 64,
+        batch: 3,
+        kind: QuantizationKind::Int8,
+        epsilon: 0.01,
+    }
+    .test::<R>(device);
+}
+
+struct QuantizedMatmulTestCase {
+    m: usize,
+    k: usize,
+    n: usize,
+    batch: usize,
+    kind: QuantizationKind,
+    epsilon: f32,
+}
+
+impl QuantizedMatmulTestCase {
+    fn test<R: Runtime>(&self, device: &R::Device) {
+        let client = R::client(device);
+        let pack_factor = self.kind.pack_factor();
+
+        let lhs = self.lhs_data();
+        let quantized = self.quantized_data();
+        let scales = (0..self.n)
+            .map(|j| 0.001 * (1 + j % 5) as f32)
+            .collect::<Vec<f32>>();
+        let zero_points = (0..self.n)
+            .map(|j| ((1 << (self.kind.bits() - 1)) + j % 3) as f32)
+            .collect::<Vec<f32>>();
+
+        let expected = self.matmul_cpu(&lhs, &quantized, &scales, &zero_points);
+
+        let lhs = TensorHandle::<R, f32>::new_contiguous(
+            vec![self.batch, self.m, self.k],
+            client.create(cast_slice(&lhs)),
+        );
+        let weights = TensorHandle::<R, u32>::new_contiguous(
+            vec![self.batch, self.k, self.n / pack_factor],
+            client.create(cast_slice(&self.pack(&quantized))),
+        );
+        let scales = TensorHandle::<R, f32>::new_contiguous(
+            vec![self.n],
+            client.create(f32::as_bytes(&scales)),
+        );
+        let zero_points = TensorHandle::<R, f32>::new_contiguous(
+            vec![self.n],
+            client.create(f32::as_bytes(&zero_points)),
+        );
+        let out = TensorHandle::new_contiguous(
+            vec![self.batch, self.m, self.n],
+            create_empty::<R>(&client, self.batch * self.m, self.n),
+        );
+
+        let out = launch::<R, f32>(
+            &client,
+            lhs,
+            weights,
+            scales,
+            zero_points,
+            out,
+            self.kind,
+            Default::default(),
+        );
+
+        assert_equals_approx::<R>(&client, out.handle, &expected, self.epsilon);
+    }
+
+    fn lhs_data(&self) -> Vec<f32> {
+        (0..self.batch * self.m * self.k)
+            .map(|i| (i % 17) as f32 / 16.)
+            .collect()
+    }
+
+    fn quantized_data(&self) -> Vec<u32> {
+        let max = 1 << self.kind.bits();
+        (0..self.batch * self.k * self.n)
+            .map(|i| (i as u32 * 7 + 3) % max)
+            .collect()
+    }
+
+    fn pack(&self, quantized: &[u32]) -> Vec<u32> {
+        let bits = self.kind.bits();
+        quantized
+            .chunks(self.kind.pack_factor())
+            .map(|values| {
+                values
+                    .iter()
+                    .enumerate()
+                    .fold(0, |word, (i, value)| word | value << (i * bits))
+            })
+            .collect()
+    }
+
+    fn matmul_cpu(
+        &self,
+        lhs: &[f32],
+        quantized: &[u32],
+        scales: &[f32],
+        zero_points: &[f32],
+    ) -> Vec<f32> {
+        let mut out = vec![0.; self.batch * self.m * self.n];
+
+        for b in 0..self.batch {
+            for i in 0..self.m {
+                for j in 0..self.n {
+                    for k_ in 0..self.k {
+                        let lhs_value = lhs[b * self.m * self.k + i * self.k + k_];
+                        let quantized_value = quantized[b * self.k * self.n + k_ * self.n + j];
+                        let rhs_value = (quantized_value as f32 - zero_points[j]) * scales[j];
+
+                        out[b * self.m * self.n + i * self.n + j] += lhs_value * rhs_value;
+                    }
+                }
+            }
+        }
+        out
+    }
+}
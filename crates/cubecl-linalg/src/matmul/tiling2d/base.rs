@@ -16,7 +16,7 @@ pub fn tiling2d_cube_kernel<F: Float>(
 ) {
     let dims = get_dims::<F>(lhs, rhs);
     let coordinates = calculate_coordinates(CUBE_POS_X, CUBE_POS_Y, UNIT_POS, config);
-    let offsets = calculate_batch_offsets::<F>(lhs, rhs, out, CUBE_POS_Z);
+    let offsets = calculate_batch_offsets::<F, F>(lhs, rhs, out, CUBE_POS_Z);
     let shared_memories = make_shared_memories::<F>(config);
     block_loop::<F>(
         lhs,
@@ -63,7 +63,7 @@ pub(crate) struct Coordinates {
 }
 
 #[cube]
-fn get_dims<F: Float>(lhs: &Tensor<F>, rhs: &Tensor<F>) -> Dimensions {
+pub(crate) fn get_dims<F: Float>(lhs: &Tensor<F>, rhs: &Tensor<F>) -> Dimensions {
     let rank = lhs.rank();
     let first_dim = rank - 2;
     let second_dim = rank - 1;
@@ -75,7 +75,7 @@ fn get_dims<F: Float>(lhs: &Tensor<F>, rhs: &Tensor<F>) -> Dimensions {
 }
 
 #[cube]
-fn calculate_coordinates(
+pub(crate) fn calculate_coordinates(
     cube_pos_x: u32,
     cube_pos_y: u32,
     unit_pos: u32,
@@ -105,16 +105,16 @@ fn calculate_coordinates(
 
 #[cube]
 #[allow(unused_mut)]
-fn calculate_batch_offsets<F: Float>(
+pub(crate) fn calculate_batch_offsets<F: Float, R: CubePrimitive>(
     lhs: &Tensor<F>,
-    rhs: &Tensor<F>,
+    rhs: &Tensor<R>,
     out: &Tensor<F>,
     batch_number: u32,
 ) -> BatchOffsets {
     let rank = out.rank();
 
     let dim_m = lhs.shape(rank - 2);
-    let dim_n = rhs.shape(rank - 1);
+    let dim_n = out.shape(rank - 1);
 
     // Batch offset for output
     let mut offset_out = dim_m * dim_n * batch_number;
@@ -136,7 +136,9 @@ fn calculate_batch_offsets<F: Float>(
 }
 
 #[cube]
-fn make_shared_memories<F: Float>(#[comptime] config: CubeTiling2dConfig) -> SharedMemories<F> {
+pub(crate) fn make_shared_memories<F: Float>(
+    #[comptime] config: CubeTiling2dConfig,
+) -> SharedMemories<F> {
     let tile_size = config.tile_size;
     let block_size_m = config.block_size_m;
     let block_size_k = config.block_size_k;
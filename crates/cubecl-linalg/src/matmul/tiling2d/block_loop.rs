@@ -50,7 +50,7 @@ pub(crate) fn block_loop<F: Float>(
 }
 
 #[cube]
-fn init_results<F: Float>(#[comptime] config: CubeTiling2dConfig) -> Array<F> {
+pub(crate) fn init_results<F: Float>(#[comptime] config: CubeTiling2dConfig) -> Array<F> {
     let tile_size = config.tile_size;
     let unroll = config.unroll_tile;
 
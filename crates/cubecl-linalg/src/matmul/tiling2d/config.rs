@@ -4,6 +4,8 @@ use cubecl_core::{
 };
 use cubecl_core::{compute::CubeCount, ir::CubeDim, CubeType, Runtime};
 
+use crate::conv2d::Im2colConfig;
+
 use super::base::TILE_SIZE;
 
 #[derive(Debug, Clone)]
@@ -58,6 +60,8 @@ pub struct CubeTiling2dConfig {
     pub lhs_transposed: bool,
     /// Rhs is transposed in global memory
     pub rhs_transposed: bool,
+    /// Parameters of the implicit im2col matrix, when loading it for a convolution
+    pub im2col: Option<Im2colConfig>,
 }
 
 impl Init for CubeTiling2dConfig {
@@ -99,6 +103,7 @@ impl CubeTiling2dConfig {
             tile_size: config.tile_size as u32,
             lhs_transposed,
             rhs_transposed,
+            im2col: None,
         }
     }
 }
@@ -1,5 +1,5 @@
 pub(crate) mod base;
-mod block_loop;
+pub(crate) mod block_loop;
 pub(crate) mod compute_loop;
 pub(crate) mod config;
 mod launch;
@@ -29,7 +29,7 @@ impl<F: Float> BlockLoader<F> for VerticalCheckBlockIO {
         let mut num_reads = 0;
         let row = check_bounds.skip_row + info.read_row;
         if check_bounds.dim_vertical > row {
-            num_reads = Min::min(check_bounds.dim_horizontal - row, tile_size);
+            num_reads = Min::min(check_bounds.dim_vertical - row, tile_size);
         }
 
         for i in 0..num_reads {
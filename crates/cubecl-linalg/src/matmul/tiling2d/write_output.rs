@@ -37,6 +37,29 @@ pub(crate) fn write_to_output<F: Float, W: OutputWriter<F>>(
     offset_output: u32,
     dims: Dimensions,
     #[comptime] config: CubeTiling2dConfig,
+) {
+    write_to_output_strided::<F, W>(
+        out,
+        results,
+        coordinates,
+        offset_output,
+        dims.n,
+        dims,
+        config,
+    );
+}
+
+#[cube]
+/// Same as [write_to_output], for outputs whose rows are `out_stride` elements apart
+/// instead of `dims.n`.
+pub(crate) fn write_to_output_strided<F: Float, W: OutputWriter<F>>(
+    out: &mut Tensor<F>,
+    results: &Array<F>,
+    coordinates: Coordinates,
+    offset_output: u32,
+    out_stride: u32,
+    dims: Dimensions,
+    #[comptime] config: CubeTiling2dConfig,
 ) {
     let check_m_bounds = config.check_m_bounds;
     let check_n_bounds = config.check_n_bounds;
@@ -44,7 +67,7 @@ pub(crate) fn write_to_output<F: Float, W: OutputWriter<F>>(
     let write_info = WriteTileInfo {
         coordinates,
         offset_output,
-        out_stride: dims.n,
+        out_stride,
     };
 
     if check_m_bounds {
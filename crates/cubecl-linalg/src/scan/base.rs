@@ -0,0 +1,293 @@
+use cubecl_core::cube;
+use cubecl_core::{self as cubecl, prelude::*};
+
+use super::config::ComptimeScanInfo;
+
+#[cube]
+/// Inclusive prefix sum of `value` across the units of a one-dimensional cube, in
+/// `UNIT_POS` order.
+///
+/// Must be reached by every unit of the cube.
+pub fn cube_inclusive_sum<N: Numeric>(value: N, #[comptime] info: ComptimeScanInfo) -> N {
+    cube_sum::<N>(value, true, info)
+}
+
+#[cube]
+/// Exclusive prefix sum of `value` across the units of a one-dimensional cube, in
+/// `UNIT_POS` order. The first unit receives zero.
+///
+/// Must be reached by every unit of the cube.
+pub fn cube_exclusive_sum<N: Numeric>(value: N, #[comptime] info: ComptimeScanInfo) -> N {
+    cube_sum::<N>(value, false, info)
+}
+
+#[cube]
+fn cube_sum<N: Numeric>(
+    value: N,
+    #[comptime] inclusive: bool,
+    #[comptime] info: ComptimeScanInfo,
+) -> N {
+    let use_subcube = info.use_subcube;
+    let mut result = N::from_int(0);
+
+    if use_subcube {
+        result = cube_sum_subcube::<N>(value, inclusive, info);
+    } else {
+        let mut buffer = SharedMemory::<N>::new(info.cube_size);
+        buffer[UNIT_POS] = value;
+        sync_units();
+
+        scan_shared::<N>(&mut buffer, info);
+
+        if inclusive {
+            result = buffer[UNIT_POS];
+        } else if UNIT_POS > 0 {
+            result = buffer[UNIT_POS - 1];
+        }
+    }
+
+    result
+}
+
+#[cube]
+/// Scans each subcube, then offsets every subcube by the sum of the previous ones.
+fn cube_sum_subcube<N: Numeric>(
+    value: N,
+    #[comptime] inclusive: bool,
+    #[comptime] info: ComptimeScanInfo,
+) -> N {
+    let subcube_id = UNIT_POS / SUBCUBE_DIM;
+    let lane = UNIT_POS - subcube_id * SUBCUBE_DIM;
+    let num_subcubes = (CUBE_DIM - 1) / SUBCUBE_DIM + 1;
+
+    let mut offsets = SharedMemory::<N>::new(info.cube_size);
+
+    // The reduced value is only guaranteed to be valid on the first unit.
+    let total = subcube_sum(value);
+    if lane == 0 {
+        offsets[subcube_id] = total;
+    }
+
+    sync_units();
+
+    if subcube_id == 0 {
+        let num_chunks = (num_subcubes - 1) / SUBCUBE_DIM + 1;
+        let mut carry = N::from_int(0);
+
+        for chunk in 0..num_chunks {
+            let index = chunk * SUBCUBE_DIM + lane;
+            let mut subcube_total = N::from_int(0);
+            if index < num_subcubes {
+                subcube_total = offsets[index];
+            }
+
+            let offset = subcube_exclusive_sum(subcube_total);
+            let chunk_total = subcube_inclusive_sum(subcube_total);
+
+            if index < num_subcubes {
+                offsets[index] = offset + carry;
+            }
+            carry += subcube_broadcast(chunk_total, SUBCUBE_DIM - 1);
+        }
+    }
+
+    sync_units();
+
+    let scan = if inclusive {
+        subcube_inclusive_sum(value)
+    } else {
+        subcube_exclusive_sum(value)
+    };
+
+    scan + offsets[subcube_id]
+}
+
+#[cube]
+/// In-place inclusive Hillis-Steele scan of one value per unit.
+pub(crate) fn scan_shared<N: Numeric>(
+    buffer: &mut SharedMemory<N>,
+    #[comptime] info: ComptimeScanInfo,
+) {
+    let mut offset = 1;
+
+    for _ in 0..info.num_steps {
+        let mut sum = buffer[UNIT_POS];
+        if UNIT_POS >= offset {
+            sum += buffer[UNIT_POS - offset];
+        }
+
+        sync_units();
+        buffer[UNIT_POS] = sum;
+        sync_units();
+
+        offset *= 2;
+    }
+}
+
+#[cube]
+/// In-place inclusive Hillis-Steele scan of one value per unit, restarting the sum at
+/// every unit whose head flag is set.
+///
+/// After the scan, `heads` holds whether any head is present up to each unit.
+pub(crate) fn segmented_scan_shared<N: Numeric>(
+    buffer: &mut SharedMemory<N>,
+    heads: &mut SharedMemory<u32>,
+    #[comptime] info: ComptimeScanInfo,
+) {
+    let mut offset = 1;
+
+    for _ in 0..info.num_steps {
+        let mut sum = buffer[UNIT_POS];
+        let mut head = heads[UNIT_POS];
+        if UNIT_POS >= offset {
+            if head == 0 {
+                sum += buffer[UNIT_POS - offset];
+            }
+            head |= heads[UNIT_POS - offset];
+        }
+
+        sync_units();
+        buffer[UNIT_POS] = sum;
+        heads[UNIT_POS] = head;
+        sync_units();
+
+        offset *= 2;
+    }
+}
+
+#[cube(launch_unchecked)]
+/// Writes the sum of the elements handled by each cube to `partials`.
+pub fn reduce_kernel<N: Numeric>(
+    input: &Tensor<N>,
+    partials: &mut Tensor<N>,
+    #[comptime] info: ComptimeScanInfo,
+) {
+    let index = CUBE_POS * CUBE_DIM + UNIT_POS;
+
+    let mut value = N::from_int(0);
+    if index < input.len() {
+        value = input[index];
+    }
+
+    let sum = cube_inclusive_sum::<N>(value, info);
+
+    if UNIT_POS == CUBE_DIM - 1 && CUBE_POS < partials.len() {
+        partials[CUBE_POS] = sum;
+    }
+}
+
+#[cube(launch_unchecked)]
+/// Scans the elements handled by each cube, offset by the inclusive scan of the
+/// previous cube sums. The prefix is never read when a single cube is launched.
+pub fn scan_kernel<N: Numeric>(
+    input: &Tensor<N>,
+    prefix: &Tensor<N>,
+    output: &mut Tensor<N>,
+    #[comptime] inclusive: bool,
+    #[comptime] info: ComptimeScanInfo,
+) {
+    let index = CUBE_POS * CUBE_DIM + UNIT_POS;
+
+    let mut value = N::from_int(0);
+    if index < input.len() {
+        value = input[index];
+    }
+
+    let mut scan = cube_sum::<N>(value, inclusive, info);
+
+    if CUBE_POS > 0 {
+        scan += prefix[CUBE_POS - 1];
+    }
+
+    if index < output.len() {
+        output[index] = scan;
+    }
+}
+
+#[cube(launch_unchecked)]
+/// Writes the segmented sum of the elements handled by each cube to `partials`, and
+/// whether the cube contains the head of a segment to `partial_heads`.
+pub fn segmented_reduce_kernel<N: Numeric>(
+    input: &Tensor<N>,
+    heads: &Tensor<u32>,
+    partials: &mut Tensor<N>,
+    partial_heads: &mut Tensor<u32>,
+    #[comptime] info: ComptimeScanInfo,
+) {
+    let index = CUBE_POS * CUBE_DIM + UNIT_POS;
+
+    let mut buffer = SharedMemory::<N>::new(info.cube_size);
+    let mut buffer_heads = SharedMemory::<u32>::new(info.cube_size);
+    load_segmented::<N>(input, heads, &mut buffer, &mut buffer_heads, index);
+
+    segmented_scan_shared::<N>(&mut buffer, &mut buffer_heads, info);
+
+    if UNIT_POS == CUBE_DIM - 1 && CUBE_POS < partials.len() {
+        partials[CUBE_POS] = buffer[UNIT_POS];
+        partial_heads[CUBE_POS] = buffer_heads[UNIT_POS];
+    }
+}
+
+#[cube(launch_unchecked)]
+/// Scans the elements handled by each cube, restarting at each segment head. Elements
+/// before the first head of the cube are offset by the inclusive segmented scan of the
+/// previous cube sums. The prefix is never read when a single cube is launched.
+pub fn segmented_scan_kernel<N: Numeric>(
+    input: &Tensor<N>,
+    heads: &Tensor<u32>,
+    prefix: &Tensor<N>,
+    output: &mut Tensor<N>,
+    #[comptime] inclusive: bool,
+    #[comptime] info: ComptimeScanInfo,
+) {
+    let index = CUBE_POS * CUBE_DIM + UNIT_POS;
+
+    let mut buffer = SharedMemory::<N>::new(info.cube_size);
+    let mut buffer_heads = SharedMemory::<u32>::new(info.cube_size);
+    load_segmented::<N>(input, heads, &mut buffer, &mut buffer_heads, index);
+    let is_head = buffer_heads[UNIT_POS];
+
+    segmented_scan_shared::<N>(&mut buffer, &mut buffer_heads, info);
+
+    let mut scan = N::from_int(0);
+    let mut has_head = 0;
+    if inclusive {
+        scan = buffer[UNIT_POS];
+        has_head = buffer_heads[UNIT_POS];
+    } else if is_head != 0 {
+        has_head = 1;
+    } else if UNIT_POS > 0 {
+        scan = buffer[UNIT_POS - 1];
+        has_head = buffer_heads[UNIT_POS - 1];
+    }
+
+    if CUBE_POS > 0 && has_head == 0 {
+        scan += prefix[CUBE_POS - 1];
+    }
+
+    if index < output.len() {
+        output[index] = scan;
+    }
+}
+
+#[cube]
+fn load_segmented<N: Numeric>(
+    input: &Tensor<N>,
+    heads: &Tensor<u32>,
+    buffer: &mut SharedMemory<N>,
+    buffer_heads: &mut SharedMemory<u32>,
+    index: u32,
+) {
+    let mut value = N::from_int(0);
+    let mut head = 0;
+    if index < input.len() {
+        value = input[index];
+        if heads[index] != 0 {
+            head = 1;
+        }
+    }
+
+    buffer[UNIT_POS] = value;
+    buffer_heads[UNIT_POS] = head;
+    sync_units();
+}
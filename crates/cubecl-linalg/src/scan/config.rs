@@ -0,0 +1,44 @@
+use cubecl_core::prelude::*;
+
+/// Number of units in a cube, each of them handling one element.
+pub(crate) const SCAN_CUBE_SIZE: u32 = 256;
+
+/// Which elements are part of the sum written at a given position.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ScanKind {
+    /// Position `i` receives the sum of elements `0..=i`.
+    Inclusive,
+    /// Position `i` receives the sum of elements `0..i`, the first position receiving zero.
+    Exclusive,
+}
+
+impl Init for ComptimeScanInfo {
+    fn init(self, _context: &mut CubeContext) -> Self {
+        self
+    }
+}
+
+#[derive(Clone, Copy, Hash, PartialEq, Eq, Debug)]
+/// Cube-level scan parameters
+pub struct ComptimeScanInfo {
+    /// Number of units in the cube, which must be one-dimensional
+    pub cube_size: u32,
+    /// Number of doubling steps needed to cover the cube, `log2(cube_size)` rounded up
+    pub num_steps: u32,
+    /// Whether to use subcube operations, or shared memory only
+    pub use_subcube: bool,
+}
+
+impl ComptimeScanInfo {
+    /// Create the parameters for a cube of `cube_size` units.
+    ///
+    /// Subcube operations should only be enabled when the runtime supports
+    /// [the subcube feature](cubecl_core::Feature::Subcube).
+    pub fn new(cube_size: u32, use_subcube: bool) -> Self {
+        Self {
+            cube_size,
+            num_steps: u32::BITS - (cube_size - 1).leading_zeros(),
+            use_subcube,
+        }
+    }
+}
@@ -0,0 +1,276 @@
+use cubecl_core::{
+    calculate_cube_count_elemwise,
+    client::ComputeClient,
+    frontend::{Numeric, TensorHandleRef},
+    CubeDim, Feature, Runtime,
+};
+
+use crate::tensor::{into_contiguous, matrix_layout, MatrixLayout, TensorHandle};
+
+use super::{
+    base::{reduce_kernel, scan_kernel, segmented_reduce_kernel, segmented_scan_kernel},
+    config::{ComptimeScanInfo, ScanKind, SCAN_CUBE_SIZE},
+};
+
+/// Prefix sum over all the elements of `input`, taken in row-major order.
+///
+/// Inputs spanning more than one cube are scanned in three passes: each cube reduces
+/// its elements, the cube sums are scanned recursively, and each cube finally scans
+/// its elements offset by the sum of the previous cubes.
+pub fn scan<R: Runtime, E: Numeric>(
+    client: &ComputeClient<R::Server, R::Channel>,
+    input: TensorHandle<R, E>,
+    kind: ScanKind,
+) -> TensorHandle<R, E> {
+    let output = empty_like::<R, E>(client, &input.shape);
+    scan_ref::<R, E>(client, input.as_ref(), output.as_ref(), kind);
+    output
+}
+
+/// Prefix sum over all the elements of `input`, taken in row-major order, written to
+/// the contiguous `output`.
+pub fn scan_ref<R: Runtime, E: Numeric>(
+    client: &ComputeClient<R::Server, R::Channel>,
+    input: TensorHandleRef<'_, R>,
+    output: TensorHandleRef<'_, R>,
+    kind: ScanKind,
+) {
+    check_output(&input, &output);
+    let input_contiguous = make_contiguous::<R, E>(client, &input);
+
+    scan_ref_no_check::<R, E>(
+        client,
+        input_contiguous.as_ref().map_or(input, |t| t.as_ref()),
+        output,
+        kind,
+    );
+}
+
+/// Prefix sum over all the elements of `input`, taken in row-major order, restarting
+/// at every element whose value in `heads` is not zero.
+pub fn segmented_scan<R: Runtime, E: Numeric>(
+    client: &ComputeClient<R::Server, R::Channel>,
+    input: TensorHandle<R, E>,
+    heads: TensorHandle<R, u32>,
+    kind: ScanKind,
+) -> TensorHandle<R, E> {
+    let output = empty_like::<R, E>(client, &input.shape);
+    segmented_scan_ref::<R, E>(
+        client,
+        input.as_ref(),
+        heads.as_ref(),
+        output.as_ref(),
+        kind,
+    );
+    output
+}
+
+/// Prefix sum over all the elements of `input`, taken in row-major order, restarting
+/// at every element whose value in `heads` is not zero, written to the contiguous
+/// `output`.
+pub fn segmented_scan_ref<R: Runtime, E: Numeric>(
+    client: &ComputeClient<R::Server, R::Channel>,
+    input: TensorHandleRef<'_, R>,
+    heads: TensorHandleRef<'_, R>,
+    output: TensorHandleRef<'_, R>,
+    kind: ScanKind,
+) {
+    check_output(&input, &output);
+    assert_eq!(
+        input.shape, heads.shape,
+        "Heads must be of the same shape as the input."
+    );
+    let input_contiguous = make_contiguous::<R, E>(client, &input);
+    let heads_contiguous = make_contiguous::<R, u32>(client, &heads);
+
+    segmented_scan_ref_no_check::<R, E>(
+        client,
+        input_contiguous.as_ref().map_or(input, |t| t.as_ref()),
+        heads_contiguous.as_ref().map_or(heads, |t| t.as_ref()),
+        output,
+        kind,
+    );
+}
+
+fn scan_ref_no_check<R: Runtime, E: Numeric>(
+    client: &ComputeClient<R::Server, R::Channel>,
+    input: TensorHandleRef<'_, R>,
+    output: TensorHandleRef<'_, R>,
+    kind: ScanKind,
+) {
+    let num_elems: usize = input.shape.iter().product();
+    if num_elems == 0 {
+        return;
+    }
+    let num_cubes = num_cubes(num_elems);
+    let info = ComptimeScanInfo::new(SCAN_CUBE_SIZE, client.features().enabled(Feature::Subcube));
+    let cube_dim = CubeDim::new(SCAN_CUBE_SIZE, 1, 1);
+    let inclusive = kind == ScanKind::Inclusive;
+
+    if num_cubes == 1 {
+        unsafe {
+            scan_kernel::launch_unchecked::<E, R>(
+                client,
+                calculate_cube_count_elemwise(num_elems, cube_dim),
+                cube_dim,
+                input.as_tensor_arg(1),
+                // Never read by a single cube.
+                input.as_tensor_arg(1),
+                output.as_tensor_arg(1),
+                inclusive,
+                info,
+            );
+        }
+        return;
+    }
+
+    let partials = empty_like::<R, E>(client, &[num_cubes]);
+    let prefix = empty_like::<R, E>(client, &[num_cubes]);
+
+    unsafe {
+        reduce_kernel::launch_unchecked::<E, R>(
+            client,
+            calculate_cube_count_elemwise(num_elems, cube_dim),
+            cube_dim,
+            input.as_tensor_arg(1),
+            partials.as_ref().as_tensor_arg(1),
+            info,
+        );
+    }
+
+    scan_ref_no_check::<R, E>(
+        client,
+        partials.as_ref(),
+        prefix.as_ref(),
+        ScanKind::Inclusive,
+    );
+
+    unsafe {
+        scan_kernel::launch_unchecked::<E, R>(
+            client,
+            calculate_cube_count_elemwise(num_elems, cube_dim),
+            cube_dim,
+            input.as_tensor_arg(1),
+            prefix.as_ref().as_tensor_arg(1),
+            output.as_tensor_arg(1),
+            inclusive,
+            info,
+        );
+    }
+}
+
+fn segmented_scan_ref_no_check<R: Runtime, E: Numeric>(
+    client: &ComputeClient<R::Server, R::Channel>,
+    input: TensorHandleRef<'_, R>,
+    heads: TensorHandleRef<'_, R>,
+    output: TensorHandleRef<'_, R>,
+    kind: ScanKind,
+) {
+    let num_elems: usize = input.shape.iter().product();
+    if num_elems == 0 {
+        return;
+    }
+    let num_cubes = num_cubes(num_elems);
+    // Segmented scans always go through shared memory.
+    let info = ComptimeScanInfo::new(SCAN_CUBE_SIZE, false);
+    let cube_dim = CubeDim::new(SCAN_CUBE_SIZE, 1, 1);
+    let inclusive = kind == ScanKind::Inclusive;
+
+    if num_cubes == 1 {
+        unsafe {
+            segmented_scan_kernel::launch_unchecked::<E, R>(
+                client,
+                calculate_cube_count_elemwise(num_elems, cube_dim),
+                cube_dim,
+                input.as_tensor_arg(1),
+                heads.as_tensor_arg(1),
+                // Never read by a single cube.
+                input.as_tensor_arg(1),
+                output.as_tensor_arg(1),
+                inclusive,
+                info,
+            );
+        }
+        return;
+    }
+
+    let partials = empty_like::<R, E>(client, &[num_cubes]);
+    let partial_heads = empty_like::<R, u32>(client, &[num_cubes]);
+    let prefix = empty_like::<R, E>(client, &[num_cubes]);
+
+    unsafe {
+        segmented_reduce_kernel::launch_unchecked::<E, R>(
+            client,
+            calculate_cube_count_elemwise(num_elems, cube_dim),
+            cube_dim,
+            input.as_tensor_arg(1),
+            heads.as_tensor_arg(1),
+            partials.as_ref().as_tensor_arg(1),
+            partial_heads.as_ref().as_tensor_arg(1),
+            info,
+        );
+    }
+
+    segmented_scan_ref_no_check::<R, E>(
+        client,
+        partials.as_ref(),
+        partial_heads.as_ref(),
+        prefix.as_ref(),
+        ScanKind::Inclusive,
+    );
+
+    unsafe {
+        segmented_scan_kernel::launch_unchecked::<E, R>(
+            client,
+            calculate_cube_count_elemwise(num_elems, cube_dim),
+            cube_dim,
+            input.as_tensor_arg(1),
+            heads.as_tensor_arg(1),
+            prefix.as_ref().as_tensor_arg(1),
+            output.as_tensor_arg(1),
+            inclusive,
+            info,
+        );
+    }
+}
+
+fn num_cubes(num_elems: usize) -> usize {
+    f32::ceil(num_elems as f32 / SCAN_CUBE_SIZE as f32) as usize
+}
+
+fn check_output<R: Runtime>(input: &TensorHandleRef<'_, R>, output: &TensorHandleRef<'_, R>) {
+    assert_eq!(
+        input.shape, output.shape,
+        "Output must be of the same shape as the input."
+    );
+    assert!(
+        matches!(matrix_layout(output.strides), MatrixLayout::Contiguous),
+        "Output must be contiguous."
+    );
+}
+
+fn make_contiguous<R: Runtime, E: Numeric>(
+    client: &ComputeClient<R::Server, R::Channel>,
+    tensor: &TensorHandleRef<'_, R>,
+) -> Option<TensorHandle<R, E>> {
+    match matrix_layout(tensor.strides) {
+        MatrixLayout::Contiguous => None,
+        _ => Some(into_contiguous::<R, E>(
+            client,
+            TensorHandleRef {
+                handle: tensor.handle,
+                strides: tensor.strides,
+                shape: tensor.shape,
+            },
+        )),
+    }
+}
+
+fn empty_like<R: Runtime, E: Numeric>(
+    client: &ComputeClient<R::Server, R::Channel>,
+    shape: &[usize],
+) -> TensorHandle<R, E> {
+    let num_elems: usize = shape.iter().product();
+    let handle = client.empty(num_elems * E::as_elem().size());
+    TensorHandle::new_contiguous(shape.to_vec(), handle)
+}
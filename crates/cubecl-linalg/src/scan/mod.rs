@@ -0,0 +1,13 @@
+mod base;
+mod config;
+mod launch;
+
+#[cfg(feature = "export_tests")]
+pub mod tests;
+
+pub use base::{cube_exclusive_sum, cube_inclusive_sum};
+pub use config::{ComptimeScanInfo, ScanKind};
+pub use launch::scan as launch;
+pub use launch::scan_ref as launch_ref;
+pub use launch::segmented_scan as launch_segmented;
+pub use launch::segmented_scan_ref as launch_segmented_ref;
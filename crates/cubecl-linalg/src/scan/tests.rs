@@ -0,0 +1,141 @@
+use cubecl_core::{CubeElement, Runtime};
+
+use crate::{
+    scan::{self, ScanKind},
+    tensor::TensorHandle,
+};
+
+pub fn test_scan_inclusive_one_cube<R: Runtime>(device: &R::Device) {
+    ScanTestCase {
+        num_elems: 200,
+        segment_every: None,
+        kind: ScanKind::Inclusive,
+    }
+    .test::<R>(device);
+}
+
+pub fn test_scan_exclusive_one_cube<R: Runtime>(device: &R::Device) {
+    ScanTestCase {
+        num_elems: 256,
+        segment_every: None,
+        kind: ScanKind::Exclusive,
+    }
+    .test::<R>(device);
+}
+
+pub fn test_scan_inclusive_many_cubes<R: Runtime>(device: &R::Device) {
+    ScanTestCase {
+        num_elems: 1000,
+        segment_every: None,
+        kind: ScanKind::Inclusive,
+    }
+    .test::<R>(device);
+}
+
+pub fn test_scan_exclusive_recursive<R: Runtime>(device: &R::Device) {
+    ScanTestCase {
+        num_elems: 256 * 300 + 13,
+        segment_every: None,
+        kind: ScanKind::Exclusive,
+    }
+    .test::<R>(device);
+}
+
+pub fn test_segmented_scan_inclusive<R: Runtime>(device: &R::Device) {
+    ScanTestCase {
+        num_elems: 1000,
+        segment_every: Some(37),
+        kind: ScanKind::Inclusive,
+    }
+    .test::<R>(device);
+}
+
+pub fn test_segmented_scan_exclusive_long_segments<R: Runtime>(device: &R::Device) {
+    ScanTestCase {
+        num_elems: 256 * 300 + 13,
+        segment_every: Some(700),
+        kind: ScanKind::Exclusive,
+    }
+    .test::<R>(device);
+}
+
+pub fn test_scan_empty<R: Runtime>(device: &R::Device) {
+    ScanTestCase {
+        num_elems: 0,
+        segment_every: None,
+        kind: ScanKind::Inclusive,
+    }
+    .test::<R>(device);
+}
+
+pub fn test_segmented_scan_empty<R: Runtime>(device: &R::Device) {
+    ScanTestCase {
+        num_elems: 0,
+        segment_every: Some(37),
+        kind: ScanKind::Exclusive,
+    }
+    .test::<R>(device);
+}
+
+struct ScanTestCase {
+    num_elems: usize,
+    segment_every: Option<usize>,
+    kind: ScanKind,
+}
+
+impl ScanTestCase {
+    fn test<R: Runtime>(&self, device: &R::Device) {
+        let client = R::client(device);
+
+        let data = (0..self.num_elems)
+            .map(|i| (i % 7) as u32)
+            .collect::<Vec<u32>>();
+        let heads = (0..self.num_elems)
+            .map(|i| match self.segment_every {
+                Some(every) => (i % every == 3) as u32,
+                None => 0,
+            })
+            .collect::<Vec<u32>>();
+
+        let input = TensorHandle::<R, u32>::new_contiguous(
+            vec![self.num_elems],
+            client.create(u32::as_bytes(&data)),
+        );
+
+        let output = match self.segment_every {
+            Some(_) => {
+                let heads = TensorHandle::<R, u32>::new_contiguous(
+                    vec![self.num_elems],
+                    client.create(u32::as_bytes(&heads)),
+                );
+                scan::launch_segmented::<R, u32>(&client, input, heads, self.kind)
+            }
+            None => scan::launch::<R, u32>(&client, input, self.kind),
+        };
+
+        let actual = client.read(output.handle.binding());
+        let actual = u32::from_bytes(&actual);
+
+        pretty_assertions::assert_eq!(actual, &self.scan_cpu(&data, &heads));
+    }
+
+    fn scan_cpu(&self, data: &[u32], heads: &[u32]) -> Vec<u32> {
+        let mut sum = 0;
+
+        data.iter()
+            .zip(heads)
+            .map(|(value, head)| {
+                if *head != 0 {
+                    sum = 0;
+                }
+                let exclusive = sum;
+                sum += value;
+
+                match self.kind {
+                    ScanKind::Inclusive => sum,
+                    ScanKind::Exclusive => exclusive,
+                }
+            })
+            .collect()
+    }
+}
@@ -1,7 +1,12 @@
 mod base;
 mod contiguous;
 mod layout;
+mod permute;
+
+#[cfg(feature = "export_tests")]
+pub mod tests;
 
 pub use base::*;
 pub use contiguous::*;
 pub use layout::*;
+pub use permute::*;
@@ -0,0 +1,143 @@
+use super::{into_contiguous, matrix_layout, MatrixLayout, TensorHandle};
+use cubecl::prelude::*;
+use cubecl_core as cubecl;
+
+/// Side of the square tile transposed by one cube.
+const TRANSPOSE_TILE_SIZE: u32 = 32;
+/// Number of rows of the tile handled by each unit in one pass.
+const TRANSPOSE_BLOCK_ROWS: u32 = 8;
+
+#[cube(launch_unchecked)]
+fn transpose_kernel<N: CubePrimitive>(
+    input: &Tensor<N>,
+    output: &mut Tensor<N>,
+    #[comptime] tile_size: u32,
+    #[comptime] block_rows: u32,
+) {
+    let rank = output.rank();
+    let num_rows = output.shape(rank - 2);
+    let num_cols = output.shape(rank - 1);
+    let row_stride = input.stride(rank - 2);
+    let col_stride = input.stride(rank - 1);
+
+    let offset_output = CUBE_POS_Z * num_rows * num_cols;
+    let mut offset_input = 0;
+    for b in 0..rank - 2 {
+        let index = offset_output / output.stride(b) % output.shape(b);
+        offset_input += index * input.stride(b);
+    }
+
+    // One extra column so that reading a column of the tile hits different banks
+    let padded_tile_size = tile_size + 1;
+    let mut tile = SharedMemory::<N>::new(tile_size * padded_tile_size);
+
+    let row_start = CUBE_POS_X * tile_size;
+    let col_start = CUBE_POS_Y * tile_size;
+
+    // Consecutive units read consecutive rows, which are contiguous in the input
+    #[unroll]
+    for i in 0..tile_size / block_rows {
+        let tile_col = UNIT_POS_Y + i * block_rows;
+        let row = row_start + UNIT_POS_X;
+        let col = col_start + tile_col;
+
+        if row < num_rows && col < num_cols {
+            tile[tile_col * padded_tile_size + UNIT_POS_X] =
+                input[offset_input + row * row_stride + col * col_stride];
+        }
+    }
+
+    sync_units();
+
+    // Consecutive units write consecutive columns, which are contiguous in the output
+    #[unroll]
+    for i in 0..tile_size / block_rows {
+        let tile_row = UNIT_POS_Y + i * block_rows;
+        let row = row_start + tile_row;
+        let col = col_start + UNIT_POS_X;
+
+        if row < num_rows && col < num_cols {
+            output[offset_output + row * num_cols + col] =
+                tile[UNIT_POS_X * padded_tile_size + tile_row];
+        }
+    }
+}
+
+/// Permute the dimensions of a tensor, so that dimension `i` of the output is dimension
+/// `axes[i]` of the input. The output is always contiguous.
+///
+/// When only the last two dimensions end up swapped, the copy goes through a tiled
+/// transpose in shared memory so that both reads and writes are coalesced.
+pub fn permute<R: Runtime, E: CubePrimitive>(
+    client: &ComputeClient<R::Server, R::Channel>,
+    input: TensorHandleRef<'_, R>,
+    axes: &[usize],
+) -> TensorHandle<R, E> {
+    let rank = input.shape.len();
+    assert_eq!(axes.len(), rank, "Expected one axis per dimension.");
+
+    let mut sorted = axes.to_vec();
+    sorted.sort();
+    assert!(
+        sorted.iter().enumerate().all(|(i, axis)| i == *axis),
+        "Axes must be a permutation of the dimensions."
+    );
+
+    let shape = axes
+        .iter()
+        .map(|axis| input.shape[*axis])
+        .collect::<Vec<_>>();
+    let strides = axes
+        .iter()
+        .map(|axis| input.strides[*axis])
+        .collect::<Vec<_>>();
+    let permuted = TensorHandleRef {
+        handle: input.handle,
+        strides: &strides,
+        shape: &shape,
+    };
+
+    match matrix_layout(&strides) {
+        MatrixLayout::MildlyPermuted {
+            transposed: true,
+            batch_swap: false,
+        } => transpose::<R, E>(client, permuted),
+        _ => into_contiguous::<R, E>(client, permuted),
+    }
+}
+
+fn transpose<R: Runtime, E: CubePrimitive>(
+    client: &ComputeClient<R::Server, R::Channel>,
+    input: TensorHandleRef<'_, R>,
+) -> TensorHandle<R, E> {
+    let rank = input.shape.len();
+    let num_rows = input.shape[rank - 2];
+    let num_cols = input.shape[rank - 1];
+    let num_batches: usize = input.shape.iter().take(rank - 2).product();
+
+    let num_elems: usize = input.shape.iter().product();
+    let handle = client.empty(num_elems * E::as_elem().size());
+    let output = TensorHandle::new_contiguous(input.shape.to_vec(), handle);
+
+    let tile_size = TRANSPOSE_TILE_SIZE as usize;
+    let cube_count = CubeCount::Static(
+        f32::ceil(num_rows as f32 / tile_size as f32) as u32,
+        f32::ceil(num_cols as f32 / tile_size as f32) as u32,
+        num_batches as u32,
+    );
+    let cube_dim = CubeDim::new(TRANSPOSE_TILE_SIZE, TRANSPOSE_BLOCK_ROWS, 1);
+
+    unsafe {
+        transpose_kernel::launch_unchecked::<E, R>(
+            client,
+            cube_count,
+            cube_dim,
+            input.as_tensor_arg(1),
+            output.as_ref().as_tensor_arg(1),
+            TRANSPOSE_TILE_SIZE,
+            TRANSPOSE_BLOCK_ROWS,
+        );
+    }
+
+    output
+}
@@ -0,0 +1,81 @@
+use cubecl_core::{CubeElement, Runtime};
+
+use crate::tensor::{permute, TensorHandle};
+
+pub fn test_permute_transpose<R: Runtime>(device: &R::Device) {
+    PermuteTestCase {
+        shape: vec![70, 45],
+        axes: vec![1, 0],
+    }
+    .test::<R>(device);
+}
+
+pub fn test_permute_transpose_batched<R: Runtime>(device: &R::Device) {
+    PermuteTestCase {
+        shape: vec![3, 33, 65],
+        axes: vec![0, 2, 1],
+    }
+    .test::<R>(device);
+}
+
+pub fn test_permute_transpose_with_batch_swap<R: Runtime>(device: &R::Device) {
+    PermuteTestCase {
+        shape: vec![2, 3, 17, 9],
+        axes: vec![1, 0, 3, 2],
+    }
+    .test::<R>(device);
+}
+
+pub fn test_permute_generic<R: Runtime>(device: &R::Device) {
+    PermuteTestCase {
+        shape: vec![4, 5, 6],
+        axes: vec![2, 0, 1],
+    }
+    .test::<R>(device);
+}
+
+struct PermuteTestCase {
+    shape: Vec<usize>,
+    axes: Vec<usize>,
+}
+
+impl PermuteTestCase {
+    fn test<R: Runtime>(&self, device: &R::Device) {
+        let client = R::client(device);
+
+        let num_elems: usize = self.shape.iter().product();
+        let data = (0..num_elems).map(|i| i as f32).collect::<Vec<f32>>();
+        let input = TensorHandle::<R, f32>::new_contiguous(
+            self.shape.clone(),
+            client.create(f32::as_bytes(&data)),
+        );
+
+        let output = permute::<R, f32>(&client, input.as_ref(), &self.axes);
+
+        let actual = client.read(output.handle.binding());
+        let actual = f32::from_bytes(&actual);
+
+        pretty_assertions::assert_eq!(actual, &self.permute_cpu(&data));
+    }
+
+    fn permute_cpu(&self, data: &[f32]) -> Vec<f32> {
+        let rank = self.shape.len();
+        let out_shape = self.axes.iter().map(|a| self.shape[*a]).collect::<Vec<_>>();
+
+        let mut in_strides = vec![1; rank];
+        for i in (0..rank - 1).rev() {
+            in_strides[i] = in_strides[i + 1] * self.shape[i + 1];
+        }
+
+        (0..data.len())
+            .map(|mut index| {
+                let mut position = 0;
+                for i in (0..rank).rev() {
+                    position += (index % out_shape[i]) * in_strides[self.axes[i]];
+                    index /= out_shape[i];
+                }
+                data[position]
+            })
+            .collect()
+    }
+}
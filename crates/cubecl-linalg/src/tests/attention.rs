@@ -0,0 +1,64 @@
+#![allow(missing_docs)]
+
+#[macro_export]
+macro_rules! testgen_attention {
+    () => {
+        use super::*;
+
+        #[test]
+        pub fn test_attention_head_dim_64() {
+            cubecl_linalg::attention::tests::test_attention_head_dim_64::<TestRuntime>(
+                &Default::default(),
+            )
+        }
+
+        #[test]
+        pub fn test_attention_head_dim_128() {
+            cubecl_linalg::attention::tests::test_attention_head_dim_128::<TestRuntime>(
+                &Default::default(),
+            )
+        }
+
+        #[test]
+        pub fn test_attention_causal() {
+            cubecl_linalg::attention::tests::test_attention_causal::<TestRuntime>(
+                &Default::default(),
+            )
+        }
+
+        #[test]
+        pub fn test_attention_with_check_bounds() {
+            cubecl_linalg::attention::tests::test_attention_with_check_bounds::<TestRuntime>(
+                &Default::default(),
+            )
+        }
+
+        #[test]
+        pub fn test_attention_causal_with_check_bounds() {
+            cubecl_linalg::attention::tests::test_attention_causal_with_check_bounds::<TestRuntime>(
+                &Default::default(),
+            )
+        }
+
+        #[test]
+        pub fn test_attention_with_batches() {
+            cubecl_linalg::attention::tests::test_attention_with_batches::<TestRuntime>(
+                &Default::default(),
+            )
+        }
+
+        #[test]
+        pub fn test_attention_with_mask() {
+            cubecl_linalg::attention::tests::test_attention_with_mask::<TestRuntime>(
+                &Default::default(),
+            )
+        }
+
+        #[test]
+        pub fn test_attention_causal_with_mask() {
+            cubecl_linalg::attention::tests::test_attention_causal_with_mask::<TestRuntime>(
+                &Default::default(),
+            )
+        }
+    };
+}
@@ -0,0 +1,46 @@
+#![allow(missing_docs)]
+
+#[macro_export]
+macro_rules! testgen_conv2d {
+    () => {
+        use super::*;
+
+        #[test]
+        pub fn test_conv2d_nchw() {
+            cubecl_linalg::conv2d::tests::test_conv2d_nchw::<TestRuntime>(&Default::default())
+        }
+
+        #[test]
+        pub fn test_conv2d_nchw_stride_padding() {
+            cubecl_linalg::conv2d::tests::test_conv2d_nchw_stride_padding::<TestRuntime>(
+                &Default::default(),
+            )
+        }
+
+        #[test]
+        pub fn test_conv2d_nchw_dilation() {
+            cubecl_linalg::conv2d::tests::test_conv2d_nchw_dilation::<TestRuntime>(
+                &Default::default(),
+            )
+        }
+
+        #[test]
+        pub fn test_conv2d_nchw_groups() {
+            cubecl_linalg::conv2d::tests::test_conv2d_nchw_groups::<TestRuntime>(
+                &Default::default(),
+            )
+        }
+
+        #[test]
+        pub fn test_conv2d_nhwc() {
+            cubecl_linalg::conv2d::tests::test_conv2d_nhwc::<TestRuntime>(&Default::default())
+        }
+
+        #[test]
+        pub fn test_conv2d_nhwc_all_options() {
+            cubecl_linalg::conv2d::tests::test_conv2d_nhwc_all_options::<TestRuntime>(
+                &Default::default(),
+            )
+        }
+    };
+}
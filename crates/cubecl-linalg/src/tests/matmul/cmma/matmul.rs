@@ -77,5 +77,10 @@ macro_rules! testgen_cmma_matmul {
         pub fn test_matmul_cmma_vec2_shapes() {
             tests::matmul_tests::test_matmul_cmma_vec2_shapes::<TestRuntime>(&Default::default())
         }
+
+        #[test]
+        pub fn test_matmul_cmma_e4m3_inputs() {
+            tests::matmul_tests::test_matmul_cmma_e4m3_inputs::<TestRuntime>(&Default::default())
+        }
     };
 }
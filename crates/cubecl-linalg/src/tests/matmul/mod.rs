@@ -1,6 +1,7 @@
 #![allow(missing_docs)]
 
 pub mod cmma;
+pub mod quantized;
 pub mod tiling2d;
 
 #[macro_export]
@@ -12,6 +13,11 @@ macro_rules! testgen_all {
 
             cubecl_linalg::testgen_cmma!();
             cubecl_linalg::testgen_tiling2d!();
+            cubecl_linalg::testgen_quantized!();
+            cubecl_linalg::testgen_attention!();
+            cubecl_linalg::testgen_conv2d!();
+            cubecl_linalg::testgen_scan!();
+            cubecl_linalg::testgen_tensor!();
         }
     };
 }
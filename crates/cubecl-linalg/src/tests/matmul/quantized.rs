@@ -0,0 +1,43 @@
+#![allow(missing_docs)]
+
+#[macro_export]
+macro_rules! testgen_quantized {
+    () => {
+        use super::*;
+
+        #[test]
+        pub fn test_matmul_quantized_int8_one_cube() {
+            tests::quantized::test_matmul_quantized_int8_one_cube::<TestRuntime>(
+                &Default::default(),
+            )
+        }
+
+        #[test]
+        pub fn test_matmul_quantized_int4_one_cube() {
+            tests::quantized::test_matmul_quantized_int4_one_cube::<TestRuntime>(
+                &Default::default(),
+            )
+        }
+
+        #[test]
+        pub fn test_matmul_quantized_several_cubes() {
+            tests::quantized::test_matmul_quantized_several_cubes::<TestRuntime>(
+                &Default::default(),
+            )
+        }
+
+        #[test]
+        pub fn test_matmul_quantized_with_check_bounds() {
+            tests::quantized::test_matmul_quantized_with_check_bounds::<TestRuntime>(
+                &Default::default(),
+            )
+        }
+
+        #[test]
+        pub fn test_matmul_quantized_with_batches() {
+            tests::quantized::test_matmul_quantized_with_batches::<TestRuntime>(
+                &Default::default(),
+            )
+        }
+    };
+}
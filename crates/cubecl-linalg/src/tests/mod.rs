@@ -1 +1,5 @@
+mod attention;
+mod conv2d;
 mod matmul;
+mod scan;
+mod tensor;
@@ -0,0 +1,62 @@
+#![allow(missing_docs)]
+
+#[macro_export]
+macro_rules! testgen_scan {
+    () => {
+        use super::*;
+
+        #[test]
+        pub fn test_scan_inclusive_one_cube() {
+            cubecl_linalg::scan::tests::test_scan_inclusive_one_cube::<TestRuntime>(
+                &Default::default(),
+            )
+        }
+
+        #[test]
+        pub fn test_scan_exclusive_one_cube() {
+            cubecl_linalg::scan::tests::test_scan_exclusive_one_cube::<TestRuntime>(
+                &Default::default(),
+            )
+        }
+
+        #[test]
+        pub fn test_scan_inclusive_many_cubes() {
+            cubecl_linalg::scan::tests::test_scan_inclusive_many_cubes::<TestRuntime>(
+                &Default::default(),
+            )
+        }
+
+        #[test]
+        pub fn test_scan_exclusive_recursive() {
+            cubecl_linalg::scan::tests::test_scan_exclusive_recursive::<TestRuntime>(
+                &Default::default(),
+            )
+        }
+
+        #[test]
+        pub fn test_segmented_scan_inclusive() {
+            cubecl_linalg::scan::tests::test_segmented_scan_inclusive::<TestRuntime>(
+                &Default::default(),
+            )
+        }
+
+        #[test]
+        pub fn test_segmented_scan_exclusive_long_segments() {
+            cubecl_linalg::scan::tests::test_segmented_scan_exclusive_long_segments::<TestRuntime>(
+                &Default::default(),
+            )
+        }
+
+        #[test]
+        pub fn test_scan_empty() {
+            cubecl_linalg::scan::tests::test_scan_empty::<TestRuntime>(&Default::default())
+        }
+
+        #[test]
+        pub fn test_segmented_scan_empty() {
+            cubecl_linalg::scan::tests::test_segmented_scan_empty::<TestRuntime>(
+                &Default::default(),
+            )
+        }
+    };
+}
@@ -0,0 +1,32 @@
+#![allow(missing_docs)]
+
+#[macro_export]
+macro_rules! testgen_tensor {
+    () => {
+        use super::*;
+
+        #[test]
+        pub fn test_permute_transpose() {
+            cubecl_linalg::tensor::tests::test_permute_transpose::<TestRuntime>(&Default::default())
+        }
+
+        #[test]
+        pub fn test_permute_transpose_batched() {
+            cubecl_linalg::tensor::tests::test_permute_transpose_batched::<TestRuntime>(
+                &Default::default(),
+            )
+        }
+
+        #[test]
+        pub fn test_permute_transpose_with_batch_swap() {
+            cubecl_linalg::tensor::tests::test_permute_transpose_with_batch_swap::<TestRuntime>(
+                &Default::default(),
+            )
+        }
+
+        #[test]
+        pub fn test_permute_generic() {
+            cubecl_linalg::tensor::tests::test_permute_generic::<TestRuntime>(&Default::default())
+        }
+    };
+}
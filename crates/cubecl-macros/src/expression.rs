@@ -1,7 +1,8 @@
 use proc_macro2::{Span, TokenStream};
 use quote::{quote, ToTokens};
 use syn::{
-    AngleBracketedGenericArguments, Ident, Lit, Member, Pat, Path, PathArguments, PathSegment, Type,
+    AngleBracketedGenericArguments, Ident, Lit, LitStr, Member, Pat, Path, PathArguments,
+    PathSegment, Type,
 };
 
 use crate::{
@@ -86,11 +87,30 @@ pub enum Expression {
         block: Block,
         scope: Scope,
     },
+    WhileLoop {
+        condition: Box<Expression>,
+        block: Block,
+        scope: Scope,
+    },
     If {
         condition: Box<Expression>,
         then_block: Block,
         else_branch: Option<Box<Expression>>,
     },
+    /// An `if let`, which is always resolved at expansion time since the variant of a value
+    /// can't change at runtime.
+    IfLet {
+        pat: Pat,
+        value: Box<Expression>,
+        then_block: Block,
+        else_branch: Option<Box<Expression>>,
+    },
+    Match {
+        value: Box<Expression>,
+        arms: Vec<MatchArm>,
+        default: MatchArm,
+        is_enum: bool,
+    },
     Return {
         expr: Option<Box<Expression>>,
         span: Span,
@@ -109,6 +129,9 @@ pub enum Expression {
     Tuple {
         elements: Vec<Expression>,
     },
+    OptionSome {
+        value: Box<Expression>,
+    },
     Index {
         expr: Box<Expression>,
         index: Box<Expression>,
@@ -132,6 +155,27 @@ pub enum Expression {
     Keyword {
         name: syn::Ident,
     },
+    /// A `cube_assert!`, only expanded with debug assertions.
+    Assert {
+        condition: Box<Expression>,
+        message: TokenStream,
+        span: Span,
+    },
+    /// A `cube_debug_print!`, only expanded with debug assertions.
+    DebugPrint {
+        format: LitStr,
+        args: Vec<Expression>,
+        span: Span,
+    },
+}
+
+/// A runtime match arm, with the values matched by its pattern and the enum payload fields it
+/// binds, as `(binding, field)` pairs.
+#[derive(Clone, Debug)]
+pub struct MatchArm {
+    pub values: Vec<TokenStream>,
+    pub bindings: Vec<(Ident, Ident)>,
+    pub block: Block,
 }
 
 #[derive(Clone, Debug, Default)]
@@ -161,11 +205,15 @@ impl Expression {
             Expression::Path { .. } => None,
             Expression::Range { start, .. } => start.ty(),
             Expression::Loop { .. } => None,
+            Expression::WhileLoop { .. } => None,
             Expression::If { then_block, .. } => then_block.ty.clone(),
+            Expression::IfLet { then_block, .. } => then_block.ty.clone(),
+            Expression::Match { default, .. } => default.block.ty.clone(),
             Expression::Return { expr, .. } => expr.as_ref().and_then(|expr| expr.ty()),
             Expression::Array { .. } => None,
             Expression::Index { .. } => None,
             Expression::Tuple { .. } => None,
+            Expression::OptionSome { .. } => None,
             Expression::Slice { expr, .. } => expr.ty(),
             Expression::ArrayInit { init, .. } => init.ty(),
             Expression::VerbatimTerminated { .. } => None,
@@ -174,6 +222,8 @@ impl Expression {
             Expression::Closure { .. } => None,
             Expression::Keyword { .. } => None,
             Expression::CompilerIntrinsic { .. } => None,
+            Expression::Assert { .. } => None,
+            Expression::DebugPrint { .. } => None,
         }
     }
 
@@ -237,9 +287,14 @@ impl Expression {
     pub fn needs_terminator(&self) -> bool {
         match self {
             Expression::If { then_block, .. } => then_block.ret.is_some(),
+            Expression::IfLet { then_block, .. } => then_block.ret.is_some(),
+            Expression::Match { arms, default, .. } => {
+                default.block.ret.is_some() && arms.iter().all(|arm| arm.block.ret.is_some())
+            }
             Expression::Block(block) => block.ret.is_some(),
             Expression::ForLoop { .. } => false,
             Expression::Loop { .. } => false,
+            Expression::WhileLoop { .. } => false,
             Expression::VerbatimTerminated { .. } => false,
             _ => true,
         }
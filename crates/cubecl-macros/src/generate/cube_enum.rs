@@ -0,0 +1,359 @@
+use darling::ast::Style;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+use crate::{
+    parse::cube_type::{enum_discriminant_name, enum_discriminants, TypeCodegen, TypeVariant},
+    paths::prelude_type,
+};
+
+/// An enum is expanded to a `u32` discriminant and the payload fields of all its variants,
+/// flattened into a single struct. Only the fields of the active variant hold meaningful values.
+impl TypeCodegen {
+    fn variants(&self) -> &[TypeVariant] {
+        self.variants.as_deref().unwrap()
+    }
+
+    fn flattened_fields(&self) -> Vec<(syn::Ident, syn::Type)> {
+        self.variants()
+            .iter()
+            .flat_map(|variant| {
+                variant
+                    .flattened_fields()
+                    .into_iter()
+                    .map(|(name, field)| (name, field.ty.clone()))
+            })
+            .collect()
+    }
+
+    pub fn enum_expand_ty(&self) -> TokenStream {
+        let expand_elem = prelude_type("ExpandElementTyped");
+        let name = &self.name_expand;
+        let generics = &self.generics;
+        let vis = &self.vis;
+        let fields = self
+            .flattened_fields()
+            .into_iter()
+            .map(|(name, ty)| quote![#vis #name: #expand_elem<#ty>]);
+
+        quote! {
+            #[derive(Clone)]
+            #[allow(non_snake_case)]
+            #vis struct #name #generics {
+                #vis discriminant: #expand_elem<u32>,
+                #(#fields),*
+            }
+        }
+    }
+
+    pub fn enum_variants_impl(&self, discriminants: &[u32]) -> TokenStream {
+        let expand_elem = prelude_type("ExpandElementTyped");
+        let context = prelude_type("CubeContext");
+        let name = &self.ident;
+        let name_expand = &self.name_expand;
+        let vis = &self.vis;
+        let (generics, generic_names, where_clause) = self.generics.split_for_impl();
+        let fields = self.flattened_fields();
+
+        let discriminant_fns = self
+            .variants()
+            .iter()
+            .zip(discriminants)
+            .map(|(variant, value)| {
+                let method = enum_discriminant_name(&variant.ident);
+                let value = *value as i64;
+                quote![#vis fn #method(&self) -> i64 { #value }]
+            });
+        let variant_fns = self
+            .variants()
+            .iter()
+            .zip(discriminants)
+            .map(|(variant, value)| {
+                let method = format_ident!("__expand_variant_{}", variant.ident);
+                let payload = fields
+                    .iter()
+                    .map(|(name, _)| quote![#name: #expand_elem::from_lit(0u32)]);
+                quote! {
+                    #vis fn #method() -> #name_expand #generic_names {
+                        #name_expand {
+                            discriminant: #expand_elem::from_lit(#value),
+                            #(#payload),*
+                        }
+                    }
+                }
+            });
+        let tuple_fns = self
+            .variants()
+            .iter()
+            .filter(|variant| variant.fields.style == Style::Tuple)
+            .map(|variant| {
+                let method = format_ident!("__expand_{}", variant.ident);
+                let method_variant = format_ident!("__expand_variant_{}", variant.ident);
+                let fields = variant.flattened_fields();
+                let args = fields
+                    .iter()
+                    .map(|(name, field)| (name, &field.ty))
+                    .map(|(name, ty)| quote![#name: #expand_elem<#ty>]);
+                let assign = fields.iter().map(|(name, _)| quote![expand.#name = #name]);
+                quote! {
+                    #vis fn #method(_context: &mut #context, #(#args),*) -> #name_expand #generic_names {
+                        let mut expand = Self::#method_variant();
+                        #(#assign;)*
+                        expand
+                    }
+                }
+            });
+
+        quote! {
+            #[allow(non_snake_case)]
+            impl #generics #name_expand #generic_names #where_clause {
+                #(#discriminant_fns)*
+            }
+
+            #[allow(non_snake_case)]
+            impl #generics #name #generic_names #where_clause {
+                #(#variant_fns)*
+                #(#tuple_fns)*
+            }
+        }
+    }
+
+    pub fn enum_expand_type_impl(&self) -> TokenStream {
+        let init = prelude_type("Init");
+        let into_runtime = prelude_type("IntoRuntime");
+        let context = prelude_type("CubeContext");
+        let name = &self.ident;
+        let name_expand = &self.name_expand;
+        let (generics, generic_names, where_clause) = self.generics.split_for_impl();
+        let body = self
+            .flattened_fields()
+            .into_iter()
+            .map(|(ident, _)| quote![#ident: #init::init(self.#ident, context)]);
+        let arms = self.variants().iter().map(|variant| {
+            let ident = &variant.ident;
+            let method = format_ident!("__expand_variant_{}", variant.ident);
+            let fields = variant.flattened_fields();
+            let bindings = fields.iter().map(|(name, field)| match &field.ident {
+                Some(member) => quote![#member: #name],
+                None => quote![#name],
+            });
+            let pattern = match variant.fields.style {
+                Style::Unit => quote![Self::#ident],
+                Style::Tuple => quote![Self::#ident(#(#bindings),*)],
+                Style::Struct => quote![Self::#ident { #(#bindings),* }],
+            };
+            let assign = fields
+                .iter()
+                .map(|(name, _)| quote![expand.#name = #name.__expand_runtime_method(context)]);
+            quote! {
+                #pattern => {
+                    #[allow(unused_mut)]
+                    let mut expand = Self::#method();
+                    #(#assign;)*
+                    expand
+                }
+            }
+        });
+
+        quote! {
+            impl #generics #init for #name_expand #generic_names #where_clause {
+                fn init(self, context: &mut #context) -> Self {
+                    Self {
+                        discriminant: #init::init(self.discriminant, context),
+                        #(#body),*
+                    }
+                }
+            }
+
+            impl #generics #into_runtime for #name #generic_names #where_clause {
+                #[allow(non_snake_case)]
+                fn __expand_runtime_method(self, context: &mut #context) -> Self::ExpandType {
+                    let expand = match self {
+                        #(#arms)*
+                    };
+                    #init::init(expand, context)
+                }
+            }
+        }
+    }
+
+    pub fn enum_launch_ty(&self) -> TokenStream {
+        let launch_arg = prelude_type("LaunchArg");
+        let name = &self.name_launch;
+        let generics = self.expanded_generics();
+        let vis = &self.vis;
+        let fields = self
+            .flattened_fields()
+            .into_iter()
+            .map(|(name, ty)| quote![#name: <#ty as #launch_arg>::RuntimeArg<'a, R>]);
+
+        quote! {
+            #[allow(non_snake_case)]
+            #vis struct #name #generics {
+                discriminant: <u32 as #launch_arg>::RuntimeArg<'a, R>,
+                #(#fields),*
+            }
+        }
+    }
+
+    pub fn enum_launch_new(&self, discriminants: &[u32]) -> TokenStream {
+        let scalar_arg = prelude_type("ScalarArg");
+        let numeric = prelude_type("Numeric");
+        let name = &self.ident;
+        let name_launch = &self.name_launch;
+        let vis = &self.vis;
+        let fields = self.flattened_fields();
+        let (_, type_generic_names, _) = self.generics.split_for_impl();
+
+        let generics = self.expanded_generics();
+        let (generics_impl, generics_use, where_clause) = generics.split_for_impl();
+
+        let arms = self
+            .variants()
+            .iter()
+            .zip(discriminants)
+            .map(|(variant, value)| {
+                let ident = &variant.ident;
+                let active = variant.flattened_fields();
+                let bindings = active.iter().map(|(flat, field)| match &field.ident {
+                    Some(member) => quote![#member: #flat],
+                    None => quote![#flat],
+                });
+                let pattern = match variant.fields.style {
+                    Style::Unit => quote![#name::#ident],
+                    Style::Tuple => quote![#name::#ident(#(#bindings),*)],
+                    Style::Struct => quote![#name::#ident { #(#bindings),* }],
+                };
+                let payload = fields.iter().map(|(flat, ty)| {
+                    if active.iter().any(|(name, _)| name == flat) {
+                        quote![#flat: #scalar_arg::new(#flat)]
+                    } else {
+                        quote![#flat: #scalar_arg::new(<#ty as #numeric>::from_int(0))]
+                    }
+                });
+                quote! {
+                    #pattern => Self {
+                        discriminant: #scalar_arg::new(#value),
+                        #(#payload),*
+                    }
+                }
+            });
+
+        quote! {
+            impl #generics_impl #name_launch #generics_use #where_clause {
+                /// Create the launch argument for the given variant.
+                #[allow(non_snake_case)]
+                #vis fn new(value: #name #type_generic_names) -> Self {
+                    match value {
+                        #(#arms),*
+                    }
+                }
+            }
+        }
+    }
+
+    pub fn enum_arg_settings_impl(&self) -> TokenStream {
+        let arg_settings = prelude_type("ArgSettings");
+        let kernel_launcher = prelude_type("KernelLauncher");
+        let name = &self.name_launch;
+        let register_body = self
+            .flattened_fields()
+            .into_iter()
+            .map(|(ident, _)| quote![#arg_settings::<R>::register(&self.#ident, launcher)]);
+
+        let generics = self.expanded_generics();
+        let (generics, generic_names, where_clause) = generics.split_for_impl();
+
+        quote! {
+            impl #generics #arg_settings<R> for #name #generic_names #where_clause {
+                fn register(&self, launcher: &mut #kernel_launcher<R>) {
+                    #arg_settings::<R>::register(&self.discriminant, launcher);
+                    #(#register_body;)*
+                }
+            }
+        }
+    }
+
+    pub fn enum_launch_arg_impl(&self) -> TokenStream {
+        let launch_arg_expand = prelude_type("LaunchArgExpand");
+        let fields = self.flattened_fields();
+        let body_input = fields.iter().map(
+            |(name, ty)| quote![#name: <#ty as #launch_arg_expand>::expand(builder, vectorization)],
+        );
+
+        let name = &self.ident;
+        let name_launch = &self.name_launch;
+        let name_expand = &self.name_expand;
+
+        let (type_generics, type_generic_names, where_clause) = self.generics.split_for_impl();
+
+        let assoc_generics = self.assoc_generics();
+        let all = self.expanded_generics();
+        let (_, all_generic_names, _) = all.split_for_impl();
+
+        quote! {
+            impl #type_generics LaunchArg for #name #type_generic_names #where_clause {
+                type RuntimeArg #assoc_generics = #name_launch #all_generic_names;
+            }
+
+            impl #type_generics LaunchArgExpand for #name #type_generic_names #where_clause {
+                fn expand(
+                    builder: &mut KernelBuilder,
+                    vectorization: cubecl::ir::Vectorization,
+                ) -> <Self as CubeType>::ExpandType {
+                    #name_expand {
+                        discriminant: <u32 as #launch_arg_expand>::expand(builder, vectorization),
+                        #(#body_input),*
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub(crate) fn generate_cube_enum(codegen: &TypeCodegen, with_launch: bool) -> TokenStream {
+    let discriminants = match enum_discriminants(codegen.variants()) {
+        Ok(discriminants) => discriminants,
+        Err(e) => return e.to_compile_error(),
+    };
+    if let Some(field) = codegen
+        .variants()
+        .iter()
+        .flat_map(|variant| variant.fields.iter())
+        .find(|field| field.comptime.is_present())
+    {
+        return syn::Error::new_spanned(&field.ty, "Enum payloads can't be comptime")
+            .to_compile_error();
+    }
+
+    let expand_ty = codegen.enum_expand_ty();
+    let variants_impl = codegen.enum_variants_impl(&discriminants);
+    let cube_type_impl = codegen.cube_type_impl();
+    let expand_type_impl = codegen.enum_expand_type_impl();
+
+    if with_launch {
+        let launch_ty = codegen.enum_launch_ty();
+        let launch_new = codegen.enum_launch_new(&discriminants);
+        let arg_settings_impl = codegen.enum_arg_settings_impl();
+        let launch_arg_impl = codegen.enum_launch_arg_impl();
+
+        quote! {
+            #expand_ty
+            #variants_impl
+            #launch_ty
+            #launch_new
+
+            #cube_type_impl
+            #arg_settings_impl
+            #launch_arg_impl
+            #expand_type_impl
+        }
+    } else {
+        quote! {
+            #expand_ty
+            #variants_impl
+            #cube_type_impl
+            #expand_type_impl
+        }
+    }
+}
@@ -4,6 +4,7 @@ use quote::quote;
 use syn::{Ident, Type, Visibility};
 
 use crate::{
+    generate::cube_enum::generate_cube_enum,
     parse::cube_type::{TypeCodegen, TypeField},
     paths::{core_type, prelude_type},
 };
@@ -230,6 +231,9 @@ pub(crate) fn generate_cube_type(ast: &syn::DeriveInput, with_launch: bool) -> T
         Ok(codegen) => codegen,
         Err(e) => return e.write_errors(),
     };
+    if codegen.variants.is_some() {
+        return generate_cube_enum(&codegen, with_launch);
+    }
 
     let expand_ty = codegen.expand_ty();
     let launch_ty = codegen.launch_ty();
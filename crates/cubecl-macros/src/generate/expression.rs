@@ -1,10 +1,11 @@
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote, quote_spanned};
-use syn::{spanned::Spanned, Member, PathArguments};
+use syn::{spanned::Spanned, Member, Path, PathArguments};
 
 use crate::{
-    expression::{Block, Expression},
+    expression::{Block, Expression, MatchArm},
     operator::Operator,
+    parse::cube_type::enum_field_name,
     paths::{frontend_path, frontend_type, prelude_type},
     scope::Context,
 };
@@ -85,6 +86,42 @@ impl Expression {
             Expression::Keyword { name } => {
                 quote![#name::expand(context)]
             }
+            Expression::Assert {
+                condition,
+                message,
+                span,
+            } => {
+                let frontend_path = frontend_path();
+                let (args, arg_names) = map_args(core::slice::from_ref(&**condition), context);
+                quote_spanned! {*span=>
+                    if cfg!(debug_assertions) {
+                        #(#args)*
+                        #frontend_path::cube_assert::expand(
+                            context,
+                            #(#arg_names),*,
+                            #message,
+                            concat!(file!(), ":", line!(), ":", column!()),
+                        );
+                    }
+                }
+            }
+            Expression::DebugPrint { format, args, span } => {
+                let frontend_path = frontend_path();
+                let expand_elem = frontend_type("ExpandElement");
+                let arg_names = (0..args.len()).map(|i| format_ident!("_arg_{i}"));
+                let (args, _) = map_args(args, context);
+                quote_spanned! {*span=>
+                    if cfg!(debug_assertions) {
+                        #(#args)*
+                        #frontend_path::cube_debug_print::expand(
+                            context,
+                            #format,
+                            concat!(file!(), ":", line!(), ":", column!()),
+                            vec![#(#expand_elem::from(#arg_names)),*],
+                        );
+                    }
+                }
+            }
             Expression::Variable(var) if var.is_const => {
                 let name = &var.name;
                 let expand_elem = frontend_type("ExpandElementTyped");
@@ -267,6 +304,37 @@ impl Expression {
 
                 quote![#loop_ty::loop_expand(context, |context| #block);]
             }
+            Expression::WhileLoop {
+                condition,
+                block,
+                scope,
+            } => {
+                let loop_ty = frontend_type("branch");
+                let (condition, block) = context.in_fn_mut(scope, |ctx| {
+                    let condition = condition
+                        .as_const(ctx)
+                        .unwrap_or_else(|| condition.to_tokens(ctx));
+                    (condition, block.to_tokens(ctx))
+                });
+
+                quote![#loop_ty::while_loop_expand(context, |context| #condition.into(), |context| #block);]
+            }
+            Expression::IfLet {
+                pat,
+                value,
+                then_block,
+                else_branch,
+            } => {
+                let value = value
+                    .as_const(context)
+                    .unwrap_or_else(|| value.to_tokens(context));
+                let then_block = then_block.to_tokens(context);
+                let else_branch = else_branch
+                    .as_ref()
+                    .map(|it| it.to_tokens(context))
+                    .map(|it| quote![else #it]);
+                quote![if let #pat = #value #then_block #else_branch]
+            }
             Expression::If {
                 condition,
                 then_block,
@@ -327,6 +395,48 @@ impl Expression {
                     }
                 }
             }
+            Expression::Match {
+                value,
+                arms,
+                default,
+                is_enum,
+            } => {
+                let path = frontend_path();
+                let value = value.to_tokens(context);
+                let arms = arms
+                    .iter()
+                    .map(|arm| {
+                        let values = &arm.values;
+                        let block = arm.to_tokens(context);
+                        quote![.case(context, &[#(#values),*], |context| #block)]
+                    })
+                    .collect::<Vec<_>>();
+                let default = default.to_tokens(context);
+                let switch_value = if *is_enum {
+                    quote![_value.discriminant.clone().into()]
+                } else {
+                    quote![_value.into()]
+                };
+                if self.needs_terminator() {
+                    quote! {
+                        {
+                            let _value = #value;
+                            #path::branch::switch_expand_expr(context, #switch_value)
+                                #(#arms)*
+                                .finish(context, |context| #default)
+                        }
+                    }
+                } else {
+                    quote! {
+                        {
+                            let _value = #value;
+                            #path::branch::switch_expand(context, #switch_value)
+                                #(#arms)*
+                                .finish(context, |context| #default);
+                        }
+                    }
+                }
+            }
             Expression::Path { path, .. } => quote![#path],
             Expression::Range {
                 start,
@@ -361,6 +471,10 @@ impl Expression {
                     error!(*span, "Array expressions can't be used at runtime")
                 }
             }
+            Expression::OptionSome { value } => {
+                let value = value.to_tokens(context);
+                quote![Some(#value)]
+            }
             Expression::Tuple { elements, .. } => {
                 if let Some(constant) = self.as_const(context) {
                     constant
@@ -389,6 +503,36 @@ impl Expression {
                     quote![#inner]
                 }
             }
+            Expression::StructInit { path, fields } if is_enum_variant(path) => {
+                let init = frontend_type("Init");
+                let mut ty_path = path.clone();
+                let variant = ty_path.segments.pop().unwrap().into_value().ident;
+                ty_path.segments.pop_punct();
+                let constructor = format_ident!("__expand_variant_{variant}");
+                let fields = fields.iter().map(|(member, value)| {
+                    let field = enum_field_name(&variant, member);
+                    let value = if let Some(as_const) = value.as_const(context) {
+                        let expand_elem = frontend_type("ExpandElementTyped");
+                        quote_spanned![as_const.span()=> #expand_elem::from_lit(#as_const)]
+                    } else {
+                        value.to_tokens(context)
+                    };
+                    quote! {
+                        _variant.#field = {
+                            let _init = #value;
+                            #init::init(_init, context)
+                        };
+                    }
+                });
+
+                quote! {
+                    {
+                        let mut _variant = #ty_path::#constructor();
+                        #(#fields)*
+                        _variant
+                    }
+                }
+            }
             Expression::StructInit { path, fields } => {
                 let cube_type = prelude_type("CubeType");
                 let fields = init_fields(fields, context);
@@ -435,7 +579,10 @@ impl Block {
         let inner: Vec<_> = self.inner.iter().map(|it| it.to_tokens(context)).collect();
         let ret = if let Some(ret) = self.ret.as_ref() {
             let as_const = ret.as_const(context);
-            if let Some(as_const) = as_const {
+            if matches!(&**ret, Expression::Path { path } if path.is_ident("None")) {
+                // `None` has no value to expand, so it can't be used to infer the option type.
+                quote![None]
+            } else if let Some(as_const) = as_const {
                 quote![#as_const.__expand_runtime_method(context)]
             } else {
                 ret.to_tokens(context)
@@ -518,3 +665,44 @@ fn init_fields<'a>(
         }
     })
 }
+
+/// Whether a struct expression constructs an enum variant, which requires the explicit
+/// `Enum::Variant { .. }` or `Self::Variant { .. }` form.
+///
+/// Rust only accepts a type before the last segment of a struct expression when the type is an
+/// enum, so the variant is identified by its parent path alone, whatever the casing of the variant.
+/// The parent resolves to a type when it's `Self`, has generic arguments or is named like a type,
+/// since modules are snake case.
+fn is_enum_variant(path: &Path) -> bool {
+    let mut segments = path.segments.iter().rev();
+
+    match (segments.next(), segments.next()) {
+        (Some(variant), Some(parent)) => {
+            let parent_name = parent.ident.to_string();
+            variant.arguments.is_none()
+                && (parent_name == "Self"
+                    || !parent.arguments.is_none()
+                    || parent_name.starts_with(|ch: char| ch.is_ascii_uppercase()))
+        }
+        _ => false,
+    }
+}
+
+impl MatchArm {
+    pub fn to_tokens(&self, context: &mut Context) -> TokenStream {
+        let block = self.block.to_tokens(context);
+        if self.bindings.is_empty() {
+            return block;
+        }
+        let bindings = self
+            .bindings
+            .iter()
+            .map(|(binding, field)| quote![let #binding = _value.#field.clone();]);
+        quote! {
+            {
+                #(#bindings)*
+                #block
+            }
+        }
+    }
+}
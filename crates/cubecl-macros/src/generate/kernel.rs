@@ -1,8 +1,8 @@
 use darling::usage::{CollectLifetimes as _, CollectTypeParams as _, GenericsExt as _, Purpose};
 use proc_macro2::TokenStream;
-use quote::{format_ident, quote, ToTokens};
+use quote::{format_ident, quote, quote_spanned, ToTokens};
 use std::iter;
-use syn::Ident;
+use syn::{spanned::Spanned, Ident};
 
 use crate::{
     parse::kernel::{KernelFn, KernelParam, KernelSignature, Launch},
@@ -25,6 +25,53 @@ impl KernelFn {
 
         out
     }
+
+    /// Expand the function body once into a separate function, and register a call to it.
+    pub fn noinline_tokens(&mut self, name: &Ident) -> TokenStream {
+        let prelude_path = prelude_path();
+        let function_expand = prelude_type("FunctionExpand");
+        let cube_type = prelude_type("CubeType");
+        let sig = &self.sig;
+        let return_type = &sig.returns;
+        let type_params = sig.generics.type_params().map(|param| &param.ident);
+        let comptime = sig
+            .parameters
+            .iter()
+            .filter(|param| param.is_const)
+            .map(|param| &param.name);
+        let args = sig
+            .parameters
+            .iter()
+            .filter(|param| !param.is_const)
+            .map(|param| {
+                let name = &param.name;
+                if param.is_ref && param.is_mut {
+                    quote_spanned![param.ty.span()=> let #name = __function.arg_mut(context, #name);]
+                } else {
+                    quote![let #name = __function.arg(context, #name);]
+                }
+            });
+        let block = self.block.to_tokens(&mut self.context);
+
+        quote! {
+            #sig {
+                use #prelude_path::IntoRuntime as _;
+
+                let __key = format!(
+                    "{}::{}{}{:?}",
+                    module_path!(),
+                    stringify!(#name),
+                    core::any::type_name::<(#(#type_params,)*)>(),
+                    (#(&#comptime,)*)
+                );
+                let mut __function = #function_expand::new(stringify!(#name), __key);
+                #(#args)*
+                __function.call(context, move |context| -> <#return_type as #cube_type>::ExpandType {
+                    #block
+                })
+            }
+        }
+    }
 }
 
 impl ToTokens for KernelSignature {
@@ -78,24 +125,18 @@ impl Launch {
 
     pub fn io_mappings(&self) -> TokenStream {
         let launch_arg_expand = prelude_type("LaunchArgExpand");
-        let expand_fn = |i, expand_name, vec_name, ty| {
+        let expand_fn = |i, expand_name, ty| {
             quote! {
-                #i => ::std::sync::Arc::new(<#ty as #launch_arg_expand>::#expand_name(builder, settings.#vec_name(#i)))
+                #i => ::std::sync::Arc::new(<#ty as #launch_arg_expand>::#expand_name(builder, settings, #i))
             }
         };
         let inputs = self.runtime_inputs().enumerate().map(|(i, input)| {
-            expand_fn(
-                i,
-                format_ident!("expand"),
-                format_ident!("vectorization_input"),
-                input.ty_owned(),
-            )
+            expand_fn(i, format_ident!("expand_with_settings"), input.ty_owned())
         });
         let outputs = self.runtime_outputs().enumerate().map(|(i, output)| {
             expand_fn(
                 i,
-                format_ident!("expand_output"),
-                format_ident!("vectorization_output"),
+                format_ident!("expand_output_with_settings"),
                 output.ty_owned(),
             )
         });
@@ -19,7 +19,11 @@ impl ToTokens for Launch {
         let kernel = self.kernel_definition();
         let mut func = self.func.clone();
         func.sig.name = format_ident!("expand");
-        let func = func.to_tokens_mut();
+        let func = if self.args.noinline.is_present() {
+            func.noinline_tokens(name)
+        } else {
+            func.to_tokens_mut()
+        };
 
         let out = quote! {
             #vis mod #name {
@@ -1,3 +1,4 @@
+pub mod cube_enum;
 pub mod cube_trait;
 pub mod cube_type;
 pub mod expression;
@@ -51,6 +51,30 @@ impl Statement {
                     quote![let #mutable #name #ty;]
                 }
             }
+            Statement::LetElse { pat, init, diverge } => {
+                let init = init
+                    .as_const(context)
+                    .unwrap_or_else(|| init.to_tokens(context));
+                let stop_expansion = registers_divergence(diverge);
+                let diverge = diverge.to_tokens(context);
+                if stop_expansion {
+                    // A runtime `return` or `break` only registers the branch, so stop expanding
+                    // the rest of the function or loop body as well.
+                    quote! {
+                        #[allow(unreachable_code)]
+                        let #pat = #init else {
+                            #diverge;
+                            return;
+                        };
+                    }
+                } else {
+                    quote! {
+                        let #pat = #init else {
+                            #diverge
+                        };
+                    }
+                }
+            }
             Statement::Expression {
                 expression,
                 terminated,
@@ -68,6 +92,22 @@ impl Statement {
     }
 }
 
+/// Whether the expression ends with a runtime `return` or `break`, which don't diverge when
+/// expanded.
+fn registers_divergence(expr: &Expression) -> bool {
+    match expr {
+        Expression::Return { .. } | Expression::Break => true,
+        Expression::Block(block) => match &block.ret {
+            Some(ret) => registers_divergence(ret),
+            None => matches!(
+                block.inner.last(),
+                Some(Statement::Expression { expression, .. }) if registers_divergence(expression)
+            ),
+        },
+        _ => false,
+    }
+}
+
 fn is_mut_owned(init: &Expression) -> bool {
     match init {
         Expression::Variable(var) => var.is_mut && !var.is_ref,
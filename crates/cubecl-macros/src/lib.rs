@@ -7,7 +7,7 @@ use parse::{
 };
 use proc_macro::TokenStream;
 use quote::quote;
-use syn::{visit_mut::VisitMut, Item};
+use syn::{parse::Parser, punctuated::Punctuated, visit_mut::VisitMut, Item};
 
 mod error;
 mod expression;
@@ -25,6 +25,9 @@ mod statement;
 /// * `launch_unchecked` - generates a launch function without checks
 /// * `debug` - panics after generation to print the output to console
 /// * `create_dummy_kernel` - Generates a function to create a kernel without launching it. Used for testing.
+/// * `noinline` - compiles the function once as a separate function of the kernel and calls it,
+///   instead of inlining its body at every call site. Runtime arguments can be primitives, global
+///   arrays, shared memories and slices, and comptime arguments must implement `Debug`.
 ///
 /// # Example
 ///
@@ -89,6 +92,9 @@ pub fn module_derive_cube_launch(input: TokenStream) -> TokenStream {
 }
 
 /// Derive macro to define a cube type that is not launched
+///
+/// Enums are expanded to a `u32` discriminant along with the flattened payload fields of every
+/// variant, which must be primitives.
 #[proc_macro_derive(CubeType, attributes(expand))]
 pub fn module_derive_cube_type(input: TokenStream) -> TokenStream {
     let input = syn::parse(input).unwrap();
@@ -114,3 +120,47 @@ pub fn comptime(input: TokenStream) -> TokenStream {
     let tokens: proc_macro2::TokenStream = input.into();
     quote![{ #tokens }].into()
 }
+
+/// Assert that a condition holds at runtime, writing the message to the debug output of the
+/// kernel when it doesn't. The debug output is printed by the client after synchronization, or
+/// returned by `read_debug_output`.
+///
+/// The assertion is only compiled with debug assertions, and in checked execution mode. Outside
+/// of a kernel, it behaves like `debug_assert!`.
+///
+/// # Example
+/// ```ignored
+/// #[cube(launch)]
+/// fn kernel(input: &Array<u32>) {
+///     cube_assert!(input[ABSOLUTE_POS] < 10, "input should be smaller than 10");
+/// }
+/// ```
+#[proc_macro]
+pub fn cube_assert(input: TokenStream) -> TokenStream {
+    let tokens: proc_macro2::TokenStream = input.into();
+    quote![debug_assert!(#tokens)].into()
+}
+
+/// Write formatted values to the debug output of the kernel, which is printed by the client after
+/// synchronization. Each `{}` in the format string is replaced by an argument.
+///
+/// The print is only compiled with debug assertions, and in checked execution mode. Outside of a
+/// kernel, the arguments are only evaluated.
+///
+/// # Example
+/// ```ignored
+/// #[cube(launch)]
+/// fn kernel(input: &Array<f32>) {
+///     cube_debug_print!("input[{}] = {}", ABSOLUTE_POS, input[ABSOLUTE_POS]);
+/// }
+/// ```
+#[proc_macro]
+pub fn cube_debug_print(input: TokenStream) -> TokenStream {
+    let parser = Punctuated::<syn::Expr, syn::Token![,]>::parse_terminated;
+    let args = match parser.parse(input) {
+        Ok(args) => args,
+        Err(err) => return err.into_compile_error().into(),
+    };
+    let args = args.into_iter().skip(1);
+    quote![{ let _ = (#(&#args,)*); }].into()
+}
@@ -1,13 +1,23 @@
+use proc_macro2::TokenStream;
 use quote::quote;
-use syn::{spanned::Spanned, ExprForLoop, ExprIf, ExprLoop, Ident};
+use syn::{
+    spanned::Spanned,
+    visit_mut::{self, VisitMut},
+    Expr, ExprForLoop, ExprIf, ExprLet, ExprLoop, ExprMatch, ExprWhile, Ident, Lit, Member, Pat,
+    PatIdent, Path, RangeLimits, Token,
+};
 
 use crate::{
-    expression::{Block, Expression},
+    expression::{Block, Expression, MatchArm},
     scope::Context,
     statement::Statement,
 };
 
-use super::{helpers::Unroll, statement::parse_pat};
+use super::{
+    cube_type::{enum_discriminant_name, enum_field_name},
+    helpers::Unroll,
+    statement::{parse_pat, pat_bindings},
+};
 
 pub fn expand_for_loop(for_loop: ExprForLoop, context: &mut Context) -> syn::Result<Expression> {
     let span = for_loop.span();
@@ -66,6 +76,25 @@ fn expand_for_in_loop(
     Ok(for_loop)
 }
 
+pub fn expand_while_loop(while_loop: ExprWhile, context: &mut Context) -> syn::Result<Expression> {
+    if let Expr::Let(cond) = *while_loop.cond {
+        return Err(syn::Error::new_spanned(
+            cond,
+            "while let loops aren't supported in kernels",
+        ));
+    }
+    let ((condition, block), scope) = context.in_scope(|ctx| {
+        let condition = Expression::from_expr(*while_loop.cond, ctx)?;
+        let block = Block::from_block(while_loop.body, ctx)?;
+        Ok((condition, block))
+    })?;
+    Ok(Expression::WhileLoop {
+        condition: Box::new(condition),
+        block,
+        scope,
+    })
+}
+
 pub fn expand_loop(loop_expr: ExprLoop, context: &mut Context) -> syn::Result<Expression> {
     let (block, scope) = context.in_scope(|ctx| Block::from_block(loop_expr.body, ctx))?;
     Ok(Expression::Loop { block, scope })
@@ -73,6 +102,9 @@ pub fn expand_loop(loop_expr: ExprLoop, context: &mut Context) -> syn::Result<Ex
 
 pub fn expand_if(if_expr: ExprIf, context: &mut Context) -> syn::Result<Expression> {
     let span = if_expr.span();
+    if let Expr::Let(let_expr) = *if_expr.cond {
+        return expand_if_let(let_expr, if_expr.then_branch, if_expr.else_branch, context);
+    }
     let condition = Expression::from_expr(*if_expr.cond, context)
         .map_err(|_| syn::Error::new(span, "Unsupported while condition"))?;
 
@@ -90,6 +122,339 @@ pub fn expand_if(if_expr: ExprIf, context: &mut Context) -> syn::Result<Expressi
     })
 }
 
+fn expand_if_let(
+    let_expr: ExprLet,
+    then_branch: syn::Block,
+    else_branch: Option<(Token![else], Box<Expr>)>,
+    context: &mut Context,
+) -> syn::Result<Expression> {
+    let value = Expression::from_expr(*let_expr.expr, context)?;
+    let is_const = value.is_const();
+    let pat = *let_expr.pat;
+
+    let (then_block, _) = context.in_scope(|ctx| {
+        for (ident, is_mut) in pat_bindings(&pat) {
+            ctx.push_variable(ident, None, is_const && !is_mut, false, is_mut);
+        }
+        Block::from_block(then_branch, ctx)
+    })?;
+    let else_branch = if let Some((_, else_branch)) = else_branch {
+        let (expr, _) = context.in_scope(|ctx| Expression::from_expr(*else_branch, ctx))?;
+        Some(Box::new(expr))
+    } else {
+        None
+    };
+    Ok(Expression::IfLet {
+        pat,
+        value: Box::new(value),
+        then_block,
+        else_branch,
+    })
+}
+
+/// Maximum number of values a range pattern may cover in a runtime match, since each value
+/// becomes a case label.
+const MAX_MATCH_RANGE_LEN: i64 = 1024;
+
+/// Finds a `break` or `continue` that would leave a loop enclosing the expression.
+///
+/// A runtime match is lowered to a switch, where `break` only leaves the switch, so these can't
+/// be supported. Loops nested in the expression are fine, unless the control flow is labeled.
+#[derive(Default)]
+struct LoopControl {
+    span: Option<proc_macro2::Span>,
+    nested_loops: usize,
+}
+
+impl LoopControl {
+    fn find(expr: &Expr) -> Option<proc_macro2::Span> {
+        let mut visitor = Self::default();
+        visitor.visit_expr_mut(&mut expr.clone());
+        visitor.span
+    }
+
+    fn visit_loop(&mut self, visit: impl FnOnce(&mut Self)) {
+        self.nested_loops += 1;
+        visit(self);
+        self.nested_loops -= 1;
+    }
+}
+
+impl VisitMut for LoopControl {
+    fn visit_expr_break_mut(&mut self, i: &mut syn::ExprBreak) {
+        if self.nested_loops == 0 || i.label.is_some() {
+            self.span.get_or_insert(i.span());
+        }
+        visit_mut::visit_expr_break_mut(self, i);
+    }
+
+    fn visit_expr_continue_mut(&mut self, i: &mut syn::ExprContinue) {
+        if self.nested_loops == 0 || i.label.is_some() {
+            self.span.get_or_insert(i.span());
+        }
+    }
+
+    fn visit_expr_for_loop_mut(&mut self, i: &mut ExprForLoop) {
+        self.visit_loop(|this| visit_mut::visit_expr_for_loop_mut(this, i));
+    }
+
+    fn visit_expr_loop_mut(&mut self, i: &mut ExprLoop) {
+        self.visit_loop(|this| visit_mut::visit_expr_loop_mut(this, i));
+    }
+
+    fn visit_expr_while_mut(&mut self, i: &mut ExprWhile) {
+        self.visit_loop(|this| visit_mut::visit_expr_while_mut(this, i));
+    }
+
+    // Closures have their own control flow.
+    fn visit_expr_closure_mut(&mut self, _i: &mut syn::ExprClosure) {}
+}
+
+pub fn expand_match(mat: ExprMatch, context: &mut Context) -> syn::Result<Expression> {
+    let span = mat.span();
+    let value = Expression::from_expr(*mat.expr.clone(), context)?;
+    if value.is_const() {
+        return Ok(Expression::Verbatim {
+            tokens: quote![#mat],
+        });
+    }
+
+    let is_enum = mat.arms.iter().any(|arm| is_enum_pat(&arm.pat));
+    let mut arms = Vec::new();
+    let mut default = None;
+
+    for arm in mat.arms {
+        if let Some((if_token, _)) = arm.guard {
+            return Err(syn::Error::new_spanned(
+                if_token,
+                "Match guards aren't supported at runtime",
+            ));
+        }
+        if let Some(span) = LoopControl::find(&arm.body) {
+            return Err(syn::Error::new(
+                span,
+                "`break` and `continue` can't leave a loop from a runtime match arm",
+            ));
+        }
+
+        let pattern = if is_enum {
+            parse_enum_pat(arm.pat)?
+        } else {
+            parse_match_pat(arm.pat)?.map(|values| {
+                let values = values.into_iter().map(|value| quote![#value]).collect();
+                (values, Vec::new())
+            })
+        };
+        let bindings = pattern
+            .as_ref()
+            .map(|(_, bindings)| bindings.clone())
+            .unwrap_or_default();
+        let (block, _) = context.in_scope(|ctx| {
+            for (binding, _) in bindings.iter() {
+                ctx.push_variable(binding.clone(), None, false, false, false);
+            }
+            match *arm.body {
+                Expr::Block(block) => Block::from_block(block.block, ctx),
+                body => {
+                    let ret = Expression::from_expr(body, ctx)?;
+                    Ok(Block {
+                        inner: Vec::new(),
+                        ty: ret.ty(),
+                        ret: Some(Box::new(ret)),
+                    })
+                }
+            }
+        })?;
+
+        match pattern {
+            Some((values, bindings)) => arms.push(MatchArm {
+                values,
+                bindings,
+                block,
+            }),
+            None => {
+                // Arms after a wildcard can never match.
+                default = Some(MatchArm {
+                    values: Vec::new(),
+                    bindings: Vec::new(),
+                    block,
+                });
+                break;
+            }
+        }
+    }
+
+    // Without a wildcard the arms are exhaustive, so the last one can act as the default.
+    let default = match default {
+        Some(default) => default,
+        None => arms
+            .pop()
+            .ok_or_else(|| syn::Error::new(span, "Runtime match must have at least one arm"))?,
+    };
+
+    Ok(Expression::Match {
+        value: Box::new(value),
+        arms,
+        default,
+        is_enum,
+    })
+}
+
+fn is_enum_pat(pat: &Pat) -> bool {
+    match pat {
+        Pat::Path(_) | Pat::TupleStruct(_) | Pat::Struct(_) => true,
+        Pat::Or(or) => or.cases.iter().any(is_enum_pat),
+        Pat::Paren(paren) => is_enum_pat(&paren.pat),
+        _ => false,
+    }
+}
+
+/// Parse the discriminants matched by a runtime enum pattern along with the payload fields it
+/// binds, or [None] for a wildcard.
+#[allow(clippy::type_complexity)]
+fn parse_enum_pat(pat: Pat) -> syn::Result<Option<(Vec<TokenStream>, Vec<(Ident, Ident)>)>> {
+    let (variant, bindings) = match pat {
+        Pat::Wild(_) => return Ok(None),
+        Pat::Path(path) => (enum_variant(&path.path)?, Vec::new()),
+        Pat::TupleStruct(tuple) => {
+            let variant = enum_variant(&tuple.path)?;
+            let mut bindings = Vec::new();
+            for (index, elem) in tuple.elems.into_iter().enumerate() {
+                if matches!(elem, Pat::Rest(_)) {
+                    break;
+                }
+                let member = Member::Unnamed(index.into());
+                if let Some(binding) = parse_enum_binding(elem)? {
+                    bindings.push((binding, enum_field_name(&variant, &member)));
+                }
+            }
+            (variant, bindings)
+        }
+        Pat::Struct(pat) => {
+            let variant = enum_variant(&pat.path)?;
+            let mut bindings = Vec::new();
+            for field in pat.fields {
+                if let Some(binding) = parse_enum_binding(*field.pat)? {
+                    bindings.push((binding, enum_field_name(&variant, &field.member)));
+                }
+            }
+            (variant, bindings)
+        }
+        Pat::Or(or) => {
+            let mut values = Vec::new();
+            for case in or.cases {
+                match parse_enum_pat(case)? {
+                    Some((_, bindings)) if !bindings.is_empty() => Err(syn::Error::new_spanned(
+                        &bindings[0].0,
+                        "Or-patterns can't bind payload fields at runtime",
+                    ))?,
+                    Some((case, _)) => values.extend(case),
+                    None => return Ok(None),
+                }
+            }
+            return Ok(Some((values, Vec::new())));
+        }
+        Pat::Paren(paren) => return parse_enum_pat(*paren.pat),
+        pat => Err(syn::Error::new_spanned(
+            pat,
+            "Only enum variants and wildcards are supported in runtime enum match patterns",
+        ))?,
+    };
+    let discriminant = enum_discriminant_name(&variant);
+    Ok(Some((vec![quote![_value.#discriminant()]], bindings)))
+}
+
+fn enum_variant(path: &Path) -> syn::Result<Ident> {
+    path.segments
+        .last()
+        .map(|segment| segment.ident.clone())
+        .ok_or_else(|| syn::Error::new_spanned(path, "Expected an enum variant"))
+}
+
+/// Parse the binding of a payload field, or [None] if the field is ignored.
+fn parse_enum_binding(pat: Pat) -> syn::Result<Option<Ident>> {
+    match pat {
+        Pat::Wild(_) => Ok(None),
+        Pat::Ident(PatIdent {
+            ident,
+            by_ref: None,
+            mutability: None,
+            subpat: None,
+            ..
+        }) => Ok(Some(ident)),
+        pat => Err(syn::Error::new_spanned(
+            pat,
+            "Only plain identifiers and wildcards can bind payload fields at runtime",
+        )),
+    }
+}
+
+/// Parse the integer values matched by a runtime match pattern, or [None] for a wildcard.
+fn parse_match_pat(pat: Pat) -> syn::Result<Option<Vec<i64>>> {
+    let values = match pat {
+        Pat::Wild(_) => return Ok(None),
+        Pat::Lit(lit) => vec![parse_match_int(&lit.lit)?],
+        Pat::Range(range) => {
+            let (Some(start), Some(end)) = (range.start.as_deref(), range.end.as_deref()) else {
+                return Err(syn::Error::new_spanned(
+                    range,
+                    "Only bounded range patterns are supported at runtime",
+                ));
+            };
+            let start = parse_match_bound(start)?;
+            let end = match range.limits {
+                RangeLimits::Closed(_) => parse_match_bound(end)?,
+                RangeLimits::HalfOpen(_) => parse_match_bound(end)? - 1,
+            };
+            if end - start >= MAX_MATCH_RANGE_LEN {
+                return Err(syn::Error::new_spanned(
+                    range,
+                    format!(
+                        "Range patterns can cover at most {MAX_MATCH_RANGE_LEN} values at runtime"
+                    ),
+                ));
+            }
+            (start..=end).collect()
+        }
+        Pat::Or(or) => {
+            let mut values = Vec::new();
+            for case in or.cases {
+                match parse_match_pat(case)? {
+                    Some(case) => values.extend(case),
+                    None => return Ok(None),
+                }
+            }
+            values
+        }
+        Pat::Paren(paren) => return parse_match_pat(*paren.pat),
+        pat => Err(syn::Error::new_spanned(
+            pat,
+            "Only integer literals, ranges and wildcards are supported in runtime match patterns",
+        ))?,
+    };
+    Ok(Some(values))
+}
+
+fn parse_match_bound(expr: &Expr) -> syn::Result<i64> {
+    match expr {
+        Expr::Lit(lit) => parse_match_int(&lit.lit),
+        expr => Err(syn::Error::new_spanned(
+            expr,
+            "Range pattern bounds must be integer literals",
+        )),
+    }
+}
+
+fn parse_match_int(lit: &Lit) -> syn::Result<i64> {
+    match lit {
+        Lit::Int(int) => int.base10_parse(),
+        lit => Err(syn::Error::new_spanned(
+            lit,
+            "Only integer literals are supported in runtime match patterns",
+        )),
+    }
+}
+
 impl Block {
     pub fn from_block(block: syn::Block, context: &mut Context) -> syn::Result<Self> {
         let mut statements = block
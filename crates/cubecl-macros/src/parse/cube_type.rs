@@ -1,20 +1,30 @@
 use std::iter;
 
-use darling::{ast::Data, util::Flag, FromDeriveInput, FromField};
+use darling::{
+    ast::{Data, Fields},
+    util::Flag,
+    FromDeriveInput, FromField, FromVariant,
+};
 use quote::format_ident;
-use syn::{parse_quote, punctuated::Punctuated, Generics, Ident, Type, Visibility};
+use syn::{
+    parse_quote, punctuated::Punctuated, Expr, ExprLit, Generics, Ident, Lit, Member, Type,
+    Visibility,
+};
 
 use crate::paths::prelude_type;
 
 #[derive(FromDeriveInput)]
-#[darling(supports(struct_named), attributes(expand), map = unwrap_fields)]
+#[darling(supports(struct_named, enum_any), attributes(expand), map = unwrap_fields)]
 pub struct TypeCodegen {
     pub ident: Ident,
     pub name_launch: Option<Ident>,
     pub name_expand: Option<Ident>,
-    data: Data<(), TypeField>,
+    data: Data<TypeVariant, TypeField>,
     #[darling(skip)]
     pub fields: Vec<TypeField>,
+    /// The variants when deriving an enum, [None] for structs.
+    #[darling(skip)]
+    pub variants: Option<Vec<TypeVariant>>,
     pub generics: Generics,
     pub vis: Visibility,
 }
@@ -28,10 +38,20 @@ pub struct TypeField {
     pub comptime: Flag,
 }
 
+#[derive(FromVariant, Clone)]
+#[darling(attributes(expand))]
+pub struct TypeVariant {
+    pub ident: Ident,
+    pub fields: Fields<TypeField>,
+    pub discriminant: Option<Expr>,
+}
+
 fn unwrap_fields(mut ty: TypeCodegen) -> TypeCodegen {
     // This will be supported inline with the next darling release
-    let fields = ty.data.as_ref().take_struct().unwrap().fields;
-    ty.fields = fields.into_iter().cloned().collect();
+    match &ty.data {
+        Data::Struct(fields) => ty.fields = fields.fields.clone(),
+        Data::Enum(variants) => ty.variants = Some(variants.clone()),
+    }
 
     let name = &ty.ident;
     ty.name_expand
@@ -57,3 +77,60 @@ impl TypeCodegen {
         parse_quote![<'a, R: #runtime>]
     }
 }
+
+impl TypeVariant {
+    /// The payload fields with the name they are flattened to in the expand type.
+    pub fn flattened_fields(&self) -> Vec<(Ident, &TypeField)> {
+        self.fields
+            .iter()
+            .enumerate()
+            .map(|(index, field)| {
+                let member = match &field.ident {
+                    Some(ident) => Member::Named(ident.clone()),
+                    None => Member::Unnamed(index.into()),
+                };
+                (enum_field_name(&self.ident, &member), field)
+            })
+            .collect()
+    }
+}
+
+/// Compute the discriminant of each variant, following the Rust rules for implicit discriminants.
+pub fn enum_discriminants(variants: &[TypeVariant]) -> syn::Result<Vec<u32>> {
+    let mut next = Some(0u32);
+    variants
+        .iter()
+        .map(|variant| {
+            let value: u32 = match &variant.discriminant {
+                Some(Expr::Lit(ExprLit {
+                    lit: Lit::Int(lit), ..
+                })) => lit.base10_parse()?,
+                Some(expr) => Err(syn::Error::new_spanned(
+                    expr,
+                    "Only unsigned integer literals are supported as discriminants",
+                ))?,
+                None => next.ok_or_else(|| {
+                    syn::Error::new_spanned(
+                        &variant.ident,
+                        "The implicit discriminant overflows u32, specify it explicitly",
+                    )
+                })?,
+            };
+            next = value.checked_add(1);
+            Ok(value)
+        })
+        .collect()
+}
+
+/// The name of an enum payload field once flattened into the expand type.
+pub fn enum_field_name(variant: &Ident, member: &Member) -> Ident {
+    match member {
+        Member::Named(ident) => format_ident!("__{variant}_{ident}"),
+        Member::Unnamed(index) => format_ident!("__{variant}_{}", index.index),
+    }
+}
+
+/// The name of the method returning the discriminant of a variant on the expand type.
+pub fn enum_discriminant_name(variant: &Ident) -> Ident {
+    format_ident!("__discriminant_{variant}")
+}
@@ -5,19 +5,11 @@ use syn::{
     parse_quote,
     spanned::Spanned,
     visit_mut::{self, VisitMut},
-    Expr, ExprLoop, ExprWhile, Index, Local, LocalInit, Pat, PatStruct, PatTuple, PatTupleStruct,
-    Stmt,
+    Index, Local, LocalInit, Pat, PatStruct, PatTuple, PatTupleStruct, Stmt,
 };
 
 pub struct Desugar;
 impl VisitMut for Desugar {
-    fn visit_expr_mut(&mut self, i: &mut syn::Expr) {
-        if let Expr::While(inner) = i {
-            *i = Expr::Loop(desugar_while(inner))
-        }
-        visit_mut::visit_expr_mut(self, i);
-    }
-
     fn visit_block_mut(&mut self, i: &mut syn::Block) {
         let stmts = desugar_pats(take(&mut i.stmts));
 
@@ -33,34 +25,18 @@ fn desugar_pats(stmts: Vec<Stmt>) -> Vec<Stmt> {
                 pat: Pat::Struct(pat),
                 init: Some(init),
                 ..
-            }) => desugar_struct_destructure(pat, init),
+            }) if init.diverge.is_none() => desugar_struct_destructure(pat, init),
             Stmt::Local(Local {
                 pat:
                     Pat::Tuple(PatTuple { elems, .. }) | Pat::TupleStruct(PatTupleStruct { elems, .. }),
                 init: Some(init),
                 ..
-            }) => desugar_tuple_destructure(elems, init),
+            }) if init.diverge.is_none() => desugar_tuple_destructure(elems, init),
             stmt => vec![stmt],
         }
     }).collect()
 }
 
-fn desugar_while(inner: &ExprWhile) -> ExprLoop {
-    let cond = &inner.cond;
-    let attrs = &inner.attrs;
-    let label = &inner.label;
-    let body = &inner.body;
-    parse_quote! {
-        #(#attrs)*
-        #label loop {
-            if !(#cond) {
-                break;
-            }
-            #body
-        }
-    }
-}
-
 fn desugar_struct_destructure(pat: PatStruct, init: LocalInit) -> Vec<Stmt> {
     let fields = pat.fields.into_iter().map(|field| {
         let attrs = field.attrs;
@@ -1,8 +1,8 @@
 use proc_macro2::Span;
 use quote::{format_ident, quote, quote_spanned, ToTokens};
 use syn::{
-    parse_quote, spanned::Spanned, Expr, ExprUnary, Lit, LitInt, Path, PathSegment, RangeLimits,
-    Type, UnOp,
+    parse_quote, punctuated::Punctuated, spanned::Spanned, Expr, ExprLit, ExprUnary, Lit, LitInt,
+    Macro, Path, PathSegment, RangeLimits, Token, Type, UnOp,
 };
 
 use crate::{
@@ -12,7 +12,7 @@ use crate::{
 };
 
 use super::{
-    branch::{expand_for_loop, expand_if, expand_loop},
+    branch::{expand_for_loop, expand_if, expand_loop, expand_match, expand_while_loop},
     operator::{parse_binop, parse_unop},
 };
 
@@ -94,6 +94,19 @@ impl Expression {
                     .map(|arg| Expression::from_expr(arg, context))
                     .collect::<Result<Vec<_>, _>>()?;
                 match *func {
+                    // `Some` only wraps the expanded value, the variant is known at expansion time.
+                    Expression::Path { path } if path.is_ident("Some") && args.len() == 1 => {
+                        let value = args.into_iter().next().unwrap();
+                        if value.is_const() {
+                            Expression::Verbatim {
+                                tokens: quote![#expr],
+                            }
+                        } else {
+                            Expression::OptionSome {
+                                value: Box::new(value),
+                            }
+                        }
+                    }
                     Expression::Path { path } if is_intrinsic(&path) => {
                         Expression::CompilerIntrinsic { func: path, args }
                     }
@@ -158,6 +171,7 @@ impl Expression {
             Expr::Continue(cont) => Expression::Continue(cont.span()),
             Expr::ForLoop(for_loop) => expand_for_loop(for_loop, context)?,
             Expr::Loop(loop_expr) => expand_loop(loop_expr, context)?,
+            Expr::While(while_loop) => expand_while_loop(while_loop, context)?,
             Expr::If(if_expr) => expand_if(if_expr, context)?,
             Expr::Range(range) => {
                 let span = range.span();
@@ -278,26 +292,19 @@ impl Expression {
                     ))?
                 }
             }
-            Expr::Match(mat) => {
-                let span = mat.span();
-                let elem = Expression::from_expr(*mat.expr.clone(), context)?;
-                if elem.is_const() {
-                    Expression::Verbatim {
-                        tokens: quote![#mat],
-                    }
-                } else {
-                    Err(syn::Error::new(
-                        span,
-                        "match expressions aren't yet supported at runtime",
-                    ))?
-                }
-            }
+            Expr::Match(mat) => expand_match(mat, context)?,
             Expr::Macro(mac) if is_comptime_macro(&mac.mac.path) => {
                 let tokens = mac.mac.tokens;
                 Expression::Verbatim {
                     tokens: quote![{ #tokens }],
                 }
             }
+            Expr::Macro(mac) if is_macro(&mac.mac.path, "cube_assert") => {
+                expand_assert(mac.mac, context)?
+            }
+            Expr::Macro(mac) if is_macro(&mac.mac.path, "cube_debug_print") => {
+                expand_debug_print(mac.mac, context)?
+            }
             Expr::Macro(mac) => Expression::Verbatim {
                 tokens: quote![#mac],
             },
@@ -432,7 +439,10 @@ fn is_slice(index: &Expression) -> bool {
 
 fn fn_associated_type(path: &Expression) -> Option<(Path, PathSegment)> {
     // All supported primitives. Primitives don't start with an uppercase letter
-    const PRIMITIVES: &[&str] = &["bool", "i32", "i64", "u32", "f16", "bf16", "f32", "f64"];
+    const PRIMITIVES: &[&str] = &[
+        "bool", "i8", "i16", "i32", "i64", "u8", "u16", "u32", "u64", "f16", "bf16", "f32", "f64",
+        "F8E4M3", "F8E5M2",
+    ];
     if !matches!(path, Expression::Path { .. }) {
         panic!("path: {path:?}");
     }
@@ -457,6 +467,65 @@ fn fn_associated_type(path: &Expression) -> Option<(Path, PathSegment)> {
 }
 
 fn is_comptime_macro(path: &Path) -> bool {
+    is_macro(path, "comptime")
+}
+
+fn is_macro(path: &Path, name: &str) -> bool {
     let path = path.to_token_stream().to_string();
-    "::cubecl::comptime".ends_with(&path)
+    format!(":: cubecl :: {name}").ends_with(&path)
+}
+
+fn expand_assert(mac: Macro, context: &mut Context) -> syn::Result<Expression> {
+    let span = mac.span();
+    let args = mac.parse_body_with(Punctuated::<Expr, Token![,]>::parse_terminated)?;
+    let mut args = args.into_iter();
+    let condition = args
+        .next()
+        .ok_or_else(|| syn::Error::new(span, "Expected a condition"))?;
+    let message = match args.next() {
+        Some(Expr::Lit(ExprLit {
+            lit: Lit::Str(message),
+            ..
+        })) => quote![#message],
+        Some(other) => Err(syn::Error::new_spanned(other, "Expected a string literal"))?,
+        None => quote![concat!("assertion failed: ", stringify!(#condition))],
+    };
+    if let Some(extra) = args.next() {
+        Err(syn::Error::new_spanned(extra, "Unexpected argument"))?;
+    }
+
+    Ok(Expression::Assert {
+        condition: Box::new(Expression::from_expr(condition, context)?),
+        message,
+        span,
+    })
+}
+
+fn expand_debug_print(mac: Macro, context: &mut Context) -> syn::Result<Expression> {
+    let span = mac.span();
+    let args = mac.parse_body_with(Punctuated::<Expr, Token![,]>::parse_terminated)?;
+    let mut args = args.into_iter();
+    let format = match args.next() {
+        Some(Expr::Lit(ExprLit {
+            lit: Lit::Str(format),
+            ..
+        })) => format,
+        _ => Err(syn::Error::new(span, "Expected a format string"))?,
+    };
+    let args = args
+        .map(|arg| Expression::from_expr(arg, context))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let placeholders = format.value().matches("{}").count();
+    if placeholders != args.len() {
+        Err(syn::Error::new_spanned(
+            &format,
+            format!(
+                "{placeholders} placeholders but {} arguments were provided",
+                args.len()
+            ),
+        ))?;
+    }
+
+    Ok(Expression::DebugPrint { format, args, span })
 }
@@ -15,6 +15,7 @@ pub(crate) struct KernelArgs {
     pub launch_unchecked: Flag,
     pub debug: Flag,
     pub create_dummy_kernel: Flag,
+    pub noinline: Flag,
 }
 
 pub fn from_tokens<T: FromMeta>(tokens: TokenStream) -> syn::Result<T> {
@@ -161,6 +162,13 @@ impl Launch {
     pub fn from_item_fn(function: ItemFn, args: KernelArgs) -> syn::Result<Self> {
         let runtime = prelude_type("Runtime");
 
+        if args.noinline.is_present() && args.is_launch() {
+            Err(syn::Error::new_spanned(
+                &function.sig.ident,
+                "A kernel can't be `noinline`, only the functions it calls",
+            ))?;
+        }
+
         let vis = function.vis;
         let func = KernelFn::from_sig_and_block(function.sig, *function.block)?;
         let mut kernel_generics = func.sig.generics.clone();
@@ -1,5 +1,5 @@
 use quote::format_ident;
-use syn::{Pat, Stmt, Type, TypeReference};
+use syn::{Expr, ExprMacro, Ident, Local, LocalInit, Pat, Stmt, Type, TypeReference};
 
 use crate::{
     expression::Expression,
@@ -10,6 +10,28 @@ use crate::{
 impl Statement {
     pub fn from_stmt(stmt: Stmt, context: &mut Context) -> syn::Result<Self> {
         let statement = match stmt {
+            Stmt::Local(Local {
+                pat,
+                init:
+                    Some(LocalInit {
+                        expr,
+                        diverge: Some((_, diverge)),
+                        ..
+                    }),
+                ..
+            }) => {
+                let init = Expression::from_expr(*expr, context)?;
+                let (diverge, _) = context.in_scope(|ctx| Expression::from_expr(*diverge, ctx))?;
+                let is_const = init.is_const();
+                for (ident, is_mut) in pat_bindings(&pat) {
+                    context.push_variable(ident, None, is_const && !is_mut, false, is_mut);
+                }
+                Self::LetElse {
+                    pat,
+                    init: Box::new(init),
+                    diverge: Box::new(diverge),
+                }
+            }
             Stmt::Local(local) => {
                 let init = local
                     .init
@@ -35,8 +57,18 @@ impl Statement {
                     expression,
                 }
             }
+            Stmt::Macro(stmt) => {
+                let expr = Expr::Macro(ExprMacro {
+                    attrs: stmt.attrs,
+                    mac: stmt.mac,
+                });
+                let expression = Box::new(Expression::from_expr(expr, context)?);
+                Statement::Expression {
+                    terminated: stmt.semi_token.is_some() || !expression.needs_terminator(),
+                    expression,
+                }
+            }
             Stmt::Item(_) => Statement::Skip,
-            stmt => Err(syn::Error::new_spanned(stmt, "Unsupported statement"))?,
         };
         Ok(statement)
     }
@@ -81,3 +113,30 @@ pub fn parse_pat(pat: Pat) -> syn::Result<Pattern> {
     };
     Ok(res)
 }
+
+/// Collect the identifiers bound by a refutable pattern, along with whether they're mutable.
+pub fn pat_bindings(pat: &Pat) -> Vec<(Ident, bool)> {
+    match pat {
+        Pat::Ident(ident) => {
+            let mut bindings = vec![(ident.ident.clone(), ident.mutability.is_some())];
+            if let Some((_, subpat)) = &ident.subpat {
+                bindings.extend(pat_bindings(subpat));
+            }
+            bindings
+        }
+        Pat::Tuple(tuple) => tuple.elems.iter().flat_map(pat_bindings).collect(),
+        Pat::TupleStruct(tuple) => tuple.elems.iter().flat_map(pat_bindings).collect(),
+        Pat::Struct(pat) => pat
+            .fields
+            .iter()
+            .flat_map(|field| pat_bindings(&field.pat))
+            .collect(),
+        Pat::Slice(slice) => slice.elems.iter().flat_map(pat_bindings).collect(),
+        // All cases of an or-pattern must bind the same names.
+        Pat::Or(or) => or.cases.first().map(pat_bindings).unwrap_or_default(),
+        Pat::Paren(paren) => pat_bindings(&paren.pat),
+        Pat::Reference(reference) => pat_bindings(&reference.pat),
+        Pat::Type(pat) => pat_bindings(&pat.pat),
+        _ => Vec::new(),
+    }
+}
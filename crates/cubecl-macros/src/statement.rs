@@ -1,5 +1,5 @@
 use crate::{expression::Expression, scope::ManagedVar};
-use syn::{Ident, Type};
+use syn::{Ident, Pat, Type};
 
 #[derive(Clone, Debug)]
 pub enum Statement {
@@ -7,6 +7,12 @@ pub enum Statement {
         variable: ManagedVar,
         init: Option<Box<Expression>>,
     },
+    /// A `let ... else`, resolved at expansion time like an `if let`.
+    LetElse {
+        pat: Pat,
+        init: Box<Expression>,
+        diverge: Box<Expression>,
+    },
     Expression {
         expression: Box<Expression>,
         terminated: bool,
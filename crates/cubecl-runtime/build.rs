@@ -4,5 +4,6 @@ fn main() {
     // Setup cfg aliases
     cfg_aliases! {
         autotune_persistent_cache: { all(feature = "std", any(target_os = "windows", target_os = "linux", target_os = "macos")) },
+        persistent_compilation_cache: { all(feature = "std", any(target_os = "windows", target_os = "linux", target_os = "macos")) },
     }
 }
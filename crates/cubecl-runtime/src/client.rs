@@ -4,8 +4,12 @@ use crate::{
     storage::ComputeStorage,
     ExecutionMode,
 };
+use alloc::boxed::Box;
 use alloc::sync::Arc;
 use alloc::vec::Vec;
+use core::any::{Any, TypeId};
+use hashbrown::HashMap;
+use spin::Mutex;
 
 pub use cubecl_common::sync_type::SyncType;
 
@@ -15,6 +19,20 @@ pub use cubecl_common::sync_type::SyncType;
 pub struct ComputeClient<Server: ComputeServer, Channel> {
     channel: Channel,
     settings: Arc<(Server::FeatureSet, Server::Properties)>,
+    pending_reads: Arc<Mutex<Vec<PendingRead<Server>>>>,
+    extensions: Arc<Mutex<HashMap<TypeId, Arc<dyn Any + Send + Sync>>>>,
+}
+
+/// A read performed after the next synchronization of the client.
+struct PendingRead<Server: ComputeServer> {
+    binding: Binding<Server>,
+    callback: Box<dyn FnOnce(Vec<u8>) + Send>,
+}
+
+impl<Server: ComputeServer> core::fmt::Debug for PendingRead<Server> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("PendingRead")
+    }
 }
 
 impl<S, C> Clone for ComputeClient<S, C>
@@ -26,6 +44,8 @@ where
         Self {
             channel: self.channel.clone(),
             settings: self.settings.clone(),
+            pending_reads: self.pending_reads.clone(),
+            extensions: self.extensions.clone(),
         }
     }
 }
@@ -44,6 +64,8 @@ where
         Self {
             channel,
             settings: Arc::new((features, properties)),
+            pending_reads: Arc::new(Mutex::new(Vec::new())),
+            extensions: Arc::new(Mutex::new(HashMap::new())),
         }
     }
 
@@ -60,6 +82,37 @@ where
         cubecl_common::reader::read_sync(self.channel.read(binding))
     }
 
+    /// Read the given binding after the next [synchronization](Self::sync) waiting for the
+    /// completion of every task, and pass its content to the callback.
+    pub fn read_on_sync(
+        &self,
+        binding: Binding<Server>,
+        callback: impl FnOnce(Vec<u8>) + Send + 'static,
+    ) {
+        self.pending_reads.lock().push(PendingRead {
+            binding,
+            callback: Box::new(callback),
+        });
+    }
+
+    /// Get the extension of type `T` attached to the client, attaching the result of `init` the
+    /// first time.
+    ///
+    /// Extensions hold the state that crates built on the client share between launches, such as
+    /// reused buffers. Every clone of the client shares the same extensions.
+    pub fn extension<T: Send + Sync + 'static>(&self, init: impl FnOnce() -> T) -> Arc<T> {
+        let extension = self
+            .extensions
+            .lock()
+            .entry(TypeId::of::<T>())
+            .or_insert_with(|| Arc::new(init()))
+            .clone();
+
+        extension
+            .downcast()
+            .expect("Extensions are stored by their type")
+    }
+
     /// Given a resource handle, returns the storage resource.
     pub fn get_resource(
         &self,
@@ -108,7 +161,14 @@ where
 
     /// Wait for the completion of every task in the server.
     pub fn sync(&self, sync_type: SyncType) {
-        self.channel.sync(sync_type)
+        self.channel.sync(sync_type);
+
+        if let SyncType::Wait = sync_type {
+            let pending_reads = core::mem::take(&mut *self.pending_reads.lock());
+            for read in pending_reads {
+                (read.callback)(self.read(read.binding));
+            }
+        }
     }
 
     /// Get the features supported by the compute server.
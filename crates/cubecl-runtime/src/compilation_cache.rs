@@ -0,0 +1,368 @@
+#[cfg(persistent_compilation_cache)]
+mod std_imports {
+    pub use std::fs;
+    pub use std::io;
+    pub use std::path::Path;
+    pub use std::path::PathBuf;
+    pub use std::time::SystemTime;
+}
+#[cfg(persistent_compilation_cache)]
+use std_imports::*;
+
+#[cfg(persistent_compilation_cache)]
+use serde::{Deserialize, Serialize};
+
+use alloc::vec::Vec;
+
+/// The default maximum size of a compilation cache on disk, in bytes.
+pub const DEFAULT_COMPILATION_CACHE_SIZE: u64 = 512 * 1024 * 1024;
+
+#[cfg(persistent_compilation_cache)]
+/// Return the directory of the compilation cache on disk
+/// prefix should identify the compiler and the device at the backend level
+pub fn get_compilation_cache_dir(prefix: &str) -> PathBuf {
+    default_root_dir().join(prefix)
+}
+
+#[cfg(persistent_compilation_cache)]
+fn default_root_dir() -> PathBuf {
+    let home_dir = dirs::home_dir().expect("An home directory should exist");
+    home_dir
+        .join(".cache")
+        .join("cubecl")
+        .join("compilation")
+        .join(env!("CARGO_PKG_VERSION"))
+}
+
+/// Metadata of a cache entry, stored next to its binary
+#[cfg(persistent_compilation_cache)]
+#[derive(Debug, Serialize, Deserialize)]
+struct PersistentCacheEntry {
+    checksum: String,
+    source: String,
+}
+
+/// Persist compiled kernels on disk, so they aren't compiled again by following processes.
+///
+/// Entries are looked up with a stable key along with the source they were compiled from: an
+/// entry compiled from a different source is ignored and replaced on the next insert. The source
+/// is stored next to the binary produced by the compiler, such as PTX for CUDA. When the cache
+/// grows larger than its maximum size, the least recently used entries are removed.
+///
+/// The size of the cache is computed from the disk on the first insert, then only updated with
+/// the inserted entries, so the disk is only scanned again when the cache seems too large.
+///
+/// The cache does nothing on platforms without a file system.
+#[derive(Debug)]
+pub struct CompilationCache {
+    #[cfg(persistent_compilation_cache)]
+    dir: PathBuf,
+    #[cfg(persistent_compilation_cache)]
+    prefix: String,
+    #[cfg(persistent_compilation_cache)]
+    max_size: u64,
+    #[cfg(persistent_compilation_cache)]
+    size: Option<u64>,
+}
+
+impl CompilationCache {
+    /// Create a new compilation cache.
+    ///
+    /// The name should identify the backend and the compiler version, while the device id
+    /// should identify the device the kernels are compiled for.
+    pub fn new(
+        #[cfg_attr(not(persistent_compilation_cache), allow(unused_variables))] name: &str,
+        #[cfg_attr(not(persistent_compilation_cache), allow(unused_variables))] device_id: &str,
+    ) -> Self {
+        #[cfg(persistent_compilation_cache)]
+        {
+            let prefix = format!("{}/{}", name, device_id);
+            CompilationCache {
+                dir: get_compilation_cache_dir(&prefix),
+                prefix,
+                max_size: DEFAULT_COMPILATION_CACHE_SIZE,
+                size: None,
+            }
+        }
+
+        #[cfg(not(persistent_compilation_cache))]
+        {
+            CompilationCache {}
+        }
+    }
+
+    /// Set the maximum size of the cache on disk, in bytes.
+    pub fn with_max_size(
+        #[cfg_attr(not(persistent_compilation_cache), allow(unused_mut))] mut self,
+        #[cfg_attr(not(persistent_compilation_cache), allow(unused_variables))] max_size: u64,
+    ) -> Self {
+        #[cfg(persistent_compilation_cache)]
+        {
+            self.max_size = max_size;
+        }
+        self
+    }
+
+    /// Store the cache in the given directory instead of the cubecl cache directory of the home
+    /// directory. Caches of different names and devices can share the same directory.
+    #[cfg(feature = "std")]
+    pub fn with_dir(
+        #[cfg_attr(not(persistent_compilation_cache), allow(unused_mut))] mut self,
+        #[cfg_attr(not(persistent_compilation_cache), allow(unused_variables))]
+        dir: std::path::PathBuf,
+    ) -> Self {
+        #[cfg(persistent_compilation_cache)]
+        {
+            self.dir = dir.join(&self.prefix);
+            self.size = None;
+        }
+        self
+    }
+
+    /// Get the binary of the given key, if it was compiled from the same source.
+    pub fn get(
+        &self,
+        #[cfg_attr(not(persistent_compilation_cache), allow(unused_variables))] key: &str,
+        #[cfg_attr(not(persistent_compilation_cache), allow(unused_variables))] source: &str,
+    ) -> Option<Vec<u8>> {
+        #[cfg(persistent_compilation_cache)]
+        {
+            match self.load(key, source) {
+                Ok(entry) => entry,
+                Err(e) => {
+                    log::warn!("Unable to load compilation cache entry ({}).", e);
+                    None
+                }
+            }
+        }
+
+        #[cfg(not(persistent_compilation_cache))]
+        None
+    }
+
+    /// Insert the binary of the given key compiled from the source, replacing any previous entry.
+    pub fn insert(
+        &mut self,
+        #[cfg_attr(not(persistent_compilation_cache), allow(unused_variables))] key: &str,
+        #[cfg_attr(not(persistent_compilation_cache), allow(unused_variables))] source: &str,
+        #[cfg_attr(not(persistent_compilation_cache), allow(unused_variables))] binary: &[u8],
+    ) {
+        #[cfg(persistent_compilation_cache)]
+        {
+            let result = self
+                .save(key, source, binary)
+                .and_then(|(replaced, written)| {
+                    let size = match self.size {
+                        Some(size) if size.saturating_sub(replaced) + written <= self.max_size => {
+                            size.saturating_sub(replaced) + written
+                        }
+                        _ => self.evict(key)?,
+                    };
+                    self.size = Some(size);
+                    Ok(())
+                });
+            if let Err(e) = result {
+                log::warn!("Unable to save compilation cache entry ({}).", e);
+            }
+        }
+    }
+
+    /// Remove every entry of the cache.
+    pub fn clear(&self) {
+        #[cfg(persistent_compilation_cache)]
+        {
+            if let Err(e) = fs::remove_dir_all(&self.dir) {
+                if e.kind() != io::ErrorKind::NotFound {
+                    log::warn!("Unable to clear compilation cache ({}).", e);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(persistent_compilation_cache)]
+impl CompilationCache {
+    /// Return the directory of the cache on disk
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    fn paths(&self, key: &str) -> (PathBuf, PathBuf) {
+        let name = format!("{:x}", md5::compute(key));
+        (
+            self.dir.join(format!("{name}.json")),
+            self.dir.join(format!("{name}.bin")),
+        )
+    }
+
+    fn load(&self, key: &str, source: &str) -> Result<Option<Vec<u8>>, io::Error> {
+        let (metadata_path, binary_path) = self.paths(key);
+        let metadata = match fs::read_to_string(&metadata_path) {
+            Ok(data) => data,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(e),
+        };
+        let metadata: PersistentCacheEntry = serde_json::from_str(&metadata)?;
+        if metadata.checksum != checksum(source) {
+            return Ok(None);
+        }
+        let binary = fs::read(&binary_path)?;
+
+        // Keep track of the last use of the entry for the eviction.
+        fs::File::options()
+            .append(true)
+            .open(&metadata_path)?
+            .set_modified(SystemTime::now())?;
+
+        Ok(Some(binary))
+    }
+
+    /// Save the entry, returning the sizes of the replaced entry and of the new one.
+    fn save(&self, key: &str, source: &str, binary: &[u8]) -> io::Result<(u64, u64)> {
+        fs::create_dir_all(&self.dir)?;
+        let (metadata_path, binary_path) = self.paths(key);
+        let metadata = PersistentCacheEntry {
+            checksum: checksum(source),
+            source: source.to_string(),
+        };
+        let metadata = serde_json::to_string(&metadata)?;
+        let replaced = entry_size(&metadata_path, &binary_path).unwrap_or(0);
+
+        // The binary is written first, so a metadata file always has a matching binary.
+        fs::write(binary_path, binary)?;
+        fs::write(metadata_path, &metadata)?;
+
+        Ok((replaced, (binary.len() + metadata.len()) as u64))
+    }
+
+    /// Remove the least recently used entries until the cache fits its maximum size, keeping
+    /// the entry of the given key, and return the remaining size.
+    fn evict(&self, key: &str) -> io::Result<u64> {
+        let (kept, _) = self.paths(key);
+        let mut entries = Vec::new();
+        let mut size = 0;
+
+        for file in fs::read_dir(&self.dir)? {
+            let path = file?.path();
+            if path.extension().and_then(|ext| ext.to_str()) != Some("json") {
+                continue;
+            }
+            let binary_path = path.with_extension("bin");
+            let entry_size = entry_size(&path, &binary_path)?;
+            let modified = fs::metadata(&path)?.modified()?;
+            size += entry_size;
+            if path != kept {
+                entries.push((modified, entry_size, path, binary_path));
+            }
+        }
+
+        entries.sort_by_key(|(modified, ..)| *modified);
+
+        for (_, entry_size, path, binary_path) in entries {
+            if size <= self.max_size {
+                break;
+            }
+            fs::remove_file(path)?;
+            let _ = fs::remove_file(binary_path);
+            size -= entry_size;
+        }
+
+        Ok(size)
+    }
+}
+
+#[cfg(persistent_compilation_cache)]
+fn entry_size(metadata_path: &Path, binary_path: &Path) -> io::Result<u64> {
+    let binary_size = fs::metadata(binary_path)
+        .map(|meta| meta.len())
+        .unwrap_or(0);
+    Ok(fs::metadata(metadata_path)?.len() + binary_size)
+}
+
+#[cfg(persistent_compilation_cache)]
+fn checksum(source: &str) -> String {
+    format!("{:x}", md5::compute(source))
+}
+
+#[cfg(all(test, persistent_compilation_cache))]
+mod tests {
+    use super::*;
+
+    fn cache(name: &str) -> CompilationCache {
+        let cache = CompilationCache {
+            dir: std::env::temp_dir()
+                .join("cubecl-compilation-cache-test")
+                .join(name),
+            prefix: name.to_string(),
+            max_size: DEFAULT_COMPILATION_CACHE_SIZE,
+            size: None,
+        };
+        cache.clear();
+        cache
+    }
+
+    /// Set the last use of the entry, since consecutive uses can share the same modification
+    /// time on disk.
+    fn set_last_use(cache: &CompilationCache, key: &str, secs_ago: u64) {
+        let (metadata_path, _) = cache.paths(key);
+        let time = SystemTime::now() - std::time::Duration::from_secs(secs_ago);
+        fs::File::options()
+            .append(true)
+            .open(metadata_path)
+            .unwrap()
+            .set_modified(time)
+            .unwrap();
+    }
+
+    #[test]
+    fn compilation_cache_hit_with_same_source() {
+        let mut cache = cache("hit");
+        cache.insert("kernel", "source", &[1; 8]);
+
+        assert_eq!(cache.get("kernel", "source"), Some(vec![1; 8]));
+        assert_eq!(cache.get("other", "source"), None);
+        cache.clear();
+    }
+
+    #[test]
+    fn compilation_cache_miss_with_different_source() {
+        let mut cache = cache("source");
+        cache.insert("kernel", "source", &[1; 8]);
+
+        assert_eq!(cache.get("kernel", "changed"), None);
+        cache.insert("kernel", "changed", &[2; 8]);
+        assert_eq!(cache.get("kernel", "changed"), Some(vec![2; 8]));
+        cache.clear();
+    }
+
+    #[test]
+    fn compilation_cache_evicts_least_recently_used() {
+        let mut cache = cache("evict").with_max_size(2500);
+        cache.insert("first", "first", &[1; 1000]);
+        cache.insert("second", "second", &[1; 1000]);
+        assert!(cache.get("first", "first").is_some());
+        set_last_use(&cache, "first", 60);
+        set_last_use(&cache, "second", 120);
+
+        cache.insert("third", "third", &[1; 1000]);
+
+        assert!(cache.get("first", "first").is_some());
+        assert!(cache.get("second", "second").is_none());
+        assert!(cache.get("third", "third").is_some());
+        cache.clear();
+    }
+
+    #[test]
+    fn compilation_cache_in_given_dir() {
+        let dir = std::env::temp_dir()
+            .join("cubecl-compilation-cache-test")
+            .join("dir");
+        let mut cache = CompilationCache::new("backend", "device").with_dir(dir.clone());
+        cache.clear();
+        cache.insert("kernel", "source", &[1; 8]);
+
+        assert_eq!(cache.dir(), dir.join("backend/device"));
+        assert_eq!(cache.get("kernel", "source"), Some(vec![1; 8]));
+        cache.clear();
+    }
+}
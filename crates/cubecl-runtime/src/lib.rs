@@ -18,6 +18,9 @@ pub mod client;
 /// Autotune module
 pub mod tune;
 
+/// Persistent compilation cache module.
+pub mod compilation_cache;
+
 /// Memory management module.
 pub mod memory_management;
 /// Compute server module.
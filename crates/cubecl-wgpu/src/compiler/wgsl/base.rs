@@ -1,4 +1,7 @@
-use cubecl_core::ir::{self as cube, ConstantScalarValue, FloatKind, IntKind};
+use cubecl_core::{
+    frontend::{F8E4M3, F8E5M2},
+    ir::{self as cube, ConstantScalarValue, FloatKind, IntKind, UIntKind},
+};
 use std::fmt::Display;
 
 #[derive(Debug, Clone, PartialEq)]
@@ -56,8 +59,15 @@ pub enum Variable {
 #[derive(Debug, Clone, PartialEq, Eq, Copy)]
 pub enum Elem {
     F32,
+    AtomicF32,
+    E4M3,
+    E5M2,
+    I8,
+    I16,
     I32,
     AtomicI32,
+    U8,
+    U16,
     U32,
     AtomicU32,
     Bool,
@@ -124,6 +134,27 @@ impl Variable {
             index,
         }
     }
+
+    pub fn is_array(&self) -> bool {
+        match self {
+            Variable::GlobalInputArray(_, _)
+            | Variable::GlobalOutputArray(_, _)
+            | Variable::SharedMemory(_, _, _)
+            | Variable::Slice { .. }
+            | Variable::LocalArray(_, _, _, _) => true,
+            Variable::Named { is_array, .. } => *is_array,
+            _ => false,
+        }
+    }
+
+    /// Whether several lines of this array share the same 32 bits word. The words are then
+    /// accessed atomically, since other units can write the other lines of a word.
+    pub fn has_shared_words(&self) -> bool {
+        self.is_array()
+            && !matches!(self, Variable::LocalArray(..))
+            && self.item().lines_per_word().is_some()
+    }
+
     pub fn is_atomic(&self) -> bool {
         match self {
             Variable::GlobalInputArray(_, item) => item.elem().is_atomic(),
@@ -198,14 +229,112 @@ impl Item {
             Item::Scalar(_) => 1,
         }
     }
+
+    /// The item used to store this item in arrays.
+    ///
+    /// WGSL doesn't have 8 and 16 bits types, so their lines are packed in 32 bits words. Lines
+    /// smaller than a word are stored in the low bits of a word, several lines sharing the same
+    /// word in global and shared memory (see [lines_per_word](Item::lines_per_word)).
+    pub fn storage(&self) -> Item {
+        let bits = match self.elem().packed_bits() {
+            Some(bits) => bits,
+            None => return *self,
+        };
+
+        match self.vectorization_factor() * bits {
+            8 | 16 | 32 => Item::Scalar(Elem::U32),
+            64 => Item::Vec2(Elem::U32),
+            _ => {
+                unreachable!("{self:?} can't be stored in arrays, which is checked at compilation")
+            }
+        }
+    }
+
+    /// Whether lines of this item can be stored in arrays, packed elements requiring lines that
+    /// divide or fill 32 or 64 bits.
+    pub fn has_storage(&self) -> bool {
+        match self.elem().packed_bits() {
+            Some(bits) => matches!(self.vectorization_factor() * bits, 8 | 16 | 32 | 64),
+            None => true,
+        }
+    }
+
+    /// The number of lines packed in each 32 bits word of global and shared arrays, when lines
+    /// are smaller than a word.
+    pub fn lines_per_word(&self) -> Option<usize> {
+        let bits = self.elem().packed_bits()?;
+
+        match self.vectorization_factor() * bits {
+            line_bits @ (8 | 16) => Some(32 / line_bits),
+            _ => None,
+        }
+    }
+
+    /// Unpack a line of this item from its [storage](Item::storage).
+    pub fn unpack(&self, storage: impl Display) -> String {
+        let elem = *self.elem();
+        let bits = match elem.packed_bits() {
+            Some(bits) => bits,
+            None => return storage.to_string(),
+        };
+        let multiple_words = self.vectorization_factor() * bits > 32;
+        let lanes = (0..self.vectorization_factor())
+            .map(|i| {
+                let offset = (i * bits) % 32;
+                let word = match multiple_words {
+                    true => format!("{storage}[{}]", i * bits / 32),
+                    false => format!("{storage}"),
+                };
+                elem.unpack_word(word, offset, bits)
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        format!("{self}({lanes})")
+    }
+
+    /// Pack the lanes of a value of this item in its [storage](Item::storage).
+    pub fn pack(&self, lanes: &[String]) -> String {
+        let elem = *self.elem();
+        let bits = match elem.packed_bits() {
+            Some(bits) => bits,
+            None => panic!("{elem:?} isn't packed"),
+        };
+        let mask = (1u64 << bits) - 1;
+        let words = lanes
+            .chunks(32 / bits)
+            .map(|lanes| {
+                lanes
+                    .iter()
+                    .enumerate()
+                    .map(|(i, lane)| match i {
+                        0 => format!("({} & {mask}u)", elem.bits_of(lane)),
+                        _ => format!("(({} & {mask}u) << {}u)", elem.bits_of(lane), i * bits),
+                    })
+                    .collect::<Vec<_>>()
+                    .join(" | ")
+            })
+            .collect::<Vec<_>>();
+
+        match words.len() {
+            1 => words[0].clone(),
+            _ => format!("{}({})", self.storage(), words.join(", ")),
+        }
+    }
 }
 
 impl Elem {
     pub fn size(&self) -> usize {
         match self {
             Self::F32 => core::mem::size_of::<f32>(),
+            Self::AtomicF32 => core::mem::size_of::<f32>(),
+            // Packed types are widened to 32 bits outside of arrays.
+            Self::E4M3 | Self::E5M2 => core::mem::size_of::<f32>(),
+            // Packed integers are widened to 32 bits outside of arrays.
+            Self::I8 | Self::I16 => core::mem::size_of::<i32>(),
             Self::I32 => core::mem::size_of::<i32>(),
             Self::AtomicI32 => core::mem::size_of::<i32>(),
+            Self::U8 | Self::U16 => core::mem::size_of::<u32>(),
             Self::U32 => core::mem::size_of::<u32>(),
             Self::AtomicU32 => core::mem::size_of::<u32>(),
             Self::Bool => core::mem::size_of::<bool>(),
@@ -213,17 +342,64 @@ impl Elem {
     }
 
     pub fn is_atomic(&self) -> bool {
-        matches!(self, Self::AtomicI32 | Self::AtomicU32)
+        matches!(self, Self::AtomicF32 | Self::AtomicI32 | Self::AtomicU32)
+    }
+
+    /// The number of bits of the values packed in 32 bits words in arrays.
+    pub fn packed_bits(&self) -> Option<usize> {
+        match self {
+            Self::I8 | Self::U8 | Self::E4M3 | Self::E5M2 => Some(8),
+            Self::I16 | Self::U16 => Some(16),
+            _ => None,
+        }
+    }
+
+    /// Cast a value to this element, wrapping packed integers to their number of bits and
+    /// rounding 8 bits floats to the nearest representable value.
+    pub fn cast(&self, value: impl Display) -> String {
+        match self {
+            Self::E4M3 => format!("e4m3_to_f32(f32_to_e4m3(f32({value})))"),
+            Self::E5M2 => format!("e5m2_to_f32(f32_to_e5m2(f32({value})))"),
+            _ => match self.packed_bits() {
+                Some(bits) => format!("extractBits({self}({value}), 0u, {bits}u)"),
+                None => format!("{self}({value})"),
+            },
+        }
+    }
+
+    /// The bits of a value of this element, stored in the lowest bits of a `u32`.
+    fn bits_of(&self, value: impl Display) -> String {
+        match self {
+            Self::E4M3 => format!("f32_to_e4m3({value})"),
+            Self::E5M2 => format!("f32_to_e5m2({value})"),
+            _ => format!("u32({value})"),
+        }
+    }
+
+    /// Read the packed integer at the given offset of a 32 bits word, extending its sign when
+    /// it's signed.
+    fn unpack_word(&self, word: impl Display, offset: usize, bits: usize) -> String {
+        match self {
+            Self::I8 | Self::I16 => {
+                format!("extractBits(bitcast<i32>({word}), {offset}u, {bits}u)")
+            }
+            Self::E4M3 => format!("e4m3_to_f32(extractBits({word}, {offset}u, {bits}u))"),
+            Self::E5M2 => format!("e5m2_to_f32(extractBits({word}, {offset}u, {bits}u))"),
+            _ => format!("extractBits({word}, {offset}u, {bits}u)"),
+        }
     }
 }
 
 impl Display for Elem {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            Self::F32 => f.write_str("f32"),
-            Self::I32 => f.write_str("i32"),
+            // WGSL doesn't have 8 and 16 bits types, so they are widened to 32 bits.
+            Self::F32 | Self::E4M3 | Self::E5M2 => f.write_str("f32"),
+            // WGSL doesn't have float atomics, so their bits are stored in an unsigned atomic.
+            Self::AtomicF32 => f.write_str("atomic<u32>"),
+            Self::I8 | Self::I16 | Self::I32 => f.write_str("i32"),
             Self::AtomicI32 => f.write_str("atomic<i32>"),
-            Self::U32 => f.write_str("u32"),
+            Self::U8 | Self::U16 | Self::U32 => f.write_str("u32"),
             Self::AtomicU32 => f.write_str("atomic<u32>"),
             Self::Bool => f.write_str("bool"),
         }
@@ -266,16 +442,28 @@ impl Display for Variable {
             Variable::GlobalOutputArray(number, _) => {
                 f.write_fmt(format_args!("output_{number}_global"))
             }
-            Variable::GlobalScalar(number, _, elem) => {
-                f.write_fmt(format_args!("scalars_{elem}[{number}]"))
-            }
+            Variable::GlobalScalar(number, elem_wgsl, elem) => match elem_wgsl.packed_bits() {
+                Some(bits) => {
+                    let per_word = 32 / bits as u16;
+                    let word = format!("scalars_{elem}[{}]", number / per_word);
+                    let offset = (number % per_word) as usize * bits;
+                    f.write_str(&elem_wgsl.unpack_word(word, offset, bits))
+                }
+                None => f.write_fmt(format_args!("scalars_{elem}[{number}]")),
+            },
             // We do the conversion in Rust and then render the number to avoid overflow or other
             // precision related problems.
             Variable::ConstantScalar(number, _elem) => match number {
                 ConstantScalarValue::Int(val, kind) => match kind {
+                    IntKind::I8 => f.write_fmt(format_args!("{}i", *val as i8)),
+                    IntKind::I16 => f.write_fmt(format_args!("{}i", *val as i16)),
                     IntKind::I32 => f.write_fmt(format_args!("{}i", *val as i32)),
                     IntKind::I64 => f.write_fmt(format_args!("{}i", { *val })),
                 },
+                // WGSL has no literal for infinities and NaN, so their f32 bits are bitcast instead.
+                ConstantScalarValue::Float(val, _kind) if !val.is_finite() => f.write_fmt(
+                    format_args!("bitcast<f32>({:#x}u)", (*val as f32).to_bits()),
+                ),
                 ConstantScalarValue::Float(val, kind) => match kind {
                     FloatKind::F16 => {
                         todo!("Unsupported")
@@ -285,8 +473,21 @@ impl Display for Variable {
                     }
                     FloatKind::F32 => f.write_fmt(format_args!("{}f", *val as f32)),
                     FloatKind::F64 => f.write_fmt(format_args!("{}f", { *val })),
+                    FloatKind::E4M3 => {
+                        f.write_fmt(format_args!("{}f", F8E4M3::from_f64(*val).to_f32()))
+                    }
+                    FloatKind::E5M2 => {
+                        f.write_fmt(format_args!("{}f", F8E5M2::from_f64(*val).to_f32()))
+                    }
+                },
+                ConstantScalarValue::UInt(val, kind) => match kind {
+                    UIntKind::U8 => f.write_fmt(format_args!("{}u", *val as u8)),
+                    UIntKind::U16 => f.write_fmt(format_args!("{}u", *val as u16)),
+                    UIntKind::U32 => f.write_fmt(format_args!("{}u", *val as u32)),
+                    UIntKind::U64 => {
+                        unreachable!("u64 is not a valid WgpuElement")
+                    }
                 },
-                ConstantScalarValue::UInt(val) => f.write_fmt(format_args!("{}u", *val as u32)),
                 ConstantScalarValue::Bool(val) => f.write_fmt(format_args!("{}", val)),
             },
             Variable::SharedMemory(number, _, _) => {
@@ -1,8 +1,9 @@
 use super::{shader::ComputeShader, Item, SharedMemory};
-use super::{LocalArray, Subgroup};
+use super::{Function, LocalArray, Subgroup};
 use crate::compiler::wgsl;
 use cubecl_core::ir as cube;
 use cubecl_runtime::ExecutionMode;
+use std::collections::HashMap;
 
 /// Wgsl Compiler.
 #[derive(Clone, Default)]
@@ -24,6 +25,12 @@ pub struct WgslCompiler {
     num_workgroup_no_axis: bool,
     shared_memories: Vec<SharedMemory>,
     local_arrays: Vec<LocalArray>,
+    float8: Vec<wgsl::Elem>,
+    function: Option<u32>,
+    function_names: Vec<String>,
+    slice_sources: HashMap<(Option<u32>, u16, u8), wgsl::Variable>,
+    function_slices: HashMap<(u32, usize), wgsl::Variable>,
+    strategy: ExecutionMode,
 }
 
 impl core::fmt::Debug for WgslCompiler {
@@ -35,8 +42,11 @@ impl core::fmt::Debug for WgslCompiler {
 impl cubecl_core::Compiler for WgslCompiler {
     type Representation = ComputeShader;
 
-    fn compile(shader: cube::KernelDefinition, _mode: ExecutionMode) -> Self::Representation {
-        let mut compiler = Self::default();
+    fn compile(shader: cube::KernelDefinition, mode: ExecutionMode) -> Self::Representation {
+        let mut compiler = Self {
+            strategy: mode,
+            ..Self::default()
+        };
         compiler.compile_shader(shader)
     }
 
@@ -47,15 +57,28 @@ impl cubecl_core::Compiler for WgslCompiler {
     fn max_shared_memory_size() -> usize {
         32768
     }
+
+    fn extension() -> &'static str {
+        "wgsl"
+    }
 }
 
 impl WgslCompiler {
     fn compile_shader(&mut self, mut value: cube::KernelDefinition) -> wgsl::ComputeShader {
+        // Debug output isn't written in unchecked mode, so the debug buffer isn't bound.
+        if let ExecutionMode::Unchecked = self.strategy {
+            value.named.retain(|(name, _)| name != "debug");
+        }
         self.num_inputs = value.inputs.len();
         self.num_outputs = value.outputs.len();
+        self.function_names = value
+            .functions
+            .iter()
+            .map(|function| format!("{}_{}", function.name, function.id))
+            .collect();
 
         let instructions = self.compile_scope(&mut value.body);
-        let extensions = register_extensions(&instructions);
+        let mut extensions = register_extensions(&instructions);
         let body = wgsl::Body {
             instructions,
             rank: true,
@@ -63,6 +86,25 @@ impl WgslCompiler {
             stride: self.stride,
             shape: self.shape,
         };
+        let local_arrays = core::mem::take(&mut self.local_arrays);
+
+        // Functions are compiled after the kernel body, so the arrays sliced by the callers are
+        // known when compiling a function.
+        let functions: Vec<Function> = value
+            .functions
+            .into_iter()
+            .map(|function| self.compile_function(function))
+            .collect();
+        for function in functions.iter() {
+            for extension in register_extensions(&function.body.instructions) {
+                if !extensions.contains(&extension) {
+                    extensions.push(extension);
+                }
+            }
+        }
+        for elem in self.float8.iter() {
+            extensions.push(wgsl::Extension::Float8(*elem));
+        }
 
         wgsl::ComputeShader {
             inputs: value
@@ -81,7 +123,8 @@ impl WgslCompiler {
                 .map(|(name, binding)| (name, Self::compile_binding(binding)))
                 .collect(),
             shared_memories: self.shared_memories.clone(),
-            local_arrays: self.local_arrays.clone(),
+            local_arrays,
+            functions,
             workgroup_size: value.cube_dim,
             global_invocation_id: self.global_invocation_id || self.id,
             local_invocation_index: self.local_invocation_index,
@@ -100,6 +143,162 @@ impl WgslCompiler {
         }
     }
 
+    fn compile_function(&mut self, mut function: cube::FunctionDefinition) -> Function {
+        let local_arrays = core::mem::take(&mut self.local_arrays);
+        let flags = (self.id, self.stride, self.shape);
+        (self.id, self.stride, self.shape) = (false, false, false);
+        self.function = Some(function.id);
+
+        let mut instructions = Vec::new();
+        let processing = function.body.process();
+
+        for var in processing.variables {
+            if let cube::Variable::Slice { .. } = var {
+                continue;
+            }
+
+            instructions.push(wgsl::Instruction::DeclareVariable {
+                var: self.compile_variable(var),
+            });
+        }
+
+        // Parameters are immutable in WGSL, so they are copied to the variables of the body.
+        let mut params = Vec::with_capacity(function.params.len());
+        for (index, param) in function.params.into_iter().enumerate() {
+            let name = format!("arg_{index}");
+            let named = |name: String, item: Item| wgsl::Variable::Named {
+                name,
+                item,
+                is_array: false,
+            };
+
+            match param {
+                cube::Variable::Slice { id, depth, .. } => {
+                    let input = self
+                        .function_slices
+                        .get(&(function.id, index))
+                        .cloned()
+                        .expect("Slice parameters should be passed by a call");
+                    self.slice_sources
+                        .insert((self.function, id, depth), input.clone());
+
+                    let length = Item::Scalar(wgsl::Elem::U32);
+                    params.push((format!("{name}_offset"), length));
+                    params.push((format!("{name}_length"), length));
+                    instructions.push(wgsl::Instruction::Slice {
+                        input,
+                        start: named(format!("{name}_offset"), length),
+                        end: named(format!("({name}_offset + {name}_length)"), length),
+                        out: self.compile_variable(param),
+                    });
+                }
+                param => {
+                    let out = self.compile_variable(param);
+                    params.push((name.clone(), out.item()));
+                    instructions.push(wgsl::Instruction::Assign {
+                        input: named(name, out.item()),
+                        out,
+                    });
+                }
+            }
+        }
+
+        processing
+            .operations
+            .into_iter()
+            .for_each(|op| self.compile_operation(&mut instructions, op, &mut function.body));
+
+        let output = function.output.map(|var| self.compile_variable(var));
+        let body = wgsl::Body {
+            instructions,
+            rank: true,
+            id: self.id,
+            stride: self.stride,
+            shape: self.shape,
+        };
+
+        self.function = None;
+        (self.id, self.stride, self.shape) = flags;
+
+        Function {
+            name: self.function_names[function.id as usize].clone(),
+            params,
+            output,
+            local_arrays: core::mem::replace(&mut self.local_arrays, local_arrays),
+            body,
+        }
+    }
+
+    fn compile_debug(&mut self, op: cube::DebugOperation) -> wgsl::Instruction {
+        match op {
+            cube::DebugOperation::Assert { condition, format } => wgsl::Instruction::DebugRecord {
+                format,
+                condition: Some(self.compile_variable(condition)),
+                args: Vec::new(),
+            },
+            cube::DebugOperation::Print { format, args } => wgsl::Instruction::DebugRecord {
+                format,
+                condition: None,
+                args: args
+                    .into_iter()
+                    .map(|arg| self.compile_variable(arg))
+                    .collect(),
+            },
+        }
+    }
+
+    fn compile_call(&mut self, call: cube::Call) -> wgsl::Instruction {
+        let mut args = Vec::with_capacity(call.args.len());
+
+        for (index, arg) in call.args.into_iter().enumerate() {
+            if let cube::Variable::Slice { id, depth, .. } = arg {
+                // Slices are passed as an offset and a length into the array they were created
+                // from, since storage pointers can't be passed to functions.
+                let input = self
+                    .slice_sources
+                    .get(&(self.function, id, depth))
+                    .cloned()
+                    .expect("Slice should be created before being passed to a function");
+                if let Some(previous) = self
+                    .function_slices
+                    .insert((call.function, index), input.clone())
+                {
+                    assert_eq!(
+                        previous, input,
+                        "A function can't be called with slices of different arrays"
+                    );
+                }
+
+                let slice = self.compile_variable(arg);
+                for suffix in ["offset", "length"] {
+                    args.push(wgsl::Variable::Named {
+                        name: format!("{slice}_{suffix}"),
+                        item: Item::Scalar(wgsl::Elem::U32),
+                        is_array: false,
+                    });
+                }
+            } else {
+                args.push(self.compile_variable(arg));
+            }
+        }
+
+        wgsl::Instruction::Call {
+            function: self.function_names[call.function as usize].clone(),
+            args,
+            out: call.out.map(|var| self.compile_variable(var)),
+        }
+    }
+
+    fn compile_array_item(item: cube::Item) -> Item {
+        let item = Self::compile_item(item);
+        assert!(
+            item.has_storage(),
+            "Arrays of {item:?} aren't supported on wgpu, since their elements are packed in 32 \
+             bits words that lines must divide or fill"
+        );
+        item
+    }
+
     fn compile_item(item: cube::Item) -> Item {
         let elem = Self::compile_elem(item.elem);
         match item.vectorization.map(|it| it.get()).unwrap_or(1) {
@@ -118,25 +317,47 @@ impl WgslCompiler {
                 cube::FloatKind::BF16 => panic!("bf16 is not a valid WgpuElement"),
                 cube::FloatKind::F32 => wgsl::Elem::F32,
                 cube::FloatKind::F64 => panic!("f64 is not a valid WgpuElement"),
+                cube::FloatKind::E4M3 => wgsl::Elem::E4M3,
+                cube::FloatKind::E5M2 => wgsl::Elem::E5M2,
             },
             cube::Elem::Int(i) => match i {
+                cube::IntKind::I8 => wgsl::Elem::I8,
+                cube::IntKind::I16 => wgsl::Elem::I16,
                 cube::IntKind::I32 => wgsl::Elem::I32,
                 cube::IntKind::I64 => panic!("i64 is not a valid WgpuElement"),
             },
-            cube::Elem::UInt => wgsl::Elem::U32,
+            cube::Elem::UInt(u) => match u {
+                cube::UIntKind::U8 => wgsl::Elem::U8,
+                cube::UIntKind::U16 => wgsl::Elem::U16,
+                cube::UIntKind::U32 => wgsl::Elem::U32,
+                cube::UIntKind::U64 => panic!("u64 is not a valid WgpuElement"),
+            },
             cube::Elem::Bool => wgsl::Elem::Bool,
+            cube::Elem::AtomicFloat(f) => match f {
+                cube::FloatKind::F32 => wgsl::Elem::AtomicF32,
+                _ => panic!("atomic<{f:?}> is not a valid WgpuElement"),
+            },
             cube::Elem::AtomicInt(i) => match i {
                 cube::IntKind::I32 => wgsl::Elem::AtomicI32,
-                cube::IntKind::I64 => panic!("atomic<i64> is not a valid WgpuElement"),
+                _ => panic!("atomic<{i:?}> is not a valid WgpuElement"),
             },
             cube::Elem::AtomicUInt => wgsl::Elem::AtomicU32,
         }
     }
 
     pub(crate) fn compile_variable(&mut self, value: cube::Variable) -> wgsl::Variable {
+        // 8 bits floats are converted in software, by functions added to the shader.
+        let elem = value.item().elem;
+        if let cube::Elem::Float(cube::FloatKind::E4M3 | cube::FloatKind::E5M2) = elem {
+            let elem = Self::compile_elem(elem);
+            if !self.float8.contains(&elem) {
+                self.float8.push(elem);
+            }
+        }
+
         match value {
             cube::Variable::GlobalInputArray { id, item } => {
-                wgsl::Variable::GlobalInputArray(id, Self::compile_item(item))
+                wgsl::Variable::GlobalInputArray(id, Self::compile_array_item(item))
             }
             cube::Variable::GlobalScalar { id, elem } => {
                 wgsl::Variable::GlobalScalar(id, Self::compile_elem(elem), elem)
@@ -157,13 +378,13 @@ impl WgslCompiler {
                 depth,
             },
             cube::Variable::GlobalOutputArray { id, item } => {
-                wgsl::Variable::GlobalOutputArray(id, Self::compile_item(item))
+                wgsl::Variable::GlobalOutputArray(id, Self::compile_array_item(item))
             }
             cube::Variable::ConstantScalar(value) => {
                 wgsl::Variable::ConstantScalar(value, Self::compile_elem(value.elem()))
             }
             cube::Variable::SharedMemory { id, item, length } => {
-                let item = Self::compile_item(item);
+                let item = Self::compile_array_item(item);
                 if !self.shared_memories.iter().any(|s| s.index == id) {
                     self.shared_memories
                         .push(SharedMemory::new(id, item, length));
@@ -176,7 +397,7 @@ impl WgslCompiler {
                 depth,
                 length,
             } => {
-                let item = Self::compile_item(item);
+                let item = Self::compile_array_item(item);
                 if !self.local_arrays.iter().any(|s| s.index == id) {
                     self.local_arrays
                         .push(LocalArray::new(id, item, depth, length));
@@ -309,6 +530,12 @@ impl WgslCompiler {
             cube::Operation::CoopMma(_) => {
                 panic!("Cooperative matrix-multiply and accumulate isn't supported on wgpu.")
             }
+            cube::Operation::Call(call) => instructions.push(self.compile_call(call)),
+            cube::Operation::Debug(op) => {
+                if let ExecutionMode::Checked = self.strategy {
+                    instructions.push(self.compile_debug(op))
+                }
+            }
         }
     }
 
@@ -350,6 +577,38 @@ impl WgslCompiler {
                 input: self.compile_variable(op.input),
                 out: self.compile_variable(op.out),
             },
+            cube::Subcube::InclusiveSum(op) => Subgroup::InclusiveSum {
+                input: self.compile_variable(op.input),
+                out: self.compile_variable(op.out),
+            },
+            cube::Subcube::ExclusiveSum(op) => Subgroup::ExclusiveSum {
+                input: self.compile_variable(op.input),
+                out: self.compile_variable(op.out),
+            },
+            cube::Subcube::Shuffle(op) => Subgroup::Shuffle {
+                lhs: self.compile_variable(op.lhs),
+                rhs: self.compile_variable(op.rhs),
+                out: self.compile_variable(op.out),
+            },
+            cube::Subcube::ShuffleXor(op) => Subgroup::ShuffleXor {
+                lhs: self.compile_variable(op.lhs),
+                rhs: self.compile_variable(op.rhs),
+                out: self.compile_variable(op.out),
+            },
+            cube::Subcube::ShuffleUp(op) => Subgroup::ShuffleUp {
+                lhs: self.compile_variable(op.lhs),
+                rhs: self.compile_variable(op.rhs),
+                out: self.compile_variable(op.out),
+            },
+            cube::Subcube::ShuffleDown(op) => Subgroup::ShuffleDown {
+                lhs: self.compile_variable(op.lhs),
+                rhs: self.compile_variable(op.rhs),
+                out: self.compile_variable(op.out),
+            },
+            cube::Subcube::Ballot(op) => Subgroup::Ballot {
+                input: self.compile_variable(op.input),
+                out: self.compile_variable(op.out),
+            },
         };
 
         instructions.push(wgsl::Instruction::Subgroup(op));
@@ -366,6 +625,23 @@ impl WgslCompiler {
                 instructions_if: self.compile_scope(&mut op.scope_if),
                 instructions_else: self.compile_scope(&mut op.scope_else),
             }),
+            cube::Branch::Switch(mut op) => instructions.push(wgsl::Instruction::Switch {
+                value: self.compile_variable(op.value),
+                instructions_default: self.compile_scope(&mut op.scope_default),
+                cases: op
+                    .cases
+                    .into_iter()
+                    .map(|(values, mut scope)| {
+                        (
+                            values
+                                .into_iter()
+                                .map(|value| self.compile_variable(value))
+                                .collect(),
+                            self.compile_scope(&mut scope),
+                        )
+                    })
+                    .collect(),
+            }),
             cube::Branch::Return => instructions.push(wgsl::Instruction::Return),
             cube::Branch::Break => instructions.push(wgsl::Instruction::Break),
             cube::Branch::RangeLoop(mut range_loop) => {
@@ -396,6 +672,20 @@ impl WgslCompiler {
             cube::Synchronization::SyncStorage => {
                 instructions.push(wgsl::Instruction::StorageBarrier)
             }
+            // WGSL has no standalone fences, and its barriers only order the accesses of a single
+            // address space within the workgroup, so both are used for a cube scope. Nothing
+            // orders the accesses across workgroups.
+            cube::Synchronization::Fence { scope, .. } => match scope {
+                cube::MemoryScope::Cube => {
+                    instructions.push(wgsl::Instruction::StorageBarrier);
+                    instructions.push(wgsl::Instruction::WorkgroupBarrier);
+                }
+                cube::MemoryScope::Device => {
+                    panic!("Device fences aren't supported on wgpu, see `Feature::DeviceFence`.")
+                }
+            },
+            // Copies are synchronous in WGSL, so there is nothing to wait for.
+            cube::Synchronization::PipelineCommit | cube::Synchronization::PipelineWait { .. } => {}
         };
     }
 
@@ -473,10 +763,29 @@ impl WgslCompiler {
                     out: self.compile_variable(out),
                 }
             }
-            cube::Metadata::Length { var, out } => wgsl::Instruction::Length {
-                out: self.compile_variable(out),
-                var: self.compile_variable(var),
-            },
+            cube::Metadata::Length { var, out } => {
+                let position = match var {
+                    cube::Variable::GlobalInputArray { id, .. } => Some(id as usize),
+                    cube::Variable::GlobalOutputArray { id, .. } => {
+                        Some(self.num_inputs + id as usize)
+                    }
+                    _ => None,
+                };
+                let var = self.compile_variable(var);
+                let out = self.compile_variable(out);
+
+                // The length of the buffer is only known in words, so the length of arrays
+                // packing several lines per word is read from the metadata.
+                match (position, var.item().lines_per_word()) {
+                    (Some(position), Some(_)) => wgsl::Instruction::PackedLength {
+                        position,
+                        num_arrays: self.num_inputs + self.num_outputs,
+                        vectorization: var.item().vectorization_factor(),
+                        out,
+                    },
+                    _ => wgsl::Instruction::Length { var, out },
+                }
+            }
         }
     }
 
@@ -586,6 +895,103 @@ impl WgslCompiler {
                 input: self.compile_variable(op.input),
                 out: self.compile_variable(op.out),
             },
+            cube::Operator::Tan(op) => wgsl::Instruction::Tan {
+                input: self.compile_variable(op.input),
+                out: self.compile_variable(op.out),
+            },
+            cube::Operator::Asin(op) => wgsl::Instruction::Asin {
+                input: self.compile_variable(op.input),
+                out: self.compile_variable(op.out),
+            },
+            cube::Operator::Acos(op) => wgsl::Instruction::Acos {
+                input: self.compile_variable(op.input),
+                out: self.compile_variable(op.out),
+            },
+            cube::Operator::Atan(op) => wgsl::Instruction::Atan {
+                input: self.compile_variable(op.input),
+                out: self.compile_variable(op.out),
+            },
+            cube::Operator::Atan2(op) => wgsl::Instruction::Atan2 {
+                lhs: self.compile_variable(op.lhs),
+                rhs: self.compile_variable(op.rhs),
+                out: self.compile_variable(op.out),
+            },
+            cube::Operator::Sinh(op) => wgsl::Instruction::Sinh {
+                input: self.compile_variable(op.input),
+                out: self.compile_variable(op.out),
+            },
+            cube::Operator::Cosh(op) => wgsl::Instruction::Cosh {
+                input: self.compile_variable(op.input),
+                out: self.compile_variable(op.out),
+            },
+            cube::Operator::Exp2(op) => wgsl::Instruction::Exp2 {
+                input: self.compile_variable(op.input),
+                out: self.compile_variable(op.out),
+            },
+            cube::Operator::Log2(op) => wgsl::Instruction::Log2 {
+                input: self.compile_variable(op.input),
+                out: self.compile_variable(op.out),
+            },
+            cube::Operator::Hypot(op) => wgsl::Instruction::Hypot {
+                lhs: self.compile_variable(op.lhs),
+                rhs: self.compile_variable(op.rhs),
+                out: self.compile_variable(op.out),
+            },
+            cube::Operator::Trunc(op) => wgsl::Instruction::Trunc {
+                input: self.compile_variable(op.input),
+                out: self.compile_variable(op.out),
+            },
+            cube::Operator::Fract(op) => wgsl::Instruction::Fract {
+                input: self.compile_variable(op.input),
+                out: self.compile_variable(op.out),
+            },
+            cube::Operator::Sign(op) => wgsl::Instruction::Sign {
+                input: self.compile_variable(op.input),
+                out: self.compile_variable(op.out),
+            },
+            cube::Operator::IsNan(op) => wgsl::Instruction::IsNan {
+                input: self.compile_variable(op.input),
+                out: self.compile_variable(op.out),
+            },
+            cube::Operator::IsInf(op) => wgsl::Instruction::IsInf {
+                input: self.compile_variable(op.input),
+                out: self.compile_variable(op.out),
+            },
+            cube::Operator::IsFinite(op) => wgsl::Instruction::IsFinite {
+                input: self.compile_variable(op.input),
+                out: self.compile_variable(op.out),
+            },
+            cube::Operator::CopySign(op) => wgsl::Instruction::CopySign {
+                lhs: self.compile_variable(op.lhs),
+                rhs: self.compile_variable(op.rhs),
+                out: self.compile_variable(op.out),
+            },
+            cube::Operator::NextAfter(op) => wgsl::Instruction::NextAfter {
+                lhs: self.compile_variable(op.lhs),
+                rhs: self.compile_variable(op.rhs),
+                out: self.compile_variable(op.out),
+            },
+            cube::Operator::CountOnes(op) => wgsl::Instruction::CountOnes {
+                input: self.compile_variable(op.input),
+                out: self.compile_variable(op.out),
+            },
+            cube::Operator::LeadingZeros(op) => wgsl::Instruction::LeadingZeros {
+                input: self.compile_variable(op.input),
+                out: self.compile_variable(op.out),
+            },
+            cube::Operator::TrailingZeros(op) => wgsl::Instruction::TrailingZeros {
+                input: self.compile_variable(op.input),
+                out: self.compile_variable(op.out),
+            },
+            cube::Operator::ReverseBits(op) => wgsl::Instruction::ReverseBits {
+                input: self.compile_variable(op.input),
+                out: self.compile_variable(op.out),
+            },
+            cube::Operator::MulHi(op) => wgsl::Instruction::MulHi {
+                lhs: self.compile_variable(op.lhs),
+                rhs: self.compile_variable(op.rhs),
+                out: self.compile_variable(op.out),
+            },
             cube::Operator::Recip(op) => wgsl::Instruction::Recip {
                 input: self.compile_variable(op.input),
                 out: self.compile_variable(op.out),
@@ -684,10 +1090,30 @@ impl WgslCompiler {
                 rhs: self.compile_variable(op.rhs),
                 out: self.compile_variable(op.out),
             },
-            cube::Operator::Slice(op) => wgsl::Instruction::Slice {
+            cube::Operator::Slice(op) => {
+                let input = self.compile_variable(op.input);
+                if let wgsl::Variable::LocalArray(_, item, _, _) = &input {
+                    assert!(
+                        item.lines_per_word().is_none(),
+                        "Slices of local arrays of {item:?} aren't supported on wgpu, since their \
+                         lines don't fill 32 bits words"
+                    );
+                }
+                if let cube::Variable::Slice { id, depth, .. } = op.out {
+                    self.slice_sources
+                        .insert((self.function, id, depth), input.clone());
+                }
+
+                wgsl::Instruction::Slice {
+                    input,
+                    start: self.compile_variable(op.start),
+                    end: self.compile_variable(op.end),
+                    out: self.compile_variable(op.out),
+                }
+            }
+            // WGSL has no asynchronous copies, so the copy is done synchronously.
+            cube::Operator::CopyAsync(op) => wgsl::Instruction::CopySlice {
                 input: self.compile_variable(op.input),
-                start: self.compile_variable(op.start),
-                end: self.compile_variable(op.end),
                 out: self.compile_variable(op.out),
             },
             cube::Operator::AtomicLoad(op) => wgsl::Instruction::AtomicLoad {
@@ -698,6 +1124,17 @@ impl WgslCompiler {
                 input: self.compile_variable(op.input),
                 out: self.compile_variable(op.out),
             },
+            // WGSL atomics are always relaxed. Emulating the ordering with barriers isn't valid in
+            // the non-uniform control flow where ordered atomics are used, like spin loops, so the
+            // ordering is dropped.
+            cube::Operator::AtomicLoadOrdered(op) => wgsl::Instruction::AtomicLoad {
+                input: self.compile_variable(op.input),
+                out: self.compile_variable(op.out),
+            },
+            cube::Operator::AtomicStoreOrdered(op) => wgsl::Instruction::AtomicStore {
+                input: self.compile_variable(op.input),
+                out: self.compile_variable(op.out),
+            },
             cube::Operator::AtomicSwap(op) => wgsl::Instruction::AtomicSwap {
                 lhs: self.compile_variable(op.lhs),
                 rhs: self.compile_variable(op.rhs),
@@ -758,6 +1195,34 @@ impl WgslCompiler {
                 input: self.compile_variable(op.input),
                 out: self.compile_variable(op.out),
             },
+            cube::Operator::Dot(op) => wgsl::Instruction::Dot {
+                lhs: self.compile_variable(op.lhs),
+                rhs: self.compile_variable(op.rhs),
+                out: self.compile_variable(op.out),
+            },
+            cube::Operator::ReduceSum(op) => wgsl::Instruction::ReduceSum {
+                input: self.compile_variable(op.input),
+                out: self.compile_variable(op.out),
+            },
+            cube::Operator::ReduceMax(op) => wgsl::Instruction::ReduceMax {
+                input: self.compile_variable(op.input),
+                out: self.compile_variable(op.out),
+            },
+            cube::Operator::ReduceMin(op) => wgsl::Instruction::ReduceMin {
+                input: self.compile_variable(op.input),
+                out: self.compile_variable(op.out),
+            },
+            cube::Operator::Swizzle(op) => wgsl::Instruction::Swizzle {
+                input: self.compile_variable(op.input),
+                lanes: op.lanes,
+                out: self.compile_variable(op.out),
+            },
+            cube::Operator::InsertLane(op) => wgsl::Instruction::InsertLane {
+                input: self.compile_variable(op.input),
+                lane: op.lane,
+                value: self.compile_variable(op.value),
+                out: self.compile_variable(op.out),
+            },
         }
     }
 
@@ -779,7 +1244,7 @@ impl WgslCompiler {
         wgsl::Binding {
             visibility: Self::compile_visibility(value.visibility),
             location: Self::compile_location(value.location),
-            item: Self::compile_item(value.item),
+            item: Self::compile_array_item(value.item),
             size: value.size,
         }
     }
@@ -809,6 +1274,32 @@ fn register_extensions(instructions: &[wgsl::Instruction]) -> Vec<wgsl::Extensio
             wgsl::Instruction::Erf { input, out: _ } => {
                 register_extension(wgsl::Extension::Erf(input.item()));
             }
+            wgsl::Instruction::Hypot {
+                lhs: _,
+                rhs: _,
+                out,
+            } => {
+                register_extension(wgsl::Extension::Hypot(out.elem()));
+            }
+            wgsl::Instruction::NextAfter {
+                lhs: _,
+                rhs: _,
+                out,
+            } => {
+                register_extension(wgsl::Extension::NextAfter(out.elem()));
+            }
+            wgsl::Instruction::MulHi {
+                lhs: _,
+                rhs: _,
+                out,
+            } => {
+                // The signed high product is computed from the unsigned one.
+                register_extension(wgsl::Extension::MulHi(wgsl::Elem::U32));
+
+                if out.elem() == wgsl::Elem::I32 {
+                    register_extension(wgsl::Extension::MulHi(wgsl::Elem::I32));
+                }
+            }
             #[cfg(target_os = "macos")]
             wgsl::Instruction::Tanh { input, out: _ } => {
                 register_extension(wgsl::Extension::SafeTanh(input.item()))
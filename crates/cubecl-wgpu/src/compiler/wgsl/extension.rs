@@ -1,4 +1,4 @@
-use super::base::Item;
+use super::base::{Elem, Item};
 use std::fmt::Display;
 
 /// Not all functions are native to WGSL, so this struct allows to support more functions.
@@ -8,6 +8,10 @@ pub enum Extension {
     PowfPrimitive(Item),
     Powf(Item),
     Erf(Item),
+    Hypot(Elem),
+    NextAfter(Elem),
+    MulHi(Elem),
+    Float8(Elem),
     #[cfg(target_os = "macos")]
     SafeTanh(Item),
 }
@@ -19,6 +23,10 @@ impl Display for Extension {
             Extension::PowfPrimitive(elem) => format_powf_primitive(f, elem),
             Extension::Powf(elem) => format_powf(f, elem),
             Extension::Erf(elem) => format_erf(f, elem),
+            Extension::Hypot(elem) => format_hypot(f, elem),
+            Extension::NextAfter(elem) => format_next_after(f, elem),
+            Extension::MulHi(elem) => format_mul_hi(f, elem),
+            Extension::Float8(elem) => format_float8(f, elem),
             #[cfg(target_os = "macos")]
             Extension::SafeTanh(elem) => format_safe_tanh(f, elem),
         }
@@ -279,3 +287,184 @@ fn safe_tanh(x: {item}) -> {item} {{
         )),
     }
 }
+
+/// The hypotenuse of two floats, which WGSL has no builtin for.
+fn format_hypot(f: &mut core::fmt::Formatter<'_>, elem: &Elem) -> core::fmt::Result {
+    f.write_fmt(format_args!(
+        "
+/// Scales by the largest input to avoid overflowing when squaring.
+fn hypot(lhs: {elem}, rhs: {elem}) -> {elem} {{
+    let high = max(abs(lhs), abs(rhs));
+    let low = min(abs(lhs), abs(rhs));
+
+    if high == 0.0 {{
+        return high;
+    }}
+
+    let ratio = low / high;
+    return high * sqrt(1.0 + ratio * ratio);
+}}
+"
+    ))
+}
+
+fn format_next_after(f: &mut core::fmt::Formatter<'_>, elem: &Elem) -> core::fmt::Result {
+    f.write_fmt(format_args!(
+        "
+/// Steps through the bits of `lhs`, since the distance to the next value depends on its exponent.
+fn next_after(lhs: {elem}, rhs: {elem}) -> {elem} {{
+    let lhs_bits = bitcast<u32>(lhs);
+    let rhs_bits = bitcast<u32>(rhs);
+
+    if (lhs_bits & 0x7fffffffu) > 0x7f800000u || (rhs_bits & 0x7fffffffu) > 0x7f800000u {{
+        return bitcast<{elem}>(0x7fc00000u);
+    }}
+    if lhs == rhs {{
+        return rhs;
+    }}
+    if (lhs_bits & 0x7fffffffu) == 0u {{
+        return bitcast<{elem}>((rhs_bits & 0x80000000u) | 1u);
+    }}
+    if (lhs < rhs) == (lhs > 0.0) {{
+        return bitcast<{elem}>(lhs_bits + 1u);
+    }}
+    return bitcast<{elem}>(lhs_bits - 1u);
+}}
+"
+    ))
+}
+
+fn format_mul_hi(f: &mut core::fmt::Formatter<'_>, elem: &Elem) -> core::fmt::Result {
+    match elem {
+        Elem::U32 => f.write_str(
+            "
+/// Multiplies the 16 bits halves of the inputs, since WGSL has no 64 bits integers.
+fn mul_hi_u32(lhs: u32, rhs: u32) -> u32 {
+    let lhs_low = lhs & 0xffffu;
+    let lhs_high = lhs >> 16u;
+    let rhs_low = rhs & 0xffffu;
+    let rhs_high = rhs >> 16u;
+
+    let low_low = lhs_low * rhs_low;
+    let low_high = lhs_low * rhs_high;
+    let high_low = lhs_high * rhs_low;
+    let high_high = lhs_high * rhs_high;
+
+    let carry = ((low_low >> 16u) + (low_high & 0xffffu) + (high_low & 0xffffu)) >> 16u;
+    return high_high + (low_high >> 16u) + (high_low >> 16u) + carry;
+}
+",
+        ),
+        Elem::I32 => f.write_str(
+            "
+/// Corrects the unsigned high product for the two's complement of negative inputs.
+fn mul_hi_i32(lhs: i32, rhs: i32) -> i32 {
+    var high = mul_hi_u32(bitcast<u32>(lhs), bitcast<u32>(rhs));
+
+    if lhs < 0 {
+        high -= bitcast<u32>(rhs);
+    }
+    if rhs < 0 {
+        high -= bitcast<u32>(lhs);
+    }
+
+    return bitcast<i32>(high);
+}
+",
+        ),
+        _ => panic!("{elem:?} doesn't have a high product extension"),
+    }
+}
+
+/// Software conversions between `f32` and the bits of 8 bits floats, following the saturating
+/// round to nearest even conversions of CUDA.
+fn format_float8(f: &mut core::fmt::Formatter<'_>, elem: &Elem) -> core::fmt::Result {
+    let (name, mantissa_bits, bias, max, overflow): (_, u32, u32, u32, f32) = match elem {
+        Elem::E4M3 => ("e4m3", 3, 7, 0x7E, 464.0),
+        // Values of at least the midpoint between the largest finite value and infinity round
+        // to infinity, so they saturate.
+        Elem::E5M2 => ("e5m2", 2, 15, 0x7B, 61440.0),
+        _ => panic!("{elem:?} isn't an 8 bits float"),
+    };
+    let overflow = match elem {
+        Elem::E4M3 => overflow.to_bits(),
+        _ => overflow.to_bits() - 1,
+    };
+    let exponent_mask = (1u32 << (7 - mantissa_bits)) - 1;
+    let mantissa_mask = (1u32 << mantissa_bits) - 1;
+    let dropped_bits = 23 - mantissa_bits;
+    let half_ulp = 1u32 << (dropped_bits - 1);
+    let exponent_offset = 127 - bias;
+    let min_normal = (exponent_offset + 1) << 23;
+    let min_subnormal_half = (exponent_offset - mantissa_bits) << 23;
+    let subnormal_exponent = 1 - bias as i32 - mantissa_bits as i32;
+    let (nan_bits, special, special_bits) = match elem {
+        Elem::E4M3 => (
+            "0x7fu".to_string(),
+            "(bits & 0x7fu) == 0x7fu".to_string(),
+            "0x7fc00000u".to_string(),
+        ),
+        _ => (
+            "0x7eu | mantissa".to_string(),
+            format!("exponent == {exponent_mask}u"),
+            format!("0x7f800000u | (mantissa << {dropped_bits}u)"),
+        ),
+    };
+
+    f.write_fmt(format_args!(
+        "
+fn f32_to_{name}(value: f32) -> u32 {{
+    let bits = bitcast<u32>(value);
+    let sign = (bits >> 24u) & 0x80u;
+    let abs_bits = bits & 0x7fffffffu;
+    let exponent = abs_bits >> 23u;
+    let mantissa = (abs_bits >> {dropped_bits}u) & {mantissa_mask}u;
+    var result = 0u;
+
+    if abs_bits > 0x7f800000u {{
+        result = {nan_bits};
+    }} else if abs_bits > {overflow:#x}u {{
+        result = {max:#x}u;
+    }} else if abs_bits <= {min_subnormal_half:#x}u {{
+        result = 0u;
+    }} else if abs_bits >= {min_normal:#x}u {{
+        result = ((exponent - {exponent_offset}u) << {mantissa_bits}u) | mantissa;
+        let round = abs_bits & {:#x}u;
+        if round > {half_ulp:#x}u || (round == {half_ulp:#x}u && (result & 1u) != 0u) {{
+            result += 1u;
+        }}
+    }} else {{
+        let shift = {}u - exponent;
+        result = (mantissa | {}u) >> shift;
+        let round = (abs_bits | 0x800000u) & (({half_ulp:#x}u << (shift + 1u)) - 1u);
+        let half = {half_ulp:#x}u << shift;
+        if round > half || (round == half && (result & 1u) != 0u) {{
+            result += 1u;
+        }}
+    }}
+
+    return result | sign;
+}}
+
+fn {name}_to_f32(bits: u32) -> f32 {{
+    let sign = (bits & 0x80u) << 24u;
+    let exponent = (bits >> {mantissa_bits}u) & {exponent_mask}u;
+    let mantissa = bits & {mantissa_mask}u;
+    var magnitude = 0u;
+
+    if {special} {{
+        magnitude = {special_bits};
+    }} else if exponent == 0u {{
+        magnitude = bitcast<u32>(ldexp(f32(mantissa), {subnormal_exponent}));
+    }} else {{
+        magnitude = ((exponent + {exponent_offset}u) << 23u) | (mantissa << {dropped_bits}u);
+    }}
+
+    return bitcast<f32>(sign | magnitude);
+}}
+",
+        (half_ulp << 1) - 1,
+        exponent_offset + 1,
+        1u32 << mantissa_bits,
+    ))
+}
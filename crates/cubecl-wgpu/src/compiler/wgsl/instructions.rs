@@ -41,7 +41,23 @@ pub enum Instruction {
         instructions_if: Vec<Instruction>,
         instructions_else: Vec<Instruction>,
     },
+    Switch {
+        value: Variable,
+        instructions_default: Vec<Instruction>,
+        cases: Vec<(Vec<Variable>, Vec<Instruction>)>,
+    },
     Return,
+    Call {
+        function: String,
+        args: Vec<Variable>,
+        out: Option<Variable>,
+    },
+    /// Write a record to the debug buffer, only when the condition is false if there is one.
+    DebugRecord {
+        format: u32,
+        condition: Option<Variable>,
+        args: Vec<Variable>,
+    },
     Break,
     WorkgroupBarrier,
     StorageBarrier,
@@ -123,6 +139,103 @@ pub enum Instruction {
         input: Variable,
         out: Variable,
     },
+    Tan {
+        input: Variable,
+        out: Variable,
+    },
+    Asin {
+        input: Variable,
+        out: Variable,
+    },
+    Acos {
+        input: Variable,
+        out: Variable,
+    },
+    Atan {
+        input: Variable,
+        out: Variable,
+    },
+    Atan2 {
+        lhs: Variable,
+        rhs: Variable,
+        out: Variable,
+    },
+    Sinh {
+        input: Variable,
+        out: Variable,
+    },
+    Cosh {
+        input: Variable,
+        out: Variable,
+    },
+    Exp2 {
+        input: Variable,
+        out: Variable,
+    },
+    Log2 {
+        input: Variable,
+        out: Variable,
+    },
+    Hypot {
+        lhs: Variable,
+        rhs: Variable,
+        out: Variable,
+    },
+    Trunc {
+        input: Variable,
+        out: Variable,
+    },
+    Fract {
+        input: Variable,
+        out: Variable,
+    },
+    Sign {
+        input: Variable,
+        out: Variable,
+    },
+    IsNan {
+        input: Variable,
+        out: Variable,
+    },
+    IsInf {
+        input: Variable,
+        out: Variable,
+    },
+    IsFinite {
+        input: Variable,
+        out: Variable,
+    },
+    CopySign {
+        lhs: Variable,
+        rhs: Variable,
+        out: Variable,
+    },
+    NextAfter {
+        lhs: Variable,
+        rhs: Variable,
+        out: Variable,
+    },
+    CountOnes {
+        input: Variable,
+        out: Variable,
+    },
+    LeadingZeros {
+        input: Variable,
+        out: Variable,
+    },
+    TrailingZeros {
+        input: Variable,
+        out: Variable,
+    },
+    ReverseBits {
+        input: Variable,
+        out: Variable,
+    },
+    MulHi {
+        lhs: Variable,
+        rhs: Variable,
+        out: Variable,
+    },
     Recip {
         input: Variable,
         out: Variable,
@@ -172,6 +285,12 @@ pub enum Instruction {
         var: Variable,
         out: Variable,
     },
+    PackedLength {
+        position: usize,
+        num_arrays: usize,
+        vectorization: usize,
+        out: Variable,
+    },
     Shape {
         dim: Variable,
         position: usize,
@@ -250,6 +369,10 @@ pub enum Instruction {
         end: Variable,
         out: Variable,
     },
+    CopySlice {
+        input: Variable,
+        out: Variable,
+    },
     Bitcast {
         input: Variable,
         out: Variable,
@@ -317,6 +440,34 @@ pub enum Instruction {
         input: Variable,
         out: Variable,
     },
+    Dot {
+        lhs: Variable,
+        rhs: Variable,
+        out: Variable,
+    },
+    ReduceSum {
+        input: Variable,
+        out: Variable,
+    },
+    ReduceMax {
+        input: Variable,
+        out: Variable,
+    },
+    ReduceMin {
+        input: Variable,
+        out: Variable,
+    },
+    Swizzle {
+        input: Variable,
+        lanes: Vec<u32>,
+        out: Variable,
+    },
+    InsertLane {
+        input: Variable,
+        lane: u32,
+        value: Variable,
+        out: Variable,
+    },
 }
 
 impl Display for Instruction {
@@ -344,6 +495,48 @@ impl Display for Instruction {
                 f.write_fmt(format_args!("let {out}_length = {end} - {start};\n"))?;
                 f.write_fmt(format_args!("let {out}_ptr = &{input};\n"))
             }
+            Instruction::CopySlice { input, out } => {
+                let i = Variable::Named {
+                    name: "copy_index".into(),
+                    item: Item::Scalar(Elem::U32),
+                    is_array: false,
+                };
+                let value = Variable::Named {
+                    name: "copy_value".into(),
+                    item: out.item(),
+                    is_array: false,
+                };
+                let input_offset = Variable::Named {
+                    name: format!("{input}_offset"),
+                    item: Item::Scalar(Elem::U32),
+                    is_array: false,
+                };
+                let out_offset = Variable::Named {
+                    name: format!("{out}_offset"),
+                    item: Item::Scalar(Elem::U32),
+                    is_array: false,
+                };
+                let input_ptr = Variable::Named {
+                    name: format!("(*{input}_ptr)"),
+                    item: input.item(),
+                    is_array: true,
+                };
+                let out_ptr = Variable::Named {
+                    name: format!("(*{out}_ptr)"),
+                    item: out.item(),
+                    is_array: true,
+                };
+                let item = out.item();
+
+                f.write_fmt(format_args!(
+                    "for (var {i}: u32 = 0u; {i} < min({input}_length, {out}_length); {i}++) {{
+var {value}: {item};
+"
+                ))?;
+                index(f, &input_ptr, &i, &value, Some(input_offset))?;
+                index_assign(f, &i, &value, &out_ptr, Some(out_offset))?;
+                f.write_str("}\n")
+            }
             Instruction::Fma { a, b, c, out } => {
                 f.write_fmt(format_args!("{out} = fma({a}, {b}, {c});\n"))
             }
@@ -474,6 +667,132 @@ impl Display for Instruction {
                 result
             }
             Instruction::Erf { input, out } => f.write_fmt(format_args!("{out} = erf({input});\n")),
+            Instruction::Tan { input, out } => f.write_fmt(format_args!("{out} = tan({input});\n")),
+            Instruction::Asin { input, out } => {
+                f.write_fmt(format_args!("{out} = asin({input});\n"))
+            }
+            Instruction::Acos { input, out } => {
+                f.write_fmt(format_args!("{out} = acos({input});\n"))
+            }
+            Instruction::Atan { input, out } => {
+                f.write_fmt(format_args!("{out} = atan({input});\n"))
+            }
+            Instruction::Atan2 { lhs, rhs, out } => unroll(
+                f,
+                out.item().vectorization_factor(),
+                [lhs, rhs, out],
+                |f, [lhs, rhs, out]| f.write_fmt(format_args!("{out} = atan2({lhs}, {rhs});\n")),
+            ),
+            Instruction::Sinh { input, out } => {
+                f.write_fmt(format_args!("{out} = sinh({input});\n"))
+            }
+            Instruction::Cosh { input, out } => {
+                f.write_fmt(format_args!("{out} = cosh({input});\n"))
+            }
+            Instruction::Exp2 { input, out } => {
+                f.write_fmt(format_args!("{out} = exp2({input});\n"))
+            }
+            Instruction::Log2 { input, out } => {
+                f.write_fmt(format_args!("{out} = log2({input});\n"))
+            }
+            Instruction::Hypot { lhs, rhs, out } => unroll(
+                f,
+                out.item().vectorization_factor(),
+                [lhs, rhs, out],
+                |f, [lhs, rhs, out]| f.write_fmt(format_args!("{out} = hypot({lhs}, {rhs});\n")),
+            ),
+            Instruction::Trunc { input, out } => {
+                f.write_fmt(format_args!("{out} = trunc({input});\n"))
+            }
+            Instruction::Fract { input, out } => {
+                // WGSL's `fract` uses floor, which differs from Rust for negative inputs.
+                f.write_fmt(format_args!("{out} = {input} - trunc({input});\n"))
+            }
+            Instruction::Sign { input, out } => {
+                f.write_fmt(format_args!("{out} = sign({input});\n"))
+            }
+            // Comparisons aren't reliable for NaN and infinities, so the bits are checked instead.
+            Instruction::IsNan { input, out } => {
+                float_check(f, input, out, "(bits & 0x7fffffffu) > 0x7f800000u")
+            }
+            Instruction::IsInf { input, out } => {
+                float_check(f, input, out, "(bits & 0x7fffffffu) == 0x7f800000u")
+            }
+            Instruction::IsFinite { input, out } => {
+                float_check(f, input, out, "(bits & 0x7fffffffu) < 0x7f800000u")
+            }
+            Instruction::CopySign { lhs, rhs, out } => unroll(
+                f,
+                out.item().vectorization_factor(),
+                [lhs, rhs, out],
+                |f, [lhs, rhs, out]| {
+                    f.write_fmt(format_args!(
+                        "{out} = bitcast<f32>((bitcast<u32>(f32({lhs})) & 0x7fffffffu) | (bitcast<u32>(f32({rhs})) & 0x80000000u));\n"
+                    ))
+                },
+            ),
+            Instruction::NextAfter { lhs, rhs, out } => unroll(
+                f,
+                out.item().vectorization_factor(),
+                [lhs, rhs, out],
+                |f, [lhs, rhs, out]| {
+                    f.write_fmt(format_args!("{out} = next_after({lhs}, {rhs});\n"))
+                },
+            ),
+            Instruction::CountOnes { input, out } => {
+                let elem = out.elem();
+                bit_function(f, input, out, "countOneBits", |input, bits| {
+                    format!(
+                        "{elem}(countOneBits(u32({input}) & {:#x}u))",
+                        u32::MAX >> (32 - bits)
+                    )
+                })
+            }
+            Instruction::LeadingZeros { input, out } => {
+                let elem = out.elem();
+                bit_function(f, input, out, "countLeadingZeros", |input, bits| {
+                    format!(
+                        "{elem}(countLeadingZeros(u32({input}) & {:#x}u) - {}u)",
+                        u32::MAX >> (32 - bits),
+                        32 - bits
+                    )
+                })
+            }
+            Instruction::TrailingZeros { input, out } => {
+                let elem = out.elem();
+                bit_function(f, input, out, "countTrailingZeros", |input, bits| {
+                    format!("{elem}(min(countTrailingZeros(u32({input})), {bits}u))")
+                })
+            }
+            // The reversed bits of narrow integers end up in the highest bits of the word.
+            Instruction::ReverseBits { input, out } => {
+                let elem = out.elem();
+                bit_function(f, input, out, "reverseBits", |input, bits| {
+                    elem.cast(format!("reverseBits(u32({input})) >> {}u", 32 - bits))
+                })
+            }
+            Instruction::MulHi { lhs, rhs, out } => {
+                let elem = out.elem();
+                unroll(
+                    f,
+                    out.item().vectorization_factor(),
+                    [lhs, rhs, out],
+                    |f, [lhs, rhs, out]| match elem {
+                        Elem::I32 => {
+                            f.write_fmt(format_args!("{out} = mul_hi_i32({lhs}, {rhs});\n"))
+                        }
+                        Elem::U32 => {
+                            f.write_fmt(format_args!("{out} = mul_hi_u32({lhs}, {rhs});\n"))
+                        }
+                        // The full product of narrow integers fits in 32 bits.
+                        _ => f.write_fmt(format_args!(
+                            "{out} = ({lhs} * {rhs}) >> {}u;\n",
+                            elem.packed_bits()
+                                .expect("Only integers have a high product")
+                        )),
+                    },
+                )
+            }
             Instruction::Recip { input, out } => {
                 f.write_fmt(format_args!("{out} = 1.0 / {input};"))
             }
@@ -492,12 +811,16 @@ impl Display for Instruction {
 
                     f.write_fmt(format_args!(
                         "{out} = vec4(
-    {elem}({input0}),
-    {elem}({input1}),
-    {elem}({input2}),
-    {elem}({input3}),
+    {},
+    {},
+    {},
+    {},
 );
-"
+",
+                        elem.cast(input0),
+                        elem.cast(input1),
+                        elem.cast(input2),
+                        elem.cast(input3),
                     ))
                 }
                 Item::Vec3(elem) => {
@@ -507,11 +830,14 @@ impl Display for Instruction {
 
                     f.write_fmt(format_args!(
                         "{out} = vec3(
-    {elem}({input0}),
-    {elem}({input1}),
-    {elem}({input2}),
+    {},
+    {},
+    {},
 );
-"
+",
+                        elem.cast(input0),
+                        elem.cast(input1),
+                        elem.cast(input2),
                     ))
                 }
                 Item::Vec2(elem) => {
@@ -520,17 +846,19 @@ impl Display for Instruction {
 
                     f.write_fmt(format_args!(
                         "{out} = vec2(
-    {elem}({input0}),
-    {elem}({input1}),
+    {},
+    {},
 );
-"
+",
+                        elem.cast(input0),
+                        elem.cast(input1),
                     ))
                 }
                 Item::Scalar(elem) => {
                     if elem.is_atomic() {
                         f.write_fmt(format_args!("let {out} = &{input};\n"))
                     } else {
-                        f.write_fmt(format_args!("{out} = {elem}({input});\n"))
+                        f.write_fmt(format_args!("{out} = {};\n", elem.cast(input)))
                     }
                 }
             },
@@ -607,6 +935,68 @@ for (var {i}: {i_ty} = {start}; {i} {cmp} {end}; {increment}) {{
                 f.write_str("}\n")
             }
             Instruction::Return => f.write_str("return;\n"),
+            Instruction::Call {
+                function,
+                args,
+                out,
+            } => {
+                let args = args
+                    .iter()
+                    .map(|arg| arg.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                match out {
+                    Some(out) => f.write_fmt(format_args!("{out} = {function}({args});\n")),
+                    None => f.write_fmt(format_args!("{function}({args});\n")),
+                }
+            }
+            Instruction::DebugRecord {
+                format,
+                condition,
+                args,
+            } => {
+                let mut words = vec![format!("{}u", format + 1)];
+                for arg in args {
+                    let item = arg.item();
+                    words.push(format!("{}u", item.vectorization_factor()));
+                    for i in 0..item.vectorization_factor() {
+                        let lane = arg.index(i);
+                        words.push(match item.elem() {
+                            Elem::F32
+                            | Elem::E4M3
+                            | Elem::E5M2
+                            | Elem::I8
+                            | Elem::I16
+                            | Elem::I32 => {
+                                format!("bitcast<u32>({lane})")
+                            }
+                            Elem::U8 | Elem::U16 | Elem::U32 => format!("{lane}"),
+                            Elem::Bool => format!("select(0u, 1u, {lane})"),
+                            Elem::AtomicF32 | Elem::AtomicI32 | Elem::AtomicU32 => {
+                                panic!("Atomics can't be written to the debug buffer")
+                            }
+                        });
+                    }
+                }
+                let len = words.len();
+
+                if let Some(condition) = condition {
+                    f.write_fmt(format_args!("if !all({condition}) {{\n"))?;
+                } else {
+                    f.write_str("{\n")?;
+                }
+                f.write_fmt(format_args!(
+                    "let debug_offset = atomicAdd(&debug[0], {len}u);
+if debug_offset + {len}u <= atomicLoad(&debug[1]) {{\n"
+                ))?;
+                for (i, word) in words.iter().enumerate() {
+                    f.write_fmt(format_args!(
+                        "atomicStore(&debug[debug_offset + {}u], {word});\n",
+                        i + 2
+                    ))?;
+                }
+                f.write_str("}\n}\n")
+            }
             Instruction::Break => f.write_str("break;\n"),
             Instruction::WorkgroupBarrier => f.write_str("workgroupBarrier();\n"),
             Instruction::StorageBarrier => f.write_str("storageBarrier();\n"),
@@ -614,6 +1004,39 @@ for (var {i}: {i_ty} = {start}; {i} {cmp} {end}; {increment}) {{
                 Variable::Slice { .. } => f.write_fmt(format_args!("{out} = {var}_length;\n")),
                 _ => f.write_fmt(format_args!("{out} = arrayLength(&{var});\n")),
             },
+            // The lengths follow the strides and shapes of all arrays in the metadata.
+            Instruction::PackedLength {
+                position,
+                num_arrays,
+                vectorization,
+                out,
+            } => f.write_fmt(format_args!(
+                "{out} = info[({num_arrays}u * 2u * rank) + {position}u + 1u] / {vectorization}u;\n"
+            )),
+            Instruction::Switch {
+                value,
+                instructions_default,
+                cases,
+            } => {
+                f.write_fmt(format_args!("switch {value} {{\n"))?;
+                for (values, instructions) in cases {
+                    let values = values
+                        .iter()
+                        .map(|value| format!("{value}"))
+                        .collect::<Vec<_>>()
+                        .join(", ");
+                    f.write_fmt(format_args!("case {values}: {{\n"))?;
+                    for i in instructions {
+                        f.write_fmt(format_args!("{i}"))?;
+                    }
+                    f.write_str("}\n")?;
+                }
+                f.write_str("default: {\n")?;
+                for i in instructions_default {
+                    f.write_fmt(format_args!("{i}"))?;
+                }
+                f.write_str("}\n}\n")
+            }
             Instruction::Loop { instructions } => {
                 f.write_fmt(format_args!("loop {{\n"))?;
                 for i in instructions {
@@ -650,44 +1073,95 @@ for (var {i}: {i_ty} = {start}; {i} {cmp} {end}; {increment}) {{
                 f.write_fmt(format_args!("{out} = bitcast<{}>({input});\n", out.elem()))
             }
             Instruction::AtomicLoad { input, out } => {
-                f.write_fmt(format_args!("{out} = atomicLoad({input});\n"))
+                if AtomicFloat::is(input) {
+                    f.write_fmt(format_args!("{out} = bitcast<f32>(atomicLoad({input}));\n"))
+                } else {
+                    f.write_fmt(format_args!("{out} = atomicLoad({input});\n"))
+                }
             }
             Instruction::AtomicStore { input, out } => {
-                f.write_fmt(format_args!("atomicStore({out},{input});\n"))
+                if AtomicFloat::is(out) {
+                    f.write_fmt(format_args!("atomicStore({out}, bitcast<u32>({input}));\n"))
+                } else {
+                    f.write_fmt(format_args!("atomicStore({out},{input});\n"))
+                }
             }
             Instruction::AtomicSwap { lhs, rhs, out } => {
-                f.write_fmt(format_args!("{out} = atomicExchange({lhs}, {rhs});"))
+                if AtomicFloat::is(lhs) {
+                    f.write_fmt(format_args!(
+                        "{out} = bitcast<f32>(atomicExchange({lhs}, bitcast<u32>({rhs})));\n"
+                    ))
+                } else {
+                    f.write_fmt(format_args!("{out} = atomicExchange({lhs}, {rhs});\n"))
+                }
             }
             Instruction::AtomicAdd { lhs, rhs, out } => {
-                f.write_fmt(format_args!("{out} = atomicAdd({lhs}, {rhs});"))
+                if AtomicFloat::is(lhs) {
+                    AtomicFloat::update(f, lhs, out, |current| format!("{current} + {rhs}"))
+                } else {
+                    f.write_fmt(format_args!("{out} = atomicAdd({lhs}, {rhs});\n"))
+                }
             }
             Instruction::AtomicSub { lhs, rhs, out } => {
-                f.write_fmt(format_args!("{out} = atomicSub({lhs}, {rhs});"))
+                if AtomicFloat::is(lhs) {
+                    AtomicFloat::update(f, lhs, out, |current| format!("{current} - {rhs}"))
+                } else {
+                    f.write_fmt(format_args!("{out} = atomicSub({lhs}, {rhs});\n"))
+                }
             }
             Instruction::AtomicMax { lhs, rhs, out } => {
-                f.write_fmt(format_args!("{out} = atomicMax({lhs}, {rhs});"))
+                if AtomicFloat::is(lhs) {
+                    AtomicFloat::update(f, lhs, out, |current| format!("max({current}, {rhs})"))
+                } else {
+                    f.write_fmt(format_args!("{out} = atomicMax({lhs}, {rhs});\n"))
+                }
             }
             Instruction::AtomicMin { lhs, rhs, out } => {
-                f.write_fmt(format_args!("{out} = atomicMin({lhs}, {rhs});"))
+                if AtomicFloat::is(lhs) {
+                    AtomicFloat::update(f, lhs, out, |current| format!("min({current}, {rhs})"))
+                } else {
+                    f.write_fmt(format_args!("{out} = atomicMin({lhs}, {rhs});\n"))
+                }
             }
             Instruction::AtomicAnd { lhs, rhs, out } => {
-                f.write_fmt(format_args!("{out} = atomicAnd({lhs}, {rhs});"))
+                assert!(
+                    !AtomicFloat::is(lhs),
+                    "Can't use bitwise and on float atomics"
+                );
+                f.write_fmt(format_args!("{out} = atomicAnd({lhs}, {rhs});\n"))
             }
             Instruction::AtomicOr { lhs, rhs, out } => {
-                f.write_fmt(format_args!("{out} = atomicOr({lhs}, {rhs});"))
+                assert!(
+                    !AtomicFloat::is(lhs),
+                    "Can't use bitwise or on float atomics"
+                );
+                f.write_fmt(format_args!("{out} = atomicOr({lhs}, {rhs});\n"))
             }
             Instruction::AtomicXor { lhs, rhs, out } => {
-                f.write_fmt(format_args!("{out} = atomicXor({lhs}, {rhs});"))
+                assert!(
+                    !AtomicFloat::is(lhs),
+                    "Can't use bitwise xor on float atomics"
+                );
+                f.write_fmt(format_args!("{out} = atomicXor({lhs}, {rhs});\n"))
             }
             Instruction::AtomicCompareExchangeWeak {
                 lhs,
                 cmp,
                 value,
                 out,
-            } => f.write_fmt(format_args!(
-                // For compatibility with cuda, only return old_value
-                "{out} = atomicCompareExchangeWeak({lhs}, {cmp}, {value}).old_value;\n"
-            )),
+            } => {
+                if AtomicFloat::is(lhs) {
+                    // For compatibility with cuda, only return old_value
+                    f.write_fmt(format_args!(
+                        "{out} = bitcast<f32>(atomicCompareExchangeWeak({lhs}, \
+                         bitcast<u32>({cmp}), bitcast<u32>({value})).old_value);\n"
+                    ))
+                } else {
+                    f.write_fmt(format_args!(
+                        "{out} = atomicCompareExchangeWeak({lhs}, {cmp}, {value}).old_value;\n"
+                    ))
+                }
+            }
             Instruction::Negate { input, out } => f.write_fmt(format_args!("{out} = -{input};\n")),
             Instruction::Normalize { input, out } => {
                 if input.item().vectorization_factor() == 1 {
@@ -702,6 +1176,57 @@ for (var {i}: {i_ty} = {start}; {i} {cmp} {end}; {increment}) {{
                     f.write_fmt(format_args!("{out} = normalize({input});\n"))
                 }
             }
+            Instruction::Dot { lhs, rhs, out } => {
+                let dot = match lhs.item().vectorization_factor() {
+                    1 => format!("{lhs} * {rhs}"),
+                    _ => format!("dot({lhs}, {rhs})"),
+                };
+
+                match out.elem().packed_bits() {
+                    Some(_) => f.write_fmt(format_args!("{out} = {};\n", out.elem().cast(dot))),
+                    None => f.write_fmt(format_args!("{out} = {dot};\n")),
+                }
+            }
+            Instruction::ReduceSum { input, out } => {
+                reduce(f, input, out, |f, lane, out| {
+                    f.write_fmt(format_args!("{out} = {out} + {lane};\n"))
+                })?;
+
+                match out.elem().packed_bits() {
+                    Some(_) => f.write_fmt(format_args!("{out} = {};\n", out.elem().cast(out))),
+                    None => Ok(()),
+                }
+            }
+            Instruction::ReduceMax { input, out } => reduce(f, input, out, |f, lane, out| {
+                f.write_fmt(format_args!("{out} = max({out}, {lane});\n"))
+            }),
+            Instruction::ReduceMin { input, out } => reduce(f, input, out, |f, lane, out| {
+                f.write_fmt(format_args!("{out} = min({out}, {lane});\n"))
+            }),
+            Instruction::Swizzle { input, lanes, out } => {
+                match (input.item().vectorization_factor(), lanes.len()) {
+                    (1, 1) => f.write_fmt(format_args!("{out} = {input};\n")),
+                    (1, _) => f.write_fmt(format_args!("{out} = {}({input});\n", out.item())),
+                    (_, 1) => f.write_fmt(format_args!("{out} = {input}[{}];\n", lanes[0])),
+                    _ => {
+                        let components = lanes
+                            .iter()
+                            .map(|lane| ['x', 'y', 'z', 'w'][*lane as usize])
+                            .collect::<String>();
+                        f.write_fmt(format_args!("{out} = {input}.{components};\n"))
+                    }
+                }
+            }
+            Instruction::InsertLane {
+                input,
+                lane,
+                value,
+                out,
+            } => {
+                let outi = out.index(*lane as usize);
+                f.write_fmt(format_args!("{out} = {input};\n"))?;
+                f.write_fmt(format_args!("{outi} = {value};\n"))
+            }
         }
     }
 }
@@ -763,6 +1288,95 @@ fn comparison(
     }
 }
 
+/// Float atomics, emulated with compare and swap loops on the bits of the value.
+struct AtomicFloat;
+
+impl AtomicFloat {
+    fn is(pointer: &Variable) -> bool {
+        pointer.elem() == Elem::AtomicF32
+    }
+
+    /// Atomically replaces the value at the pointer with the one computed from the current value,
+    /// retrying until no other invocation wrote in between.
+    fn update(
+        f: &mut core::fmt::Formatter<'_>,
+        pointer: &Variable,
+        out: &Variable,
+        value: impl Fn(&str) -> String,
+    ) -> core::fmt::Result {
+        let value = value("bitcast<f32>(old)");
+
+        f.write_fmt(format_args!(
+            "{{
+var old = atomicLoad({pointer});
+loop {{
+    let exchanged = atomicCompareExchangeWeak({pointer}, old, bitcast<u32>({value}));
+    if exchanged.exchanged {{
+        break;
+    }}
+    old = exchanged.old_value;
+}}
+{out} = bitcast<f32>(old);
+}}
+"
+        ))
+    }
+}
+
+/// Combines the lanes of a vectorized value one after the other.
+fn reduce(
+    f: &mut core::fmt::Formatter<'_>,
+    input: &Variable,
+    out: &Variable,
+    combine: impl Fn(&mut core::fmt::Formatter<'_>, IndexedVariable, &Variable) -> core::fmt::Result,
+) -> core::fmt::Result {
+    f.write_fmt(format_args!("{out} = {};\n", input.index(0)))?;
+
+    for i in 1..input.item().vectorization_factor() {
+        combine(f, input.index(i), out)?;
+    }
+
+    Ok(())
+}
+
+/// Applies a bit manipulation builtin, narrow integers being handled lane by lane since they are
+/// widened to 32 bits.
+fn bit_function(
+    f: &mut core::fmt::Formatter<'_>,
+    input: &Variable,
+    out: &Variable,
+    builtin: &str,
+    narrow: impl Fn(&IndexedVariable, usize) -> String,
+) -> core::fmt::Result {
+    match out.elem().packed_bits() {
+        Some(bits) => unroll(
+            f,
+            out.item().vectorization_factor(),
+            [input, out],
+            |f, [input, out]| f.write_fmt(format_args!("{out} = {};\n", narrow(&input, bits))),
+        ),
+        None => f.write_fmt(format_args!("{out} = {builtin}({input});\n")),
+    }
+}
+
+/// Checks the bits of each lane of a float, the condition being written using `bits`.
+fn float_check(
+    f: &mut core::fmt::Formatter<'_>,
+    input: &Variable,
+    out: &Variable,
+    condition: &str,
+) -> core::fmt::Result {
+    unroll(
+        f,
+        out.item().vectorization_factor(),
+        [input, out],
+        |f, [input, out]| {
+            let condition = condition.replace("bits", &format!("bitcast<u32>(f32({input}))"));
+            f.write_fmt(format_args!("{out} = {condition};\n"))
+        },
+    )
+}
+
 fn unroll<
     const N: usize,
     F: Fn(&mut core::fmt::Formatter<'_>, [IndexedVariable; N]) -> core::fmt::Result,
@@ -820,6 +1434,26 @@ fn index(
     out: &Variable,
     offset: Option<Variable>,
 ) -> core::fmt::Result {
+    if lhs.is_array() && lhs.elem().packed_bits().is_some() {
+        let position = match offset {
+            Some(offset) => format!("{rhs} + {offset}"),
+            None => format!("{rhs}"),
+        };
+        let value = match lhs.item().lines_per_word() {
+            // The line is moved to the low bits of its word.
+            Some(lines) if lhs.has_shared_words() => format!(
+                "(atomicLoad(&{lhs}[({position}) / {lines}u]) >> (({position}) % {lines}u * {}u))",
+                32 / lines
+            ),
+            _ => format!("{lhs}[{position}]"),
+        };
+        let value = lhs.item().unpack(value);
+        return match lhs.elem() != out.elem() {
+            true => f.write_fmt(format_args!("{out} = {}({value});\n", out.item())),
+            false => f.write_fmt(format_args!("{out} = {value};\n")),
+        };
+    }
+
     if out.item().elem().is_atomic() {
         match offset {
             Some(offset) => f.write_fmt(format_args!("let {out} = &{lhs}[{rhs} + {offset}];\n")),
@@ -846,6 +1480,29 @@ fn index_assign(
     out: &Variable,
     offset: Option<Variable>,
 ) -> core::fmt::Result {
+    if out.is_array() && out.elem().packed_bits().is_some() {
+        let item = out.item();
+        let lanes = (0..item.vectorization_factor())
+            .map(|i| rhs.index(i).to_string())
+            .collect::<Vec<_>>();
+        let lhs = IndexOffset::new(lhs, &offset, 0);
+        let value = item.pack(&lanes);
+
+        return match item.lines_per_word() {
+            // Only the bits of the line are replaced, since other units can write the other lines
+            // of the word.
+            Some(lines) if out.has_shared_words() => {
+                let line_bits = 32 / lines;
+                let mask = (1u64 << line_bits) - 1;
+                let word = format!("{out}[({lhs}) / {lines}u]");
+                let shift = format!("(({lhs}) % {lines}u * {line_bits}u)");
+                f.write_fmt(format_args!("atomicAnd(&{word}, ~({mask}u << {shift}));\n"))?;
+                f.write_fmt(format_args!("atomicOr(&{word}, ({value}) << {shift});\n"))
+            }
+            _ => f.write_fmt(format_args!("{out}[{lhs}] = {value};\n")),
+        };
+    }
+
     match lhs.item() {
         Item::Vec4(elem) => {
             let lhs0 = IndexOffset::new(lhs, &offset, 0);
@@ -887,17 +1544,7 @@ fn index_assign(
             f.write_fmt(format_args!("{out}[{lhs1}] = {elem}({rhs1});\n"))
         }
         Item::Scalar(_elem) => {
-            let is_array = match out {
-                Variable::GlobalInputArray(_, _)
-                | Variable::GlobalOutputArray(_, _)
-                | Variable::SharedMemory(_, _, _)
-                | Variable::Slice { .. }
-                | Variable::LocalArray(_, _, _, _) => true,
-                Variable::Named { is_array, .. } => *is_array,
-                _ => false,
-            };
-
-            if !is_array {
+            if !out.is_array() {
                 let elem_out = out.elem();
                 let casting_type = match rhs.item() {
                     Item::Vec4(_) => Item::Vec4(elem_out),
@@ -1,4 +1,4 @@
-use super::{Body, Extension, Item};
+use super::{Body, Extension, Item, Variable};
 use cubecl_core::{ir::CubeDim, CompilerRepresentation};
 use std::fmt::Display;
 
@@ -60,6 +60,15 @@ impl LocalArray {
     }
 }
 
+#[derive(Debug, Clone)]
+pub struct Function {
+    pub name: String,
+    pub params: Vec<(String, Item)>,
+    pub output: Option<Variable>,
+    pub local_arrays: Vec<LocalArray>,
+    pub body: Body,
+}
+
 #[derive(Debug, Clone)]
 pub struct ComputeShader {
     pub inputs: Vec<Binding>,
@@ -67,6 +76,7 @@ pub struct ComputeShader {
     pub named: Vec<(String, Binding)>,
     pub shared_memories: Vec<SharedMemory>,
     pub local_arrays: Vec<LocalArray>,
+    pub functions: Vec<Function>,
     pub workgroup_size: CubeDim,
     pub global_invocation_id: bool,
     pub local_invocation_index: bool,
@@ -96,9 +106,13 @@ impl Display for ComputeShader {
         }
 
         for array in self.shared_memories.iter() {
+            let (storage, size) = match array.item.lines_per_word() {
+                Some(lines) => ("atomic<u32>".to_string(), array.size.div_ceil(lines as u32)),
+                None => (array.item.storage().to_string(), array.size),
+            };
             f.write_fmt(format_args!(
-                "var<{}> shared_memory_{}: array<{}, {}>;\n\n",
-                array.location, array.index, array.item, array.size
+                "var<{}> shared_memory_{}: array<{storage}, {size}>;\n\n",
+                array.location, array.index,
             ))?;
         }
 
@@ -148,7 +162,10 @@ fn main(
         for array in self.local_arrays.iter() {
             f.write_fmt(format_args!(
                 "var a_{}_{}: array<{}, {}>;\n\n",
-                array.name, array.index, array.item, array.size
+                array.name,
+                array.index,
+                array.item.storage(),
+                array.size
             ))?;
         }
 
@@ -170,6 +187,10 @@ fn main(
         // Close body
         f.write_fmt(format_args!("}}"))?;
 
+        for function in self.functions.iter() {
+            f.write_fmt(format_args!("\n\n{function}"))?;
+        }
+
         for extension in self.extensions.iter() {
             f.write_fmt(format_args!("{extension}\n\n"))?;
         }
@@ -178,6 +199,42 @@ fn main(
     }
 }
 
+impl Display for Function {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params = self
+            .params
+            .iter()
+            .map(|(name, item)| format!("{name}: {item}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        f.write_fmt(format_args!("fn {}({params})", self.name))?;
+
+        if let Some(output) = &self.output {
+            f.write_fmt(format_args!(" -> {}", output.item()))?;
+        }
+
+        f.write_str(" {\n")?;
+
+        for array in self.local_arrays.iter() {
+            f.write_fmt(format_args!(
+                "var a_{}_{}: array<{}, {}>;\n\n",
+                array.name,
+                array.index,
+                array.item.storage(),
+                array.size
+            ))?;
+        }
+
+        f.write_fmt(format_args!("{}", self.body))?;
+
+        if let Some(output) = &self.output {
+            f.write_fmt(format_args!("return {output};\n"))?;
+        }
+
+        f.write_str("}")
+    }
+}
+
 impl ComputeShader {
     fn format_bindings(
         f: &mut core::fmt::Formatter<'_>,
@@ -203,9 +260,19 @@ impl ComputeShader {
         binding: &Binding,
         num_entry: usize,
     ) -> core::fmt::Result {
-        let ty = match binding.size {
-            Some(size) => format!("array<{}, {}>", binding.item, size),
-            None => format!("array<{}>", binding.item),
+        let shared_words = binding.size.is_none() && binding.item.lines_per_word().is_some();
+        let ty = match (binding.size, binding.item.elem().packed_bits()) {
+            // Packed scalars are read from their word.
+            (Some(size), Some(bits)) => format!("array<u32, {}>", (size * bits).div_ceil(32)),
+            (Some(size), None) => format!("array<{}, {}>", binding.item, size),
+            // Lines sharing a word are accessed atomically.
+            (None, _) if shared_words => "array<atomic<u32>>".to_string(),
+            (None, _) => format!("array<{}>", binding.item.storage()),
+        };
+        // Atomics can only be used in read-write storage.
+        let visibility = match shared_words {
+            true => Visibility::ReadWrite,
+            false => binding.visibility,
         };
 
         f.write_fmt(format_args!(
@@ -213,7 +280,7 @@ impl ComputeShader {
 @binding({})
 var<{}, {}> {}: {};
 \n",
-            num_entry, binding.location, binding.visibility, name, ty
+            num_entry, binding.location, visibility, name, ty
         ))?;
 
         Ok(())
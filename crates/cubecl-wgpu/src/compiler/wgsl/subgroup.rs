@@ -36,6 +36,38 @@ pub enum Subgroup {
         input: Variable,
         out: Variable,
     },
+    InclusiveSum {
+        input: Variable,
+        out: Variable,
+    },
+    ExclusiveSum {
+        input: Variable,
+        out: Variable,
+    },
+    Shuffle {
+        lhs: Variable,
+        rhs: Variable,
+        out: Variable,
+    },
+    ShuffleXor {
+        lhs: Variable,
+        rhs: Variable,
+        out: Variable,
+    },
+    ShuffleUp {
+        lhs: Variable,
+        rhs: Variable,
+        out: Variable,
+    },
+    ShuffleDown {
+        lhs: Variable,
+        rhs: Variable,
+        out: Variable,
+    },
+    Ballot {
+        input: Variable,
+        out: Variable,
+    },
 }
 
 impl Display for Subgroup {
@@ -63,6 +95,28 @@ impl Display for Subgroup {
             Subgroup::Max { input, out } => {
                 f.write_fmt(format_args!("{out} = subgroupMax({input});\n"))
             }
+            Subgroup::InclusiveSum { input, out } => {
+                f.write_fmt(format_args!("{out} = subgroupInclusiveAdd({input});\n"))
+            }
+            Subgroup::ExclusiveSum { input, out } => {
+                f.write_fmt(format_args!("{out} = subgroupExclusiveAdd({input});\n"))
+            }
+            Subgroup::Shuffle { lhs, rhs, out } => {
+                f.write_fmt(format_args!("{out} = subgroupShuffle({lhs}, {rhs});\n"))
+            }
+            Subgroup::ShuffleXor { lhs, rhs, out } => {
+                f.write_fmt(format_args!("{out} = subgroupShuffleXor({lhs}, {rhs});\n"))
+            }
+            Subgroup::ShuffleUp { lhs, rhs, out } => {
+                f.write_fmt(format_args!("{out} = subgroupShuffleUp({lhs}, {rhs});\n"))
+            }
+            Subgroup::ShuffleDown { lhs, rhs, out } => {
+                f.write_fmt(format_args!("{out} = subgroupShuffleDown({lhs}, {rhs});\n"))
+            }
+            // Only the first 32 lanes are kept, which covers the most common subgroup sizes.
+            Subgroup::Ballot { input, out } => {
+                f.write_fmt(format_args!("{out} = subgroupBallot({input}).x;\n"))
+            }
         }
     }
 }
@@ -1,4 +1,4 @@
-use std::num::NonZero;
+use std::{num::NonZero, path::PathBuf};
 
 use super::WgpuStorage;
 use alloc::{borrow::Cow, sync::Arc};
@@ -7,6 +7,7 @@ use cubecl_core::{
     compute::DebugInformation, prelude::*, server::Handle, FeatureSet, KernelId, Properties,
 };
 use cubecl_runtime::{
+    compilation_cache::CompilationCache,
     debug::DebugLogger,
     memory_management::{MemoryHandle, MemoryManagement},
     server::{self, ComputeServer},
@@ -14,7 +15,10 @@ use cubecl_runtime::{
     ExecutionMode,
 };
 use hashbrown::HashMap;
-use wgpu::{CommandEncoder, ComputePass, ComputePipeline, ShaderModuleDescriptor};
+use wgpu::{
+    CommandEncoder, ComputePass, ComputePipeline, PipelineCache, PipelineCacheDescriptor,
+    ShaderModuleDescriptor,
+};
 
 /// Wgpu compute server.
 #[derive(Debug)]
@@ -28,10 +32,99 @@ pub struct WgpuServer<MM: MemoryManagement<WgpuStorage>> {
     compute_storage_used: Vec<StorageId>,
     copy_handles_used: Vec<(StorageId, u32)>,
     pipelines: HashMap<KernelId, Arc<ComputePipeline>>,
+    shader_cache: Option<CompilationCache>,
+    pipeline_cache: Option<PersistentPipelineCache>,
     tasks_max: usize,
     logger: DebugLogger,
 }
 
+/// A [pipeline cache](PipelineCache) saved to the [compilation cache](CompilationCache), so
+/// following processes don't compile the pipelines again. Only some backends support it, such
+/// as Vulkan, while the shaders are cached on every backend.
+///
+/// The cache is only saved on sync and when the server is dropped, if pipelines were compiled
+/// since the last save.
+#[derive(Debug)]
+struct PersistentPipelineCache {
+    cache: PipelineCache,
+    storage: CompilationCache,
+    key: String,
+    modified: bool,
+}
+
+const PIPELINE_CACHE_ENTRY: &str = "pipelines";
+
+/// The WGSL only depends on the kernel and on the version of the compiler, so the cached shaders
+/// are shared by every adapter.
+const SHADER_CACHE_DEVICE: &str = "wgsl";
+
+impl PersistentPipelineCache {
+    fn new(
+        device: &wgpu::Device,
+        adapter_info: &wgpu::AdapterInfo,
+        options: &CompilationCacheOptions,
+    ) -> Option<Self> {
+        if !device.features().contains(wgpu::Features::PIPELINE_CACHE) {
+            return None;
+        }
+        let key = wgpu::util::pipeline_cache_key(adapter_info)?;
+        let storage = options.cache(&key);
+        let data = storage.get(PIPELINE_CACHE_ENTRY, &key);
+
+        // Safety: The data was returned by `PipelineCache::get_data` for the same adapter, and
+        // the cache falls back to an empty one when the data is invalid.
+        let cache = unsafe {
+            device.create_pipeline_cache(&PipelineCacheDescriptor {
+                label: Some("CubeCL Pipeline Cache"),
+                data: data.as_deref(),
+                fallback: true,
+            })
+        };
+
+        Some(Self {
+            cache,
+            storage,
+            key,
+            modified: false,
+        })
+    }
+
+    fn save(&mut self) {
+        if !self.modified {
+            return;
+        }
+        if let Some(data) = self.cache.get_data() {
+            self.storage.insert(PIPELINE_CACHE_ENTRY, &self.key, &data);
+        }
+        self.modified = false;
+    }
+}
+
+impl Drop for PersistentPipelineCache {
+    fn drop(&mut self) {
+        self.save();
+    }
+}
+
+/// Where the compiled shaders and pipelines are saved on disk.
+#[derive(Debug, Clone)]
+pub(crate) struct CompilationCacheOptions {
+    pub(crate) dir: Option<PathBuf>,
+    pub(crate) max_size: u64,
+}
+
+impl CompilationCacheOptions {
+    fn cache(&self, device_id: &str) -> CompilationCache {
+        let cache = CompilationCache::new("wgpu", device_id).with_max_size(self.max_size);
+
+        match &self.dir {
+            #[cfg(feature = "std")]
+            Some(dir) => cache.with_dir(dir.clone()),
+            _ => cache,
+        }
+    }
+}
+
 fn create_encoder(device: &wgpu::Device) -> CommandEncoder {
     device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
         label: Some("CubeCL Command Encoder"),
@@ -59,11 +152,25 @@ where
             compute_storage_used: Vec::new(),
             copy_handles_used: Vec::new(),
             pipelines: HashMap::new(),
+            shader_cache: None,
+            pipeline_cache: None,
             tasks_max,
             logger: DebugLogger::new(),
         }
     }
 
+    /// Persist the compiled shaders on disk, along with the compiled pipelines of the given adapter
+    /// when the device supports it.
+    pub(crate) fn with_compilation_cache(
+        mut self,
+        adapter_info: &wgpu::AdapterInfo,
+        options: &CompilationCacheOptions,
+    ) -> Self {
+        self.shader_cache = Some(options.cache(SHADER_CACHE_DEVICE));
+        self.pipeline_cache = PersistentPipelineCache::new(&self.device, adapter_info, options);
+        self
+    }
+
     fn pipeline(
         &mut self,
         kernel: <Self as ComputeServer>::Kernel,
@@ -76,20 +183,73 @@ where
             return pipeline.clone();
         }
 
-        let mut compile = kernel.compile(mode);
-        if self.logger.is_activated() {
-            compile.debug_info = Some(DebugInformation::new("wgsl", kernel_id.clone()));
-        }
-
-        let compile = self.logger.debug(compile);
-        let pipeline = self.compile_source(&compile.source, mode);
+        let pipeline = match self.cached_shader(kernel.as_ref(), mode) {
+            Some((entry_point, source)) => self.compile_source(&source, &entry_point, mode),
+            None => {
+                let mut compile = kernel.compile(mode);
+                if self.logger.is_activated() {
+                    compile.debug_info = Some(DebugInformation::new("wgsl", kernel_id.clone()));
+                }
+
+                let compile = self.logger.debug(compile);
+                let entry_point = compile.entry_point.as_deref().unwrap_or("main");
+                self.cache_shader(kernel.as_ref(), mode, entry_point, &compile.source);
+                self.compile_source(&compile.source, entry_point, mode)
+            }
+        };
 
         self.pipelines.insert(kernel_id.clone(), pipeline.clone());
 
+        if let Some(cache) = &mut self.pipeline_cache {
+            cache.modified = true;
+        }
+
         pipeline
     }
 
-    fn compile_source(&self, source: &str, mode: ExecutionMode) -> Arc<ComputePipeline> {
+    /// Get the entry point and the WGSL of the kernel saved by a previous process.
+    ///
+    /// The kernels are compiled again when the logger is activated, so they are still logged.
+    fn cached_shader(
+        &self,
+        kernel: &dyn CubeTask,
+        mode: ExecutionMode,
+    ) -> Option<(String, String)> {
+        if self.logger.is_activated() {
+            return None;
+        }
+        let cache = self.shader_cache.as_ref()?;
+        let entry = cache.get(&shader_cache_key(kernel, mode), SHADER_CACHE_VERSION)?;
+        let entry = String::from_utf8(entry).ok()?;
+        let (entry_point, source) = entry.split_once('\n')?;
+
+        Some((entry_point.to_string(), source.to_string()))
+    }
+
+    fn cache_shader(
+        &mut self,
+        kernel: &dyn CubeTask,
+        mode: ExecutionMode,
+        entry_point: &str,
+        source: &str,
+    ) {
+        if let Some(cache) = &mut self.shader_cache {
+            // Identifiers can't contain line breaks, so the entry point ends at the first one.
+            let entry = format!("{entry_point}\n{source}");
+            cache.insert(
+                &shader_cache_key(kernel, mode),
+                SHADER_CACHE_VERSION,
+                entry.as_bytes(),
+            );
+        }
+    }
+
+    fn compile_source(
+        &self,
+        source: &str,
+        entry_point: &str,
+        mode: ExecutionMode,
+    ) -> Arc<ComputePipeline> {
         let module = match mode {
             ExecutionMode::Checked => self.device.create_shader_module(ShaderModuleDescriptor {
                 label: None,
@@ -110,9 +270,9 @@ where
                     label: None,
                     layout: None,
                     module: &module,
-                    entry_point: "main",
+                    entry_point,
                     compilation_options: Default::default(),
-                    cache: None,
+                    cache: self.pipeline_cache.as_ref().map(|cache| &cache.cache),
                 }),
         )
     }
@@ -122,6 +282,14 @@ where
     }
 }
 
+/// The cached shaders are looked up before compiling the kernel, so the stable id is the only thing
+/// identifying the generated code, and entries are invalidated when the compiler changes.
+const SHADER_CACHE_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+fn shader_cache_key(kernel: &dyn CubeTask, mode: ExecutionMode) -> String {
+    format!("{}-{mode:?}", kernel.stable_id())
+}
+
 impl<MM> ComputeServer for WgpuServer<MM>
 where
     MM: MemoryManagement<WgpuStorage>,
@@ -250,6 +418,15 @@ where
         bindings: Vec<server::Binding<Self>>,
         mode: ExecutionMode,
     ) {
+        if let Some(num_bindings) = kernel.num_bindings() {
+            assert_eq!(
+                bindings.len(),
+                num_bindings,
+                "The kernel expects {num_bindings} bindings, but {} were given",
+                bindings.len()
+            );
+        }
+
         let pipeline = self.pipeline(kernel, mode);
         let group_layout = pipeline.get_bind_group_layout(0);
 
@@ -353,6 +530,10 @@ where
             self.device.poll(wgpu::Maintain::Wait);
         }
 
+        if let Some(cache) = &mut self.pipeline_cache {
+            cache.save();
+        }
+
         // Cleanup allocations and deallocations.
         self.memory_management.storage().perform_deallocations();
     }
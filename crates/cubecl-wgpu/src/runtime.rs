@@ -1,12 +1,13 @@
 use crate::{
     compiler::wgsl,
-    compute::{WgpuServer, WgpuStorage},
+    compute::{CompilationCacheOptions, WgpuServer, WgpuStorage},
     AutoGraphicsApi, GraphicsApi, WgpuDevice,
 };
 use alloc::sync::Arc;
 use cubecl_core::{Feature, FeatureSet, Properties, Runtime};
-use cubecl_runtime::memory_management;
 use cubecl_runtime::{channel::MutexComputeChannel, client::ComputeClient, ComputeRuntime};
+use cubecl_runtime::{compilation_cache::DEFAULT_COMPILATION_CACHE_SIZE, memory_management};
+use std::path::PathBuf;
 use wgpu::{DeviceDescriptor, Limits};
 
 /// Runtime that uses the [wgpu] crate with the wgsl compiler. This is used in the Wgpu backend.
@@ -68,12 +69,21 @@ impl Runtime for WgpuRuntime {
     fn name() -> &'static str {
         "wgpu"
     }
+
+    fn require_array_lengths() -> bool {
+        true
+    }
 }
 
 /// The values that control how a WGPU Runtime will perform its calculations.
 pub struct RuntimeOptions {
     /// Control the amount of compute tasks to be aggregated into a single GPU command.
     pub tasks_max: usize,
+    /// The directory where the compiled shaders are persisted, instead of the cubecl cache
+    /// directory of the home directory.
+    pub compilation_cache_dir: Option<PathBuf>,
+    /// The maximum size of the persisted shaders on disk, in bytes.
+    pub compilation_cache_max_size: u64,
 }
 
 impl Default for RuntimeOptions {
@@ -90,7 +100,11 @@ impl Default for RuntimeOptions {
             Err(_) => DEFAULT_MAX_TASKS,
         };
 
-        Self { tasks_max }
+        Self {
+            tasks_max,
+            compilation_cache_dir: None,
+            compilation_cache_max_size: DEFAULT_COMPILATION_CACHE_SIZE,
+        }
     }
 }
 
@@ -141,7 +155,14 @@ fn create_client(
 {
     let limits = device_wgpu.limits();
     let memory_management = init_memory_management(device_wgpu.clone(), &limits);
-    let server = WgpuServer::new(memory_management, device_wgpu, queue, options.tasks_max);
+    let server = WgpuServer::new(memory_management, device_wgpu, queue, options.tasks_max)
+        .with_compilation_cache(
+            &adapter.get_info(),
+            &CompilationCacheOptions {
+                dir: options.compilation_cache_dir,
+                max_size: options.compilation_cache_max_size,
+            },
+        );
     let channel = MutexComputeChannel::new(server);
 
     let features = adapter.features();
@@ -0,0 +1,14 @@
+use std::path::PathBuf;
+
+/// Export the kernels of the example, for deployment without compiling them at runtime.
+///
+/// Usage: cargo xtask export --package gelu --runtimes wgpu --output <OUTPUT_DIR>
+fn main() {
+    #[allow(unused_variables)]
+    let dir = PathBuf::from(std::env::args().nth(1).unwrap_or("kernels".to_string()));
+
+    #[cfg(feature = "cuda")]
+    gelu::export::<cubecl::cuda::CudaRuntime>(&dir.join("cuda")).unwrap();
+    #[cfg(feature = "wgpu")]
+    gelu::export::<cubecl::wgpu::WgpuRuntime>(&dir.join("wgpu")).unwrap();
+}
@@ -1,4 +1,5 @@
-use cubecl::prelude::*;
+use cubecl::{compute::KernelExporter, prelude::*, ExecutionMode, KernelSettings};
+use std::path::Path;
 
 #[cube(launch_unchecked)]
 fn gelu_array<F: Float>(input: &Array<F>, output: &mut Array<F>) {
@@ -34,3 +35,20 @@ pub fn launch<R: Runtime>(device: &R::Device) {
     // Should be [-0.1587,  0.0000,  0.8413,  5.0000]
     println!("Executed gelu with runtime {:?} => {output:?}", R::name());
 }
+
+/// Compile the kernels used by [launch] ahead of time and write them in the given directory.
+pub fn export<R: Runtime>(dir: &Path) -> std::io::Result<()> {
+    let settings = KernelSettings::default().cube_dim(CubeDim::new(4, 1, 1));
+    let mut exporter = KernelExporter::<R::Compiler>::new(ExecutionMode::Unchecked);
+    exporter.kernel(
+        "gelu_array_f32",
+        gelu_array::GeluArray::<f32, R>::new(settings),
+    );
+
+    exporter.bundle().save(dir)?;
+    println!(
+        "Exported gelu kernels for runtime {:?} in {dir:?}",
+        R::name()
+    );
+    Ok(())
+}
@@ -0,0 +1,42 @@
+use std::process::Command;
+
+use tracel_xtask::prelude::*;
+
+#[derive(clap::Args, Clone)]
+pub struct CubeCLExportCmdArgs {
+    /// Package whose kernels are exported. It should have an `export` example writing the kernel
+    /// bundles with a `KernelExporter` to the directory given as argument.
+    #[arg(short, long, default_value = "gelu")]
+    pub package: String,
+    /// Runtimes to export the kernels for, enabled as features of the package.
+    #[arg(short, long, value_delimiter = ',', default_value = "wgpu")]
+    pub runtimes: Vec<String>,
+    /// Directory where the kernel bundles are written.
+    #[arg(short, long, default_value = "kernels")]
+    pub output: String,
+}
+
+pub(crate) fn handle_command(args: CubeCLExportCmdArgs) -> anyhow::Result<()> {
+    let features = args.runtimes.join(",");
+    let status = Command::new("cargo")
+        .args([
+            "run",
+            "--package",
+            &args.package,
+            "--example",
+            "export",
+            "--features",
+            &features,
+            "--",
+            &args.output,
+        ])
+        .status()?;
+
+    if !status.success() {
+        return Err(anyhow::anyhow!(
+            "Failed to export the kernels of {}",
+            args.package
+        ));
+    }
+    Ok(())
+}
@@ -1,2 +1,3 @@
 pub(crate) mod build;
+pub(crate) mod export;
 pub(crate) mod test;
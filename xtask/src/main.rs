@@ -21,6 +21,8 @@ use tracel_xtask::prelude::*;
 pub enum Command {
     /// Build Burn in different modes.
     Build(commands::build::CubeCLBuildCmdArgs),
+    /// Export the kernels of a package ahead of time.
+    Export(commands::export::CubeCLExportCmdArgs),
     /// Test Burn.
     Test(commands::test::CubeCLTestCmdArgs),
 }
@@ -30,6 +32,7 @@ fn main() -> anyhow::Result<()> {
     let args = init_xtask::<Command>()?;
     match args.command {
         Command::Build(cmd_args) => commands::build::handle_command(cmd_args),
+        Command::Export(cmd_args) => commands::export::handle_command(cmd_args),
         Command::Test(cmd_args) => commands::test::handle_command(cmd_args),
         _ => dispatch_base_commands(args),
     }?;